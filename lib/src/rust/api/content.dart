@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'database.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `as_str`, `download_version_file`, `enabled_path`, `folder`, `from_modrinth`, `install_version`, `is_disabled`, `modrinth_loaders`, `path_with_state`, `primary_file`, `set_enabled`, `version_record`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// 把 Modrinth 版本安装到实例。`content_type` 为空时按项目类型推断；
+/// 安装数据包时 `world` 为存档目录名
+Future<ContentRecord>  installContent({required String resourceDir , required String instanceId , required String versionId , ContentType? contentType , String? world }) => RustLib.instance.api.crateApiContentInstallContent(resourceDir: resourceDir, instanceId: instanceId, versionId: versionId, contentType: contentType, world: world);
+
+/// 启用或禁用内容 (重命名为 / 去掉 `.disabled` 后缀)，返回新的相对路径
+Future<String>  setContentEnabled({required String resourceDir , required String instanceId , required String path , required bool enabled }) => RustLib.instance.api.crateApiContentSetContentEnabled(resourceDir: resourceDir, instanceId: instanceId, path: path, enabled: enabled);
+
+/// 批量启用或禁用带有指定标签的内容，返回变更后的路径
+Future<List<String>>  setContentEnabledByTag({required String resourceDir , required String instanceId , required String tag , required bool enabled }) => RustLib.instance.api.crateApiContentSetContentEnabledByTag(resourceDir: resourceDir, instanceId: instanceId, tag: tag, enabled: enabled);
+
+/// 以标签作为配置方案：启用带有该标签的模组，禁用其余模组。返回变更后的路径
+Future<List<String>>  applyContentProfile({required String resourceDir , required String instanceId , required String tag }) => RustLib.instance.api.crateApiContentApplyContentProfile(resourceDir: resourceDir, instanceId: instanceId, tag: tag);
+
+            /// 实例内容类型，与 Modrinth 的 `project_type` 一致
+enum ContentType {
+                    mod,
+resourcePack,
+shader,
+dataPack,
+                    ;
+                    
+                }
+            
\ No newline at end of file
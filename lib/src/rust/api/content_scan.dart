@@ -0,0 +1,97 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'content.dart';
+import 'mod_loader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cached_identification`, `from_metadata`, `from_row`, `list_content_files`, `lookup_modrinth`, `needs_lookup`, `read_embedded`, `save_identification`, `scan_instance_content_with`, `sync_content_records`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Identification`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// 扫描实例的模组、资源包与光影包，按哈希到 Modrinth 识别，未收录的读取文件内嵌元数据。
+/// 结果按哈希缓存，并同步到实例内容记录
+Future<List<ScannedContent>>  scanInstanceContent({required String resourceDir , required String instanceId }) => RustLib.instance.api.crateApiContentScanScanInstanceContent(resourceDir: resourceDir, instanceId: instanceId);
+
+            /// 文件内嵌的模组元数据
+class EmbeddedMetadata  {
+                /// 元数据所属的加载器 (mcmod.info 视为 Forge)
+final ModLoader? loader;
+final String? modId;
+final String? name;
+final String? version;
+final String? description;
+final List<String> authors;
+
+                const EmbeddedMetadata({this.loader ,this.modId ,this.name ,this.version ,this.description ,required this.authors ,});
+
+                static Future<EmbeddedMetadata>  default_()=>RustLib.instance.api.crateApiContentScanEmbeddedMetadataDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => loader.hashCode^modId.hashCode^name.hashCode^version.hashCode^description.hashCode^authors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddedMetadata &&
+                runtimeType == other.runtimeType
+                && loader == other.loader&& modId == other.modId&& name == other.name&& version == other.version&& description == other.description&& authors == other.authors;
+        
+            }
+
+/// 扫描到的内容文件
+class ScannedContent  {
+                /// 相对实例目录的路径
+final String path;
+final String fileName;
+final ContentType contentType;
+/// 文件名带 `.disabled` 后缀时为 false
+final bool enabled;
+/// 用户设置的标签 (数据库未初始化时为空)
+final List<String> tags;
+final BigInt size;
+final String sha1;
+final String sha512;
+/// Modrinth 未收录时为空
+final String? projectId;
+final String? versionId;
+final String? title;
+final String? versionNumber;
+final String? iconUrl;
+/// Modrinth 未收录时从文件中读取
+final EmbeddedMetadata? embedded;
+/// 文件内嵌的图标
+final Uint8List? icon;
+
+                const ScannedContent({required this.path ,required this.fileName ,required this.contentType ,required this.enabled ,required this.tags ,required this.size ,required this.sha1 ,required this.sha512 ,this.projectId ,this.versionId ,this.title ,this.versionNumber ,this.iconUrl ,this.embedded ,this.icon ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^fileName.hashCode^contentType.hashCode^enabled.hashCode^tags.hashCode^size.hashCode^sha1.hashCode^sha512.hashCode^projectId.hashCode^versionId.hashCode^title.hashCode^versionNumber.hashCode^iconUrl.hashCode^embedded.hashCode^icon.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScannedContent &&
+                runtimeType == other.runtimeType
+                && path == other.path&& fileName == other.fileName&& contentType == other.contentType&& enabled == other.enabled&& tags == other.tags&& size == other.size&& sha1 == other.sha1&& sha512 == other.sha512&& projectId == other.projectId&& versionId == other.versionId&& title == other.title&& versionNumber == other.versionNumber&& iconUrl == other.iconUrl&& embedded == other.embedded&& icon == other.icon;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,118 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'content.dart';
+import 'database.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_content_updates_with`, `backup_dir`, `backups_dir`, `check_content_updates_with`, `current_record`, `move_file`, `restore_entries`, `stage_updates`, `write_backup`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// 检查实例中模组、资源包与光影包的更新 (适用于实例的游戏版本与加载器)
+Future<List<ContentUpdate>>  checkContentUpdates({required String resourceDir , required String instanceId }) => RustLib.instance.api.crateApiContentUpdateCheckContentUpdates(resourceDir: resourceDir, instanceId: instanceId);
+
+/// 批量更新：先下载并校验全部新文件，再逐个替换，旧文件移入备份目录以便回滚。
+/// 任一步失败时恢复到更新前的状态
+Future<UpdateBackup>  applyContentUpdates({required String resourceDir , required String instanceId , required List<ContentUpdate> updates }) => RustLib.instance.api.crateApiContentUpdateApplyContentUpdates(resourceDir: resourceDir, instanceId: instanceId, updates: updates);
+
+/// 列出实例的更新备份，新的在前
+Future<List<UpdateBackup>>  listUpdateBackups({required String resourceDir , required String instanceId }) => RustLib.instance.api.crateApiContentUpdateListUpdateBackups(resourceDir: resourceDir, instanceId: instanceId);
+
+/// 回滚一次批量更新：删除新文件、恢复旧文件及其内容记录，然后删除备份
+Future<void>  rollbackContentUpdate({required String resourceDir , required String instanceId , required String backupId }) => RustLib.instance.api.crateApiContentUpdateRollbackContentUpdate(resourceDir: resourceDir, instanceId: instanceId, backupId: backupId);
+
+/// 删除更新备份 (确认不再需要回滚时释放空间)
+Future<void>  deleteUpdateBackup({required String resourceDir , required String instanceId , required String backupId }) => RustLib.instance.api.crateApiContentUpdateDeleteUpdateBackup(resourceDir: resourceDir, instanceId: instanceId, backupId: backupId);
+
+            /// 可用的更新
+class ContentUpdate  {
+                /// 当前文件相对实例目录的路径
+final String path;
+final ContentType contentType;
+final String projectId;
+final String? title;
+final String? currentVersionId;
+final String? currentVersionNumber;
+final String newVersionId;
+final String newVersionNumber;
+/// release / beta / alpha
+final String versionType;
+final String? changelog;
+final DateTime? datePublished;
+
+                const ContentUpdate({required this.path ,required this.contentType ,required this.projectId ,this.title ,this.currentVersionId ,this.currentVersionNumber ,required this.newVersionId ,required this.newVersionNumber ,required this.versionType ,this.changelog ,this.datePublished ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^contentType.hashCode^projectId.hashCode^title.hashCode^currentVersionId.hashCode^currentVersionNumber.hashCode^newVersionId.hashCode^newVersionNumber.hashCode^versionType.hashCode^changelog.hashCode^datePublished.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ContentUpdate &&
+                runtimeType == other.runtimeType
+                && path == other.path&& contentType == other.contentType&& projectId == other.projectId&& title == other.title&& currentVersionId == other.currentVersionId&& currentVersionNumber == other.currentVersionNumber&& newVersionId == other.newVersionId&& newVersionNumber == other.newVersionNumber&& versionType == other.versionType&& changelog == other.changelog&& datePublished == other.datePublished;
+        
+            }
+
+/// 一次批量更新的备份，可用于回滚
+class UpdateBackup  {
+                final String id;
+final DateTime created;
+final List<UpdateBackupEntry> entries;
+
+                const UpdateBackup({required this.id ,required this.created ,required this.entries ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^created.hashCode^entries.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateBackup &&
+                runtimeType == other.runtimeType
+                && id == other.id&& created == other.created&& entries == other.entries;
+        
+            }
+
+/// 一次更新中被替换的文件
+class UpdateBackupEntry  {
+                final ContentRecord old;
+final ContentRecord new_;
+
+                const UpdateBackupEntry({required this.old ,required this.new_ ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => old.hashCode^new_.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateBackupEntry &&
+                runtimeType == other.runtimeType
+                && old == other.old&& new_ == other.new_;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,108 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `collect_candidates`, `find_latest_crash_file`, `match_known_causes`, `parse_crash_report`, `parse_hs_err`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// 诊断实例最近一次崩溃，`since` 为游戏启动时间 (只查找之后生成的文件)
+Future<CrashDiagnosis?>  diagnoseLatestCrash({required String gameDir , DateTime? since }) => RustLib.instance.api.crateApiCrashReportDiagnoseLatestCrash(gameDir: gameDir, since: since);
+
+/// 诊断任意文本 (如崩溃时的游戏输出)
+Future<CrashDiagnosis>  diagnoseCrashText({required String text }) => RustLib.instance.api.crateApiCrashReportDiagnoseCrashText(text: text);
+
+            /// 已知的崩溃原因
+enum CrashCause {
+                    /// Java 主版本不匹配
+wrongJavaVersion,
+/// 使用了 32 位 JVM
+jvm32Bit,
+/// 内存不足
+outOfMemory,
+/// 缺少前置依赖
+missingDependency,
+/// Mixin 注入冲突
+mixinConflict,
+/// 显卡驱动 / OpenGL 问题
+openGl,
+                    ;
+                    
+                }
+
+/// 崩溃诊断结果
+class CrashDiagnosis  {
+                final CrashFileKind kind;
+final String? filePath;
+final String? description;
+final List<String> suspectedMods;
+final String? stackTrace;
+final List<MatchedCause> causes;
+
+                const CrashDiagnosis({required this.kind ,this.filePath ,this.description ,required this.suspectedMods ,this.stackTrace ,required this.causes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^filePath.hashCode^description.hashCode^suspectedMods.hashCode^stackTrace.hashCode^causes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CrashDiagnosis &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& filePath == other.filePath&& description == other.description&& suspectedMods == other.suspectedMods&& stackTrace == other.stackTrace&& causes == other.causes;
+        
+            }
+
+/// 崩溃文件类型
+enum CrashFileKind {
+                    /// crash-reports/*.txt
+crashReport,
+/// JVM 致命错误日志 hs_err_pid*.log
+hsErr,
+/// 直接传入的文本 (如游戏输出)
+text,
+                    ;
+                    
+                }
+
+/// 命中的崩溃原因
+class MatchedCause  {
+                final CrashCause cause;
+/// 命中的规则 ID
+final String ruleId;
+/// 命中规则的原文
+final String evidence;
+/// 给用户的建议
+final String suggestion;
+
+                const MatchedCause({required this.cause ,required this.ruleId ,required this.evidence ,required this.suggestion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cause.hashCode^ruleId.hashCode^evidence.hashCode^suggestion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MatchedCause &&
+                runtimeType == other.runtimeType
+                && cause == other.cause&& ruleId == other.ruleId&& evidence == other.evidence&& suggestion == other.suggestion;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'java_download.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cached_download_from_row`, `cached_download`, `column`, `content_by_project`, `content_from_row`, `download_source`, `index_instance`, `is_initialized`, `open_pool`, `pool`, `record_download`, `remove_content_with`, `remove_content`, `remove_instance`, `replace_content`, `sync_instance_index`, `upsert_content_with`, `upsert_content`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedDownload`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// 打开 (必要时创建) 资源目录下的数据库并执行迁移，重复调用无副作用
+Future<void>  initDatabase({required String resourceDir }) => RustLib.instance.api.crateApiDatabaseInitDatabase(resourceDir: resourceDir);
+
+/// 以磁盘上的实例清单为准重建实例索引，返回索引的实例数
+Future<int>  rebuildInstanceIndex({required String resourceDir }) => RustLib.instance.api.crateApiDatabaseRebuildInstanceIndex(resourceDir: resourceDir);
+
+/// 按名称或游戏版本搜索实例并排序，返回实例 ID 列表 (可再用 `get_instance` 读取完整清单)
+Future<List<String>>  searchInstances({required String query , required InstanceSort sort , required bool descending }) => RustLib.instance.api.crateApiDatabaseSearchInstances(query: query, sort: sort, descending: descending);
+
+/// 设置内容的标签
+Future<void>  setContentTags({required String instanceId , required String path , required List<String> tags }) => RustLib.instance.api.crateApiDatabaseSetContentTags(instanceId: instanceId, path: path, tags: tags);
+
+/// 列出实例中的内容，可按项目类型过滤
+Future<List<ContentRecord>>  listInstanceContent({required String instanceId , String? projectType }) => RustLib.instance.api.crateApiDatabaseListInstanceContent(instanceId: instanceId, projectType: projectType);
+
+/// 保存检测到的 Java 运行时 (按路径去重)
+Future<void>  saveJavaRuntimes({required List<JavaRuntimeVersion> runtimes }) => RustLib.instance.api.crateApiDatabaseSaveJavaRuntimes(runtimes: runtimes);
+
+/// 列出已保存的 Java 运行时 (新版本在前)，`major_version` 不为空时只返回该主版本
+Future<List<JavaRuntimeVersion>>  listJavaRuntimes({int? majorVersion }) => RustLib.instance.api.crateApiDatabaseListJavaRuntimes(majorVersion: majorVersion);
+
+/// 删除 Java 运行时记录
+Future<void>  removeJavaRuntime({required String path }) => RustLib.instance.api.crateApiDatabaseRemoveJavaRuntime(path: path);
+
+/// 清空下载缓存记录 (不删除文件)，返回删除的条目数
+Future<BigInt>  clearDownloadCache() => RustLib.instance.api.crateApiDatabaseClearDownloadCache();
+
+            /// 实例中已安装的内容
+class ContentRecord  {
+                final String instanceId;
+/// 相对实例目录的路径
+final String path;
+/// mod / resourcepack / shader / datapack
+final String projectType;
+final String? projectId;
+final String? versionId;
+final String sha1;
+final String? sha512;
+final BigInt fileSize;
+final bool enabled;
+final DateTime installedAt;
+/// 用户设置的标签
+final List<String> tags;
+
+                const ContentRecord({required this.instanceId ,required this.path ,required this.projectType ,this.projectId ,this.versionId ,required this.sha1 ,this.sha512 ,required this.fileSize ,required this.enabled ,required this.installedAt ,required this.tags ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => instanceId.hashCode^path.hashCode^projectType.hashCode^projectId.hashCode^versionId.hashCode^sha1.hashCode^sha512.hashCode^fileSize.hashCode^enabled.hashCode^installedAt.hashCode^tags.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ContentRecord &&
+                runtimeType == other.runtimeType
+                && instanceId == other.instanceId&& path == other.path&& projectType == other.projectType&& projectId == other.projectId&& versionId == other.versionId&& sha1 == other.sha1&& sha512 == other.sha512&& fileSize == other.fileSize&& enabled == other.enabled&& installedAt == other.installedAt&& tags == other.tags;
+        
+            }
+
+/// 实例排序方式
+enum InstanceSort {
+                    name,
+lastPlayed,
+playTime,
+created,
+gameVersion,
+                    ;
+                    
+                }
+            
\ No newline at end of file
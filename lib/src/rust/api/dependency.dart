@@ -0,0 +1,173 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'database.dart';
+import 'modrinth.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `compatible_loaders`, `installed_content`, `is_compatible`, `pick_version`, `resolve`, `stage_files`, `undo_moves`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Chosen`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `project_versions`, `projects`, `version`, `versions`
+
+
+            /// 为安装 Modrinth 版本生成计划 (含必需依赖、升级与冲突)，不下载任何文件
+Future<InstallPlan>  resolveContentInstall({required String resourceDir , required String instanceId , required String versionId }) => RustLib.instance.api.crateApiDependencyResolveContentInstall(resourceDir: resourceDir, instanceId: instanceId, versionId: versionId);
+
+/// 执行确认后的安装计划，有冲突时拒绝执行。`world` 用于安装数据包。
+/// 先下载并校验全部文件，再统一替换并在一个事务中写入记录，任一步失败时实例保持原样
+Future<List<ContentRecord>>  applyInstallPlan({required String resourceDir , required String instanceId , required InstallPlan plan , String? world }) => RustLib.instance.api.crateApiDependencyApplyInstallPlan(resourceDir: resourceDir, instanceId: instanceId, plan: plan, world: world);
+
+            
+                abstract class VersionSource {
+                    /// 项目适用于指定加载器与游戏版本的版本，新版本在前
+ Future<List<Version>>  projectVersions({required String projectId , required List<String> loaders , required List<String> gameVersions });
+
+
+ Future<List<Project>>  projects({required List<String> ids });
+
+
+ Future<Version>  version({required String id });
+
+
+ Future<List<Version>>  versions({required List<String> ids });
+
+
+                }
+                
+
+/// 冲突类型
+enum ConflictKind {
+                    /// 声明了不兼容
+incompatible,
+/// 没有适用于实例游戏版本与加载器的版本
+noCompatibleVersion,
+/// 不同项目要求了同一依赖的不同版本
+versionMismatch,
+                    ;
+                    
+                }
+
+/// 依赖冲突
+class DependencyConflict  {
+                final ConflictKind kind;
+/// 出现问题的项目
+final String projectId;
+/// 引起冲突的项目
+final String sourceProjectId;
+final String message;
+
+                const DependencyConflict({required this.kind ,required this.projectId ,required this.sourceProjectId ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^projectId.hashCode^sourceProjectId.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DependencyConflict &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& projectId == other.projectId&& sourceProjectId == other.sourceProjectId&& message == other.message;
+        
+            }
+
+/// 安装计划，由界面确认后交给 `apply_install_plan` 执行
+class InstallPlan  {
+                final List<PlannedInstall> install;
+final List<PlannedUpgrade> upgrade;
+final List<DependencyConflict> conflicts;
+/// 未安装的可选依赖 (项目 ID)
+final List<String> optional;
+
+                const InstallPlan({required this.install ,required this.upgrade ,required this.conflicts ,required this.optional ,});
+
+                static Future<InstallPlan>  default_()=>RustLib.instance.api.crateApiDependencyInstallPlanDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => install.hashCode^upgrade.hashCode^conflicts.hashCode^optional.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InstallPlan &&
+                runtimeType == other.runtimeType
+                && install == other.install&& upgrade == other.upgrade&& conflicts == other.conflicts&& optional == other.optional;
+        
+            }
+
+/// 计划安装的项目
+class PlannedInstall  {
+                final String projectId;
+final String versionId;
+final String title;
+final String projectType;
+final String versionNumber;
+/// 引入该依赖的项目，用户选择的项目为 None
+final String? requiredBy;
+
+                const PlannedInstall({required this.projectId ,required this.versionId ,required this.title ,required this.projectType ,required this.versionNumber ,this.requiredBy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => projectId.hashCode^versionId.hashCode^title.hashCode^projectType.hashCode^versionNumber.hashCode^requiredBy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlannedInstall &&
+                runtimeType == other.runtimeType
+                && projectId == other.projectId&& versionId == other.versionId&& title == other.title&& projectType == other.projectType&& versionNumber == other.versionNumber&& requiredBy == other.requiredBy;
+        
+            }
+
+/// 计划升级的已安装项目
+class PlannedUpgrade  {
+                final String projectId;
+final String fromVersionId;
+final String toVersionId;
+final String title;
+final String projectType;
+final String versionNumber;
+final String? requiredBy;
+
+                const PlannedUpgrade({required this.projectId ,required this.fromVersionId ,required this.toVersionId ,required this.title ,required this.projectType ,required this.versionNumber ,this.requiredBy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => projectId.hashCode^fromVersionId.hashCode^toVersionId.hashCode^title.hashCode^projectType.hashCode^versionNumber.hashCode^requiredBy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlannedUpgrade &&
+                runtimeType == other.runtimeType
+                && projectId == other.projectId&& fromVersionId == other.fromVersionId&& toVersionId == other.toVersionId&& title == other.title&& projectType == other.projectType&& versionNumber == other.versionNumber&& requiredBy == other.requiredBy;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'loader_meta.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `fabric_source`
+
+
+            /// 列出指定游戏版本可用的 Fabric 加载器，Modrinth 元数据不可用时回退到 meta.fabricmc.net
+Future<List<LoaderVersion>>  listFabricLoaders({required String gameVersion }) => RustLib.instance.api.crateApiFabricListFabricLoaders(gameVersion: gameVersion);
+
+/// 安装 Fabric 加载器，返回新版本 ID
+Future<String>  installFabric({required String minecraftDir , required String gameVersion , required String loaderVersion , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiFabricInstallFabric(minecraftDir: minecraftDir, gameVersion: gameVersion, loaderVersion: loaderVersion, onProgress: onProgress);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'loader_meta.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `extract_zip_prefix`, `forge_installer_url`, `install_legacy`, `install_modern`, `install_with_installer`, `installer_url_in`, `jar_main_class`, `library_path`, `list_maven_versions`, `list_versions_in`, `outputs_valid`, `read_zip_entry`, `resolve_arg`, `resolve_data_value`, `run_processor`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `InstallProfile`, `InstallerEnv`, `LegacyInstall`, `ProcessorContext`, `Processor`, `SidedDataEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// 列出指定游戏版本可用的 Forge 版本 (完整 Maven 版本号，新版本在前)，Modrinth 元数据不可用时回退到 Forge Maven
+Future<List<LoaderVersion>>  listForgeVersions({required String gameVersion }) => RustLib.instance.api.crateApiForgeListForgeVersions(gameVersion: gameVersion);
+
+/// 安装 Forge，`java_path` 用于运行安装处理器，返回新版本 ID
+Future<String>  installForge({required String minecraftDir , required String gameVersion , required String forgeVersion , required String javaPath , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiForgeInstallForge(minecraftDir: minecraftDir, gameVersion: gameVersion, forgeVersion: forgeVersion, javaPath: javaPath, onProgress: onProgress);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,63 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'game_process.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `append_content`, `finish`, `new`, `parse_xml_event`, `parse`, `plain_event`, `prepare_logging_config`, `push_line`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Log4jStreamParser`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// 结构化的游戏日志事件
+class GameLogEvent  {
+                final String processId;
+final LogSource source;
+final DateTime? timestamp;
+final LogLevel level;
+final String? thread;
+final String? logger;
+final String message;
+final String? throwable;
+/// 是否来自 log4j XML 事件 (否则为纯文本回退)
+final bool structured;
+
+                const GameLogEvent({required this.processId ,required this.source ,this.timestamp ,required this.level ,this.thread ,this.logger ,required this.message ,this.throwable ,required this.structured ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => processId.hashCode^source.hashCode^timestamp.hashCode^level.hashCode^thread.hashCode^logger.hashCode^message.hashCode^throwable.hashCode^structured.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GameLogEvent &&
+                runtimeType == other.runtimeType
+                && processId == other.processId&& source == other.source&& timestamp == other.timestamp&& level == other.level&& thread == other.thread&& logger == other.logger&& message == other.message&& throwable == other.throwable&& structured == other.structured;
+        
+            }
+
+/// 日志级别
+enum LogLevel {
+                    trace,
+debug,
+info,
+warn,
+error,
+fatal,
+unknown,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'game_log.dart';
+import 'launch.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `pump_lines`, `send_stop_request`, `send_terminate`, `spawn_game_process`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ManagedProcess`, `StopRequest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// 启动游戏，解析后的日志事件推送到 `log_sink`，退出时回调 `on_exit`
+Stream<GameLogEvent>  startGame({required LaunchCommand command , required FutureOr<void> Function(GameProcessInfo) onExit }) => RustLib.instance.api.crateApiGameProcessStartGame(command: command, onExit: onExit);
+
+/// 列出正在运行的游戏进程
+Future<List<GameProcessInfo>>  listGameProcesses() => RustLib.instance.api.crateApiGameProcessListGameProcesses();
+
+/// 获取指定游戏进程的信息
+Future<GameProcessInfo?>  getGameProcess({required String processId }) => RustLib.instance.api.crateApiGameProcessGetGameProcess(processId: processId);
+
+/// 立即终止游戏进程
+Future<void>  killGame({required String processId }) => RustLib.instance.api.crateApiGameProcessKillGame(processId: processId);
+
+/// 优雅停止游戏进程，超时后强制终止
+Future<void>  stopGame({required String processId , BigInt? timeoutSecs }) => RustLib.instance.api.crateApiGameProcessStopGame(processId: processId, timeoutSecs: timeoutSecs);
+
+            /// 游戏进程信息
+class GameProcessInfo  {
+                /// 启动器内部的进程 ID
+final String processId;
+/// 系统进程 PID
+final int pid;
+final DateTime startTime;
+final DateTime? endTime;
+/// 退出码，被信号终止时为空
+final int? exitCode;
+final bool running;
+
+                const GameProcessInfo({required this.processId ,required this.pid ,required this.startTime ,this.endTime ,this.exitCode ,required this.running ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => processId.hashCode^pid.hashCode^startTime.hashCode^endTime.hashCode^exitCode.hashCode^running.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GameProcessInfo &&
+                runtimeType == other.runtimeType
+                && processId == other.processId&& pid == other.pid&& startTime == other.startTime&& endTime == other.endTime&& exitCode == other.exitCode&& running == other.running;
+        
+            }
+
+/// 输出来源
+enum LogSource {
+                    stdout,
+stderr,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'launch.dart';
+import 'mod_loader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `allocate_id`, `commit_staging`, `copy_dir_all`, `copy_symlink`, `instance_path`, `instances_dir`, `read_manifest`, `slugify`, `update_manifest`, `write_manifest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// 新建实例，返回写入的清单
+Future<InstanceManifest>  createInstance({required String resourceDir , required NewInstance options }) => RustLib.instance.api.crateApiInstanceCreateInstance(resourceDir: resourceDir, options: options);
+
+/// 列出全部实例 (按名称排序)，清单损坏的目录会被跳过
+Future<List<InstanceManifest>>  listInstances({required String resourceDir }) => RustLib.instance.api.crateApiInstanceListInstances(resourceDir: resourceDir);
+
+/// 读取单个实例
+Future<InstanceManifest>  getInstance({required String resourceDir , required String id }) => RustLib.instance.api.crateApiInstanceGetInstance(resourceDir: resourceDir, id: id);
+
+/// 保存实例设置 (Java、内存、JVM 参数、分辨率、图标等)，`id` 与游戏时长以磁盘上的为准
+Future<InstanceManifest>  saveInstance({required String resourceDir , required InstanceManifest manifest }) => RustLib.instance.api.crateApiInstanceSaveInstance(resourceDir: resourceDir, manifest: manifest);
+
+/// 重命名实例 (只修改显示名称，目录保持不变)
+Future<InstanceManifest>  renameInstance({required String resourceDir , required String id , required String name }) => RustLib.instance.api.crateApiInstanceRenameInstance(resourceDir: resourceDir, id: id, name: name);
+
+/// 复制实例 (含全部文件)，新实例的游戏时长清零。
+/// 文件先复制到临时目录，只在分配目录并登记清单时持有存储锁
+Future<InstanceManifest>  duplicateInstance({required String resourceDir , required String id , required String newName }) => RustLib.instance.api.crateApiInstanceDuplicateInstance(resourceDir: resourceDir, id: id, newName: newName);
+
+/// 删除实例：先移入临时目录使其立即从列表中消失，再删除文件
+Future<void>  deleteInstance({required String resourceDir , required String id }) => RustLib.instance.api.crateApiInstanceDeleteInstance(resourceDir: resourceDir, id: id);
+
+            /// 实例清单 (`<资源目录>/instances/<id>/instance.json`)
+class InstanceManifest  {
+                final BigInt formatVersion;
+/// 实例目录名，创建后不再改变
+final String id;
+/// 显示名称
+final String name;
+final String gameVersion;
+final ModLoader loader;
+final String? loaderVersion;
+/// 覆盖全局设置的 Java 路径
+final String? javaPath;
+final int? maxMemoryMb;
+final int? minMemoryMb;
+final List<String> jvmArgs;
+final Resolution? resolution;
+/// 图标路径或 data URL
+final String? icon;
+/// 累计游戏时长 (秒)
+final BigInt playTimeSecs;
+final DateTime created;
+final DateTime? lastPlayed;
+/// 实例目录 (同时也是游戏目录)，不写入清单
+final String path;
+
+                const InstanceManifest({required this.formatVersion ,required this.id ,required this.name ,required this.gameVersion ,required this.loader ,this.loaderVersion ,this.javaPath ,this.maxMemoryMb ,this.minMemoryMb ,required this.jvmArgs ,this.resolution ,this.icon ,required this.playTimeSecs ,required this.created ,this.lastPlayed ,required this.path ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => formatVersion.hashCode^id.hashCode^name.hashCode^gameVersion.hashCode^loader.hashCode^loaderVersion.hashCode^javaPath.hashCode^maxMemoryMb.hashCode^minMemoryMb.hashCode^jvmArgs.hashCode^resolution.hashCode^icon.hashCode^playTimeSecs.hashCode^created.hashCode^lastPlayed.hashCode^path.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InstanceManifest &&
+                runtimeType == other.runtimeType
+                && formatVersion == other.formatVersion&& id == other.id&& name == other.name&& gameVersion == other.gameVersion&& loader == other.loader&& loaderVersion == other.loaderVersion&& javaPath == other.javaPath&& maxMemoryMb == other.maxMemoryMb&& minMemoryMb == other.minMemoryMb&& jvmArgs == other.jvmArgs&& resolution == other.resolution&& icon == other.icon&& playTimeSecs == other.playTimeSecs&& created == other.created&& lastPlayed == other.lastPlayed&& path == other.path;
+        
+            }
+
+/// 新建实例的参数
+class NewInstance  {
+                final String name;
+final String gameVersion;
+final ModLoader loader;
+final String? loaderVersion;
+final String? icon;
+
+                const NewInstance({required this.name ,required this.gameVersion ,required this.loader ,this.loaderVersion ,this.icon ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^gameVersion.hashCode^loader.hashCode^loaderVersion.hashCode^icon.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NewInstance &&
+                runtimeType == other.runtimeType
+                && name == other.name&& gameVersion == other.gameVersion&& loader == other.loader&& loaderVersion == other.loaderVersion&& icon == other.icon;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'instance.dart';
+import 'launch.dart';
+import 'mod_loader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build_globset`, `collect_files`, `download_references`, `extract_files`, `read_export_manifest`, `split_references`, `write_archive`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExportFile`, `ExportManifest`, `FileReference`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// 默认导出的条目
+Future<List<String>>  defaultExportEntries() => RustLib.instance.api.crateApiInstanceExportDefaultExportEntries();
+
+/// 导出实例为 zip 压缩包
+Future<void>  exportInstance({required String resourceDir , required String instanceId , required String destPath , required ExportOptions options , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiInstanceExportExportInstance(resourceDir: resourceDir, instanceId: instanceId, destPath: destPath, options: options, onProgress: onProgress);
+
+/// 从导出的压缩包导入实例，仅以引用保存的文件会重新下载
+Future<InstanceManifest>  importInstanceArchive({required String resourceDir , required String archivePath , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiInstanceExportImportInstanceArchive(resourceDir: resourceDir, archivePath: archivePath, onProgress: onProgress);
+
+            /// 导出选项
+class ExportOptions  {
+                /// 要导出的顶层条目 (相对实例目录，如 `mods`、`saves`、`options.txt`)
+final List<String> include;
+/// 排除规则 (glob，相对实例目录，如 `saves/*/backups/**`、`**/*.log`)
+final List<String> exclude;
+/// 能从下载记录找到来源的文件只写入下载地址，导入时重新下载
+final bool referenceDownloads;
+
+                const ExportOptions({required this.include ,required this.exclude ,required this.referenceDownloads ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => include.hashCode^exclude.hashCode^referenceDownloads.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExportOptions &&
+                runtimeType == other.runtimeType
+                && include == other.include&& exclude == other.exclude&& referenceDownloads == other.referenceDownloads;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,90 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'instance.dart';
+import 'launch.dart';
+import 'mod_loader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `create_imported`, `forge_maven_version`, `link_entry`, `parse_curseforge_instance`, `parse_curseforge_loader`, `parse_hmcl_version`, `parse_memory_mb`, `parse_multimc_config`, `parse_multimc_instance`, `parse_vanilla_profiles`, `reparse`, `resolve_vanilla_version`, `scan_instances_dir`, `split_java_args`, `transfer_game_files`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CurseForgeInstance`, `CurseForgeModLoader`, `HmclPatch`, `HmclVersionJson`, `HmclVersionSetting`, `ImportSettings`, `LauncherProfiles`, `MmcComponent`, `MmcPack`, `ParsedInstance`, `VanillaProfile`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+
+            /// 列出官方启动器 (`.minecraft/launcher_profiles.json`) 中可导入的配置
+Future<List<ImportCandidate>>  scanVanillaLauncher({required String minecraftDir }) => RustLib.instance.api.crateApiInstanceImportScanVanillaLauncher(minecraftDir: minecraftDir);
+
+/// 列出 MultiMC / Prism 实例目录 (`<启动器>/instances`) 中可导入的实例
+Future<List<ImportCandidate>>  scanMultimcInstances({required String instancesDir }) => RustLib.instance.api.crateApiInstanceImportScanMultimcInstances(instancesDir: instancesDir);
+
+/// 列出 CurseForge 实例目录 (`curseforge/minecraft/Instances`) 中可导入的实例
+Future<List<ImportCandidate>>  scanCurseforgeInstances({required String instancesDir }) => RustLib.instance.api.crateApiInstanceImportScanCurseforgeInstances(instancesDir: instancesDir);
+
+/// 列出 HMCL 使用的 .minecraft 目录中可导入的版本
+Future<List<ImportCandidate>>  scanHmclVersions({required String minecraftDir }) => RustLib.instance.api.crateApiInstanceImportScanHmclVersions(minecraftDir: minecraftDir);
+
+/// 导入实例：按原设置创建新实例，并复制或链接游戏文件 (mods、config、saves 等)。
+/// 游戏版本与加载器需另行通过 `install_loader` 安装
+Future<InstanceManifest>  importInstance({required String resourceDir , required ImportCandidate candidate , required ImportMode mode }) => RustLib.instance.api.crateApiInstanceImportImportInstance(resourceDir: resourceDir, candidate: candidate, mode: mode);
+
+            /// 可导入的实例
+class ImportCandidate  {
+                final ImportSource source;
+/// 官方启动器与 HMCL 为 .minecraft 目录，其余为实例目录
+final String location;
+/// 官方启动器中的配置键，HMCL 中的版本 ID
+final String? profileKey;
+final String name;
+final String gameVersion;
+final ModLoader loader;
+final String? loaderVersion;
+/// 游戏文件所在目录
+final String gameDir;
+
+                const ImportCandidate({required this.source ,required this.location ,this.profileKey ,required this.name ,required this.gameVersion ,required this.loader ,this.loaderVersion ,required this.gameDir ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^location.hashCode^profileKey.hashCode^name.hashCode^gameVersion.hashCode^loader.hashCode^loaderVersion.hashCode^gameDir.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportCandidate &&
+                runtimeType == other.runtimeType
+                && source == other.source&& location == other.location&& profileKey == other.profileKey&& name == other.name&& gameVersion == other.gameVersion&& loader == other.loader&& loaderVersion == other.loaderVersion&& gameDir == other.gameDir;
+        
+            }
+
+/// 游戏文件的导入方式
+enum ImportMode {
+                    copy,
+/// 创建符号链接，失败时回退为复制
+link,
+                    ;
+                    
+                }
+
+/// 导入来源
+enum ImportSource {
+                    /// 官方启动器 (launcher_profiles.json)
+vanillaLauncher,
+/// MultiMC / Prism Launcher
+multiMc,
+curseForge,
+/// HMCL (.minecraft/versions 下的每个版本)
+hmcl,
+                    ;
+                    
+                }
+            
\ No newline at end of file
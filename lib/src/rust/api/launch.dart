@@ -0,0 +1,145 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'java_download.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build_classpath`, `build_launch_args`, `classpath_separator`, `substitute_placeholders`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// 根据系统内存计算默认最大内存 (MB)
+Future<int>  defaultMaxMemoryMb() => RustLib.instance.api.crateApiLaunchDefaultMaxMemoryMb();
+
+/// 生成启动命令
+Future<LaunchCommand>  buildLaunchCommand({required LaunchOptions options }) => RustLib.instance.api.crateApiLaunchBuildLaunchCommand(options: options);
+
+            /// 启动所用的账户信息
+class Account  {
+                final String username;
+/// 无连字符的 UUID
+final String uuid;
+final String accessToken;
+/// msa / legacy / mojang
+final String userType;
+
+                const Account({required this.username ,required this.uuid ,required this.accessToken ,required this.userType ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => username.hashCode^uuid.hashCode^accessToken.hashCode^userType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Account &&
+                runtimeType == other.runtimeType
+                && username == other.username&& uuid == other.uuid&& accessToken == other.accessToken&& userType == other.userType;
+        
+            }
+
+/// 生成的启动命令
+class LaunchCommand  {
+                final String javaPath;
+/// 不含 Java 可执行文件本身的参数列表
+final List<String> args;
+final String workingDir;
+/// 本次启动使用的原生库目录，进程退出后清理
+final String? nativesDir;
+
+                const LaunchCommand({required this.javaPath ,required this.args ,required this.workingDir ,this.nativesDir ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => javaPath.hashCode^args.hashCode^workingDir.hashCode^nativesDir.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LaunchCommand &&
+                runtimeType == other.runtimeType
+                && javaPath == other.javaPath&& args == other.args&& workingDir == other.workingDir&& nativesDir == other.nativesDir;
+        
+            }
+
+/// 启动选项
+class LaunchOptions  {
+                /// 版本 JSON 路径 (versions/<id>/<id>.json，inheritsFrom 会自动展开)
+final String versionJsonPath;
+/// .minecraft 根目录 (包含 libraries / assets / versions)
+final String minecraftDir;
+/// 实例目录 (${game_directory})
+final String gameDir;
+/// 本次启动使用的原生库目录
+final String nativesDir;
+final JavaRuntimeVersion java;
+final Account account;
+/// 最大内存 (MB)，为空时按系统内存推算
+final int? maxMemoryMb;
+/// 最小内存 (MB)
+final int? minMemoryMb;
+final Resolution? resolution;
+/// 用户自定义 JVM 参数
+final List<String> extraJvmArgs;
+/// 用户自定义游戏参数
+final List<String> extraGameArgs;
+
+                const LaunchOptions({required this.versionJsonPath ,required this.minecraftDir ,required this.gameDir ,required this.nativesDir ,required this.java ,required this.account ,this.maxMemoryMb ,this.minMemoryMb ,this.resolution ,required this.extraJvmArgs ,required this.extraGameArgs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => versionJsonPath.hashCode^minecraftDir.hashCode^gameDir.hashCode^nativesDir.hashCode^java.hashCode^account.hashCode^maxMemoryMb.hashCode^minMemoryMb.hashCode^resolution.hashCode^extraJvmArgs.hashCode^extraGameArgs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LaunchOptions &&
+                runtimeType == other.runtimeType
+                && versionJsonPath == other.versionJsonPath&& minecraftDir == other.minecraftDir&& gameDir == other.gameDir&& nativesDir == other.nativesDir&& java == other.java&& account == other.account&& maxMemoryMb == other.maxMemoryMb&& minMemoryMb == other.minMemoryMb&& resolution == other.resolution&& extraJvmArgs == other.extraJvmArgs&& extraGameArgs == other.extraGameArgs;
+        
+            }
+
+/// 游戏窗口分辨率
+class Resolution  {
+                final int width;
+final int height;
+
+                const Resolution({required this.width ,required this.height ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Resolution &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `fetch_maven_sha1`, `install_libraries`, `is_strict_verification`, `library_tasks`, `run_task`, `task_from_download`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LibraryTask`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// 下载版本 JSON (含 inheritsFrom 父版本) 中当前系统需要的依赖库，返回未经校验下载的文件路径
+Future<List<String>>  installVersionLibraries({required String versionJsonPath , required String librariesDir }) => RustLib.instance.api.crateApiLibrariesInstallVersionLibraries(versionJsonPath: versionJsonPath, librariesDir: librariesDir);
+
+/// 开启或关闭严格校验。开启后 Maven 仓库没有提供 `.sha1` 的依赖库会下载失败
+Future<void>  setStrictLibraryVerification({required bool strict }) => RustLib.instance.api.crateApiLibrariesSetStrictLibraryVerification(strict: strict);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `fetch_manifest`, `fetch_maven_versions`, `fetch_meta_profile`, `fetch_partial_version`, `fetch_profile`, `fetch_upstream_profile`, `install`, `list_loaders`, `list_meta_loaders`, `list_upstream_loaders`, `loaders_for`, `write_loader_version`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FabricLikeSource`, `MetaGameVersion`, `MetaLoaderVersion`, `MetaManifest`, `UpstreamLoaderEntry`, `UpstreamLoader`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// 加载器版本
+class LoaderVersion  {
+                final String id;
+final bool stable;
+
+                const LoaderVersion({required this.id ,required this.stable ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^stable.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoaderVersion &&
+                runtimeType == other.runtimeType
+                && id == other.id&& stable == other.stable;
+        
+            }
+            
\ No newline at end of file
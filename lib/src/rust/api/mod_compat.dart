@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `accepts`, `builtin_providers`, `check_dependency`, `check_mods`, `display_name`, `enabled_mod_files`, `new`, `register`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ModFile`, `Provider`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`
+
+
+            /// 启动前检查已启用模组的兼容性：缺少的前置、版本范围、重复模组、加载器与 Java 版本。
+/// `java_major_version` 为启动使用的 Java 主版本，为空时不检查 Java
+Future<CompatReport>  checkModCompatibility({required String resourceDir , required String instanceId , int? javaMajorVersion }) => RustLib.instance.api.crateApiModCompatCheckModCompatibility(resourceDir: resourceDir, instanceId: instanceId, javaMajorVersion: javaMajorVersion);
+
+            /// 兼容性检查发现的问题
+class CompatIssue  {
+                final CompatSeverity severity;
+final CompatIssueKind kind;
+final String? modId;
+/// 相对实例目录的文件路径
+final String? file;
+final String message;
+/// 建议的处理方式
+final String suggestion;
+
+                const CompatIssue({required this.severity ,required this.kind ,this.modId ,this.file ,required this.message ,required this.suggestion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => severity.hashCode^kind.hashCode^modId.hashCode^file.hashCode^message.hashCode^suggestion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompatIssue &&
+                runtimeType == other.runtimeType
+                && severity == other.severity&& kind == other.kind&& modId == other.modId&& file == other.file&& message == other.message&& suggestion == other.suggestion;
+        
+            }
+
+enum CompatIssueKind {
+                    /// 缺少必需的前置模组
+missingDependency,
+/// 依赖的版本不在要求的范围内
+versionMismatch,
+/// 与已安装的模组冲突
+incompatible,
+/// 多个文件提供同一个模组
+duplicateMod,
+/// 模组不适用于实例的加载器
+wrongLoader,
+/// 需要更高版本的 Java
+javaVersion,
+/// 无法读取模组元数据
+unknownMod,
+                    ;
+                    
+                }
+
+class CompatReport  {
+                /// 检查的模组文件数
+final BigInt checkedMods;
+/// 按严重程度排序，Error 在前
+final List<CompatIssue> issues;
+
+                const CompatReport({required this.checkedMods ,required this.issues ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => checkedMods.hashCode^issues.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompatReport &&
+                runtimeType == other.runtimeType
+                && checkedMods == other.checkedMods&& issues == other.issues;
+        
+            }
+
+/// 问题的严重程度，Error 会导致游戏无法启动或崩溃
+enum CompatSeverity {
+                    error,
+warning,
+                    ;
+                    
+                }
+            
\ No newline at end of file
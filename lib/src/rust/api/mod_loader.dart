@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'loader_meta.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `artifact_prefixes`, `as_str`, `detect_loader`, `find_profile`, `install_vanilla`, `matches`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `hash`
+
+
+            /// 列出指定游戏版本可用的加载器版本，原版返回空列表
+Future<List<LoaderVersion>>  listLoaderVersions({required ModLoader loader , required String gameVersion }) => RustLib.instance.api.crateApiModLoaderListLoaderVersions(loader: loader, gameVersion: gameVersion);
+
+/// 安装游戏版本及加载器，返回可用于启动的版本 ID。
+/// 原版忽略 `loader_version`；Forge / NeoForge 需要 `java_path` 运行安装处理器
+Future<String>  installLoader({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion , String? javaPath , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiModLoaderInstallLoader(minecraftDir: minecraftDir, loader: loader, gameVersion: gameVersion, loaderVersion: loaderVersion, javaPath: javaPath, onProgress: onProgress);
+
+/// 查找已安装的启动配置，返回版本 JSON 路径 (可直接用于 `LaunchOptions::version_json_path`)，未安装时返回 None
+Future<String?>  resolveLaunchProfile({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion }) => RustLib.instance.api.crateApiModLoaderResolveLaunchProfile(minecraftDir: minecraftDir, loader: loader, gameVersion: gameVersion, loaderVersion: loaderVersion);
+
+/// 卸载加载器的启动配置 (删除对应 versions 子目录)，共享的依赖库保留。
+/// 原版仍被其他版本继承时拒绝卸载
+Future<void>  uninstallLoader({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion }) => RustLib.instance.api.crateApiModLoaderUninstallLoader(minecraftDir: minecraftDir, loader: loader, gameVersion: gameVersion, loaderVersion: loaderVersion);
+
+            /// 模组加载器
+enum ModLoader {
+                    vanilla,
+fabric,
+quilt,
+forge,
+neoForge,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'instance.dart';
+import 'launch.dart';
+import 'mod_loader.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `client_support`, `dependencies_of`, `download_pack_file`, `download_pack_files`, `export_mrpack_with`, `extract_overrides`, `from_side`, `install_mrpack`, `loader`, `read_index`, `safe_relative_path`, `split_known_files`, `write_mrpack`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EnvSupport`, `PackEnv`, `PackFile`, `PackIndex`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// 读取 `.mrpack` 的概要信息
+Future<MrpackInfo>  readMrpack({required String path }) => RustLib.instance.api.crateApiModpackReadMrpack(path: path);
+
+/// 从 `.mrpack` 创建实例，`skipped_optional` 为不安装的可选文件路径
+Future<InstanceManifest>  importMrpack({required String resourceDir , required String path , required List<String> skippedOptional , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiModpackImportMrpack(resourceDir: resourceDir, path: path, skippedOptional: skippedOptional, onProgress: onProgress);
+
+/// 下载 Modrinth 上的整合包版本并创建实例
+Future<InstanceManifest>  importMrpackVersion({required String resourceDir , required String versionId , required List<String> skippedOptional , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiModpackImportMrpackVersion(resourceDir: resourceDir, versionId: versionId, skippedOptional: skippedOptional, onProgress: onProgress);
+
+/// 导出实例为 `.mrpack`，Modrinth 上能找到的文件以下载地址引用，其余放入 `overrides/`
+Future<void>  exportMrpack({required String resourceDir , required String instanceId , required String destPath , required MrpackExportOptions options , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiModpackExportMrpack(resourceDir: resourceDir, instanceId: instanceId, destPath: destPath, options: options, onProgress: onProgress);
+
+            /// 导出整合包的选项
+class MrpackExportOptions  {
+                /// 整合包名称，为空时使用实例名称
+final String? name;
+/// 整合包版本号
+final String versionId;
+final String? summary;
+/// 要导出的顶层条目 (相对实例目录)
+final List<String> include;
+/// 排除规则 (glob，相对实例目录)
+final List<String> exclude;
+
+                const MrpackExportOptions({this.name ,required this.versionId ,this.summary ,required this.include ,required this.exclude ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^versionId.hashCode^summary.hashCode^include.hashCode^exclude.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MrpackExportOptions &&
+                runtimeType == other.runtimeType
+                && name == other.name&& versionId == other.versionId&& summary == other.summary&& include == other.include&& exclude == other.exclude;
+        
+            }
+
+/// 整合包概要，用于导入前展示
+class MrpackInfo  {
+                final String name;
+final String versionId;
+final String? summary;
+final String gameVersion;
+final ModLoader loader;
+final String? loaderVersion;
+/// 客户端需要下载的文件数
+final int fileCount;
+/// 客户端可选的文件，导入时可跳过
+final List<MrpackOptionalFile> optionalFiles;
+
+                const MrpackInfo({required this.name ,required this.versionId ,this.summary ,required this.gameVersion ,required this.loader ,this.loaderVersion ,required this.fileCount ,required this.optionalFiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^versionId.hashCode^summary.hashCode^gameVersion.hashCode^loader.hashCode^loaderVersion.hashCode^fileCount.hashCode^optionalFiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MrpackInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& versionId == other.versionId&& summary == other.summary&& gameVersion == other.gameVersion&& loader == other.loader&& loaderVersion == other.loaderVersion&& fileCount == other.fileCount&& optionalFiles == other.optionalFiles;
+        
+            }
+
+/// 整合包的可选文件
+class MrpackOptionalFile  {
+                final String path;
+final BigInt size;
+
+                const MrpackOptionalFile({required this.path ,required this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MrpackOptionalFile &&
+                runtimeType == other.runtimeType
+                && path == other.path&& size == other.size;
+        
+            }
+            
\ No newline at end of file
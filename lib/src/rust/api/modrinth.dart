@@ -0,0 +1,584 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `as_str`, `categories`, `fetch_and_store`, `fetch_batch`, `fetch_icon`, `game_versions`, `get_many`, `get`, `icon`, `latest_versions_from_hashes`, `loaders`, `new`, `parse`, `post`, `primary_file`, `project_members`, `project`, `read_rate_limit`, `revalidate`, `search`, `send`, `team_members`, `url`, `version_from_hash`, `versions_from_hashes`, `wait_for_rate_limit`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ModrinthClient`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// 搜索项目
+Future<SearchResults>  modrinthSearch({required SearchQuery query }) => RustLib.instance.api.crateApiModrinthModrinthSearch(query: query);
+
+/// 获取项目详情 (ID 或 slug)
+Future<Project>  modrinthGetProject({required String idOrSlug }) => RustLib.instance.api.crateApiModrinthModrinthGetProject(idOrSlug: idOrSlug);
+
+/// 批量获取项目
+Future<List<Project>>  modrinthGetProjects({required List<String> ids }) => RustLib.instance.api.crateApiModrinthModrinthGetProjects(ids: ids);
+
+/// 列出项目的版本，可按加载器与游戏版本过滤
+Future<List<Version>>  modrinthGetProjectVersions({required String idOrSlug , required List<String> loaders , required List<String> gameVersions }) => RustLib.instance.api.crateApiModrinthModrinthGetProjectVersions(idOrSlug: idOrSlug, loaders: loaders, gameVersions: gameVersions);
+
+/// 获取版本详情
+Future<Version>  modrinthGetVersion({required String id }) => RustLib.instance.api.crateApiModrinthModrinthGetVersion(id: id);
+
+/// 批量获取版本
+Future<List<Version>>  modrinthGetVersions({required List<String> ids }) => RustLib.instance.api.crateApiModrinthModrinthGetVersions(ids: ids);
+
+/// 获取项目的团队成员
+Future<List<TeamMember>>  modrinthGetProjectMembers({required String idOrSlug }) => RustLib.instance.api.crateApiModrinthModrinthGetProjectMembers(idOrSlug: idOrSlug);
+
+/// 获取团队成员
+Future<List<TeamMember>>  modrinthGetTeamMembers({required String teamId }) => RustLib.instance.api.crateApiModrinthModrinthGetTeamMembers(teamId: teamId);
+
+/// 按文件哈希查询版本
+Future<Version>  modrinthGetVersionFromHash({required String hash , required HashAlgorithm algorithm }) => RustLib.instance.api.crateApiModrinthModrinthGetVersionFromHash(hash: hash, algorithm: algorithm);
+
+/// 批量按文件哈希查询版本
+Future<Map<String, Version>>  modrinthGetVersionsFromHashes({required List<String> hashes , required HashAlgorithm algorithm }) => RustLib.instance.api.crateApiModrinthModrinthGetVersionsFromHashes(hashes: hashes, algorithm: algorithm);
+
+/// 分类标签
+Future<List<CategoryTag>>  modrinthGetCategories() => RustLib.instance.api.crateApiModrinthModrinthGetCategories();
+
+/// 加载器标签
+Future<List<LoaderTag>>  modrinthGetLoaders() => RustLib.instance.api.crateApiModrinthModrinthGetLoaders();
+
+/// 游戏版本标签
+Future<List<GameVersionTag>>  modrinthGetGameVersions() => RustLib.instance.api.crateApiModrinthModrinthGetGameVersions();
+
+/// 最近一次请求返回的限流状态，尚未请求过时为 None
+Future<RateLimitStatus?>  modrinthRateLimitStatus() => RustLib.instance.api.crateApiModrinthModrinthRateLimitStatus();
+
+            /// 分类标签
+class CategoryTag  {
+                final String name;
+final String projectType;
+final String header;
+/// SVG 图标
+final String icon;
+
+                const CategoryTag({required this.name ,required this.projectType ,required this.header ,required this.icon ,});
+
+                static Future<CategoryTag>  default_()=>RustLib.instance.api.crateApiModrinthCategoryTagDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^projectType.hashCode^header.hashCode^icon.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CategoryTag &&
+                runtimeType == other.runtimeType
+                && name == other.name&& projectType == other.projectType&& header == other.header&& icon == other.icon;
+        
+            }
+
+/// 文件哈希
+class FileHashes  {
+                final String sha1;
+final String sha512;
+
+                const FileHashes({required this.sha1 ,required this.sha512 ,});
+
+                static Future<FileHashes>  default_()=>RustLib.instance.api.crateApiModrinthFileHashesDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => sha1.hashCode^sha512.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileHashes &&
+                runtimeType == other.runtimeType
+                && sha1 == other.sha1&& sha512 == other.sha512;
+        
+            }
+
+/// 项目画廊图片
+class GalleryImage  {
+                final String url;
+final bool featured;
+final String? title;
+final String? description;
+
+                const GalleryImage({required this.url ,required this.featured ,this.title ,this.description ,});
+
+                static Future<GalleryImage>  default_()=>RustLib.instance.api.crateApiModrinthGalleryImageDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^featured.hashCode^title.hashCode^description.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GalleryImage &&
+                runtimeType == other.runtimeType
+                && url == other.url&& featured == other.featured&& title == other.title&& description == other.description;
+        
+            }
+
+/// 游戏版本标签
+class GameVersionTag  {
+                final String version;
+/// release / snapshot / alpha / beta
+final String versionType;
+final DateTime? date;
+final bool major;
+
+                const GameVersionTag({required this.version ,required this.versionType ,this.date ,required this.major ,});
+
+                static Future<GameVersionTag>  default_()=>RustLib.instance.api.crateApiModrinthGameVersionTagDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => version.hashCode^versionType.hashCode^date.hashCode^major.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GameVersionTag &&
+                runtimeType == other.runtimeType
+                && version == other.version&& versionType == other.versionType&& date == other.date&& major == other.major;
+        
+            }
+
+/// 文件哈希算法
+enum HashAlgorithm {
+                    sha1,
+sha512,
+                    ;
+                    
+                }
+
+/// 项目许可证
+class License  {
+                final String id;
+final String name;
+final String? url;
+
+                const License({required this.id ,required this.name ,this.url ,});
+
+                static Future<License>  default_()=>RustLib.instance.api.crateApiModrinthLicenseDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^url.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is License &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& url == other.url;
+        
+            }
+
+/// 加载器标签
+class LoaderTag  {
+                final String name;
+final List<String> supportedProjectTypes;
+final String icon;
+
+                const LoaderTag({required this.name ,required this.supportedProjectTypes ,required this.icon ,});
+
+                static Future<LoaderTag>  default_()=>RustLib.instance.api.crateApiModrinthLoaderTagDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^supportedProjectTypes.hashCode^icon.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoaderTag &&
+                runtimeType == other.runtimeType
+                && name == other.name&& supportedProjectTypes == other.supportedProjectTypes&& icon == other.icon;
+        
+            }
+
+/// 用户
+class ModrinthUser  {
+                final String id;
+final String username;
+final String? name;
+final String? avatarUrl;
+
+                const ModrinthUser({required this.id ,required this.username ,this.name ,this.avatarUrl ,});
+
+                static Future<ModrinthUser>  default_()=>RustLib.instance.api.crateApiModrinthModrinthUserDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^username.hashCode^name.hashCode^avatarUrl.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ModrinthUser &&
+                runtimeType == other.runtimeType
+                && id == other.id&& username == other.username&& name == other.name&& avatarUrl == other.avatarUrl;
+        
+            }
+
+/// 项目详情
+class Project  {
+                final String id;
+final String slug;
+final String projectType;
+final String team;
+final String title;
+final String description;
+final String body;
+final List<String> categories;
+final List<String> additionalCategories;
+final String clientSide;
+final String serverSide;
+final String status;
+final BigInt downloads;
+final BigInt followers;
+final String? iconUrl;
+final int? color;
+final License license;
+final String? sourceUrl;
+final String? issuesUrl;
+final String? wikiUrl;
+final String? discordUrl;
+final List<String> versions;
+final List<String> gameVersions;
+final List<String> loaders;
+final List<GalleryImage> gallery;
+final DateTime? published;
+final DateTime? updated;
+
+                const Project({required this.id ,required this.slug ,required this.projectType ,required this.team ,required this.title ,required this.description ,required this.body ,required this.categories ,required this.additionalCategories ,required this.clientSide ,required this.serverSide ,required this.status ,required this.downloads ,required this.followers ,this.iconUrl ,this.color ,required this.license ,this.sourceUrl ,this.issuesUrl ,this.wikiUrl ,this.discordUrl ,required this.versions ,required this.gameVersions ,required this.loaders ,required this.gallery ,this.published ,this.updated ,});
+
+                static Future<Project>  default_()=>RustLib.instance.api.crateApiModrinthProjectDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^slug.hashCode^projectType.hashCode^team.hashCode^title.hashCode^description.hashCode^body.hashCode^categories.hashCode^additionalCategories.hashCode^clientSide.hashCode^serverSide.hashCode^status.hashCode^downloads.hashCode^followers.hashCode^iconUrl.hashCode^color.hashCode^license.hashCode^sourceUrl.hashCode^issuesUrl.hashCode^wikiUrl.hashCode^discordUrl.hashCode^versions.hashCode^gameVersions.hashCode^loaders.hashCode^gallery.hashCode^published.hashCode^updated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Project &&
+                runtimeType == other.runtimeType
+                && id == other.id&& slug == other.slug&& projectType == other.projectType&& team == other.team&& title == other.title&& description == other.description&& body == other.body&& categories == other.categories&& additionalCategories == other.additionalCategories&& clientSide == other.clientSide&& serverSide == other.serverSide&& status == other.status&& downloads == other.downloads&& followers == other.followers&& iconUrl == other.iconUrl&& color == other.color&& license == other.license&& sourceUrl == other.sourceUrl&& issuesUrl == other.issuesUrl&& wikiUrl == other.wikiUrl&& discordUrl == other.discordUrl&& versions == other.versions&& gameVersions == other.gameVersions&& loaders == other.loaders&& gallery == other.gallery&& published == other.published&& updated == other.updated;
+        
+            }
+
+/// 限流状态 (来自 `X-Ratelimit-*` 响应头)
+class RateLimitStatus  {
+                /// 当前窗口内剩余的请求数
+final int remaining;
+/// 每个窗口允许的请求数
+final int limit;
+/// 距窗口重置的秒数
+final BigInt resetSecs;
+
+                const RateLimitStatus({required this.remaining ,required this.limit ,required this.resetSecs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => remaining.hashCode^limit.hashCode^resetSecs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RateLimitStatus &&
+                runtimeType == other.runtimeType
+                && remaining == other.remaining&& limit == other.limit&& resetSecs == other.resetSecs;
+        
+            }
+
+/// 搜索结果中的项目
+class SearchHit  {
+                final String projectId;
+final String slug;
+final String title;
+final String description;
+final String projectType;
+final String author;
+final List<String> categories;
+final List<String> displayCategories;
+final List<String> versions;
+final String clientSide;
+final String serverSide;
+final BigInt downloads;
+final BigInt follows;
+final String? iconUrl;
+final int? color;
+final String? latestVersion;
+final String license;
+final List<String> gallery;
+final String? featuredGallery;
+final DateTime? dateCreated;
+final DateTime? dateModified;
+
+                const SearchHit({required this.projectId ,required this.slug ,required this.title ,required this.description ,required this.projectType ,required this.author ,required this.categories ,required this.displayCategories ,required this.versions ,required this.clientSide ,required this.serverSide ,required this.downloads ,required this.follows ,this.iconUrl ,this.color ,this.latestVersion ,required this.license ,required this.gallery ,this.featuredGallery ,this.dateCreated ,this.dateModified ,});
+
+                static Future<SearchHit>  default_()=>RustLib.instance.api.crateApiModrinthSearchHitDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => projectId.hashCode^slug.hashCode^title.hashCode^description.hashCode^projectType.hashCode^author.hashCode^categories.hashCode^displayCategories.hashCode^versions.hashCode^clientSide.hashCode^serverSide.hashCode^downloads.hashCode^follows.hashCode^iconUrl.hashCode^color.hashCode^latestVersion.hashCode^license.hashCode^gallery.hashCode^featuredGallery.hashCode^dateCreated.hashCode^dateModified.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && projectId == other.projectId&& slug == other.slug&& title == other.title&& description == other.description&& projectType == other.projectType&& author == other.author&& categories == other.categories&& displayCategories == other.displayCategories&& versions == other.versions&& clientSide == other.clientSide&& serverSide == other.serverSide&& downloads == other.downloads&& follows == other.follows&& iconUrl == other.iconUrl&& color == other.color&& latestVersion == other.latestVersion&& license == other.license&& gallery == other.gallery&& featuredGallery == other.featuredGallery&& dateCreated == other.dateCreated&& dateModified == other.dateModified;
+        
+            }
+
+/// 搜索参数
+class SearchQuery  {
+                final String? query;
+/// 外层为 AND，内层为 OR，如 `[["project_type:mod"], ["versions:1.20.1"]]`
+final List<List<String>> facets;
+/// relevance / downloads / follows / newest / updated
+final String? index;
+final int offset;
+final int limit;
+
+                const SearchQuery({this.query ,required this.facets ,this.index ,required this.offset ,required this.limit ,});
+
+                static Future<SearchQuery>  default_()=>RustLib.instance.api.crateApiModrinthSearchQueryDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => query.hashCode^facets.hashCode^index.hashCode^offset.hashCode^limit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchQuery &&
+                runtimeType == other.runtimeType
+                && query == other.query&& facets == other.facets&& index == other.index&& offset == other.offset&& limit == other.limit;
+        
+            }
+
+/// 搜索结果
+class SearchResults  {
+                final List<SearchHit> hits;
+final int offset;
+final int limit;
+final int totalHits;
+
+                const SearchResults({required this.hits ,required this.offset ,required this.limit ,required this.totalHits ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^offset.hashCode^limit.hashCode^totalHits.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchResults &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& offset == other.offset&& limit == other.limit&& totalHits == other.totalHits;
+        
+            }
+
+/// 团队成员
+class TeamMember  {
+                final String teamId;
+final ModrinthUser user;
+final String role;
+final PlatformInt64 ordering;
+
+                const TeamMember({required this.teamId ,required this.user ,required this.role ,required this.ordering ,});
+
+                static Future<TeamMember>  default_()=>RustLib.instance.api.crateApiModrinthTeamMemberDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => teamId.hashCode^user.hashCode^role.hashCode^ordering.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TeamMember &&
+                runtimeType == other.runtimeType
+                && teamId == other.teamId&& user == other.user&& role == other.role&& ordering == other.ordering;
+        
+            }
+
+/// 项目版本
+class Version  {
+                final String id;
+final String projectId;
+final String authorId;
+final String name;
+final String versionNumber;
+final String? changelog;
+/// release / beta / alpha
+final String versionType;
+final String status;
+final bool featured;
+final BigInt downloads;
+final List<String> gameVersions;
+final List<String> loaders;
+final List<VersionFile> files;
+final List<VersionDependency> dependencies;
+final DateTime? datePublished;
+
+                const Version({required this.id ,required this.projectId ,required this.authorId ,required this.name ,required this.versionNumber ,this.changelog ,required this.versionType ,required this.status ,required this.featured ,required this.downloads ,required this.gameVersions ,required this.loaders ,required this.files ,required this.dependencies ,this.datePublished ,});
+
+                static Future<Version>  default_()=>RustLib.instance.api.crateApiModrinthVersionDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^projectId.hashCode^authorId.hashCode^name.hashCode^versionNumber.hashCode^changelog.hashCode^versionType.hashCode^status.hashCode^featured.hashCode^downloads.hashCode^gameVersions.hashCode^loaders.hashCode^files.hashCode^dependencies.hashCode^datePublished.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Version &&
+                runtimeType == other.runtimeType
+                && id == other.id&& projectId == other.projectId&& authorId == other.authorId&& name == other.name&& versionNumber == other.versionNumber&& changelog == other.changelog&& versionType == other.versionType&& status == other.status&& featured == other.featured&& downloads == other.downloads&& gameVersions == other.gameVersions&& loaders == other.loaders&& files == other.files&& dependencies == other.dependencies&& datePublished == other.datePublished;
+        
+            }
+
+/// 版本依赖
+class VersionDependency  {
+                final String? versionId;
+final String? projectId;
+final String? fileName;
+/// required / optional / incompatible / embedded
+final String dependencyType;
+
+                const VersionDependency({this.versionId ,this.projectId ,this.fileName ,required this.dependencyType ,});
+
+                static Future<VersionDependency>  default_()=>RustLib.instance.api.crateApiModrinthVersionDependencyDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => versionId.hashCode^projectId.hashCode^fileName.hashCode^dependencyType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VersionDependency &&
+                runtimeType == other.runtimeType
+                && versionId == other.versionId&& projectId == other.projectId&& fileName == other.fileName&& dependencyType == other.dependencyType;
+        
+            }
+
+/// 版本中的文件
+class VersionFile  {
+                final FileHashes hashes;
+final String url;
+final String filename;
+final bool primary;
+final BigInt size;
+/// required-resource-pack / optional-resource-pack 等，普通文件为空
+final String? fileType;
+
+                const VersionFile({required this.hashes ,required this.url ,required this.filename ,required this.primary ,required this.size ,this.fileType ,});
+
+                static Future<VersionFile>  default_()=>RustLib.instance.api.crateApiModrinthVersionFileDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => hashes.hashCode^url.hashCode^filename.hashCode^primary.hashCode^size.hashCode^fileType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VersionFile &&
+                runtimeType == other.runtimeType
+                && hashes == other.hashes&& url == other.url&& filename == other.filename&& primary == other.primary&& size == other.size&& fileType == other.fileType;
+        
+            }
+            
\ No newline at end of file
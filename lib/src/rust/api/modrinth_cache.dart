@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `begin_refresh`, `cached_icon`, `cached_response`, `download_icon`, `end_refresh`, `freshness`, `freshness`, `freshness`, `is_network_error`, `is_offline`, `offline_error`, `store_icon`, `store_response`, `ttl`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedIcon`, `CachedResponse`, `Freshness`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// 获取项目图标，优先使用缓存 (过期后在后台刷新)，离线或请求失败时返回已缓存的图标
+Future<Uint8List>  modrinthGetIcon({required String url }) => RustLib.instance.api.crateApiModrinthCacheModrinthGetIcon(url: url);
+
+/// 开启或关闭离线模式。离线时 Modrinth 请求只读缓存 (忽略有效期)，没有缓存时返回错误
+Future<void>  setModrinthOffline({required bool offline }) => RustLib.instance.api.crateApiModrinthCacheSetModrinthOffline(offline: offline);
+
+/// 是否处于离线模式
+Future<bool>  modrinthIsOffline() => RustLib.instance.api.crateApiModrinthCacheModrinthIsOffline();
+
+/// 清空 Modrinth 响应与图标缓存，返回删除的条目数
+Future<BigInt>  clearModrinthCache() => RustLib.instance.api.crateApiModrinthCacheClearModrinthCache();
+
+            
+            
\ No newline at end of file
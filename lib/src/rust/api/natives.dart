@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cleanup_natives`, `collect_native_artifacts`, `extract_native_jar`, `extract_natives`, `new_natives_dir`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NativeArtifact`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// 为指定版本解压原生库，返回本次启动使用的原生库目录
+Future<String>  extractVersionNatives({required String versionJsonPath , required String librariesDir , required String nativesRoot }) => RustLib.instance.api.crateApiNativesExtractVersionNatives(versionJsonPath: versionJsonPath, librariesDir: librariesDir, nativesRoot: nativesRoot);
+
+/// 清理原生库目录
+Future<void>  cleanupVersionNatives({required String nativesDir }) => RustLib.instance.api.crateApiNativesCleanupVersionNatives(nativesDir: nativesDir);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'loader_meta.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `game_version_of`, `installer_url_in`, `installer_url`, `list_maven_versions`, `list_versions_in`, `metadata_url`
+
+
+            /// 列出指定游戏版本可用的 NeoForge 版本，Modrinth 元数据不可用时回退到 NeoForged Maven
+Future<List<LoaderVersion>>  listNeoforgeVersions({required String gameVersion }) => RustLib.instance.api.crateApiNeoforgeListNeoforgeVersions(gameVersion: gameVersion);
+
+/// 安装 NeoForge，`java_path` 用于运行安装处理器，返回新版本 ID
+Future<String>  installNeoforge({required String minecraftDir , required String gameVersion , required String neoforgeVersion , required String javaPath , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiNeoforgeInstallNeoforge(minecraftDir: minecraftDir, gameVersion: gameVersion, neoforgeVersion: neoforgeVersion, javaPath: javaPath, onProgress: onProgress);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,116 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'game_log.dart';
+import 'game_process.dart';
+import 'launch.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `duration_secs`, `finish_session`, `get_session`, `heartbeat`, `insert_session`, `process_alive`, `session_from_row`, `spawn_session`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// 启动实例的游戏并记录会话，退出时自动累加游戏时长并判断是否崩溃
+Stream<GameLogEvent>  startInstanceGame({required String resourceDir , required String instanceId , required LaunchCommand command , required String javaVersion , required FutureOr<void> Function(GameProcessInfo) onExit }) => RustLib.instance.api.crateApiPlaySessionStartInstanceGame(resourceDir: resourceDir, instanceId: instanceId, command: command, javaVersion: javaVersion, onExit: onExit);
+
+/// 启动器启动时调用：仍在运行的会话重新挂接并继续计时，
+/// 进程已不存在的会话以最后一次心跳作为结束时间补记。返回重新挂接的会话
+Future<List<PlaySession>>  reattachPlaySessions({required String resourceDir }) => RustLib.instance.api.crateApiPlaySessionReattachPlaySessions(resourceDir: resourceDir);
+
+/// 列出会话 (新的在前)，`instance_id` 为空时列出全部实例
+Future<List<PlaySession>>  listPlaySessions({String? instanceId , required int limit }) => RustLib.instance.api.crateApiPlaySessionListPlaySessions(instanceId: instanceId, limit: limit);
+
+/// 游戏时长汇总，`instance_id` 为空时汇总全部实例；`weeks` 默认 12 周
+Future<PlayStats>  getPlayStats({String? instanceId , int? weeks }) => RustLib.instance.api.crateApiPlaySessionGetPlayStats(instanceId: instanceId, weeks: weeks);
+
+            /// 一次游戏会话
+class PlaySession  {
+                final PlatformInt64 id;
+final String instanceId;
+final int? pid;
+final String? javaVersion;
+final DateTime startTime;
+final DateTime? endTime;
+final int? exitCode;
+final bool crashed;
+
+                const PlaySession({required this.id ,required this.instanceId ,this.pid ,this.javaVersion ,required this.startTime ,this.endTime ,this.exitCode ,required this.crashed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^instanceId.hashCode^pid.hashCode^javaVersion.hashCode^startTime.hashCode^endTime.hashCode^exitCode.hashCode^crashed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlaySession &&
+                runtimeType == other.runtimeType
+                && id == other.id&& instanceId == other.instanceId&& pid == other.pid&& javaVersion == other.javaVersion&& startTime == other.startTime&& endTime == other.endTime&& exitCode == other.exitCode&& crashed == other.crashed;
+        
+            }
+
+/// 游戏时长汇总
+class PlayStats  {
+                final BigInt totalPlayTimeSecs;
+final int sessionCount;
+final int crashCount;
+final DateTime? lastPlayed;
+/// 最近若干周的统计，旧的在前
+final List<WeeklyPlayStats> weekly;
+
+                const PlayStats({required this.totalPlayTimeSecs ,required this.sessionCount ,required this.crashCount ,this.lastPlayed ,required this.weekly ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalPlayTimeSecs.hashCode^sessionCount.hashCode^crashCount.hashCode^lastPlayed.hashCode^weekly.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlayStats &&
+                runtimeType == other.runtimeType
+                && totalPlayTimeSecs == other.totalPlayTimeSecs&& sessionCount == other.sessionCount&& crashCount == other.crashCount&& lastPlayed == other.lastPlayed&& weekly == other.weekly;
+        
+            }
+
+/// 每周会话统计
+class WeeklyPlayStats  {
+                /// 该周周一 (UTC)
+final DateTime weekStart;
+final int sessions;
+final BigInt playTimeSecs;
+
+                const WeeklyPlayStats({required this.weekStart ,required this.sessions ,required this.playTimeSecs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => weekStart.hashCode^sessions.hashCode^playTimeSecs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WeeklyPlayStats &&
+                runtimeType == other.runtimeType
+                && weekStart == other.weekStart&& sessions == other.sessions&& playTimeSecs == other.playTimeSecs;
+        
+            }
+            
\ No newline at end of file
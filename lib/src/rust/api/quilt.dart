@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'loader_meta.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `quilt_source`
+
+
+            /// 列出指定游戏版本可用的 Quilt 加载器，Modrinth 元数据不可用时回退到 meta.quiltmc.org
+Future<List<LoaderVersion>>  listQuiltLoaders({required String gameVersion }) => RustLib.instance.api.crateApiQuiltListQuiltLoaders(gameVersion: gameVersion);
+
+/// 安装 Quilt 加载器 (在原版旁写入 inheritsFrom 版本 JSON)，返回新版本 ID
+Future<String>  installQuilt({required String minecraftDir , required String gameVersion , required String loaderVersion , required FutureOr<void> Function(double, String) onProgress }) => RustLib.instance.api.crateApiQuiltInstallQuilt(minecraftDir: minecraftDir, gameVersion: gameVersion, loaderVersion: loaderVersion, onProgress: onProgress);
+
+            
+            
\ No newline at end of file
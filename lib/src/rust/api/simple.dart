@@ -6,4 +6,10 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-Future<void> initApp() => RustLib.instance.api.crateApiSimpleInitApp();
+
+            
+
+            Future<void>  initApp() => RustLib.instance.api.crateApiSimpleInitApp();
+
+            
+            
\ No newline at end of file
@@ -3,729 +3,5978 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/content.dart';
+import 'api/content_scan.dart';
+import 'api/content_update.dart';
+import 'api/crash_report.dart';
+import 'api/database.dart';
+import 'api/dependency.dart';
+import 'api/fabric.dart';
+import 'api/forge.dart';
+import 'api/game_log.dart';
+import 'api/game_process.dart';
+import 'api/instance.dart';
+import 'api/instance_export.dart';
+import 'api/instance_import.dart';
 import 'api/java_download.dart';
+import 'api/launch.dart';
+import 'api/libraries.dart';
+import 'api/loader_meta.dart';
+import 'api/mod_compat.dart';
+import 'api/mod_loader.dart';
+import 'api/modpack.dart';
+import 'api/modrinth.dart';
+import 'api/modrinth_cache.dart';
+import 'api/natives.dart';
+import 'api/neoforge.dart';
+import 'api/play_session.dart';
+import 'api/quilt.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.10.0';
-
-  @override
-  int get rustContentHash => 1838672179;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_aml',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<String?> crateApiJavaDownloadAutoInstallJava(
-      {required int javaVersion,
-      required String appDataDir,
-      required FutureOr<void> Function(double, String) onProgress,
-      required FutureOr<void> Function(bool, String?) onComplete});
-
-  Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJavaInstallation();
-
-  Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJre(
-      {required String javaPath});
-
-  Future<int> crateApiJavaDownloadExtractJavaVersion({required String version});
-
-  Future<PlatformInt64> crateApiJavaDownloadGetMaxMemory();
-
-  Future<void> crateApiSimpleInitApp();
-
-  Future<bool> crateApiJavaDownloadTestJre(
-      {required String javaPath, required int expectedMajorVersion});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<String?> crateApiJavaDownloadAutoInstallJava(
-      {required int javaVersion,
-      required String appDataDir,
-      required FutureOr<void> Function(double, String) onProgress,
-      required FutureOr<void> Function(bool, String?) onComplete}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(javaVersion, serializer);
-        sse_encode_String(appDataDir, serializer);
-        sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(
-            onProgress, serializer);
-        sse_encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(
-            onComplete, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiJavaDownloadAutoInstallJavaConstMeta,
-      argValues: [javaVersion, appDataDir, onProgress, onComplete],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiJavaDownloadAutoInstallJavaConstMeta =>
-      const TaskConstMeta(
-        debugName: "auto_install_java",
-        argNames: ["javaVersion", "appDataDir", "onProgress", "onComplete"],
-      );
-
-  @override
-  Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJavaInstallation() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_java_runtime_version,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiJavaDownloadCheckJavaInstallationConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiJavaDownloadCheckJavaInstallationConstMeta =>
-      const TaskConstMeta(
-        debugName: "check_java_installation",
-        argNames: [],
-      );
-
-  @override
-  Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJre(
-      {required String javaPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(javaPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_java_runtime_version,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiJavaDownloadCheckJreConstMeta,
-      argValues: [javaPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiJavaDownloadCheckJreConstMeta =>
-      const TaskConstMeta(
-        debugName: "check_jre",
-        argNames: ["javaPath"],
-      );
-
-  @override
-  Future<int> crateApiJavaDownloadExtractJavaVersion(
-      {required String version}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(version, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_32,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiJavaDownloadExtractJavaVersionConstMeta,
-      argValues: [version],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiJavaDownloadExtractJavaVersionConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_java_version",
-        argNames: ["version"],
-      );
-
-  @override
-  Future<PlatformInt64> crateApiJavaDownloadGetMaxMemory() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_64,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiJavaDownloadGetMaxMemoryConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiJavaDownloadGetMaxMemoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_max_memory",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSimpleInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiJavaDownloadTestJre(
-      {required String javaPath, required int expectedMajorVersion}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(javaPath, serializer);
-        sse_encode_i_32(expectedMajorVersion, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiJavaDownloadTestJreConstMeta,
-      argValues: [javaPath, expectedMajorVersion],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiJavaDownloadTestJreConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_jre",
-        argNames: ["javaPath", "expectedMajorVersion"],
-      );
-
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(
-          FutureOr<void> Function(bool, String?) raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_bool(rawArg0);
-      final arg1 = dco_decode_opt_String(rawArg1);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0, arg1));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  Future<void> Function(int, dynamic, dynamic)
-      encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(
-          FutureOr<void> Function(double, String) raw) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_f_64(rawArg0);
-      final arg1 = dco_decode_String(rawArg1);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0, arg1));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-          callId: callId,
-          ptr: output.ptr,
-          rustVecLen: output.rustVecLen,
-          dataLen: output.dataLen);
-    };
-  }
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  FutureOr<void> Function(bool, String?)
-      dco_decode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(double, String)
-      dco_decode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  Object dco_decode_DartOpaque(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return decodeDartOpaque(raw, generalizedFrbRustBinding);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  JavaRuntimeVersion dco_decode_box_autoadd_java_runtime_version(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_java_runtime_version(raw);
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  PlatformInt64 dco_decode_isize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  JavaRuntimeVersion dco_decode_java_runtime_version(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return JavaRuntimeVersion(
-      version: dco_decode_String(arr[0]),
-      path: dco_decode_String(arr[1]),
-      majorVersion: dco_decode_i_32(arr[2]),
-    );
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  JavaRuntimeVersion? dco_decode_opt_box_autoadd_java_runtime_version(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_java_runtime_version(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_isize(deserializer);
-    return decodeDartOpaque(inner, generalizedFrbRustBinding);
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  JavaRuntimeVersion sse_decode_box_autoadd_java_runtime_version(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_java_runtime_version(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  JavaRuntimeVersion sse_decode_java_runtime_version(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_version = sse_decode_String(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    var var_majorVersion = sse_decode_i_32(deserializer);
-    return JavaRuntimeVersion(
-        version: var_version, path: var_path, majorVersion: var_majorVersion);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  JavaRuntimeVersion? sse_decode_opt_box_autoadd_java_runtime_version(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_java_runtime_version(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(bool, String?) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(self),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(double, String) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-        encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(self),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_isize(
-        PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(
-            self, portManager.dartHandlerPort, generalizedFrbRustBinding)),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_java_runtime_version(
-      JavaRuntimeVersion self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_java_runtime_version(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_java_runtime_version(
-      JavaRuntimeVersion self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.version, serializer);
-    sse_encode_String(self.path, serializer);
-    sse_encode_i_32(self.majorVersion, serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_java_runtime_version(
-      JavaRuntimeVersion? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_java_runtime_version(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.10.0';
+
+                  @override
+                  int get rustContentHash => 1807930781;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_aml',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<List<String>> crateApiContentApplyContentProfile({required String resourceDir , required String instanceId , required String tag });
+
+Future<UpdateBackup> crateApiContentUpdateApplyContentUpdates({required String resourceDir , required String instanceId , required List<ContentUpdate> updates });
+
+Future<List<ContentRecord>> crateApiDependencyApplyInstallPlan({required String resourceDir , required String instanceId , required InstallPlan plan , String? world });
+
+Future<String?> crateApiJavaDownloadAutoInstallJava({required int javaVersion , required String appDataDir , required FutureOr<void> Function(double, String) onProgress , required FutureOr<void> Function(bool, String?) onComplete });
+
+Future<LaunchCommand> crateApiLaunchBuildLaunchCommand({required LaunchOptions options });
+
+Future<CategoryTag> crateApiModrinthCategoryTagDefault();
+
+Future<List<ContentUpdate>> crateApiContentUpdateCheckContentUpdates({required String resourceDir , required String instanceId });
+
+Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJavaInstallation();
+
+Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJre({required String javaPath });
+
+Future<CompatReport> crateApiModCompatCheckModCompatibility({required String resourceDir , required String instanceId , int? javaMajorVersion });
+
+Future<void> crateApiNativesCleanupVersionNatives({required String nativesDir });
+
+Future<BigInt> crateApiDatabaseClearDownloadCache();
+
+Future<BigInt> crateApiModrinthCacheClearModrinthCache();
+
+Future<InstanceManifest> crateApiInstanceCreateInstance({required String resourceDir , required NewInstance options });
+
+Future<List<String>> crateApiInstanceExportDefaultExportEntries();
+
+Future<int> crateApiLaunchDefaultMaxMemoryMb();
+
+Future<void> crateApiInstanceDeleteInstance({required String resourceDir , required String id });
+
+Future<void> crateApiContentUpdateDeleteUpdateBackup({required String resourceDir , required String instanceId , required String backupId });
+
+Future<CrashDiagnosis> crateApiCrashReportDiagnoseCrashText({required String text });
+
+Future<CrashDiagnosis?> crateApiCrashReportDiagnoseLatestCrash({required String gameDir , DateTime? since });
+
+Future<InstanceManifest> crateApiInstanceDuplicateInstance({required String resourceDir , required String id , required String newName });
+
+Future<EmbeddedMetadata> crateApiContentScanEmbeddedMetadataDefault();
+
+Future<void> crateApiInstanceExportExportInstance({required String resourceDir , required String instanceId , required String destPath , required ExportOptions options , required FutureOr<void> Function(double, String) onProgress });
+
+Future<void> crateApiModpackExportMrpack({required String resourceDir , required String instanceId , required String destPath , required MrpackExportOptions options , required FutureOr<void> Function(double, String) onProgress });
+
+Future<int> crateApiJavaDownloadExtractJavaVersion({required String version });
+
+Future<String> crateApiNativesExtractVersionNatives({required String versionJsonPath , required String librariesDir , required String nativesRoot });
+
+Future<FileHashes> crateApiModrinthFileHashesDefault();
+
+Future<GalleryImage> crateApiModrinthGalleryImageDefault();
+
+Future<GameVersionTag> crateApiModrinthGameVersionTagDefault();
+
+Future<GameProcessInfo?> crateApiGameProcessGetGameProcess({required String processId });
+
+Future<InstanceManifest> crateApiInstanceGetInstance({required String resourceDir , required String id });
+
+Future<PlatformInt64> crateApiJavaDownloadGetMaxMemory();
+
+Future<PlayStats> crateApiPlaySessionGetPlayStats({String? instanceId , int? weeks });
+
+Future<InstanceManifest> crateApiInstanceImportImportInstance({required String resourceDir , required ImportCandidate candidate , required ImportMode mode });
+
+Future<InstanceManifest> crateApiInstanceExportImportInstanceArchive({required String resourceDir , required String archivePath , required FutureOr<void> Function(double, String) onProgress });
+
+Future<InstanceManifest> crateApiModpackImportMrpack({required String resourceDir , required String path , required List<String> skippedOptional , required FutureOr<void> Function(double, String) onProgress });
+
+Future<InstanceManifest> crateApiModpackImportMrpackVersion({required String resourceDir , required String versionId , required List<String> skippedOptional , required FutureOr<void> Function(double, String) onProgress });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<void> crateApiDatabaseInitDatabase({required String resourceDir });
+
+Future<ContentRecord> crateApiContentInstallContent({required String resourceDir , required String instanceId , required String versionId , ContentType? contentType , String? world });
+
+Future<String> crateApiFabricInstallFabric({required String minecraftDir , required String gameVersion , required String loaderVersion , required FutureOr<void> Function(double, String) onProgress });
+
+Future<String> crateApiForgeInstallForge({required String minecraftDir , required String gameVersion , required String forgeVersion , required String javaPath , required FutureOr<void> Function(double, String) onProgress });
+
+Future<String> crateApiModLoaderInstallLoader({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion , String? javaPath , required FutureOr<void> Function(double, String) onProgress });
+
+Future<String> crateApiNeoforgeInstallNeoforge({required String minecraftDir , required String gameVersion , required String neoforgeVersion , required String javaPath , required FutureOr<void> Function(double, String) onProgress });
+
+Future<InstallPlan> crateApiDependencyInstallPlanDefault();
+
+Future<String> crateApiQuiltInstallQuilt({required String minecraftDir , required String gameVersion , required String loaderVersion , required FutureOr<void> Function(double, String) onProgress });
+
+Future<List<String>> crateApiLibrariesInstallVersionLibraries({required String versionJsonPath , required String librariesDir });
+
+Future<void> crateApiGameProcessKillGame({required String processId });
+
+Future<License> crateApiModrinthLicenseDefault();
+
+Future<List<LoaderVersion>> crateApiFabricListFabricLoaders({required String gameVersion });
+
+Future<List<LoaderVersion>> crateApiForgeListForgeVersions({required String gameVersion });
+
+Future<List<GameProcessInfo>> crateApiGameProcessListGameProcesses();
+
+Future<List<ContentRecord>> crateApiDatabaseListInstanceContent({required String instanceId , String? projectType });
+
+Future<List<InstanceManifest>> crateApiInstanceListInstances({required String resourceDir });
+
+Future<List<JavaRuntimeVersion>> crateApiDatabaseListJavaRuntimes({int? majorVersion });
+
+Future<List<LoaderVersion>> crateApiModLoaderListLoaderVersions({required ModLoader loader , required String gameVersion });
+
+Future<List<LoaderVersion>> crateApiNeoforgeListNeoforgeVersions({required String gameVersion });
+
+Future<List<PlaySession>> crateApiPlaySessionListPlaySessions({String? instanceId , required int limit });
+
+Future<List<LoaderVersion>> crateApiQuiltListQuiltLoaders({required String gameVersion });
+
+Future<List<UpdateBackup>> crateApiContentUpdateListUpdateBackups({required String resourceDir , required String instanceId });
+
+Future<LoaderTag> crateApiModrinthLoaderTagDefault();
+
+Future<List<CategoryTag>> crateApiModrinthModrinthGetCategories();
+
+Future<List<GameVersionTag>> crateApiModrinthModrinthGetGameVersions();
+
+Future<Uint8List> crateApiModrinthCacheModrinthGetIcon({required String url });
+
+Future<List<LoaderTag>> crateApiModrinthModrinthGetLoaders();
+
+Future<Project> crateApiModrinthModrinthGetProject({required String idOrSlug });
+
+Future<List<TeamMember>> crateApiModrinthModrinthGetProjectMembers({required String idOrSlug });
+
+Future<List<Version>> crateApiModrinthModrinthGetProjectVersions({required String idOrSlug , required List<String> loaders , required List<String> gameVersions });
+
+Future<List<Project>> crateApiModrinthModrinthGetProjects({required List<String> ids });
+
+Future<List<TeamMember>> crateApiModrinthModrinthGetTeamMembers({required String teamId });
+
+Future<Version> crateApiModrinthModrinthGetVersion({required String id });
+
+Future<Version> crateApiModrinthModrinthGetVersionFromHash({required String hash , required HashAlgorithm algorithm });
+
+Future<List<Version>> crateApiModrinthModrinthGetVersions({required List<String> ids });
+
+Future<Map<String, Version>> crateApiModrinthModrinthGetVersionsFromHashes({required List<String> hashes , required HashAlgorithm algorithm });
+
+Future<bool> crateApiModrinthCacheModrinthIsOffline();
+
+Future<RateLimitStatus?> crateApiModrinthModrinthRateLimitStatus();
+
+Future<SearchResults> crateApiModrinthModrinthSearch({required SearchQuery query });
+
+Future<ModrinthUser> crateApiModrinthModrinthUserDefault();
+
+Future<Project> crateApiModrinthProjectDefault();
+
+Future<MrpackInfo> crateApiModpackReadMrpack({required String path });
+
+Future<List<PlaySession>> crateApiPlaySessionReattachPlaySessions({required String resourceDir });
+
+Future<int> crateApiDatabaseRebuildInstanceIndex({required String resourceDir });
+
+Future<void> crateApiDatabaseRemoveJavaRuntime({required String path });
+
+Future<InstanceManifest> crateApiInstanceRenameInstance({required String resourceDir , required String id , required String name });
+
+Future<InstallPlan> crateApiDependencyResolveContentInstall({required String resourceDir , required String instanceId , required String versionId });
+
+Future<String?> crateApiModLoaderResolveLaunchProfile({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion });
+
+Future<void> crateApiContentUpdateRollbackContentUpdate({required String resourceDir , required String instanceId , required String backupId });
+
+Future<InstanceManifest> crateApiInstanceSaveInstance({required String resourceDir , required InstanceManifest manifest });
+
+Future<void> crateApiDatabaseSaveJavaRuntimes({required List<JavaRuntimeVersion> runtimes });
+
+Future<List<ImportCandidate>> crateApiInstanceImportScanCurseforgeInstances({required String instancesDir });
+
+Future<List<ImportCandidate>> crateApiInstanceImportScanHmclVersions({required String minecraftDir });
+
+Future<List<ScannedContent>> crateApiContentScanScanInstanceContent({required String resourceDir , required String instanceId });
+
+Future<List<ImportCandidate>> crateApiInstanceImportScanMultimcInstances({required String instancesDir });
+
+Future<List<ImportCandidate>> crateApiInstanceImportScanVanillaLauncher({required String minecraftDir });
+
+Future<SearchHit> crateApiModrinthSearchHitDefault();
+
+Future<List<String>> crateApiDatabaseSearchInstances({required String query , required InstanceSort sort , required bool descending });
+
+Future<SearchQuery> crateApiModrinthSearchQueryDefault();
+
+Future<String> crateApiContentSetContentEnabled({required String resourceDir , required String instanceId , required String path , required bool enabled });
+
+Future<List<String>> crateApiContentSetContentEnabledByTag({required String resourceDir , required String instanceId , required String tag , required bool enabled });
+
+Future<void> crateApiDatabaseSetContentTags({required String instanceId , required String path , required List<String> tags });
+
+Future<void> crateApiModrinthCacheSetModrinthOffline({required bool offline });
+
+Future<void> crateApiLibrariesSetStrictLibraryVerification({required bool strict });
+
+Stream<GameLogEvent> crateApiGameProcessStartGame({required LaunchCommand command , required FutureOr<void> Function(GameProcessInfo) onExit });
+
+Stream<GameLogEvent> crateApiPlaySessionStartInstanceGame({required String resourceDir , required String instanceId , required LaunchCommand command , required String javaVersion , required FutureOr<void> Function(GameProcessInfo) onExit });
+
+Future<void> crateApiGameProcessStopGame({required String processId , BigInt? timeoutSecs });
+
+Future<TeamMember> crateApiModrinthTeamMemberDefault();
+
+Future<bool> crateApiJavaDownloadTestJre({required String javaPath , required int expectedMajorVersion });
+
+Future<void> crateApiModLoaderUninstallLoader({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion });
+
+Future<Version> crateApiModrinthVersionDefault();
+
+Future<VersionDependency> crateApiModrinthVersionDependencyDefault();
+
+Future<VersionFile> crateApiModrinthVersionFileDefault();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<List<String>> crateApiContentApplyContentProfile({required String resourceDir , required String instanceId , required String tag })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(tag, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentApplyContentProfileConstMeta,
+            argValues: [resourceDir, instanceId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentApplyContentProfileConstMeta => const TaskConstMeta(
+            debugName: "apply_content_profile",
+            argNames: ["resourceDir", "instanceId", "tag"],
+        );
+        
+
+@override Future<UpdateBackup> crateApiContentUpdateApplyContentUpdates({required String resourceDir , required String instanceId , required List<ContentUpdate> updates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_list_content_update(updates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_update_backup,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentUpdateApplyContentUpdatesConstMeta,
+            argValues: [resourceDir, instanceId, updates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentUpdateApplyContentUpdatesConstMeta => const TaskConstMeta(
+            debugName: "apply_content_updates",
+            argNames: ["resourceDir", "instanceId", "updates"],
+        );
+        
+
+@override Future<List<ContentRecord>> crateApiDependencyApplyInstallPlan({required String resourceDir , required String instanceId , required InstallPlan plan , String? world })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_box_autoadd_install_plan(plan, serializer);
+sse_encode_opt_String(world, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_content_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDependencyApplyInstallPlanConstMeta,
+            argValues: [resourceDir, instanceId, plan, world],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDependencyApplyInstallPlanConstMeta => const TaskConstMeta(
+            debugName: "apply_install_plan",
+            argNames: ["resourceDir", "instanceId", "plan", "world"],
+        );
+        
+
+@override Future<String?> crateApiJavaDownloadAutoInstallJava({required int javaVersion , required String appDataDir , required FutureOr<void> Function(double, String) onProgress , required FutureOr<void> Function(bool, String?) onComplete })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(javaVersion, serializer);
+sse_encode_String(appDataDir, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+sse_encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(onComplete, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJavaDownloadAutoInstallJavaConstMeta,
+            argValues: [javaVersion, appDataDir, onProgress, onComplete],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJavaDownloadAutoInstallJavaConstMeta => const TaskConstMeta(
+            debugName: "auto_install_java",
+            argNames: ["javaVersion", "appDataDir", "onProgress", "onComplete"],
+        );
+        
+
+@override Future<LaunchCommand> crateApiLaunchBuildLaunchCommand({required LaunchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_launch_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_launch_command,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLaunchBuildLaunchCommandConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaunchBuildLaunchCommandConstMeta => const TaskConstMeta(
+            debugName: "build_launch_command",
+            argNames: ["options"],
+        );
+        
+
+@override Future<CategoryTag> crateApiModrinthCategoryTagDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_category_tag,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthCategoryTagDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthCategoryTagDefaultConstMeta => const TaskConstMeta(
+            debugName: "category_tag_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<ContentUpdate>> crateApiContentUpdateCheckContentUpdates({required String resourceDir , required String instanceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_content_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentUpdateCheckContentUpdatesConstMeta,
+            argValues: [resourceDir, instanceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentUpdateCheckContentUpdatesConstMeta => const TaskConstMeta(
+            debugName: "check_content_updates",
+            argNames: ["resourceDir", "instanceId"],
+        );
+        
+
+@override Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJavaInstallation()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_java_runtime_version,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJavaDownloadCheckJavaInstallationConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJavaDownloadCheckJavaInstallationConstMeta => const TaskConstMeta(
+            debugName: "check_java_installation",
+            argNames: [],
+        );
+        
+
+@override Future<JavaRuntimeVersion?> crateApiJavaDownloadCheckJre({required String javaPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(javaPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_java_runtime_version,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJavaDownloadCheckJreConstMeta,
+            argValues: [javaPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJavaDownloadCheckJreConstMeta => const TaskConstMeta(
+            debugName: "check_jre",
+            argNames: ["javaPath"],
+        );
+        
+
+@override Future<CompatReport> crateApiModCompatCheckModCompatibility({required String resourceDir , required String instanceId , int? javaMajorVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_opt_box_autoadd_i_32(javaMajorVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_compat_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModCompatCheckModCompatibilityConstMeta,
+            argValues: [resourceDir, instanceId, javaMajorVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModCompatCheckModCompatibilityConstMeta => const TaskConstMeta(
+            debugName: "check_mod_compatibility",
+            argNames: ["resourceDir", "instanceId", "javaMajorVersion"],
+        );
+        
+
+@override Future<void> crateApiNativesCleanupVersionNatives({required String nativesDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(nativesDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNativesCleanupVersionNativesConstMeta,
+            argValues: [nativesDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNativesCleanupVersionNativesConstMeta => const TaskConstMeta(
+            debugName: "cleanup_version_natives",
+            argNames: ["nativesDir"],
+        );
+        
+
+@override Future<BigInt> crateApiDatabaseClearDownloadCache()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseClearDownloadCacheConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseClearDownloadCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_download_cache",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiModrinthCacheClearModrinthCache()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthCacheClearModrinthCacheConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthCacheClearModrinthCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_modrinth_cache",
+            argNames: [],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceCreateInstance({required String resourceDir , required NewInstance options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_box_autoadd_new_instance(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceCreateInstanceConstMeta,
+            argValues: [resourceDir, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceCreateInstanceConstMeta => const TaskConstMeta(
+            debugName: "create_instance",
+            argNames: ["resourceDir", "options"],
+        );
+        
+
+@override Future<List<String>> crateApiInstanceExportDefaultExportEntries()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInstanceExportDefaultExportEntriesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceExportDefaultExportEntriesConstMeta => const TaskConstMeta(
+            debugName: "default_export_entries",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiLaunchDefaultMaxMemoryMb()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLaunchDefaultMaxMemoryMbConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLaunchDefaultMaxMemoryMbConstMeta => const TaskConstMeta(
+            debugName: "default_max_memory_mb",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiInstanceDeleteInstance({required String resourceDir , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceDeleteInstanceConstMeta,
+            argValues: [resourceDir, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceDeleteInstanceConstMeta => const TaskConstMeta(
+            debugName: "delete_instance",
+            argNames: ["resourceDir", "id"],
+        );
+        
+
+@override Future<void> crateApiContentUpdateDeleteUpdateBackup({required String resourceDir , required String instanceId , required String backupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(backupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentUpdateDeleteUpdateBackupConstMeta,
+            argValues: [resourceDir, instanceId, backupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentUpdateDeleteUpdateBackupConstMeta => const TaskConstMeta(
+            debugName: "delete_update_backup",
+            argNames: ["resourceDir", "instanceId", "backupId"],
+        );
+        
+
+@override Future<CrashDiagnosis> crateApiCrashReportDiagnoseCrashText({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crash_diagnosis,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCrashReportDiagnoseCrashTextConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrashReportDiagnoseCrashTextConstMeta => const TaskConstMeta(
+            debugName: "diagnose_crash_text",
+            argNames: ["text"],
+        );
+        
+
+@override Future<CrashDiagnosis?> crateApiCrashReportDiagnoseLatestCrash({required String gameDir , DateTime? since })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gameDir, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(since, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_crash_diagnosis,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCrashReportDiagnoseLatestCrashConstMeta,
+            argValues: [gameDir, since],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrashReportDiagnoseLatestCrashConstMeta => const TaskConstMeta(
+            debugName: "diagnose_latest_crash",
+            argNames: ["gameDir", "since"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceDuplicateInstance({required String resourceDir , required String id , required String newName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(id, serializer);
+sse_encode_String(newName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceDuplicateInstanceConstMeta,
+            argValues: [resourceDir, id, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceDuplicateInstanceConstMeta => const TaskConstMeta(
+            debugName: "duplicate_instance",
+            argNames: ["resourceDir", "id", "newName"],
+        );
+        
+
+@override Future<EmbeddedMetadata> crateApiContentScanEmbeddedMetadataDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_embedded_metadata,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiContentScanEmbeddedMetadataDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentScanEmbeddedMetadataDefaultConstMeta => const TaskConstMeta(
+            debugName: "embedded_metadata_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiInstanceExportExportInstance({required String resourceDir , required String instanceId , required String destPath , required ExportOptions options , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(destPath, serializer);
+sse_encode_box_autoadd_export_options(options, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceExportExportInstanceConstMeta,
+            argValues: [resourceDir, instanceId, destPath, options, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceExportExportInstanceConstMeta => const TaskConstMeta(
+            debugName: "export_instance",
+            argNames: ["resourceDir", "instanceId", "destPath", "options", "onProgress"],
+        );
+        
+
+@override Future<void> crateApiModpackExportMrpack({required String resourceDir , required String instanceId , required String destPath , required MrpackExportOptions options , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(destPath, serializer);
+sse_encode_box_autoadd_mrpack_export_options(options, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModpackExportMrpackConstMeta,
+            argValues: [resourceDir, instanceId, destPath, options, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModpackExportMrpackConstMeta => const TaskConstMeta(
+            debugName: "export_mrpack",
+            argNames: ["resourceDir", "instanceId", "destPath", "options", "onProgress"],
+        );
+        
+
+@override Future<int> crateApiJavaDownloadExtractJavaVersion({required String version })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(version, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiJavaDownloadExtractJavaVersionConstMeta,
+            argValues: [version],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJavaDownloadExtractJavaVersionConstMeta => const TaskConstMeta(
+            debugName: "extract_java_version",
+            argNames: ["version"],
+        );
+        
+
+@override Future<String> crateApiNativesExtractVersionNatives({required String versionJsonPath , required String librariesDir , required String nativesRoot })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(versionJsonPath, serializer);
+sse_encode_String(librariesDir, serializer);
+sse_encode_String(nativesRoot, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNativesExtractVersionNativesConstMeta,
+            argValues: [versionJsonPath, librariesDir, nativesRoot],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNativesExtractVersionNativesConstMeta => const TaskConstMeta(
+            debugName: "extract_version_natives",
+            argNames: ["versionJsonPath", "librariesDir", "nativesRoot"],
+        );
+        
+
+@override Future<FileHashes> crateApiModrinthFileHashesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_file_hashes,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthFileHashesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthFileHashesDefaultConstMeta => const TaskConstMeta(
+            debugName: "file_hashes_default",
+            argNames: [],
+        );
+        
+
+@override Future<GalleryImage> crateApiModrinthGalleryImageDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gallery_image,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthGalleryImageDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthGalleryImageDefaultConstMeta => const TaskConstMeta(
+            debugName: "gallery_image_default",
+            argNames: [],
+        );
+        
+
+@override Future<GameVersionTag> crateApiModrinthGameVersionTagDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_game_version_tag,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthGameVersionTagDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthGameVersionTagDefaultConstMeta => const TaskConstMeta(
+            debugName: "game_version_tag_default",
+            argNames: [],
+        );
+        
+
+@override Future<GameProcessInfo?> crateApiGameProcessGetGameProcess({required String processId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(processId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_game_process_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGameProcessGetGameProcessConstMeta,
+            argValues: [processId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGameProcessGetGameProcessConstMeta => const TaskConstMeta(
+            debugName: "get_game_process",
+            argNames: ["processId"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceGetInstance({required String resourceDir , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceGetInstanceConstMeta,
+            argValues: [resourceDir, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceGetInstanceConstMeta => const TaskConstMeta(
+            debugName: "get_instance",
+            argNames: ["resourceDir", "id"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiJavaDownloadGetMaxMemory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJavaDownloadGetMaxMemoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJavaDownloadGetMaxMemoryConstMeta => const TaskConstMeta(
+            debugName: "get_max_memory",
+            argNames: [],
+        );
+        
+
+@override Future<PlayStats> crateApiPlaySessionGetPlayStats({String? instanceId , int? weeks })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(instanceId, serializer);
+sse_encode_opt_box_autoadd_u_32(weeks, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_play_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPlaySessionGetPlayStatsConstMeta,
+            argValues: [instanceId, weeks],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPlaySessionGetPlayStatsConstMeta => const TaskConstMeta(
+            debugName: "get_play_stats",
+            argNames: ["instanceId", "weeks"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceImportImportInstance({required String resourceDir , required ImportCandidate candidate , required ImportMode mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_box_autoadd_import_candidate(candidate, serializer);
+sse_encode_import_mode(mode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceImportImportInstanceConstMeta,
+            argValues: [resourceDir, candidate, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceImportImportInstanceConstMeta => const TaskConstMeta(
+            debugName: "import_instance",
+            argNames: ["resourceDir", "candidate", "mode"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceExportImportInstanceArchive({required String resourceDir , required String archivePath , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(archivePath, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceExportImportInstanceArchiveConstMeta,
+            argValues: [resourceDir, archivePath, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceExportImportInstanceArchiveConstMeta => const TaskConstMeta(
+            debugName: "import_instance_archive",
+            argNames: ["resourceDir", "archivePath", "onProgress"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiModpackImportMrpack({required String resourceDir , required String path , required List<String> skippedOptional , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(path, serializer);
+sse_encode_list_String(skippedOptional, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModpackImportMrpackConstMeta,
+            argValues: [resourceDir, path, skippedOptional, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModpackImportMrpackConstMeta => const TaskConstMeta(
+            debugName: "import_mrpack",
+            argNames: ["resourceDir", "path", "skippedOptional", "onProgress"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiModpackImportMrpackVersion({required String resourceDir , required String versionId , required List<String> skippedOptional , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(versionId, serializer);
+sse_encode_list_String(skippedOptional, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModpackImportMrpackVersionConstMeta,
+            argValues: [resourceDir, versionId, skippedOptional, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModpackImportMrpackVersionConstMeta => const TaskConstMeta(
+            debugName: "import_mrpack_version",
+            argNames: ["resourceDir", "versionId", "skippedOptional", "onProgress"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiDatabaseInitDatabase({required String resourceDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseInitDatabaseConstMeta,
+            argValues: [resourceDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseInitDatabaseConstMeta => const TaskConstMeta(
+            debugName: "init_database",
+            argNames: ["resourceDir"],
+        );
+        
+
+@override Future<ContentRecord> crateApiContentInstallContent({required String resourceDir , required String instanceId , required String versionId , ContentType? contentType , String? world })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(versionId, serializer);
+sse_encode_opt_box_autoadd_content_type(contentType, serializer);
+sse_encode_opt_String(world, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_content_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentInstallContentConstMeta,
+            argValues: [resourceDir, instanceId, versionId, contentType, world],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentInstallContentConstMeta => const TaskConstMeta(
+            debugName: "install_content",
+            argNames: ["resourceDir", "instanceId", "versionId", "contentType", "world"],
+        );
+        
+
+@override Future<String> crateApiFabricInstallFabric({required String minecraftDir , required String gameVersion , required String loaderVersion , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_String(loaderVersion, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFabricInstallFabricConstMeta,
+            argValues: [minecraftDir, gameVersion, loaderVersion, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFabricInstallFabricConstMeta => const TaskConstMeta(
+            debugName: "install_fabric",
+            argNames: ["minecraftDir", "gameVersion", "loaderVersion", "onProgress"],
+        );
+        
+
+@override Future<String> crateApiForgeInstallForge({required String minecraftDir , required String gameVersion , required String forgeVersion , required String javaPath , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_String(forgeVersion, serializer);
+sse_encode_String(javaPath, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiForgeInstallForgeConstMeta,
+            argValues: [minecraftDir, gameVersion, forgeVersion, javaPath, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiForgeInstallForgeConstMeta => const TaskConstMeta(
+            debugName: "install_forge",
+            argNames: ["minecraftDir", "gameVersion", "forgeVersion", "javaPath", "onProgress"],
+        );
+        
+
+@override Future<String> crateApiModLoaderInstallLoader({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion , String? javaPath , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_mod_loader(loader, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_opt_String(loaderVersion, serializer);
+sse_encode_opt_String(javaPath, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModLoaderInstallLoaderConstMeta,
+            argValues: [minecraftDir, loader, gameVersion, loaderVersion, javaPath, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModLoaderInstallLoaderConstMeta => const TaskConstMeta(
+            debugName: "install_loader",
+            argNames: ["minecraftDir", "loader", "gameVersion", "loaderVersion", "javaPath", "onProgress"],
+        );
+        
+
+@override Future<String> crateApiNeoforgeInstallNeoforge({required String minecraftDir , required String gameVersion , required String neoforgeVersion , required String javaPath , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_String(neoforgeVersion, serializer);
+sse_encode_String(javaPath, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNeoforgeInstallNeoforgeConstMeta,
+            argValues: [minecraftDir, gameVersion, neoforgeVersion, javaPath, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNeoforgeInstallNeoforgeConstMeta => const TaskConstMeta(
+            debugName: "install_neoforge",
+            argNames: ["minecraftDir", "gameVersion", "neoforgeVersion", "javaPath", "onProgress"],
+        );
+        
+
+@override Future<InstallPlan> crateApiDependencyInstallPlanDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_install_plan,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDependencyInstallPlanDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDependencyInstallPlanDefaultConstMeta => const TaskConstMeta(
+            debugName: "install_plan_default",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiQuiltInstallQuilt({required String minecraftDir , required String gameVersion , required String loaderVersion , required FutureOr<void> Function(double, String) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_String(loaderVersion, serializer);
+sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiQuiltInstallQuiltConstMeta,
+            argValues: [minecraftDir, gameVersion, loaderVersion, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQuiltInstallQuiltConstMeta => const TaskConstMeta(
+            debugName: "install_quilt",
+            argNames: ["minecraftDir", "gameVersion", "loaderVersion", "onProgress"],
+        );
+        
+
+@override Future<List<String>> crateApiLibrariesInstallVersionLibraries({required String versionJsonPath , required String librariesDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(versionJsonPath, serializer);
+sse_encode_String(librariesDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibrariesInstallVersionLibrariesConstMeta,
+            argValues: [versionJsonPath, librariesDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibrariesInstallVersionLibrariesConstMeta => const TaskConstMeta(
+            debugName: "install_version_libraries",
+            argNames: ["versionJsonPath", "librariesDir"],
+        );
+        
+
+@override Future<void> crateApiGameProcessKillGame({required String processId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(processId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGameProcessKillGameConstMeta,
+            argValues: [processId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGameProcessKillGameConstMeta => const TaskConstMeta(
+            debugName: "kill_game",
+            argNames: ["processId"],
+        );
+        
+
+@override Future<License> crateApiModrinthLicenseDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_license,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthLicenseDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthLicenseDefaultConstMeta => const TaskConstMeta(
+            debugName: "license_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<LoaderVersion>> crateApiFabricListFabricLoaders({required String gameVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gameVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loader_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFabricListFabricLoadersConstMeta,
+            argValues: [gameVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFabricListFabricLoadersConstMeta => const TaskConstMeta(
+            debugName: "list_fabric_loaders",
+            argNames: ["gameVersion"],
+        );
+        
+
+@override Future<List<LoaderVersion>> crateApiForgeListForgeVersions({required String gameVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gameVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loader_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiForgeListForgeVersionsConstMeta,
+            argValues: [gameVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiForgeListForgeVersionsConstMeta => const TaskConstMeta(
+            debugName: "list_forge_versions",
+            argNames: ["gameVersion"],
+        );
+        
+
+@override Future<List<GameProcessInfo>> crateApiGameProcessListGameProcesses()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_game_process_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGameProcessListGameProcessesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGameProcessListGameProcessesConstMeta => const TaskConstMeta(
+            debugName: "list_game_processes",
+            argNames: [],
+        );
+        
+
+@override Future<List<ContentRecord>> crateApiDatabaseListInstanceContent({required String instanceId , String? projectType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(instanceId, serializer);
+sse_encode_opt_String(projectType, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_content_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseListInstanceContentConstMeta,
+            argValues: [instanceId, projectType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseListInstanceContentConstMeta => const TaskConstMeta(
+            debugName: "list_instance_content",
+            argNames: ["instanceId", "projectType"],
+        );
+        
+
+@override Future<List<InstanceManifest>> crateApiInstanceListInstances({required String resourceDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceListInstancesConstMeta,
+            argValues: [resourceDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceListInstancesConstMeta => const TaskConstMeta(
+            debugName: "list_instances",
+            argNames: ["resourceDir"],
+        );
+        
+
+@override Future<List<JavaRuntimeVersion>> crateApiDatabaseListJavaRuntimes({int? majorVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_i_32(majorVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_java_runtime_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseListJavaRuntimesConstMeta,
+            argValues: [majorVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseListJavaRuntimesConstMeta => const TaskConstMeta(
+            debugName: "list_java_runtimes",
+            argNames: ["majorVersion"],
+        );
+        
+
+@override Future<List<LoaderVersion>> crateApiModLoaderListLoaderVersions({required ModLoader loader , required String gameVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_mod_loader(loader, serializer);
+sse_encode_String(gameVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loader_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModLoaderListLoaderVersionsConstMeta,
+            argValues: [loader, gameVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModLoaderListLoaderVersionsConstMeta => const TaskConstMeta(
+            debugName: "list_loader_versions",
+            argNames: ["loader", "gameVersion"],
+        );
+        
+
+@override Future<List<LoaderVersion>> crateApiNeoforgeListNeoforgeVersions({required String gameVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gameVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loader_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNeoforgeListNeoforgeVersionsConstMeta,
+            argValues: [gameVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNeoforgeListNeoforgeVersionsConstMeta => const TaskConstMeta(
+            debugName: "list_neoforge_versions",
+            argNames: ["gameVersion"],
+        );
+        
+
+@override Future<List<PlaySession>> crateApiPlaySessionListPlaySessions({String? instanceId , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(instanceId, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_play_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPlaySessionListPlaySessionsConstMeta,
+            argValues: [instanceId, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPlaySessionListPlaySessionsConstMeta => const TaskConstMeta(
+            debugName: "list_play_sessions",
+            argNames: ["instanceId", "limit"],
+        );
+        
+
+@override Future<List<LoaderVersion>> crateApiQuiltListQuiltLoaders({required String gameVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(gameVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loader_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiQuiltListQuiltLoadersConstMeta,
+            argValues: [gameVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQuiltListQuiltLoadersConstMeta => const TaskConstMeta(
+            debugName: "list_quilt_loaders",
+            argNames: ["gameVersion"],
+        );
+        
+
+@override Future<List<UpdateBackup>> crateApiContentUpdateListUpdateBackups({required String resourceDir , required String instanceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_update_backup,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentUpdateListUpdateBackupsConstMeta,
+            argValues: [resourceDir, instanceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentUpdateListUpdateBackupsConstMeta => const TaskConstMeta(
+            debugName: "list_update_backups",
+            argNames: ["resourceDir", "instanceId"],
+        );
+        
+
+@override Future<LoaderTag> crateApiModrinthLoaderTagDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_loader_tag,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthLoaderTagDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthLoaderTagDefaultConstMeta => const TaskConstMeta(
+            debugName: "loader_tag_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<CategoryTag>> crateApiModrinthModrinthGetCategories()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_category_tag,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetCategoriesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetCategoriesConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_categories",
+            argNames: [],
+        );
+        
+
+@override Future<List<GameVersionTag>> crateApiModrinthModrinthGetGameVersions()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_game_version_tag,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetGameVersionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetGameVersionsConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_game_versions",
+            argNames: [],
+        );
+        
+
+@override Future<Uint8List> crateApiModrinthCacheModrinthGetIcon({required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthCacheModrinthGetIconConstMeta,
+            argValues: [url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthCacheModrinthGetIconConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_icon",
+            argNames: ["url"],
+        );
+        
+
+@override Future<List<LoaderTag>> crateApiModrinthModrinthGetLoaders()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_loader_tag,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetLoadersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetLoadersConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_loaders",
+            argNames: [],
+        );
+        
+
+@override Future<Project> crateApiModrinthModrinthGetProject({required String idOrSlug })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(idOrSlug, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_project,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetProjectConstMeta,
+            argValues: [idOrSlug],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetProjectConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_project",
+            argNames: ["idOrSlug"],
+        );
+        
+
+@override Future<List<TeamMember>> crateApiModrinthModrinthGetProjectMembers({required String idOrSlug })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(idOrSlug, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_team_member,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetProjectMembersConstMeta,
+            argValues: [idOrSlug],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetProjectMembersConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_project_members",
+            argNames: ["idOrSlug"],
+        );
+        
+
+@override Future<List<Version>> crateApiModrinthModrinthGetProjectVersions({required String idOrSlug , required List<String> loaders , required List<String> gameVersions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(idOrSlug, serializer);
+sse_encode_list_String(loaders, serializer);
+sse_encode_list_String(gameVersions, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetProjectVersionsConstMeta,
+            argValues: [idOrSlug, loaders, gameVersions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetProjectVersionsConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_project_versions",
+            argNames: ["idOrSlug", "loaders", "gameVersions"],
+        );
+        
+
+@override Future<List<Project>> crateApiModrinthModrinthGetProjects({required List<String> ids })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(ids, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_project,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetProjectsConstMeta,
+            argValues: [ids],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetProjectsConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_projects",
+            argNames: ["ids"],
+        );
+        
+
+@override Future<List<TeamMember>> crateApiModrinthModrinthGetTeamMembers({required String teamId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(teamId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_team_member,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetTeamMembersConstMeta,
+            argValues: [teamId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetTeamMembersConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_team_members",
+            argNames: ["teamId"],
+        );
+        
+
+@override Future<Version> crateApiModrinthModrinthGetVersion({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetVersionConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetVersionConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_version",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Version> crateApiModrinthModrinthGetVersionFromHash({required String hash , required HashAlgorithm algorithm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(hash, serializer);
+sse_encode_hash_algorithm(algorithm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetVersionFromHashConstMeta,
+            argValues: [hash, algorithm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetVersionFromHashConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_version_from_hash",
+            argNames: ["hash", "algorithm"],
+        );
+        
+
+@override Future<List<Version>> crateApiModrinthModrinthGetVersions({required List<String> ids })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(ids, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetVersionsConstMeta,
+            argValues: [ids],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetVersionsConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_versions",
+            argNames: ["ids"],
+        );
+        
+
+@override Future<Map<String, Version>> crateApiModrinthModrinthGetVersionsFromHashes({required List<String> hashes , required HashAlgorithm algorithm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(hashes, serializer);
+sse_encode_hash_algorithm(algorithm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Map_String_version_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthGetVersionsFromHashesConstMeta,
+            argValues: [hashes, algorithm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthGetVersionsFromHashesConstMeta => const TaskConstMeta(
+            debugName: "modrinth_get_versions_from_hashes",
+            argNames: ["hashes", "algorithm"],
+        );
+        
+
+@override Future<bool> crateApiModrinthCacheModrinthIsOffline()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthCacheModrinthIsOfflineConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthCacheModrinthIsOfflineConstMeta => const TaskConstMeta(
+            debugName: "modrinth_is_offline",
+            argNames: [],
+        );
+        
+
+@override Future<RateLimitStatus?> crateApiModrinthModrinthRateLimitStatus()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_rate_limit_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthRateLimitStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthRateLimitStatusConstMeta => const TaskConstMeta(
+            debugName: "modrinth_rate_limit_status",
+            argNames: [],
+        );
+        
+
+@override Future<SearchResults> crateApiModrinthModrinthSearch({required SearchQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_search_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_results,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthSearchConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthSearchConstMeta => const TaskConstMeta(
+            debugName: "modrinth_search",
+            argNames: ["query"],
+        );
+        
+
+@override Future<ModrinthUser> crateApiModrinthModrinthUserDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_modrinth_user,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthModrinthUserDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthModrinthUserDefaultConstMeta => const TaskConstMeta(
+            debugName: "modrinth_user_default",
+            argNames: [],
+        );
+        
+
+@override Future<Project> crateApiModrinthProjectDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_project,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthProjectDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthProjectDefaultConstMeta => const TaskConstMeta(
+            debugName: "project_default",
+            argNames: [],
+        );
+        
+
+@override Future<MrpackInfo> crateApiModpackReadMrpack({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_mrpack_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModpackReadMrpackConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModpackReadMrpackConstMeta => const TaskConstMeta(
+            debugName: "read_mrpack",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<PlaySession>> crateApiPlaySessionReattachPlaySessions({required String resourceDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_play_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPlaySessionReattachPlaySessionsConstMeta,
+            argValues: [resourceDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPlaySessionReattachPlaySessionsConstMeta => const TaskConstMeta(
+            debugName: "reattach_play_sessions",
+            argNames: ["resourceDir"],
+        );
+        
+
+@override Future<int> crateApiDatabaseRebuildInstanceIndex({required String resourceDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseRebuildInstanceIndexConstMeta,
+            argValues: [resourceDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseRebuildInstanceIndexConstMeta => const TaskConstMeta(
+            debugName: "rebuild_instance_index",
+            argNames: ["resourceDir"],
+        );
+        
+
+@override Future<void> crateApiDatabaseRemoveJavaRuntime({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseRemoveJavaRuntimeConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseRemoveJavaRuntimeConstMeta => const TaskConstMeta(
+            debugName: "remove_java_runtime",
+            argNames: ["path"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceRenameInstance({required String resourceDir , required String id , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(id, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceRenameInstanceConstMeta,
+            argValues: [resourceDir, id, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceRenameInstanceConstMeta => const TaskConstMeta(
+            debugName: "rename_instance",
+            argNames: ["resourceDir", "id", "name"],
+        );
+        
+
+@override Future<InstallPlan> crateApiDependencyResolveContentInstall({required String resourceDir , required String instanceId , required String versionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(versionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_install_plan,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDependencyResolveContentInstallConstMeta,
+            argValues: [resourceDir, instanceId, versionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDependencyResolveContentInstallConstMeta => const TaskConstMeta(
+            debugName: "resolve_content_install",
+            argNames: ["resourceDir", "instanceId", "versionId"],
+        );
+        
+
+@override Future<String?> crateApiModLoaderResolveLaunchProfile({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_mod_loader(loader, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_opt_String(loaderVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModLoaderResolveLaunchProfileConstMeta,
+            argValues: [minecraftDir, loader, gameVersion, loaderVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModLoaderResolveLaunchProfileConstMeta => const TaskConstMeta(
+            debugName: "resolve_launch_profile",
+            argNames: ["minecraftDir", "loader", "gameVersion", "loaderVersion"],
+        );
+        
+
+@override Future<void> crateApiContentUpdateRollbackContentUpdate({required String resourceDir , required String instanceId , required String backupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(backupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentUpdateRollbackContentUpdateConstMeta,
+            argValues: [resourceDir, instanceId, backupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentUpdateRollbackContentUpdateConstMeta => const TaskConstMeta(
+            debugName: "rollback_content_update",
+            argNames: ["resourceDir", "instanceId", "backupId"],
+        );
+        
+
+@override Future<InstanceManifest> crateApiInstanceSaveInstance({required String resourceDir , required InstanceManifest manifest })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_box_autoadd_instance_manifest(manifest, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_instance_manifest,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceSaveInstanceConstMeta,
+            argValues: [resourceDir, manifest],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceSaveInstanceConstMeta => const TaskConstMeta(
+            debugName: "save_instance",
+            argNames: ["resourceDir", "manifest"],
+        );
+        
+
+@override Future<void> crateApiDatabaseSaveJavaRuntimes({required List<JavaRuntimeVersion> runtimes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_java_runtime_version(runtimes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseSaveJavaRuntimesConstMeta,
+            argValues: [runtimes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseSaveJavaRuntimesConstMeta => const TaskConstMeta(
+            debugName: "save_java_runtimes",
+            argNames: ["runtimes"],
+        );
+        
+
+@override Future<List<ImportCandidate>> crateApiInstanceImportScanCurseforgeInstances({required String instancesDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(instancesDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_import_candidate,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceImportScanCurseforgeInstancesConstMeta,
+            argValues: [instancesDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceImportScanCurseforgeInstancesConstMeta => const TaskConstMeta(
+            debugName: "scan_curseforge_instances",
+            argNames: ["instancesDir"],
+        );
+        
+
+@override Future<List<ImportCandidate>> crateApiInstanceImportScanHmclVersions({required String minecraftDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_import_candidate,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceImportScanHmclVersionsConstMeta,
+            argValues: [minecraftDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceImportScanHmclVersionsConstMeta => const TaskConstMeta(
+            debugName: "scan_hmcl_versions",
+            argNames: ["minecraftDir"],
+        );
+        
+
+@override Future<List<ScannedContent>> crateApiContentScanScanInstanceContent({required String resourceDir , required String instanceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_scanned_content,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentScanScanInstanceContentConstMeta,
+            argValues: [resourceDir, instanceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentScanScanInstanceContentConstMeta => const TaskConstMeta(
+            debugName: "scan_instance_content",
+            argNames: ["resourceDir", "instanceId"],
+        );
+        
+
+@override Future<List<ImportCandidate>> crateApiInstanceImportScanMultimcInstances({required String instancesDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(instancesDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_import_candidate,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceImportScanMultimcInstancesConstMeta,
+            argValues: [instancesDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceImportScanMultimcInstancesConstMeta => const TaskConstMeta(
+            debugName: "scan_multimc_instances",
+            argNames: ["instancesDir"],
+        );
+        
+
+@override Future<List<ImportCandidate>> crateApiInstanceImportScanVanillaLauncher({required String minecraftDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_import_candidate,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiInstanceImportScanVanillaLauncherConstMeta,
+            argValues: [minecraftDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstanceImportScanVanillaLauncherConstMeta => const TaskConstMeta(
+            debugName: "scan_vanilla_launcher",
+            argNames: ["minecraftDir"],
+        );
+        
+
+@override Future<SearchHit> crateApiModrinthSearchHitDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_hit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthSearchHitDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthSearchHitDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_hit_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiDatabaseSearchInstances({required String query , required InstanceSort sort , required bool descending })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_instance_sort(sort, serializer);
+sse_encode_bool(descending, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseSearchInstancesConstMeta,
+            argValues: [query, sort, descending],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseSearchInstancesConstMeta => const TaskConstMeta(
+            debugName: "search_instances",
+            argNames: ["query", "sort", "descending"],
+        );
+        
+
+@override Future<SearchQuery> crateApiModrinthSearchQueryDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_query,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthSearchQueryDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthSearchQueryDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_query_default",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiContentSetContentEnabled({required String resourceDir , required String instanceId , required String path , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(path, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentSetContentEnabledConstMeta,
+            argValues: [resourceDir, instanceId, path, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentSetContentEnabledConstMeta => const TaskConstMeta(
+            debugName: "set_content_enabled",
+            argNames: ["resourceDir", "instanceId", "path", "enabled"],
+        );
+        
+
+@override Future<List<String>> crateApiContentSetContentEnabledByTag({required String resourceDir , required String instanceId , required String tag , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_String(tag, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiContentSetContentEnabledByTagConstMeta,
+            argValues: [resourceDir, instanceId, tag, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiContentSetContentEnabledByTagConstMeta => const TaskConstMeta(
+            debugName: "set_content_enabled_by_tag",
+            argNames: ["resourceDir", "instanceId", "tag", "enabled"],
+        );
+        
+
+@override Future<void> crateApiDatabaseSetContentTags({required String instanceId , required String path , required List<String> tags })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(instanceId, serializer);
+sse_encode_String(path, serializer);
+sse_encode_list_String(tags, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseSetContentTagsConstMeta,
+            argValues: [instanceId, path, tags],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseSetContentTagsConstMeta => const TaskConstMeta(
+            debugName: "set_content_tags",
+            argNames: ["instanceId", "path", "tags"],
+        );
+        
+
+@override Future<void> crateApiModrinthCacheSetModrinthOffline({required bool offline })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(offline, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthCacheSetModrinthOfflineConstMeta,
+            argValues: [offline],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthCacheSetModrinthOfflineConstMeta => const TaskConstMeta(
+            debugName: "set_modrinth_offline",
+            argNames: ["offline"],
+        );
+        
+
+@override Future<void> crateApiLibrariesSetStrictLibraryVerification({required bool strict })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(strict, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLibrariesSetStrictLibraryVerificationConstMeta,
+            argValues: [strict],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibrariesSetStrictLibraryVerificationConstMeta => const TaskConstMeta(
+            debugName: "set_strict_library_verification",
+            argNames: ["strict"],
+        );
+        
+
+@override Stream<GameLogEvent> crateApiGameProcessStartGame({required LaunchCommand command , required FutureOr<void> Function(GameProcessInfo) onExit })  { 
+            final logSink = RustStreamSink<GameLogEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_launch_command(command, serializer);
+sse_encode_StreamSink_game_log_event_Sse(logSink, serializer);
+sse_encode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(onExit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_game_process_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGameProcessStartGameConstMeta,
+            argValues: [command, logSink, onExit],
+            apiImpl: this,
+        )));
+            return logSink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiGameProcessStartGameConstMeta => const TaskConstMeta(
+            debugName: "start_game",
+            argNames: ["command", "logSink", "onExit"],
+        );
+        
+
+@override Stream<GameLogEvent> crateApiPlaySessionStartInstanceGame({required String resourceDir , required String instanceId , required LaunchCommand command , required String javaVersion , required FutureOr<void> Function(GameProcessInfo) onExit })  { 
+            final logSink = RustStreamSink<GameLogEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(resourceDir, serializer);
+sse_encode_String(instanceId, serializer);
+sse_encode_box_autoadd_launch_command(command, serializer);
+sse_encode_String(javaVersion, serializer);
+sse_encode_StreamSink_game_log_event_Sse(logSink, serializer);
+sse_encode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(onExit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_game_process_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPlaySessionStartInstanceGameConstMeta,
+            argValues: [resourceDir, instanceId, command, javaVersion, logSink, onExit],
+            apiImpl: this,
+        )));
+            return logSink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiPlaySessionStartInstanceGameConstMeta => const TaskConstMeta(
+            debugName: "start_instance_game",
+            argNames: ["resourceDir", "instanceId", "command", "javaVersion", "logSink", "onExit"],
+        );
+        
+
+@override Future<void> crateApiGameProcessStopGame({required String processId , BigInt? timeoutSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(processId, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiGameProcessStopGameConstMeta,
+            argValues: [processId, timeoutSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGameProcessStopGameConstMeta => const TaskConstMeta(
+            debugName: "stop_game",
+            argNames: ["processId", "timeoutSecs"],
+        );
+        
+
+@override Future<TeamMember> crateApiModrinthTeamMemberDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_team_member,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthTeamMemberDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthTeamMemberDefaultConstMeta => const TaskConstMeta(
+            debugName: "team_member_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiJavaDownloadTestJre({required String javaPath , required int expectedMajorVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(javaPath, serializer);
+sse_encode_i_32(expectedMajorVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiJavaDownloadTestJreConstMeta,
+            argValues: [javaPath, expectedMajorVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiJavaDownloadTestJreConstMeta => const TaskConstMeta(
+            debugName: "test_jre",
+            argNames: ["javaPath", "expectedMajorVersion"],
+        );
+        
+
+@override Future<void> crateApiModLoaderUninstallLoader({required String minecraftDir , required ModLoader loader , required String gameVersion , String? loaderVersion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(minecraftDir, serializer);
+sse_encode_mod_loader(loader, serializer);
+sse_encode_String(gameVersion, serializer);
+sse_encode_opt_String(loaderVersion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiModLoaderUninstallLoaderConstMeta,
+            argValues: [minecraftDir, loader, gameVersion, loaderVersion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModLoaderUninstallLoaderConstMeta => const TaskConstMeta(
+            debugName: "uninstall_loader",
+            argNames: ["minecraftDir", "loader", "gameVersion", "loaderVersion"],
+        );
+        
+
+@override Future<Version> crateApiModrinthVersionDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_version,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthVersionDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthVersionDefaultConstMeta => const TaskConstMeta(
+            debugName: "version_default",
+            argNames: [],
+        );
+        
+
+@override Future<VersionDependency> crateApiModrinthVersionDependencyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_version_dependency,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthVersionDependencyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthVersionDependencyDefaultConstMeta => const TaskConstMeta(
+            debugName: "version_dependency_default",
+            argNames: [],
+        );
+        
+
+@override Future<VersionFile> crateApiModrinthVersionFileDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_version_file,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModrinthVersionFileDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModrinthVersionFileDefaultConstMeta => const TaskConstMeta(
+            debugName: "version_file_default",
+            argNames: [],
+        );
+        
+
+
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(FutureOr<void> Function(bool, String?) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_bool(rawArg0);
+final arg1 = dco_decode_opt_String(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(FutureOr<void> Function(double, String) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_f_64(rawArg0);
+final arg1 = dco_decode_String(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(FutureOr<void> Function(GameProcessInfo) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_game_process_info(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected DateTime dco_decode_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true); }
+
+@protected FutureOr<void> Function(bool, String?) dco_decode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(double, String) dco_decode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(GameProcessInfo) dco_decode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected Map<String, Version> dco_decode_Map_String_version_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_version(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<GameLogEvent> dco_decode_StreamSink_game_log_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected VersionSource dco_decode_TraitDef_VersionSource(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected Account dco_decode_account(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Account(username: dco_decode_String(arr[0]),
+uuid: dco_decode_String(arr[1]),
+accessToken: dco_decode_String(arr[2]),
+userType: dco_decode_String(arr[3]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Chrono_Utc(raw); }
+
+@protected ContentType dco_decode_box_autoadd_content_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_content_type(raw); }
+
+@protected CrashDiagnosis dco_decode_box_autoadd_crash_diagnosis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_crash_diagnosis(raw); }
+
+@protected EmbeddedMetadata dco_decode_box_autoadd_embedded_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_embedded_metadata(raw); }
+
+@protected ExportOptions dco_decode_box_autoadd_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_export_options(raw); }
+
+@protected GameProcessInfo dco_decode_box_autoadd_game_process_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_game_process_info(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected ImportCandidate dco_decode_box_autoadd_import_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_import_candidate(raw); }
+
+@protected InstallPlan dco_decode_box_autoadd_install_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_install_plan(raw); }
+
+@protected InstanceManifest dco_decode_box_autoadd_instance_manifest(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_instance_manifest(raw); }
+
+@protected JavaRuntimeVersion dco_decode_box_autoadd_java_runtime_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_java_runtime_version(raw); }
+
+@protected LaunchCommand dco_decode_box_autoadd_launch_command(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_launch_command(raw); }
+
+@protected LaunchOptions dco_decode_box_autoadd_launch_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_launch_options(raw); }
+
+@protected ModLoader dco_decode_box_autoadd_mod_loader(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_mod_loader(raw); }
+
+@protected MrpackExportOptions dco_decode_box_autoadd_mrpack_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_mrpack_export_options(raw); }
+
+@protected NewInstance dco_decode_box_autoadd_new_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_new_instance(raw); }
+
+@protected RateLimitStatus dco_decode_box_autoadd_rate_limit_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rate_limit_status(raw); }
+
+@protected Resolution dco_decode_box_autoadd_resolution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_resolution(raw); }
+
+@protected SearchQuery dco_decode_box_autoadd_search_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_query(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected CategoryTag dco_decode_category_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CategoryTag(name: dco_decode_String(arr[0]),
+projectType: dco_decode_String(arr[1]),
+header: dco_decode_String(arr[2]),
+icon: dco_decode_String(arr[3]),); }
+
+@protected CompatIssue dco_decode_compat_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CompatIssue(severity: dco_decode_compat_severity(arr[0]),
+kind: dco_decode_compat_issue_kind(arr[1]),
+modId: dco_decode_opt_String(arr[2]),
+file: dco_decode_opt_String(arr[3]),
+message: dco_decode_String(arr[4]),
+suggestion: dco_decode_String(arr[5]),); }
+
+@protected CompatIssueKind dco_decode_compat_issue_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CompatIssueKind.values[raw as int]; }
+
+@protected CompatReport dco_decode_compat_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CompatReport(checkedMods: dco_decode_usize(arr[0]),
+issues: dco_decode_list_compat_issue(arr[1]),); }
+
+@protected CompatSeverity dco_decode_compat_severity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CompatSeverity.values[raw as int]; }
+
+@protected ConflictKind dco_decode_conflict_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConflictKind.values[raw as int]; }
+
+@protected ContentRecord dco_decode_content_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return ContentRecord(instanceId: dco_decode_String(arr[0]),
+path: dco_decode_String(arr[1]),
+projectType: dco_decode_String(arr[2]),
+projectId: dco_decode_opt_String(arr[3]),
+versionId: dco_decode_opt_String(arr[4]),
+sha1: dco_decode_String(arr[5]),
+sha512: dco_decode_opt_String(arr[6]),
+fileSize: dco_decode_u_64(arr[7]),
+enabled: dco_decode_bool(arr[8]),
+installedAt: dco_decode_Chrono_Utc(arr[9]),
+tags: dco_decode_list_String(arr[10]),); }
+
+@protected ContentType dco_decode_content_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ContentType.values[raw as int]; }
+
+@protected ContentUpdate dco_decode_content_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return ContentUpdate(path: dco_decode_String(arr[0]),
+contentType: dco_decode_content_type(arr[1]),
+projectId: dco_decode_String(arr[2]),
+title: dco_decode_opt_String(arr[3]),
+currentVersionId: dco_decode_opt_String(arr[4]),
+currentVersionNumber: dco_decode_opt_String(arr[5]),
+newVersionId: dco_decode_String(arr[6]),
+newVersionNumber: dco_decode_String(arr[7]),
+versionType: dco_decode_String(arr[8]),
+changelog: dco_decode_opt_String(arr[9]),
+datePublished: dco_decode_opt_box_autoadd_Chrono_Utc(arr[10]),); }
+
+@protected CrashCause dco_decode_crash_cause(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CrashCause.values[raw as int]; }
+
+@protected CrashDiagnosis dco_decode_crash_diagnosis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CrashDiagnosis(kind: dco_decode_crash_file_kind(arr[0]),
+filePath: dco_decode_opt_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+suspectedMods: dco_decode_list_String(arr[3]),
+stackTrace: dco_decode_opt_String(arr[4]),
+causes: dco_decode_list_matched_cause(arr[5]),); }
+
+@protected CrashFileKind dco_decode_crash_file_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CrashFileKind.values[raw as int]; }
+
+@protected DependencyConflict dco_decode_dependency_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DependencyConflict(kind: dco_decode_conflict_kind(arr[0]),
+projectId: dco_decode_String(arr[1]),
+sourceProjectId: dco_decode_String(arr[2]),
+message: dco_decode_String(arr[3]),); }
+
+@protected EmbeddedMetadata dco_decode_embedded_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return EmbeddedMetadata(loader: dco_decode_opt_box_autoadd_mod_loader(arr[0]),
+modId: dco_decode_opt_String(arr[1]),
+name: dco_decode_opt_String(arr[2]),
+version: dco_decode_opt_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+authors: dco_decode_list_String(arr[5]),); }
+
+@protected ExportOptions dco_decode_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ExportOptions(include: dco_decode_list_String(arr[0]),
+exclude: dco_decode_list_String(arr[1]),
+referenceDownloads: dco_decode_bool(arr[2]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileHashes dco_decode_file_hashes(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FileHashes(sha1: dco_decode_String(arr[0]),
+sha512: dco_decode_String(arr[1]),); }
+
+@protected GalleryImage dco_decode_gallery_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return GalleryImage(url: dco_decode_String(arr[0]),
+featured: dco_decode_bool(arr[1]),
+title: dco_decode_opt_String(arr[2]),
+description: dco_decode_opt_String(arr[3]),); }
+
+@protected GameLogEvent dco_decode_game_log_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return GameLogEvent(processId: dco_decode_String(arr[0]),
+source: dco_decode_log_source(arr[1]),
+timestamp: dco_decode_opt_box_autoadd_Chrono_Utc(arr[2]),
+level: dco_decode_log_level(arr[3]),
+thread: dco_decode_opt_String(arr[4]),
+logger: dco_decode_opt_String(arr[5]),
+message: dco_decode_String(arr[6]),
+throwable: dco_decode_opt_String(arr[7]),
+structured: dco_decode_bool(arr[8]),); }
+
+@protected GameProcessInfo dco_decode_game_process_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return GameProcessInfo(processId: dco_decode_String(arr[0]),
+pid: dco_decode_u_32(arr[1]),
+startTime: dco_decode_Chrono_Utc(arr[2]),
+endTime: dco_decode_opt_box_autoadd_Chrono_Utc(arr[3]),
+exitCode: dco_decode_opt_box_autoadd_i_32(arr[4]),
+running: dco_decode_bool(arr[5]),); }
+
+@protected GameVersionTag dco_decode_game_version_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return GameVersionTag(version: dco_decode_String(arr[0]),
+versionType: dco_decode_String(arr[1]),
+date: dco_decode_opt_box_autoadd_Chrono_Utc(arr[2]),
+major: dco_decode_bool(arr[3]),); }
+
+@protected HashAlgorithm dco_decode_hash_algorithm(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HashAlgorithm.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImportCandidate dco_decode_import_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ImportCandidate(source: dco_decode_import_source(arr[0]),
+location: dco_decode_String(arr[1]),
+profileKey: dco_decode_opt_String(arr[2]),
+name: dco_decode_String(arr[3]),
+gameVersion: dco_decode_String(arr[4]),
+loader: dco_decode_mod_loader(arr[5]),
+loaderVersion: dco_decode_opt_String(arr[6]),
+gameDir: dco_decode_String(arr[7]),); }
+
+@protected ImportMode dco_decode_import_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ImportMode.values[raw as int]; }
+
+@protected ImportSource dco_decode_import_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ImportSource.values[raw as int]; }
+
+@protected InstallPlan dco_decode_install_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return InstallPlan(install: dco_decode_list_planned_install(arr[0]),
+upgrade: dco_decode_list_planned_upgrade(arr[1]),
+conflicts: dco_decode_list_dependency_conflict(arr[2]),
+optional: dco_decode_list_String(arr[3]),); }
+
+@protected InstanceManifest dco_decode_instance_manifest(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+                return InstanceManifest(formatVersion: dco_decode_usize(arr[0]),
+id: dco_decode_String(arr[1]),
+name: dco_decode_String(arr[2]),
+gameVersion: dco_decode_String(arr[3]),
+loader: dco_decode_mod_loader(arr[4]),
+loaderVersion: dco_decode_opt_String(arr[5]),
+javaPath: dco_decode_opt_String(arr[6]),
+maxMemoryMb: dco_decode_opt_box_autoadd_u_32(arr[7]),
+minMemoryMb: dco_decode_opt_box_autoadd_u_32(arr[8]),
+jvmArgs: dco_decode_list_String(arr[9]),
+resolution: dco_decode_opt_box_autoadd_resolution(arr[10]),
+icon: dco_decode_opt_String(arr[11]),
+playTimeSecs: dco_decode_u_64(arr[12]),
+created: dco_decode_Chrono_Utc(arr[13]),
+lastPlayed: dco_decode_opt_box_autoadd_Chrono_Utc(arr[14]),
+path: dco_decode_String(arr[15]),); }
+
+@protected InstanceSort dco_decode_instance_sort(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InstanceSort.values[raw as int]; }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected JavaRuntimeVersion dco_decode_java_runtime_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return JavaRuntimeVersion(version: dco_decode_String(arr[0]),
+path: dco_decode_String(arr[1]),
+majorVersion: dco_decode_i_32(arr[2]),); }
+
+@protected LaunchCommand dco_decode_launch_command(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LaunchCommand(javaPath: dco_decode_String(arr[0]),
+args: dco_decode_list_String(arr[1]),
+workingDir: dco_decode_String(arr[2]),
+nativesDir: dco_decode_opt_String(arr[3]),); }
+
+@protected LaunchOptions dco_decode_launch_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return LaunchOptions(versionJsonPath: dco_decode_String(arr[0]),
+minecraftDir: dco_decode_String(arr[1]),
+gameDir: dco_decode_String(arr[2]),
+nativesDir: dco_decode_String(arr[3]),
+java: dco_decode_java_runtime_version(arr[4]),
+account: dco_decode_account(arr[5]),
+maxMemoryMb: dco_decode_opt_box_autoadd_u_32(arr[6]),
+minMemoryMb: dco_decode_opt_box_autoadd_u_32(arr[7]),
+resolution: dco_decode_opt_box_autoadd_resolution(arr[8]),
+extraJvmArgs: dco_decode_list_String(arr[9]),
+extraGameArgs: dco_decode_list_String(arr[10]),); }
+
+@protected License dco_decode_license(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return License(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+url: dco_decode_opt_String(arr[2]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<CategoryTag> dco_decode_list_category_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_category_tag).toList(); }
+
+@protected List<CompatIssue> dco_decode_list_compat_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_compat_issue).toList(); }
+
+@protected List<ContentRecord> dco_decode_list_content_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_content_record).toList(); }
+
+@protected List<ContentUpdate> dco_decode_list_content_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_content_update).toList(); }
+
+@protected List<DependencyConflict> dco_decode_list_dependency_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_dependency_conflict).toList(); }
+
+@protected List<GalleryImage> dco_decode_list_gallery_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_gallery_image).toList(); }
+
+@protected List<GameProcessInfo> dco_decode_list_game_process_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_game_process_info).toList(); }
+
+@protected List<GameVersionTag> dco_decode_list_game_version_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_game_version_tag).toList(); }
+
+@protected List<ImportCandidate> dco_decode_list_import_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_import_candidate).toList(); }
+
+@protected List<InstanceManifest> dco_decode_list_instance_manifest(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_instance_manifest).toList(); }
+
+@protected List<JavaRuntimeVersion> dco_decode_list_java_runtime_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_java_runtime_version).toList(); }
+
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_String).toList(); }
+
+@protected List<LoaderTag> dco_decode_list_loader_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_loader_tag).toList(); }
+
+@protected List<LoaderVersion> dco_decode_list_loader_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_loader_version).toList(); }
+
+@protected List<MatchedCause> dco_decode_list_matched_cause(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_matched_cause).toList(); }
+
+@protected List<MrpackOptionalFile> dco_decode_list_mrpack_optional_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mrpack_optional_file).toList(); }
+
+@protected List<PlannedInstall> dco_decode_list_planned_install(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_planned_install).toList(); }
+
+@protected List<PlannedUpgrade> dco_decode_list_planned_upgrade(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_planned_upgrade).toList(); }
+
+@protected List<PlaySession> dco_decode_list_play_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_play_session).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<Project> dco_decode_list_project(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_project).toList(); }
+
+@protected List<(String,Version)> dco_decode_list_record_string_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_version).toList(); }
+
+@protected List<ScannedContent> dco_decode_list_scanned_content(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_scanned_content).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<TeamMember> dco_decode_list_team_member(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_team_member).toList(); }
+
+@protected List<UpdateBackup> dco_decode_list_update_backup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_update_backup).toList(); }
+
+@protected List<UpdateBackupEntry> dco_decode_list_update_backup_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_update_backup_entry).toList(); }
+
+@protected List<Version> dco_decode_list_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_version).toList(); }
+
+@protected List<VersionDependency> dco_decode_list_version_dependency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_version_dependency).toList(); }
+
+@protected List<VersionFile> dco_decode_list_version_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_version_file).toList(); }
+
+@protected List<WeeklyPlayStats> dco_decode_list_weekly_play_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_weekly_play_stats).toList(); }
+
+@protected LoaderTag dco_decode_loader_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LoaderTag(name: dco_decode_String(arr[0]),
+supportedProjectTypes: dco_decode_list_String(arr[1]),
+icon: dco_decode_String(arr[2]),); }
+
+@protected LoaderVersion dco_decode_loader_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LoaderVersion(id: dco_decode_String(arr[0]),
+stable: dco_decode_bool(arr[1]),); }
+
+@protected LogLevel dco_decode_log_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogLevel.values[raw as int]; }
+
+@protected LogSource dco_decode_log_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogSource.values[raw as int]; }
+
+@protected MatchedCause dco_decode_matched_cause(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MatchedCause(cause: dco_decode_crash_cause(arr[0]),
+ruleId: dco_decode_String(arr[1]),
+evidence: dco_decode_String(arr[2]),
+suggestion: dco_decode_String(arr[3]),); }
+
+@protected ModLoader dco_decode_mod_loader(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ModLoader.values[raw as int]; }
+
+@protected ModrinthUser dco_decode_modrinth_user(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ModrinthUser(id: dco_decode_String(arr[0]),
+username: dco_decode_String(arr[1]),
+name: dco_decode_opt_String(arr[2]),
+avatarUrl: dco_decode_opt_String(arr[3]),); }
+
+@protected MrpackExportOptions dco_decode_mrpack_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MrpackExportOptions(name: dco_decode_opt_String(arr[0]),
+versionId: dco_decode_String(arr[1]),
+summary: dco_decode_opt_String(arr[2]),
+include: dco_decode_list_String(arr[3]),
+exclude: dco_decode_list_String(arr[4]),); }
+
+@protected MrpackInfo dco_decode_mrpack_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MrpackInfo(name: dco_decode_String(arr[0]),
+versionId: dco_decode_String(arr[1]),
+summary: dco_decode_opt_String(arr[2]),
+gameVersion: dco_decode_String(arr[3]),
+loader: dco_decode_mod_loader(arr[4]),
+loaderVersion: dco_decode_opt_String(arr[5]),
+fileCount: dco_decode_u_32(arr[6]),
+optionalFiles: dco_decode_list_mrpack_optional_file(arr[7]),); }
+
+@protected MrpackOptionalFile dco_decode_mrpack_optional_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MrpackOptionalFile(path: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),); }
+
+@protected NewInstance dco_decode_new_instance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return NewInstance(name: dco_decode_String(arr[0]),
+gameVersion: dco_decode_String(arr[1]),
+loader: dco_decode_mod_loader(arr[2]),
+loaderVersion: dco_decode_opt_String(arr[3]),
+icon: dco_decode_opt_String(arr[4]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Chrono_Utc(raw); }
+
+@protected ContentType? dco_decode_opt_box_autoadd_content_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_content_type(raw); }
+
+@protected CrashDiagnosis? dco_decode_opt_box_autoadd_crash_diagnosis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_crash_diagnosis(raw); }
+
+@protected EmbeddedMetadata? dco_decode_opt_box_autoadd_embedded_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_embedded_metadata(raw); }
+
+@protected GameProcessInfo? dco_decode_opt_box_autoadd_game_process_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_game_process_info(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected JavaRuntimeVersion? dco_decode_opt_box_autoadd_java_runtime_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_java_runtime_version(raw); }
+
+@protected ModLoader? dco_decode_opt_box_autoadd_mod_loader(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_mod_loader(raw); }
+
+@protected RateLimitStatus? dco_decode_opt_box_autoadd_rate_limit_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_rate_limit_status(raw); }
+
+@protected Resolution? dco_decode_opt_box_autoadd_resolution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_resolution(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PlannedInstall dco_decode_planned_install(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PlannedInstall(projectId: dco_decode_String(arr[0]),
+versionId: dco_decode_String(arr[1]),
+title: dco_decode_String(arr[2]),
+projectType: dco_decode_String(arr[3]),
+versionNumber: dco_decode_String(arr[4]),
+requiredBy: dco_decode_opt_String(arr[5]),); }
+
+@protected PlannedUpgrade dco_decode_planned_upgrade(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PlannedUpgrade(projectId: dco_decode_String(arr[0]),
+fromVersionId: dco_decode_String(arr[1]),
+toVersionId: dco_decode_String(arr[2]),
+title: dco_decode_String(arr[3]),
+projectType: dco_decode_String(arr[4]),
+versionNumber: dco_decode_String(arr[5]),
+requiredBy: dco_decode_opt_String(arr[6]),); }
+
+@protected PlaySession dco_decode_play_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return PlaySession(id: dco_decode_i_64(arr[0]),
+instanceId: dco_decode_String(arr[1]),
+pid: dco_decode_opt_box_autoadd_u_32(arr[2]),
+javaVersion: dco_decode_opt_String(arr[3]),
+startTime: dco_decode_Chrono_Utc(arr[4]),
+endTime: dco_decode_opt_box_autoadd_Chrono_Utc(arr[5]),
+exitCode: dco_decode_opt_box_autoadd_i_32(arr[6]),
+crashed: dco_decode_bool(arr[7]),); }
+
+@protected PlayStats dco_decode_play_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PlayStats(totalPlayTimeSecs: dco_decode_u_64(arr[0]),
+sessionCount: dco_decode_u_32(arr[1]),
+crashCount: dco_decode_u_32(arr[2]),
+lastPlayed: dco_decode_opt_box_autoadd_Chrono_Utc(arr[3]),
+weekly: dco_decode_list_weekly_play_stats(arr[4]),); }
+
+@protected Project dco_decode_project(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 27) throw Exception('unexpected arr length: expect 27 but see ${arr.length}');
+                return Project(id: dco_decode_String(arr[0]),
+slug: dco_decode_String(arr[1]),
+projectType: dco_decode_String(arr[2]),
+team: dco_decode_String(arr[3]),
+title: dco_decode_String(arr[4]),
+description: dco_decode_String(arr[5]),
+body: dco_decode_String(arr[6]),
+categories: dco_decode_list_String(arr[7]),
+additionalCategories: dco_decode_list_String(arr[8]),
+clientSide: dco_decode_String(arr[9]),
+serverSide: dco_decode_String(arr[10]),
+status: dco_decode_String(arr[11]),
+downloads: dco_decode_u_64(arr[12]),
+followers: dco_decode_u_64(arr[13]),
+iconUrl: dco_decode_opt_String(arr[14]),
+color: dco_decode_opt_box_autoadd_u_32(arr[15]),
+license: dco_decode_license(arr[16]),
+sourceUrl: dco_decode_opt_String(arr[17]),
+issuesUrl: dco_decode_opt_String(arr[18]),
+wikiUrl: dco_decode_opt_String(arr[19]),
+discordUrl: dco_decode_opt_String(arr[20]),
+versions: dco_decode_list_String(arr[21]),
+gameVersions: dco_decode_list_String(arr[22]),
+loaders: dco_decode_list_String(arr[23]),
+gallery: dco_decode_list_gallery_image(arr[24]),
+published: dco_decode_opt_box_autoadd_Chrono_Utc(arr[25]),
+updated: dco_decode_opt_box_autoadd_Chrono_Utc(arr[26]),); }
+
+@protected RateLimitStatus dco_decode_rate_limit_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RateLimitStatus(remaining: dco_decode_u_32(arr[0]),
+limit: dco_decode_u_32(arr[1]),
+resetSecs: dco_decode_u_64(arr[2]),); }
+
+@protected (String,Version) dco_decode_record_string_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_version(arr[1]),); }
+
+@protected Resolution dco_decode_resolution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Resolution(width: dco_decode_u_32(arr[0]),
+height: dco_decode_u_32(arr[1]),); }
+
+@protected ScannedContent dco_decode_scanned_content(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return ScannedContent(path: dco_decode_String(arr[0]),
+fileName: dco_decode_String(arr[1]),
+contentType: dco_decode_content_type(arr[2]),
+enabled: dco_decode_bool(arr[3]),
+tags: dco_decode_list_String(arr[4]),
+size: dco_decode_u_64(arr[5]),
+sha1: dco_decode_String(arr[6]),
+sha512: dco_decode_String(arr[7]),
+projectId: dco_decode_opt_String(arr[8]),
+versionId: dco_decode_opt_String(arr[9]),
+title: dco_decode_opt_String(arr[10]),
+versionNumber: dco_decode_opt_String(arr[11]),
+iconUrl: dco_decode_opt_String(arr[12]),
+embedded: dco_decode_opt_box_autoadd_embedded_metadata(arr[13]),
+icon: dco_decode_opt_list_prim_u_8_strict(arr[14]),); }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 21) throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
+                return SearchHit(projectId: dco_decode_String(arr[0]),
+slug: dco_decode_String(arr[1]),
+title: dco_decode_String(arr[2]),
+description: dco_decode_String(arr[3]),
+projectType: dco_decode_String(arr[4]),
+author: dco_decode_String(arr[5]),
+categories: dco_decode_list_String(arr[6]),
+displayCategories: dco_decode_list_String(arr[7]),
+versions: dco_decode_list_String(arr[8]),
+clientSide: dco_decode_String(arr[9]),
+serverSide: dco_decode_String(arr[10]),
+downloads: dco_decode_u_64(arr[11]),
+follows: dco_decode_u_64(arr[12]),
+iconUrl: dco_decode_opt_String(arr[13]),
+color: dco_decode_opt_box_autoadd_u_32(arr[14]),
+latestVersion: dco_decode_opt_String(arr[15]),
+license: dco_decode_String(arr[16]),
+gallery: dco_decode_list_String(arr[17]),
+featuredGallery: dco_decode_opt_String(arr[18]),
+dateCreated: dco_decode_opt_box_autoadd_Chrono_Utc(arr[19]),
+dateModified: dco_decode_opt_box_autoadd_Chrono_Utc(arr[20]),); }
+
+@protected SearchQuery dco_decode_search_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SearchQuery(query: dco_decode_opt_String(arr[0]),
+facets: dco_decode_list_list_String(arr[1]),
+index: dco_decode_opt_String(arr[2]),
+offset: dco_decode_u_32(arr[3]),
+limit: dco_decode_u_32(arr[4]),); }
+
+@protected SearchResults dco_decode_search_results(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SearchResults(hits: dco_decode_list_search_hit(arr[0]),
+offset: dco_decode_u_32(arr[1]),
+limit: dco_decode_u_32(arr[2]),
+totalHits: dco_decode_u_32(arr[3]),); }
+
+@protected TeamMember dco_decode_team_member(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return TeamMember(teamId: dco_decode_String(arr[0]),
+user: dco_decode_modrinth_user(arr[1]),
+role: dco_decode_String(arr[2]),
+ordering: dco_decode_i_64(arr[3]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UpdateBackup dco_decode_update_backup(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UpdateBackup(id: dco_decode_String(arr[0]),
+created: dco_decode_Chrono_Utc(arr[1]),
+entries: dco_decode_list_update_backup_entry(arr[2]),); }
+
+@protected UpdateBackupEntry dco_decode_update_backup_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UpdateBackupEntry(old: dco_decode_content_record(arr[0]),
+new_: dco_decode_content_record(arr[1]),); }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected Version dco_decode_version(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return Version(id: dco_decode_String(arr[0]),
+projectId: dco_decode_String(arr[1]),
+authorId: dco_decode_String(arr[2]),
+name: dco_decode_String(arr[3]),
+versionNumber: dco_decode_String(arr[4]),
+changelog: dco_decode_opt_String(arr[5]),
+versionType: dco_decode_String(arr[6]),
+status: dco_decode_String(arr[7]),
+featured: dco_decode_bool(arr[8]),
+downloads: dco_decode_u_64(arr[9]),
+gameVersions: dco_decode_list_String(arr[10]),
+loaders: dco_decode_list_String(arr[11]),
+files: dco_decode_list_version_file(arr[12]),
+dependencies: dco_decode_list_version_dependency(arr[13]),
+datePublished: dco_decode_opt_box_autoadd_Chrono_Utc(arr[14]),); }
+
+@protected VersionDependency dco_decode_version_dependency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VersionDependency(versionId: dco_decode_opt_String(arr[0]),
+projectId: dco_decode_opt_String(arr[1]),
+fileName: dco_decode_opt_String(arr[2]),
+dependencyType: dco_decode_String(arr[3]),); }
+
+@protected VersionFile dco_decode_version_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return VersionFile(hashes: dco_decode_file_hashes(arr[0]),
+url: dco_decode_String(arr[1]),
+filename: dco_decode_String(arr[2]),
+primary: dco_decode_bool(arr[3]),
+size: dco_decode_u_64(arr[4]),
+fileType: dco_decode_opt_String(arr[5]),); }
+
+@protected WeeklyPlayStats dco_decode_weekly_play_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return WeeklyPlayStats(weekStart: dco_decode_Chrono_Utc(arr[0]),
+sessions: dco_decode_u_32(arr[1]),
+playTimeSecs: dco_decode_u_64(arr[2]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_64(deserializer);
+        return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected Map<String, Version> sse_decode_Map_String_version_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_version(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected RustStreamSink<GameLogEvent> sse_decode_StreamSink_game_log_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected Account sse_decode_account(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_username = sse_decode_String(deserializer);
+var var_uuid = sse_decode_String(deserializer);
+var var_accessToken = sse_decode_String(deserializer);
+var var_userType = sse_decode_String(deserializer);
+return Account(username: var_username, uuid: var_uuid, accessToken: var_accessToken, userType: var_userType); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Chrono_Utc(deserializer)); }
+
+@protected ContentType sse_decode_box_autoadd_content_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_content_type(deserializer)); }
+
+@protected CrashDiagnosis sse_decode_box_autoadd_crash_diagnosis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_crash_diagnosis(deserializer)); }
+
+@protected EmbeddedMetadata sse_decode_box_autoadd_embedded_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_embedded_metadata(deserializer)); }
+
+@protected ExportOptions sse_decode_box_autoadd_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_export_options(deserializer)); }
+
+@protected GameProcessInfo sse_decode_box_autoadd_game_process_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_game_process_info(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected ImportCandidate sse_decode_box_autoadd_import_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_import_candidate(deserializer)); }
+
+@protected InstallPlan sse_decode_box_autoadd_install_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_install_plan(deserializer)); }
+
+@protected InstanceManifest sse_decode_box_autoadd_instance_manifest(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_instance_manifest(deserializer)); }
+
+@protected JavaRuntimeVersion sse_decode_box_autoadd_java_runtime_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_java_runtime_version(deserializer)); }
+
+@protected LaunchCommand sse_decode_box_autoadd_launch_command(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_launch_command(deserializer)); }
+
+@protected LaunchOptions sse_decode_box_autoadd_launch_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_launch_options(deserializer)); }
+
+@protected ModLoader sse_decode_box_autoadd_mod_loader(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_mod_loader(deserializer)); }
+
+@protected MrpackExportOptions sse_decode_box_autoadd_mrpack_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_mrpack_export_options(deserializer)); }
+
+@protected NewInstance sse_decode_box_autoadd_new_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_new_instance(deserializer)); }
+
+@protected RateLimitStatus sse_decode_box_autoadd_rate_limit_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rate_limit_status(deserializer)); }
+
+@protected Resolution sse_decode_box_autoadd_resolution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_resolution(deserializer)); }
+
+@protected SearchQuery sse_decode_box_autoadd_search_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_query(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected CategoryTag sse_decode_category_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_projectType = sse_decode_String(deserializer);
+var var_header = sse_decode_String(deserializer);
+var var_icon = sse_decode_String(deserializer);
+return CategoryTag(name: var_name, projectType: var_projectType, header: var_header, icon: var_icon); }
+
+@protected CompatIssue sse_decode_compat_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_severity = sse_decode_compat_severity(deserializer);
+var var_kind = sse_decode_compat_issue_kind(deserializer);
+var var_modId = sse_decode_opt_String(deserializer);
+var var_file = sse_decode_opt_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_suggestion = sse_decode_String(deserializer);
+return CompatIssue(severity: var_severity, kind: var_kind, modId: var_modId, file: var_file, message: var_message, suggestion: var_suggestion); }
+
+@protected CompatIssueKind sse_decode_compat_issue_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CompatIssueKind.values[inner]; }
+
+@protected CompatReport sse_decode_compat_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_checkedMods = sse_decode_usize(deserializer);
+var var_issues = sse_decode_list_compat_issue(deserializer);
+return CompatReport(checkedMods: var_checkedMods, issues: var_issues); }
+
+@protected CompatSeverity sse_decode_compat_severity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CompatSeverity.values[inner]; }
+
+@protected ConflictKind sse_decode_conflict_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConflictKind.values[inner]; }
+
+@protected ContentRecord sse_decode_content_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_instanceId = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_projectType = sse_decode_String(deserializer);
+var var_projectId = sse_decode_opt_String(deserializer);
+var var_versionId = sse_decode_opt_String(deserializer);
+var var_sha1 = sse_decode_String(deserializer);
+var var_sha512 = sse_decode_opt_String(deserializer);
+var var_fileSize = sse_decode_u_64(deserializer);
+var var_enabled = sse_decode_bool(deserializer);
+var var_installedAt = sse_decode_Chrono_Utc(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+return ContentRecord(instanceId: var_instanceId, path: var_path, projectType: var_projectType, projectId: var_projectId, versionId: var_versionId, sha1: var_sha1, sha512: var_sha512, fileSize: var_fileSize, enabled: var_enabled, installedAt: var_installedAt, tags: var_tags); }
+
+@protected ContentType sse_decode_content_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ContentType.values[inner]; }
+
+@protected ContentUpdate sse_decode_content_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_contentType = sse_decode_content_type(deserializer);
+var var_projectId = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_currentVersionId = sse_decode_opt_String(deserializer);
+var var_currentVersionNumber = sse_decode_opt_String(deserializer);
+var var_newVersionId = sse_decode_String(deserializer);
+var var_newVersionNumber = sse_decode_String(deserializer);
+var var_versionType = sse_decode_String(deserializer);
+var var_changelog = sse_decode_opt_String(deserializer);
+var var_datePublished = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+return ContentUpdate(path: var_path, contentType: var_contentType, projectId: var_projectId, title: var_title, currentVersionId: var_currentVersionId, currentVersionNumber: var_currentVersionNumber, newVersionId: var_newVersionId, newVersionNumber: var_newVersionNumber, versionType: var_versionType, changelog: var_changelog, datePublished: var_datePublished); }
+
+@protected CrashCause sse_decode_crash_cause(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CrashCause.values[inner]; }
+
+@protected CrashDiagnosis sse_decode_crash_diagnosis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_crash_file_kind(deserializer);
+var var_filePath = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_suspectedMods = sse_decode_list_String(deserializer);
+var var_stackTrace = sse_decode_opt_String(deserializer);
+var var_causes = sse_decode_list_matched_cause(deserializer);
+return CrashDiagnosis(kind: var_kind, filePath: var_filePath, description: var_description, suspectedMods: var_suspectedMods, stackTrace: var_stackTrace, causes: var_causes); }
+
+@protected CrashFileKind sse_decode_crash_file_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CrashFileKind.values[inner]; }
+
+@protected DependencyConflict sse_decode_dependency_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_conflict_kind(deserializer);
+var var_projectId = sse_decode_String(deserializer);
+var var_sourceProjectId = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return DependencyConflict(kind: var_kind, projectId: var_projectId, sourceProjectId: var_sourceProjectId, message: var_message); }
+
+@protected EmbeddedMetadata sse_decode_embedded_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_loader = sse_decode_opt_box_autoadd_mod_loader(deserializer);
+var var_modId = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_version = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_authors = sse_decode_list_String(deserializer);
+return EmbeddedMetadata(loader: var_loader, modId: var_modId, name: var_name, version: var_version, description: var_description, authors: var_authors); }
+
+@protected ExportOptions sse_decode_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_include = sse_decode_list_String(deserializer);
+var var_exclude = sse_decode_list_String(deserializer);
+var var_referenceDownloads = sse_decode_bool(deserializer);
+return ExportOptions(include: var_include, exclude: var_exclude, referenceDownloads: var_referenceDownloads); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FileHashes sse_decode_file_hashes(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sha1 = sse_decode_String(deserializer);
+var var_sha512 = sse_decode_String(deserializer);
+return FileHashes(sha1: var_sha1, sha512: var_sha512); }
+
+@protected GalleryImage sse_decode_gallery_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_featured = sse_decode_bool(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+return GalleryImage(url: var_url, featured: var_featured, title: var_title, description: var_description); }
+
+@protected GameLogEvent sse_decode_game_log_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_processId = sse_decode_String(deserializer);
+var var_source = sse_decode_log_source(deserializer);
+var var_timestamp = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_level = sse_decode_log_level(deserializer);
+var var_thread = sse_decode_opt_String(deserializer);
+var var_logger = sse_decode_opt_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_throwable = sse_decode_opt_String(deserializer);
+var var_structured = sse_decode_bool(deserializer);
+return GameLogEvent(processId: var_processId, source: var_source, timestamp: var_timestamp, level: var_level, thread: var_thread, logger: var_logger, message: var_message, throwable: var_throwable, structured: var_structured); }
+
+@protected GameProcessInfo sse_decode_game_process_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_processId = sse_decode_String(deserializer);
+var var_pid = sse_decode_u_32(deserializer);
+var var_startTime = sse_decode_Chrono_Utc(deserializer);
+var var_endTime = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_running = sse_decode_bool(deserializer);
+return GameProcessInfo(processId: var_processId, pid: var_pid, startTime: var_startTime, endTime: var_endTime, exitCode: var_exitCode, running: var_running); }
+
+@protected GameVersionTag sse_decode_game_version_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_String(deserializer);
+var var_versionType = sse_decode_String(deserializer);
+var var_date = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_major = sse_decode_bool(deserializer);
+return GameVersionTag(version: var_version, versionType: var_versionType, date: var_date, major: var_major); }
+
+@protected HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HashAlgorithm.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImportCandidate sse_decode_import_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_import_source(deserializer);
+var var_location = sse_decode_String(deserializer);
+var var_profileKey = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_gameVersion = sse_decode_String(deserializer);
+var var_loader = sse_decode_mod_loader(deserializer);
+var var_loaderVersion = sse_decode_opt_String(deserializer);
+var var_gameDir = sse_decode_String(deserializer);
+return ImportCandidate(source: var_source, location: var_location, profileKey: var_profileKey, name: var_name, gameVersion: var_gameVersion, loader: var_loader, loaderVersion: var_loaderVersion, gameDir: var_gameDir); }
+
+@protected ImportMode sse_decode_import_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ImportMode.values[inner]; }
+
+@protected ImportSource sse_decode_import_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ImportSource.values[inner]; }
+
+@protected InstallPlan sse_decode_install_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_install = sse_decode_list_planned_install(deserializer);
+var var_upgrade = sse_decode_list_planned_upgrade(deserializer);
+var var_conflicts = sse_decode_list_dependency_conflict(deserializer);
+var var_optional = sse_decode_list_String(deserializer);
+return InstallPlan(install: var_install, upgrade: var_upgrade, conflicts: var_conflicts, optional: var_optional); }
+
+@protected InstanceManifest sse_decode_instance_manifest(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_formatVersion = sse_decode_usize(deserializer);
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_gameVersion = sse_decode_String(deserializer);
+var var_loader = sse_decode_mod_loader(deserializer);
+var var_loaderVersion = sse_decode_opt_String(deserializer);
+var var_javaPath = sse_decode_opt_String(deserializer);
+var var_maxMemoryMb = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_minMemoryMb = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_jvmArgs = sse_decode_list_String(deserializer);
+var var_resolution = sse_decode_opt_box_autoadd_resolution(deserializer);
+var var_icon = sse_decode_opt_String(deserializer);
+var var_playTimeSecs = sse_decode_u_64(deserializer);
+var var_created = sse_decode_Chrono_Utc(deserializer);
+var var_lastPlayed = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_path = sse_decode_String(deserializer);
+return InstanceManifest(formatVersion: var_formatVersion, id: var_id, name: var_name, gameVersion: var_gameVersion, loader: var_loader, loaderVersion: var_loaderVersion, javaPath: var_javaPath, maxMemoryMb: var_maxMemoryMb, minMemoryMb: var_minMemoryMb, jvmArgs: var_jvmArgs, resolution: var_resolution, icon: var_icon, playTimeSecs: var_playTimeSecs, created: var_created, lastPlayed: var_lastPlayed, path: var_path); }
+
+@protected InstanceSort sse_decode_instance_sort(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return InstanceSort.values[inner]; }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected JavaRuntimeVersion sse_decode_java_runtime_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_majorVersion = sse_decode_i_32(deserializer);
+return JavaRuntimeVersion(version: var_version, path: var_path, majorVersion: var_majorVersion); }
+
+@protected LaunchCommand sse_decode_launch_command(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_javaPath = sse_decode_String(deserializer);
+var var_args = sse_decode_list_String(deserializer);
+var var_workingDir = sse_decode_String(deserializer);
+var var_nativesDir = sse_decode_opt_String(deserializer);
+return LaunchCommand(javaPath: var_javaPath, args: var_args, workingDir: var_workingDir, nativesDir: var_nativesDir); }
+
+@protected LaunchOptions sse_decode_launch_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_versionJsonPath = sse_decode_String(deserializer);
+var var_minecraftDir = sse_decode_String(deserializer);
+var var_gameDir = sse_decode_String(deserializer);
+var var_nativesDir = sse_decode_String(deserializer);
+var var_java = sse_decode_java_runtime_version(deserializer);
+var var_account = sse_decode_account(deserializer);
+var var_maxMemoryMb = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_minMemoryMb = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_resolution = sse_decode_opt_box_autoadd_resolution(deserializer);
+var var_extraJvmArgs = sse_decode_list_String(deserializer);
+var var_extraGameArgs = sse_decode_list_String(deserializer);
+return LaunchOptions(versionJsonPath: var_versionJsonPath, minecraftDir: var_minecraftDir, gameDir: var_gameDir, nativesDir: var_nativesDir, java: var_java, account: var_account, maxMemoryMb: var_maxMemoryMb, minMemoryMb: var_minMemoryMb, resolution: var_resolution, extraJvmArgs: var_extraJvmArgs, extraGameArgs: var_extraGameArgs); }
+
+@protected License sse_decode_license(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_url = sse_decode_opt_String(deserializer);
+return License(id: var_id, name: var_name, url: var_url); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CategoryTag> sse_decode_list_category_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CategoryTag>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_category_tag(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CompatIssue> sse_decode_list_compat_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CompatIssue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_compat_issue(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ContentRecord> sse_decode_list_content_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ContentRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_content_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ContentUpdate> sse_decode_list_content_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ContentUpdate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_content_update(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DependencyConflict> sse_decode_list_dependency_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DependencyConflict>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_dependency_conflict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GalleryImage> sse_decode_list_gallery_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GalleryImage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_gallery_image(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GameProcessInfo> sse_decode_list_game_process_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GameProcessInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_game_process_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GameVersionTag> sse_decode_list_game_version_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GameVersionTag>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_game_version_tag(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ImportCandidate> sse_decode_list_import_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ImportCandidate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_import_candidate(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InstanceManifest> sse_decode_list_instance_manifest(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InstanceManifest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_instance_manifest(deserializer)); }
+        return ans_;
+         }
+
+@protected List<JavaRuntimeVersion> sse_decode_list_java_runtime_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <JavaRuntimeVersion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_java_runtime_version(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<String>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LoaderTag> sse_decode_list_loader_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LoaderTag>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_loader_tag(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LoaderVersion> sse_decode_list_loader_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LoaderVersion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_loader_version(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MatchedCause> sse_decode_list_matched_cause(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MatchedCause>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_matched_cause(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MrpackOptionalFile> sse_decode_list_mrpack_optional_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MrpackOptionalFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mrpack_optional_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PlannedInstall> sse_decode_list_planned_install(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PlannedInstall>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_planned_install(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PlannedUpgrade> sse_decode_list_planned_upgrade(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PlannedUpgrade>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_planned_upgrade(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PlaySession> sse_decode_list_play_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PlaySession>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_play_session(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<Project> sse_decode_list_project(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Project>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_project(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,Version)> sse_decode_list_record_string_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,Version)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_version(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ScannedContent> sse_decode_list_scanned_content(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ScannedContent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_scanned_content(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TeamMember> sse_decode_list_team_member(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TeamMember>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_team_member(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UpdateBackup> sse_decode_list_update_backup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UpdateBackup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_update_backup(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UpdateBackupEntry> sse_decode_list_update_backup_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UpdateBackupEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_update_backup_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Version> sse_decode_list_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Version>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_version(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VersionDependency> sse_decode_list_version_dependency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VersionDependency>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_version_dependency(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VersionFile> sse_decode_list_version_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VersionFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_version_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WeeklyPlayStats> sse_decode_list_weekly_play_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WeeklyPlayStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_weekly_play_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected LoaderTag sse_decode_loader_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_supportedProjectTypes = sse_decode_list_String(deserializer);
+var var_icon = sse_decode_String(deserializer);
+return LoaderTag(name: var_name, supportedProjectTypes: var_supportedProjectTypes, icon: var_icon); }
+
+@protected LoaderVersion sse_decode_loader_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_stable = sse_decode_bool(deserializer);
+return LoaderVersion(id: var_id, stable: var_stable); }
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogLevel.values[inner]; }
+
+@protected LogSource sse_decode_log_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogSource.values[inner]; }
+
+@protected MatchedCause sse_decode_matched_cause(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cause = sse_decode_crash_cause(deserializer);
+var var_ruleId = sse_decode_String(deserializer);
+var var_evidence = sse_decode_String(deserializer);
+var var_suggestion = sse_decode_String(deserializer);
+return MatchedCause(cause: var_cause, ruleId: var_ruleId, evidence: var_evidence, suggestion: var_suggestion); }
+
+@protected ModLoader sse_decode_mod_loader(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ModLoader.values[inner]; }
+
+@protected ModrinthUser sse_decode_modrinth_user(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_username = sse_decode_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_avatarUrl = sse_decode_opt_String(deserializer);
+return ModrinthUser(id: var_id, username: var_username, name: var_name, avatarUrl: var_avatarUrl); }
+
+@protected MrpackExportOptions sse_decode_mrpack_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_versionId = sse_decode_String(deserializer);
+var var_summary = sse_decode_opt_String(deserializer);
+var var_include = sse_decode_list_String(deserializer);
+var var_exclude = sse_decode_list_String(deserializer);
+return MrpackExportOptions(name: var_name, versionId: var_versionId, summary: var_summary, include: var_include, exclude: var_exclude); }
+
+@protected MrpackInfo sse_decode_mrpack_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_versionId = sse_decode_String(deserializer);
+var var_summary = sse_decode_opt_String(deserializer);
+var var_gameVersion = sse_decode_String(deserializer);
+var var_loader = sse_decode_mod_loader(deserializer);
+var var_loaderVersion = sse_decode_opt_String(deserializer);
+var var_fileCount = sse_decode_u_32(deserializer);
+var var_optionalFiles = sse_decode_list_mrpack_optional_file(deserializer);
+return MrpackInfo(name: var_name, versionId: var_versionId, summary: var_summary, gameVersion: var_gameVersion, loader: var_loader, loaderVersion: var_loaderVersion, fileCount: var_fileCount, optionalFiles: var_optionalFiles); }
+
+@protected MrpackOptionalFile sse_decode_mrpack_optional_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+return MrpackOptionalFile(path: var_path, size: var_size); }
+
+@protected NewInstance sse_decode_new_instance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_gameVersion = sse_decode_String(deserializer);
+var var_loader = sse_decode_mod_loader(deserializer);
+var var_loaderVersion = sse_decode_opt_String(deserializer);
+var var_icon = sse_decode_opt_String(deserializer);
+return NewInstance(name: var_name, gameVersion: var_gameVersion, loader: var_loader, loaderVersion: var_loaderVersion, icon: var_icon); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Chrono_Utc(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ContentType? sse_decode_opt_box_autoadd_content_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_content_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CrashDiagnosis? sse_decode_opt_box_autoadd_crash_diagnosis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_crash_diagnosis(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected EmbeddedMetadata? sse_decode_opt_box_autoadd_embedded_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_embedded_metadata(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected GameProcessInfo? sse_decode_opt_box_autoadd_game_process_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_game_process_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected JavaRuntimeVersion? sse_decode_opt_box_autoadd_java_runtime_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_java_runtime_version(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ModLoader? sse_decode_opt_box_autoadd_mod_loader(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_mod_loader(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RateLimitStatus? sse_decode_opt_box_autoadd_rate_limit_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_rate_limit_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Resolution? sse_decode_opt_box_autoadd_resolution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_resolution(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlannedInstall sse_decode_planned_install(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_projectId = sse_decode_String(deserializer);
+var var_versionId = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_projectType = sse_decode_String(deserializer);
+var var_versionNumber = sse_decode_String(deserializer);
+var var_requiredBy = sse_decode_opt_String(deserializer);
+return PlannedInstall(projectId: var_projectId, versionId: var_versionId, title: var_title, projectType: var_projectType, versionNumber: var_versionNumber, requiredBy: var_requiredBy); }
+
+@protected PlannedUpgrade sse_decode_planned_upgrade(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_projectId = sse_decode_String(deserializer);
+var var_fromVersionId = sse_decode_String(deserializer);
+var var_toVersionId = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_projectType = sse_decode_String(deserializer);
+var var_versionNumber = sse_decode_String(deserializer);
+var var_requiredBy = sse_decode_opt_String(deserializer);
+return PlannedUpgrade(projectId: var_projectId, fromVersionId: var_fromVersionId, toVersionId: var_toVersionId, title: var_title, projectType: var_projectType, versionNumber: var_versionNumber, requiredBy: var_requiredBy); }
+
+@protected PlaySession sse_decode_play_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_64(deserializer);
+var var_instanceId = sse_decode_String(deserializer);
+var var_pid = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_javaVersion = sse_decode_opt_String(deserializer);
+var var_startTime = sse_decode_Chrono_Utc(deserializer);
+var var_endTime = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_crashed = sse_decode_bool(deserializer);
+return PlaySession(id: var_id, instanceId: var_instanceId, pid: var_pid, javaVersion: var_javaVersion, startTime: var_startTime, endTime: var_endTime, exitCode: var_exitCode, crashed: var_crashed); }
+
+@protected PlayStats sse_decode_play_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalPlayTimeSecs = sse_decode_u_64(deserializer);
+var var_sessionCount = sse_decode_u_32(deserializer);
+var var_crashCount = sse_decode_u_32(deserializer);
+var var_lastPlayed = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_weekly = sse_decode_list_weekly_play_stats(deserializer);
+return PlayStats(totalPlayTimeSecs: var_totalPlayTimeSecs, sessionCount: var_sessionCount, crashCount: var_crashCount, lastPlayed: var_lastPlayed, weekly: var_weekly); }
+
+@protected Project sse_decode_project(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_slug = sse_decode_String(deserializer);
+var var_projectType = sse_decode_String(deserializer);
+var var_team = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_categories = sse_decode_list_String(deserializer);
+var var_additionalCategories = sse_decode_list_String(deserializer);
+var var_clientSide = sse_decode_String(deserializer);
+var var_serverSide = sse_decode_String(deserializer);
+var var_status = sse_decode_String(deserializer);
+var var_downloads = sse_decode_u_64(deserializer);
+var var_followers = sse_decode_u_64(deserializer);
+var var_iconUrl = sse_decode_opt_String(deserializer);
+var var_color = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_license = sse_decode_license(deserializer);
+var var_sourceUrl = sse_decode_opt_String(deserializer);
+var var_issuesUrl = sse_decode_opt_String(deserializer);
+var var_wikiUrl = sse_decode_opt_String(deserializer);
+var var_discordUrl = sse_decode_opt_String(deserializer);
+var var_versions = sse_decode_list_String(deserializer);
+var var_gameVersions = sse_decode_list_String(deserializer);
+var var_loaders = sse_decode_list_String(deserializer);
+var var_gallery = sse_decode_list_gallery_image(deserializer);
+var var_published = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_updated = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+return Project(id: var_id, slug: var_slug, projectType: var_projectType, team: var_team, title: var_title, description: var_description, body: var_body, categories: var_categories, additionalCategories: var_additionalCategories, clientSide: var_clientSide, serverSide: var_serverSide, status: var_status, downloads: var_downloads, followers: var_followers, iconUrl: var_iconUrl, color: var_color, license: var_license, sourceUrl: var_sourceUrl, issuesUrl: var_issuesUrl, wikiUrl: var_wikiUrl, discordUrl: var_discordUrl, versions: var_versions, gameVersions: var_gameVersions, loaders: var_loaders, gallery: var_gallery, published: var_published, updated: var_updated); }
+
+@protected RateLimitStatus sse_decode_rate_limit_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_remaining = sse_decode_u_32(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+var var_resetSecs = sse_decode_u_64(deserializer);
+return RateLimitStatus(remaining: var_remaining, limit: var_limit, resetSecs: var_resetSecs); }
+
+@protected (String,Version) sse_decode_record_string_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_version(deserializer);
+return (var_field0, var_field1); }
+
+@protected Resolution sse_decode_resolution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+return Resolution(width: var_width, height: var_height); }
+
+@protected ScannedContent sse_decode_scanned_content(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_contentType = sse_decode_content_type(deserializer);
+var var_enabled = sse_decode_bool(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_sha1 = sse_decode_String(deserializer);
+var var_sha512 = sse_decode_String(deserializer);
+var var_projectId = sse_decode_opt_String(deserializer);
+var var_versionId = sse_decode_opt_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_versionNumber = sse_decode_opt_String(deserializer);
+var var_iconUrl = sse_decode_opt_String(deserializer);
+var var_embedded = sse_decode_opt_box_autoadd_embedded_metadata(deserializer);
+var var_icon = sse_decode_opt_list_prim_u_8_strict(deserializer);
+return ScannedContent(path: var_path, fileName: var_fileName, contentType: var_contentType, enabled: var_enabled, tags: var_tags, size: var_size, sha1: var_sha1, sha512: var_sha512, projectId: var_projectId, versionId: var_versionId, title: var_title, versionNumber: var_versionNumber, iconUrl: var_iconUrl, embedded: var_embedded, icon: var_icon); }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_projectId = sse_decode_String(deserializer);
+var var_slug = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_projectType = sse_decode_String(deserializer);
+var var_author = sse_decode_String(deserializer);
+var var_categories = sse_decode_list_String(deserializer);
+var var_displayCategories = sse_decode_list_String(deserializer);
+var var_versions = sse_decode_list_String(deserializer);
+var var_clientSide = sse_decode_String(deserializer);
+var var_serverSide = sse_decode_String(deserializer);
+var var_downloads = sse_decode_u_64(deserializer);
+var var_follows = sse_decode_u_64(deserializer);
+var var_iconUrl = sse_decode_opt_String(deserializer);
+var var_color = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_latestVersion = sse_decode_opt_String(deserializer);
+var var_license = sse_decode_String(deserializer);
+var var_gallery = sse_decode_list_String(deserializer);
+var var_featuredGallery = sse_decode_opt_String(deserializer);
+var var_dateCreated = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_dateModified = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+return SearchHit(projectId: var_projectId, slug: var_slug, title: var_title, description: var_description, projectType: var_projectType, author: var_author, categories: var_categories, displayCategories: var_displayCategories, versions: var_versions, clientSide: var_clientSide, serverSide: var_serverSide, downloads: var_downloads, follows: var_follows, iconUrl: var_iconUrl, color: var_color, latestVersion: var_latestVersion, license: var_license, gallery: var_gallery, featuredGallery: var_featuredGallery, dateCreated: var_dateCreated, dateModified: var_dateModified); }
+
+@protected SearchQuery sse_decode_search_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_query = sse_decode_opt_String(deserializer);
+var var_facets = sse_decode_list_list_String(deserializer);
+var var_index = sse_decode_opt_String(deserializer);
+var var_offset = sse_decode_u_32(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+return SearchQuery(query: var_query, facets: var_facets, index: var_index, offset: var_offset, limit: var_limit); }
+
+@protected SearchResults sse_decode_search_results(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_search_hit(deserializer);
+var var_offset = sse_decode_u_32(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+var var_totalHits = sse_decode_u_32(deserializer);
+return SearchResults(hits: var_hits, offset: var_offset, limit: var_limit, totalHits: var_totalHits); }
+
+@protected TeamMember sse_decode_team_member(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_teamId = sse_decode_String(deserializer);
+var var_user = sse_decode_modrinth_user(deserializer);
+var var_role = sse_decode_String(deserializer);
+var var_ordering = sse_decode_i_64(deserializer);
+return TeamMember(teamId: var_teamId, user: var_user, role: var_role, ordering: var_ordering); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UpdateBackup sse_decode_update_backup(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_created = sse_decode_Chrono_Utc(deserializer);
+var var_entries = sse_decode_list_update_backup_entry(deserializer);
+return UpdateBackup(id: var_id, created: var_created, entries: var_entries); }
+
+@protected UpdateBackupEntry sse_decode_update_backup_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_old = sse_decode_content_record(deserializer);
+var var_new_ = sse_decode_content_record(deserializer);
+return UpdateBackupEntry(old: var_old, new_: var_new_); }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected Version sse_decode_version(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_projectId = sse_decode_String(deserializer);
+var var_authorId = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_versionNumber = sse_decode_String(deserializer);
+var var_changelog = sse_decode_opt_String(deserializer);
+var var_versionType = sse_decode_String(deserializer);
+var var_status = sse_decode_String(deserializer);
+var var_featured = sse_decode_bool(deserializer);
+var var_downloads = sse_decode_u_64(deserializer);
+var var_gameVersions = sse_decode_list_String(deserializer);
+var var_loaders = sse_decode_list_String(deserializer);
+var var_files = sse_decode_list_version_file(deserializer);
+var var_dependencies = sse_decode_list_version_dependency(deserializer);
+var var_datePublished = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+return Version(id: var_id, projectId: var_projectId, authorId: var_authorId, name: var_name, versionNumber: var_versionNumber, changelog: var_changelog, versionType: var_versionType, status: var_status, featured: var_featured, downloads: var_downloads, gameVersions: var_gameVersions, loaders: var_loaders, files: var_files, dependencies: var_dependencies, datePublished: var_datePublished); }
+
+@protected VersionDependency sse_decode_version_dependency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_versionId = sse_decode_opt_String(deserializer);
+var var_projectId = sse_decode_opt_String(deserializer);
+var var_fileName = sse_decode_opt_String(deserializer);
+var var_dependencyType = sse_decode_String(deserializer);
+return VersionDependency(versionId: var_versionId, projectId: var_projectId, fileName: var_fileName, dependencyType: var_dependencyType); }
+
+@protected VersionFile sse_decode_version_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hashes = sse_decode_file_hashes(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_filename = sse_decode_String(deserializer);
+var var_primary = sse_decode_bool(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_fileType = sse_decode_opt_String(deserializer);
+return VersionFile(hashes: var_hashes, url: var_url, filename: var_filename, primary: var_primary, size: var_size, fileType: var_fileType); }
+
+@protected WeeklyPlayStats sse_decode_weekly_play_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_weekStart = sse_decode_Chrono_Utc(deserializer);
+var var_sessions = sse_decode_u_32(deserializer);
+var var_playTimeSecs = sse_decode_u_64(deserializer);
+return WeeklyPlayStats(weekStart: var_weekStart, sessions: var_sessions, playTimeSecs: var_playTimeSecs); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(FutureOr<void> Function(bool, String?) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(FutureOr<void> Function(double, String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(FutureOr<void> Function(GameProcessInfo) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_Map_String_version_None(Map<String, Version> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_version(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_StreamSink_game_log_event_Sse(RustStreamSink<GameLogEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_game_log_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_account(Account self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.username, serializer);
+sse_encode_String(self.uuid, serializer);
+sse_encode_String(self.accessToken, serializer);
+sse_encode_String(self.userType, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_Chrono_Utc(DateTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Chrono_Utc(self, serializer); }
+
+@protected void sse_encode_box_autoadd_content_type(ContentType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_content_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_crash_diagnosis(CrashDiagnosis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_crash_diagnosis(self, serializer); }
+
+@protected void sse_encode_box_autoadd_embedded_metadata(EmbeddedMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_embedded_metadata(self, serializer); }
+
+@protected void sse_encode_box_autoadd_export_options(ExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_export_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_game_process_info(GameProcessInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_game_process_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_import_candidate(ImportCandidate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_import_candidate(self, serializer); }
+
+@protected void sse_encode_box_autoadd_install_plan(InstallPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_install_plan(self, serializer); }
+
+@protected void sse_encode_box_autoadd_instance_manifest(InstanceManifest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_instance_manifest(self, serializer); }
+
+@protected void sse_encode_box_autoadd_java_runtime_version(JavaRuntimeVersion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_java_runtime_version(self, serializer); }
+
+@protected void sse_encode_box_autoadd_launch_command(LaunchCommand self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_launch_command(self, serializer); }
+
+@protected void sse_encode_box_autoadd_launch_options(LaunchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_launch_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_mod_loader(ModLoader self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_mod_loader(self, serializer); }
+
+@protected void sse_encode_box_autoadd_mrpack_export_options(MrpackExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_mrpack_export_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_new_instance(NewInstance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_new_instance(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rate_limit_status(RateLimitStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rate_limit_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_resolution(Resolution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_resolution(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_query(SearchQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_category_tag(CategoryTag self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.projectType, serializer);
+sse_encode_String(self.header, serializer);
+sse_encode_String(self.icon, serializer);
+ }
+
+@protected void sse_encode_compat_issue(CompatIssue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_compat_severity(self.severity, serializer);
+sse_encode_compat_issue_kind(self.kind, serializer);
+sse_encode_opt_String(self.modId, serializer);
+sse_encode_opt_String(self.file, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_String(self.suggestion, serializer);
+ }
+
+@protected void sse_encode_compat_issue_kind(CompatIssueKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_compat_report(CompatReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.checkedMods, serializer);
+sse_encode_list_compat_issue(self.issues, serializer);
+ }
+
+@protected void sse_encode_compat_severity(CompatSeverity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_conflict_kind(ConflictKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_content_record(ContentRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.instanceId, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.projectType, serializer);
+sse_encode_opt_String(self.projectId, serializer);
+sse_encode_opt_String(self.versionId, serializer);
+sse_encode_String(self.sha1, serializer);
+sse_encode_opt_String(self.sha512, serializer);
+sse_encode_u_64(self.fileSize, serializer);
+sse_encode_bool(self.enabled, serializer);
+sse_encode_Chrono_Utc(self.installedAt, serializer);
+sse_encode_list_String(self.tags, serializer);
+ }
+
+@protected void sse_encode_content_type(ContentType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_content_update(ContentUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_content_type(self.contentType, serializer);
+sse_encode_String(self.projectId, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.currentVersionId, serializer);
+sse_encode_opt_String(self.currentVersionNumber, serializer);
+sse_encode_String(self.newVersionId, serializer);
+sse_encode_String(self.newVersionNumber, serializer);
+sse_encode_String(self.versionType, serializer);
+sse_encode_opt_String(self.changelog, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.datePublished, serializer);
+ }
+
+@protected void sse_encode_crash_cause(CrashCause self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_crash_diagnosis(CrashDiagnosis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_crash_file_kind(self.kind, serializer);
+sse_encode_opt_String(self.filePath, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_list_String(self.suspectedMods, serializer);
+sse_encode_opt_String(self.stackTrace, serializer);
+sse_encode_list_matched_cause(self.causes, serializer);
+ }
+
+@protected void sse_encode_crash_file_kind(CrashFileKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_dependency_conflict(DependencyConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_conflict_kind(self.kind, serializer);
+sse_encode_String(self.projectId, serializer);
+sse_encode_String(self.sourceProjectId, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_embedded_metadata(EmbeddedMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_mod_loader(self.loader, serializer);
+sse_encode_opt_String(self.modId, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.version, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_list_String(self.authors, serializer);
+ }
+
+@protected void sse_encode_export_options(ExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.include, serializer);
+sse_encode_list_String(self.exclude, serializer);
+sse_encode_bool(self.referenceDownloads, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_file_hashes(FileHashes self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.sha1, serializer);
+sse_encode_String(self.sha512, serializer);
+ }
+
+@protected void sse_encode_gallery_image(GalleryImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_bool(self.featured, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.description, serializer);
+ }
+
+@protected void sse_encode_game_log_event(GameLogEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.processId, serializer);
+sse_encode_log_source(self.source, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.timestamp, serializer);
+sse_encode_log_level(self.level, serializer);
+sse_encode_opt_String(self.thread, serializer);
+sse_encode_opt_String(self.logger, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_String(self.throwable, serializer);
+sse_encode_bool(self.structured, serializer);
+ }
+
+@protected void sse_encode_game_process_info(GameProcessInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.processId, serializer);
+sse_encode_u_32(self.pid, serializer);
+sse_encode_Chrono_Utc(self.startTime, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.endTime, serializer);
+sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+sse_encode_bool(self.running, serializer);
+ }
+
+@protected void sse_encode_game_version_tag(GameVersionTag self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.versionType, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.date, serializer);
+sse_encode_bool(self.major, serializer);
+ }
+
+@protected void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_import_candidate(ImportCandidate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_import_source(self.source, serializer);
+sse_encode_String(self.location, serializer);
+sse_encode_opt_String(self.profileKey, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.gameVersion, serializer);
+sse_encode_mod_loader(self.loader, serializer);
+sse_encode_opt_String(self.loaderVersion, serializer);
+sse_encode_String(self.gameDir, serializer);
+ }
+
+@protected void sse_encode_import_mode(ImportMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_import_source(ImportSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_install_plan(InstallPlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_planned_install(self.install, serializer);
+sse_encode_list_planned_upgrade(self.upgrade, serializer);
+sse_encode_list_dependency_conflict(self.conflicts, serializer);
+sse_encode_list_String(self.optional, serializer);
+ }
+
+@protected void sse_encode_instance_manifest(InstanceManifest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.formatVersion, serializer);
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.gameVersion, serializer);
+sse_encode_mod_loader(self.loader, serializer);
+sse_encode_opt_String(self.loaderVersion, serializer);
+sse_encode_opt_String(self.javaPath, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxMemoryMb, serializer);
+sse_encode_opt_box_autoadd_u_32(self.minMemoryMb, serializer);
+sse_encode_list_String(self.jvmArgs, serializer);
+sse_encode_opt_box_autoadd_resolution(self.resolution, serializer);
+sse_encode_opt_String(self.icon, serializer);
+sse_encode_u_64(self.playTimeSecs, serializer);
+sse_encode_Chrono_Utc(self.created, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.lastPlayed, serializer);
+sse_encode_String(self.path, serializer);
+ }
+
+@protected void sse_encode_instance_sort(InstanceSort self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_java_runtime_version(JavaRuntimeVersion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_i_32(self.majorVersion, serializer);
+ }
+
+@protected void sse_encode_launch_command(LaunchCommand self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.javaPath, serializer);
+sse_encode_list_String(self.args, serializer);
+sse_encode_String(self.workingDir, serializer);
+sse_encode_opt_String(self.nativesDir, serializer);
+ }
+
+@protected void sse_encode_launch_options(LaunchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.versionJsonPath, serializer);
+sse_encode_String(self.minecraftDir, serializer);
+sse_encode_String(self.gameDir, serializer);
+sse_encode_String(self.nativesDir, serializer);
+sse_encode_java_runtime_version(self.java, serializer);
+sse_encode_account(self.account, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxMemoryMb, serializer);
+sse_encode_opt_box_autoadd_u_32(self.minMemoryMb, serializer);
+sse_encode_opt_box_autoadd_resolution(self.resolution, serializer);
+sse_encode_list_String(self.extraJvmArgs, serializer);
+sse_encode_list_String(self.extraGameArgs, serializer);
+ }
+
+@protected void sse_encode_license(License self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.url, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_category_tag(List<CategoryTag> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_category_tag(item, serializer); } }
+
+@protected void sse_encode_list_compat_issue(List<CompatIssue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_compat_issue(item, serializer); } }
+
+@protected void sse_encode_list_content_record(List<ContentRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_content_record(item, serializer); } }
+
+@protected void sse_encode_list_content_update(List<ContentUpdate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_content_update(item, serializer); } }
+
+@protected void sse_encode_list_dependency_conflict(List<DependencyConflict> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_dependency_conflict(item, serializer); } }
+
+@protected void sse_encode_list_gallery_image(List<GalleryImage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_gallery_image(item, serializer); } }
+
+@protected void sse_encode_list_game_process_info(List<GameProcessInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_game_process_info(item, serializer); } }
+
+@protected void sse_encode_list_game_version_tag(List<GameVersionTag> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_game_version_tag(item, serializer); } }
+
+@protected void sse_encode_list_import_candidate(List<ImportCandidate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_import_candidate(item, serializer); } }
+
+@protected void sse_encode_list_instance_manifest(List<InstanceManifest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_instance_manifest(item, serializer); } }
+
+@protected void sse_encode_list_java_runtime_version(List<JavaRuntimeVersion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_java_runtime_version(item, serializer); } }
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_String(item, serializer); } }
+
+@protected void sse_encode_list_loader_tag(List<LoaderTag> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_loader_tag(item, serializer); } }
+
+@protected void sse_encode_list_loader_version(List<LoaderVersion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_loader_version(item, serializer); } }
+
+@protected void sse_encode_list_matched_cause(List<MatchedCause> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_matched_cause(item, serializer); } }
+
+@protected void sse_encode_list_mrpack_optional_file(List<MrpackOptionalFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mrpack_optional_file(item, serializer); } }
+
+@protected void sse_encode_list_planned_install(List<PlannedInstall> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_planned_install(item, serializer); } }
+
+@protected void sse_encode_list_planned_upgrade(List<PlannedUpgrade> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_planned_upgrade(item, serializer); } }
+
+@protected void sse_encode_list_play_session(List<PlaySession> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_play_session(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_project(List<Project> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_project(item, serializer); } }
+
+@protected void sse_encode_list_record_string_version(List<(String,Version)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_version(item, serializer); } }
+
+@protected void sse_encode_list_scanned_content(List<ScannedContent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_scanned_content(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_team_member(List<TeamMember> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_team_member(item, serializer); } }
+
+@protected void sse_encode_list_update_backup(List<UpdateBackup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_update_backup(item, serializer); } }
+
+@protected void sse_encode_list_update_backup_entry(List<UpdateBackupEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_update_backup_entry(item, serializer); } }
+
+@protected void sse_encode_list_version(List<Version> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_version(item, serializer); } }
+
+@protected void sse_encode_list_version_dependency(List<VersionDependency> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_version_dependency(item, serializer); } }
+
+@protected void sse_encode_list_version_file(List<VersionFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_version_file(item, serializer); } }
+
+@protected void sse_encode_list_weekly_play_stats(List<WeeklyPlayStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_weekly_play_stats(item, serializer); } }
+
+@protected void sse_encode_loader_tag(LoaderTag self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_list_String(self.supportedProjectTypes, serializer);
+sse_encode_String(self.icon, serializer);
+ }
+
+@protected void sse_encode_loader_version(LoaderVersion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_bool(self.stable, serializer);
+ }
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_log_source(LogSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_matched_cause(MatchedCause self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_crash_cause(self.cause, serializer);
+sse_encode_String(self.ruleId, serializer);
+sse_encode_String(self.evidence, serializer);
+sse_encode_String(self.suggestion, serializer);
+ }
+
+@protected void sse_encode_mod_loader(ModLoader self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_modrinth_user(ModrinthUser self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.username, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.avatarUrl, serializer);
+ }
+
+@protected void sse_encode_mrpack_export_options(MrpackExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_String(self.versionId, serializer);
+sse_encode_opt_String(self.summary, serializer);
+sse_encode_list_String(self.include, serializer);
+sse_encode_list_String(self.exclude, serializer);
+ }
+
+@protected void sse_encode_mrpack_info(MrpackInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.versionId, serializer);
+sse_encode_opt_String(self.summary, serializer);
+sse_encode_String(self.gameVersion, serializer);
+sse_encode_mod_loader(self.loader, serializer);
+sse_encode_opt_String(self.loaderVersion, serializer);
+sse_encode_u_32(self.fileCount, serializer);
+sse_encode_list_mrpack_optional_file(self.optionalFiles, serializer);
+ }
+
+@protected void sse_encode_mrpack_optional_file(MrpackOptionalFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+ }
+
+@protected void sse_encode_new_instance(NewInstance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.gameVersion, serializer);
+sse_encode_mod_loader(self.loader, serializer);
+sse_encode_opt_String(self.loaderVersion, serializer);
+sse_encode_opt_String(self.icon, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Chrono_Utc(DateTime? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Chrono_Utc(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_content_type(ContentType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_content_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_crash_diagnosis(CrashDiagnosis? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_crash_diagnosis(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_embedded_metadata(EmbeddedMetadata? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_embedded_metadata(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_game_process_info(GameProcessInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_game_process_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_java_runtime_version(JavaRuntimeVersion? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_java_runtime_version(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_mod_loader(ModLoader? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_mod_loader(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_rate_limit_status(RateLimitStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_rate_limit_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_resolution(Resolution? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_resolution(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_planned_install(PlannedInstall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.projectId, serializer);
+sse_encode_String(self.versionId, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.projectType, serializer);
+sse_encode_String(self.versionNumber, serializer);
+sse_encode_opt_String(self.requiredBy, serializer);
+ }
+
+@protected void sse_encode_planned_upgrade(PlannedUpgrade self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.projectId, serializer);
+sse_encode_String(self.fromVersionId, serializer);
+sse_encode_String(self.toVersionId, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.projectType, serializer);
+sse_encode_String(self.versionNumber, serializer);
+sse_encode_opt_String(self.requiredBy, serializer);
+ }
+
+@protected void sse_encode_play_session(PlaySession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.id, serializer);
+sse_encode_String(self.instanceId, serializer);
+sse_encode_opt_box_autoadd_u_32(self.pid, serializer);
+sse_encode_opt_String(self.javaVersion, serializer);
+sse_encode_Chrono_Utc(self.startTime, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.endTime, serializer);
+sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+sse_encode_bool(self.crashed, serializer);
+ }
+
+@protected void sse_encode_play_stats(PlayStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.totalPlayTimeSecs, serializer);
+sse_encode_u_32(self.sessionCount, serializer);
+sse_encode_u_32(self.crashCount, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.lastPlayed, serializer);
+sse_encode_list_weekly_play_stats(self.weekly, serializer);
+ }
+
+@protected void sse_encode_project(Project self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.slug, serializer);
+sse_encode_String(self.projectType, serializer);
+sse_encode_String(self.team, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_list_String(self.categories, serializer);
+sse_encode_list_String(self.additionalCategories, serializer);
+sse_encode_String(self.clientSide, serializer);
+sse_encode_String(self.serverSide, serializer);
+sse_encode_String(self.status, serializer);
+sse_encode_u_64(self.downloads, serializer);
+sse_encode_u_64(self.followers, serializer);
+sse_encode_opt_String(self.iconUrl, serializer);
+sse_encode_opt_box_autoadd_u_32(self.color, serializer);
+sse_encode_license(self.license, serializer);
+sse_encode_opt_String(self.sourceUrl, serializer);
+sse_encode_opt_String(self.issuesUrl, serializer);
+sse_encode_opt_String(self.wikiUrl, serializer);
+sse_encode_opt_String(self.discordUrl, serializer);
+sse_encode_list_String(self.versions, serializer);
+sse_encode_list_String(self.gameVersions, serializer);
+sse_encode_list_String(self.loaders, serializer);
+sse_encode_list_gallery_image(self.gallery, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.published, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.updated, serializer);
+ }
+
+@protected void sse_encode_rate_limit_status(RateLimitStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.remaining, serializer);
+sse_encode_u_32(self.limit, serializer);
+sse_encode_u_64(self.resetSecs, serializer);
+ }
+
+@protected void sse_encode_record_string_version((String,Version) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_version(self.$2, serializer);
+ }
+
+@protected void sse_encode_resolution(Resolution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+ }
+
+@protected void sse_encode_scanned_content(ScannedContent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.fileName, serializer);
+sse_encode_content_type(self.contentType, serializer);
+sse_encode_bool(self.enabled, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_String(self.sha1, serializer);
+sse_encode_String(self.sha512, serializer);
+sse_encode_opt_String(self.projectId, serializer);
+sse_encode_opt_String(self.versionId, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.versionNumber, serializer);
+sse_encode_opt_String(self.iconUrl, serializer);
+sse_encode_opt_box_autoadd_embedded_metadata(self.embedded, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.icon, serializer);
+ }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.projectId, serializer);
+sse_encode_String(self.slug, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_String(self.projectType, serializer);
+sse_encode_String(self.author, serializer);
+sse_encode_list_String(self.categories, serializer);
+sse_encode_list_String(self.displayCategories, serializer);
+sse_encode_list_String(self.versions, serializer);
+sse_encode_String(self.clientSide, serializer);
+sse_encode_String(self.serverSide, serializer);
+sse_encode_u_64(self.downloads, serializer);
+sse_encode_u_64(self.follows, serializer);
+sse_encode_opt_String(self.iconUrl, serializer);
+sse_encode_opt_box_autoadd_u_32(self.color, serializer);
+sse_encode_opt_String(self.latestVersion, serializer);
+sse_encode_String(self.license, serializer);
+sse_encode_list_String(self.gallery, serializer);
+sse_encode_opt_String(self.featuredGallery, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.dateCreated, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.dateModified, serializer);
+ }
+
+@protected void sse_encode_search_query(SearchQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.query, serializer);
+sse_encode_list_list_String(self.facets, serializer);
+sse_encode_opt_String(self.index, serializer);
+sse_encode_u_32(self.offset, serializer);
+sse_encode_u_32(self.limit, serializer);
+ }
+
+@protected void sse_encode_search_results(SearchResults self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_hit(self.hits, serializer);
+sse_encode_u_32(self.offset, serializer);
+sse_encode_u_32(self.limit, serializer);
+sse_encode_u_32(self.totalHits, serializer);
+ }
+
+@protected void sse_encode_team_member(TeamMember self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.teamId, serializer);
+sse_encode_modrinth_user(self.user, serializer);
+sse_encode_String(self.role, serializer);
+sse_encode_i_64(self.ordering, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_update_backup(UpdateBackup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_Chrono_Utc(self.created, serializer);
+sse_encode_list_update_backup_entry(self.entries, serializer);
+ }
+
+@protected void sse_encode_update_backup_entry(UpdateBackupEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_content_record(self.old, serializer);
+sse_encode_content_record(self.new_, serializer);
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_version(Version self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.projectId, serializer);
+sse_encode_String(self.authorId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.versionNumber, serializer);
+sse_encode_opt_String(self.changelog, serializer);
+sse_encode_String(self.versionType, serializer);
+sse_encode_String(self.status, serializer);
+sse_encode_bool(self.featured, serializer);
+sse_encode_u_64(self.downloads, serializer);
+sse_encode_list_String(self.gameVersions, serializer);
+sse_encode_list_String(self.loaders, serializer);
+sse_encode_list_version_file(self.files, serializer);
+sse_encode_list_version_dependency(self.dependencies, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.datePublished, serializer);
+ }
+
+@protected void sse_encode_version_dependency(VersionDependency self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.versionId, serializer);
+sse_encode_opt_String(self.projectId, serializer);
+sse_encode_opt_String(self.fileName, serializer);
+sse_encode_String(self.dependencyType, serializer);
+ }
+
+@protected void sse_encode_version_file(VersionFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_file_hashes(self.hashes, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.filename, serializer);
+sse_encode_bool(self.primary, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_opt_String(self.fileType, serializer);
+ }
+
+@protected void sse_encode_weekly_play_stats(WeeklyPlayStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Chrono_Utc(self.weekStart, serializer);
+sse_encode_u_32(self.sessions, serializer);
+sse_encode_u_64(self.playTimeSecs, serializer);
+ }
+                }
+                
@@ -3,205 +3,952 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/content.dart';
+import 'api/content_scan.dart';
+import 'api/content_update.dart';
+import 'api/crash_report.dart';
+import 'api/database.dart';
+import 'api/dependency.dart';
+import 'api/fabric.dart';
+import 'api/forge.dart';
+import 'api/game_log.dart';
+import 'api/game_process.dart';
+import 'api/instance.dart';
+import 'api/instance_export.dart';
+import 'api/instance_import.dart';
 import 'api/java_download.dart';
+import 'api/launch.dart';
+import 'api/libraries.dart';
+import 'api/loader_meta.dart';
+import 'api/mod_compat.dart';
+import 'api/mod_loader.dart';
+import 'api/modpack.dart';
+import 'api/modrinth.dart';
+import 'api/modrinth_cache.dart';
+import 'api/natives.dart';
+import 'api/neoforge.dart';
+import 'api/play_session.dart';
+import 'api/quilt.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  FutureOr<void> Function(bool, String?)
-      dco_decode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(
-          dynamic raw);
 
-  @protected
-  FutureOr<void> Function(double, String)
-      dco_decode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(
-          dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected DateTime dco_decode_Chrono_Utc(dynamic raw);
+
+@protected FutureOr<void> Function(bool, String?) dco_decode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(double, String) dco_decode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(GameProcessInfo) dco_decode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
+@protected Map<String, Version> dco_decode_Map_String_version_None(dynamic raw);
+
+@protected RustStreamSink<GameLogEvent> dco_decode_StreamSink_game_log_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected VersionSource dco_decode_TraitDef_VersionSource(dynamic raw);
+
+@protected Account dco_decode_account(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw);
+
+@protected ContentType dco_decode_box_autoadd_content_type(dynamic raw);
+
+@protected CrashDiagnosis dco_decode_box_autoadd_crash_diagnosis(dynamic raw);
+
+@protected EmbeddedMetadata dco_decode_box_autoadd_embedded_metadata(dynamic raw);
+
+@protected ExportOptions dco_decode_box_autoadd_export_options(dynamic raw);
+
+@protected GameProcessInfo dco_decode_box_autoadd_game_process_info(dynamic raw);
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
+
+@protected ImportCandidate dco_decode_box_autoadd_import_candidate(dynamic raw);
+
+@protected InstallPlan dco_decode_box_autoadd_install_plan(dynamic raw);
+
+@protected InstanceManifest dco_decode_box_autoadd_instance_manifest(dynamic raw);
+
+@protected JavaRuntimeVersion dco_decode_box_autoadd_java_runtime_version(dynamic raw);
+
+@protected LaunchCommand dco_decode_box_autoadd_launch_command(dynamic raw);
+
+@protected LaunchOptions dco_decode_box_autoadd_launch_options(dynamic raw);
+
+@protected ModLoader dco_decode_box_autoadd_mod_loader(dynamic raw);
+
+@protected MrpackExportOptions dco_decode_box_autoadd_mrpack_export_options(dynamic raw);
+
+@protected NewInstance dco_decode_box_autoadd_new_instance(dynamic raw);
+
+@protected RateLimitStatus dco_decode_box_autoadd_rate_limit_status(dynamic raw);
+
+@protected Resolution dco_decode_box_autoadd_resolution(dynamic raw);
+
+@protected SearchQuery dco_decode_box_autoadd_search_query(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected CategoryTag dco_decode_category_tag(dynamic raw);
+
+@protected CompatIssue dco_decode_compat_issue(dynamic raw);
+
+@protected CompatIssueKind dco_decode_compat_issue_kind(dynamic raw);
+
+@protected CompatReport dco_decode_compat_report(dynamic raw);
+
+@protected CompatSeverity dco_decode_compat_severity(dynamic raw);
+
+@protected ConflictKind dco_decode_conflict_kind(dynamic raw);
+
+@protected ContentRecord dco_decode_content_record(dynamic raw);
+
+@protected ContentType dco_decode_content_type(dynamic raw);
+
+@protected ContentUpdate dco_decode_content_update(dynamic raw);
+
+@protected CrashCause dco_decode_crash_cause(dynamic raw);
+
+@protected CrashDiagnosis dco_decode_crash_diagnosis(dynamic raw);
+
+@protected CrashFileKind dco_decode_crash_file_kind(dynamic raw);
+
+@protected DependencyConflict dco_decode_dependency_conflict(dynamic raw);
+
+@protected EmbeddedMetadata dco_decode_embedded_metadata(dynamic raw);
+
+@protected ExportOptions dco_decode_export_options(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FileHashes dco_decode_file_hashes(dynamic raw);
+
+@protected GalleryImage dco_decode_gallery_image(dynamic raw);
+
+@protected GameLogEvent dco_decode_game_log_event(dynamic raw);
+
+@protected GameProcessInfo dco_decode_game_process_info(dynamic raw);
+
+@protected GameVersionTag dco_decode_game_version_tag(dynamic raw);
+
+@protected HashAlgorithm dco_decode_hash_algorithm(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected ImportCandidate dco_decode_import_candidate(dynamic raw);
+
+@protected ImportMode dco_decode_import_mode(dynamic raw);
+
+@protected ImportSource dco_decode_import_source(dynamic raw);
+
+@protected InstallPlan dco_decode_install_plan(dynamic raw);
+
+@protected InstanceManifest dco_decode_instance_manifest(dynamic raw);
+
+@protected InstanceSort dco_decode_instance_sort(dynamic raw);
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
+@protected JavaRuntimeVersion dco_decode_java_runtime_version(dynamic raw);
+
+@protected LaunchCommand dco_decode_launch_command(dynamic raw);
+
+@protected LaunchOptions dco_decode_launch_options(dynamic raw);
+
+@protected License dco_decode_license(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<CategoryTag> dco_decode_list_category_tag(dynamic raw);
+
+@protected List<CompatIssue> dco_decode_list_compat_issue(dynamic raw);
+
+@protected List<ContentRecord> dco_decode_list_content_record(dynamic raw);
+
+@protected List<ContentUpdate> dco_decode_list_content_update(dynamic raw);
+
+@protected List<DependencyConflict> dco_decode_list_dependency_conflict(dynamic raw);
+
+@protected List<GalleryImage> dco_decode_list_gallery_image(dynamic raw);
+
+@protected List<GameProcessInfo> dco_decode_list_game_process_info(dynamic raw);
+
+@protected List<GameVersionTag> dco_decode_list_game_version_tag(dynamic raw);
+
+@protected List<ImportCandidate> dco_decode_list_import_candidate(dynamic raw);
+
+@protected List<InstanceManifest> dco_decode_list_instance_manifest(dynamic raw);
+
+@protected List<JavaRuntimeVersion> dco_decode_list_java_runtime_version(dynamic raw);
+
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw);
+
+@protected List<LoaderTag> dco_decode_list_loader_tag(dynamic raw);
+
+@protected List<LoaderVersion> dco_decode_list_loader_version(dynamic raw);
+
+@protected List<MatchedCause> dco_decode_list_matched_cause(dynamic raw);
+
+@protected List<MrpackOptionalFile> dco_decode_list_mrpack_optional_file(dynamic raw);
+
+@protected List<PlannedInstall> dco_decode_list_planned_install(dynamic raw);
+
+@protected List<PlannedUpgrade> dco_decode_list_planned_upgrade(dynamic raw);
+
+@protected List<PlaySession> dco_decode_list_play_session(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<Project> dco_decode_list_project(dynamic raw);
+
+@protected List<(String,Version)> dco_decode_list_record_string_version(dynamic raw);
+
+@protected List<ScannedContent> dco_decode_list_scanned_content(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<TeamMember> dco_decode_list_team_member(dynamic raw);
+
+@protected List<UpdateBackup> dco_decode_list_update_backup(dynamic raw);
+
+@protected List<UpdateBackupEntry> dco_decode_list_update_backup_entry(dynamic raw);
+
+@protected List<Version> dco_decode_list_version(dynamic raw);
+
+@protected List<VersionDependency> dco_decode_list_version_dependency(dynamic raw);
+
+@protected List<VersionFile> dco_decode_list_version_file(dynamic raw);
+
+@protected List<WeeklyPlayStats> dco_decode_list_weekly_play_stats(dynamic raw);
+
+@protected LoaderTag dco_decode_loader_tag(dynamic raw);
+
+@protected LoaderVersion dco_decode_loader_version(dynamic raw);
+
+@protected LogLevel dco_decode_log_level(dynamic raw);
+
+@protected LogSource dco_decode_log_source(dynamic raw);
+
+@protected MatchedCause dco_decode_matched_cause(dynamic raw);
+
+@protected ModLoader dco_decode_mod_loader(dynamic raw);
+
+@protected ModrinthUser dco_decode_modrinth_user(dynamic raw);
+
+@protected MrpackExportOptions dco_decode_mrpack_export_options(dynamic raw);
+
+@protected MrpackInfo dco_decode_mrpack_info(dynamic raw);
+
+@protected MrpackOptionalFile dco_decode_mrpack_optional_file(dynamic raw);
+
+@protected NewInstance dco_decode_new_instance(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
+
+@protected ContentType? dco_decode_opt_box_autoadd_content_type(dynamic raw);
+
+@protected CrashDiagnosis? dco_decode_opt_box_autoadd_crash_diagnosis(dynamic raw);
+
+@protected EmbeddedMetadata? dco_decode_opt_box_autoadd_embedded_metadata(dynamic raw);
+
+@protected GameProcessInfo? dco_decode_opt_box_autoadd_game_process_info(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected JavaRuntimeVersion? dco_decode_opt_box_autoadd_java_runtime_version(dynamic raw);
+
+@protected ModLoader? dco_decode_opt_box_autoadd_mod_loader(dynamic raw);
+
+@protected RateLimitStatus? dco_decode_opt_box_autoadd_rate_limit_status(dynamic raw);
+
+@protected Resolution? dco_decode_opt_box_autoadd_resolution(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected PlannedInstall dco_decode_planned_install(dynamic raw);
+
+@protected PlannedUpgrade dco_decode_planned_upgrade(dynamic raw);
+
+@protected PlaySession dco_decode_play_session(dynamic raw);
+
+@protected PlayStats dco_decode_play_stats(dynamic raw);
+
+@protected Project dco_decode_project(dynamic raw);
+
+@protected RateLimitStatus dco_decode_rate_limit_status(dynamic raw);
+
+@protected (String,Version) dco_decode_record_string_version(dynamic raw);
+
+@protected Resolution dco_decode_resolution(dynamic raw);
+
+@protected ScannedContent dco_decode_scanned_content(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SearchQuery dco_decode_search_query(dynamic raw);
+
+@protected SearchResults dco_decode_search_results(dynamic raw);
+
+@protected TeamMember dco_decode_team_member(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UpdateBackup dco_decode_update_backup(dynamic raw);
+
+@protected UpdateBackupEntry dco_decode_update_backup_entry(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected Version dco_decode_version(dynamic raw);
+
+@protected VersionDependency dco_decode_version_dependency(dynamic raw);
+
+@protected VersionFile dco_decode_version_file(dynamic raw);
+
+@protected WeeklyPlayStats dco_decode_weekly_play_stats(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+@protected Map<String, Version> sse_decode_Map_String_version_None(SseDeserializer deserializer);
+
+@protected RustStreamSink<GameLogEvent> sse_decode_StreamSink_game_log_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected Account sse_decode_account(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
+@protected ContentType sse_decode_box_autoadd_content_type(SseDeserializer deserializer);
+
+@protected CrashDiagnosis sse_decode_box_autoadd_crash_diagnosis(SseDeserializer deserializer);
+
+@protected EmbeddedMetadata sse_decode_box_autoadd_embedded_metadata(SseDeserializer deserializer);
+
+@protected ExportOptions sse_decode_box_autoadd_export_options(SseDeserializer deserializer);
+
+@protected GameProcessInfo sse_decode_box_autoadd_game_process_info(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected ImportCandidate sse_decode_box_autoadd_import_candidate(SseDeserializer deserializer);
+
+@protected InstallPlan sse_decode_box_autoadd_install_plan(SseDeserializer deserializer);
+
+@protected InstanceManifest sse_decode_box_autoadd_instance_manifest(SseDeserializer deserializer);
+
+@protected JavaRuntimeVersion sse_decode_box_autoadd_java_runtime_version(SseDeserializer deserializer);
+
+@protected LaunchCommand sse_decode_box_autoadd_launch_command(SseDeserializer deserializer);
+
+@protected LaunchOptions sse_decode_box_autoadd_launch_options(SseDeserializer deserializer);
+
+@protected ModLoader sse_decode_box_autoadd_mod_loader(SseDeserializer deserializer);
+
+@protected MrpackExportOptions sse_decode_box_autoadd_mrpack_export_options(SseDeserializer deserializer);
+
+@protected NewInstance sse_decode_box_autoadd_new_instance(SseDeserializer deserializer);
+
+@protected RateLimitStatus sse_decode_box_autoadd_rate_limit_status(SseDeserializer deserializer);
+
+@protected Resolution sse_decode_box_autoadd_resolution(SseDeserializer deserializer);
+
+@protected SearchQuery sse_decode_box_autoadd_search_query(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected CategoryTag sse_decode_category_tag(SseDeserializer deserializer);
+
+@protected CompatIssue sse_decode_compat_issue(SseDeserializer deserializer);
+
+@protected CompatIssueKind sse_decode_compat_issue_kind(SseDeserializer deserializer);
+
+@protected CompatReport sse_decode_compat_report(SseDeserializer deserializer);
+
+@protected CompatSeverity sse_decode_compat_severity(SseDeserializer deserializer);
+
+@protected ConflictKind sse_decode_conflict_kind(SseDeserializer deserializer);
+
+@protected ContentRecord sse_decode_content_record(SseDeserializer deserializer);
+
+@protected ContentType sse_decode_content_type(SseDeserializer deserializer);
+
+@protected ContentUpdate sse_decode_content_update(SseDeserializer deserializer);
+
+@protected CrashCause sse_decode_crash_cause(SseDeserializer deserializer);
+
+@protected CrashDiagnosis sse_decode_crash_diagnosis(SseDeserializer deserializer);
+
+@protected CrashFileKind sse_decode_crash_file_kind(SseDeserializer deserializer);
+
+@protected DependencyConflict sse_decode_dependency_conflict(SseDeserializer deserializer);
+
+@protected EmbeddedMetadata sse_decode_embedded_metadata(SseDeserializer deserializer);
+
+@protected ExportOptions sse_decode_export_options(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FileHashes sse_decode_file_hashes(SseDeserializer deserializer);
+
+@protected GalleryImage sse_decode_gallery_image(SseDeserializer deserializer);
+
+@protected GameLogEvent sse_decode_game_log_event(SseDeserializer deserializer);
+
+@protected GameProcessInfo sse_decode_game_process_info(SseDeserializer deserializer);
+
+@protected GameVersionTag sse_decode_game_version_tag(SseDeserializer deserializer);
+
+@protected HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected ImportCandidate sse_decode_import_candidate(SseDeserializer deserializer);
+
+@protected ImportMode sse_decode_import_mode(SseDeserializer deserializer);
+
+@protected ImportSource sse_decode_import_source(SseDeserializer deserializer);
+
+@protected InstallPlan sse_decode_install_plan(SseDeserializer deserializer);
+
+@protected InstanceManifest sse_decode_instance_manifest(SseDeserializer deserializer);
+
+@protected InstanceSort sse_decode_instance_sort(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+@protected JavaRuntimeVersion sse_decode_java_runtime_version(SseDeserializer deserializer);
+
+@protected LaunchCommand sse_decode_launch_command(SseDeserializer deserializer);
+
+@protected LaunchOptions sse_decode_launch_options(SseDeserializer deserializer);
+
+@protected License sse_decode_license(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<CategoryTag> sse_decode_list_category_tag(SseDeserializer deserializer);
+
+@protected List<CompatIssue> sse_decode_list_compat_issue(SseDeserializer deserializer);
+
+@protected List<ContentRecord> sse_decode_list_content_record(SseDeserializer deserializer);
+
+@protected List<ContentUpdate> sse_decode_list_content_update(SseDeserializer deserializer);
+
+@protected List<DependencyConflict> sse_decode_list_dependency_conflict(SseDeserializer deserializer);
+
+@protected List<GalleryImage> sse_decode_list_gallery_image(SseDeserializer deserializer);
+
+@protected List<GameProcessInfo> sse_decode_list_game_process_info(SseDeserializer deserializer);
+
+@protected List<GameVersionTag> sse_decode_list_game_version_tag(SseDeserializer deserializer);
+
+@protected List<ImportCandidate> sse_decode_list_import_candidate(SseDeserializer deserializer);
+
+@protected List<InstanceManifest> sse_decode_list_instance_manifest(SseDeserializer deserializer);
+
+@protected List<JavaRuntimeVersion> sse_decode_list_java_runtime_version(SseDeserializer deserializer);
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+@protected List<LoaderTag> sse_decode_list_loader_tag(SseDeserializer deserializer);
+
+@protected List<LoaderVersion> sse_decode_list_loader_version(SseDeserializer deserializer);
+
+@protected List<MatchedCause> sse_decode_list_matched_cause(SseDeserializer deserializer);
+
+@protected List<MrpackOptionalFile> sse_decode_list_mrpack_optional_file(SseDeserializer deserializer);
+
+@protected List<PlannedInstall> sse_decode_list_planned_install(SseDeserializer deserializer);
+
+@protected List<PlannedUpgrade> sse_decode_list_planned_upgrade(SseDeserializer deserializer);
+
+@protected List<PlaySession> sse_decode_list_play_session(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<Project> sse_decode_list_project(SseDeserializer deserializer);
+
+@protected List<(String,Version)> sse_decode_list_record_string_version(SseDeserializer deserializer);
+
+@protected List<ScannedContent> sse_decode_list_scanned_content(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<TeamMember> sse_decode_list_team_member(SseDeserializer deserializer);
+
+@protected List<UpdateBackup> sse_decode_list_update_backup(SseDeserializer deserializer);
+
+@protected List<UpdateBackupEntry> sse_decode_list_update_backup_entry(SseDeserializer deserializer);
+
+@protected List<Version> sse_decode_list_version(SseDeserializer deserializer);
+
+@protected List<VersionDependency> sse_decode_list_version_dependency(SseDeserializer deserializer);
+
+@protected List<VersionFile> sse_decode_list_version_file(SseDeserializer deserializer);
+
+@protected List<WeeklyPlayStats> sse_decode_list_weekly_play_stats(SseDeserializer deserializer);
+
+@protected LoaderTag sse_decode_loader_tag(SseDeserializer deserializer);
+
+@protected LoaderVersion sse_decode_loader_version(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+@protected LogSource sse_decode_log_source(SseDeserializer deserializer);
+
+@protected MatchedCause sse_decode_matched_cause(SseDeserializer deserializer);
+
+@protected ModLoader sse_decode_mod_loader(SseDeserializer deserializer);
+
+@protected ModrinthUser sse_decode_modrinth_user(SseDeserializer deserializer);
+
+@protected MrpackExportOptions sse_decode_mrpack_export_options(SseDeserializer deserializer);
+
+@protected MrpackInfo sse_decode_mrpack_info(SseDeserializer deserializer);
+
+@protected MrpackOptionalFile sse_decode_mrpack_optional_file(SseDeserializer deserializer);
+
+@protected NewInstance sse_decode_new_instance(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
+@protected ContentType? sse_decode_opt_box_autoadd_content_type(SseDeserializer deserializer);
+
+@protected CrashDiagnosis? sse_decode_opt_box_autoadd_crash_diagnosis(SseDeserializer deserializer);
+
+@protected EmbeddedMetadata? sse_decode_opt_box_autoadd_embedded_metadata(SseDeserializer deserializer);
+
+@protected GameProcessInfo? sse_decode_opt_box_autoadd_game_process_info(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected JavaRuntimeVersion? sse_decode_opt_box_autoadd_java_runtime_version(SseDeserializer deserializer);
+
+@protected ModLoader? sse_decode_opt_box_autoadd_mod_loader(SseDeserializer deserializer);
+
+@protected RateLimitStatus? sse_decode_opt_box_autoadd_rate_limit_status(SseDeserializer deserializer);
+
+@protected Resolution? sse_decode_opt_box_autoadd_resolution(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PlannedInstall sse_decode_planned_install(SseDeserializer deserializer);
+
+@protected PlannedUpgrade sse_decode_planned_upgrade(SseDeserializer deserializer);
+
+@protected PlaySession sse_decode_play_session(SseDeserializer deserializer);
+
+@protected PlayStats sse_decode_play_stats(SseDeserializer deserializer);
+
+@protected Project sse_decode_project(SseDeserializer deserializer);
+
+@protected RateLimitStatus sse_decode_rate_limit_status(SseDeserializer deserializer);
+
+@protected (String,Version) sse_decode_record_string_version(SseDeserializer deserializer);
+
+@protected Resolution sse_decode_resolution(SseDeserializer deserializer);
+
+@protected ScannedContent sse_decode_scanned_content(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SearchQuery sse_decode_search_query(SseDeserializer deserializer);
+
+@protected SearchResults sse_decode_search_results(SseDeserializer deserializer);
+
+@protected TeamMember sse_decode_team_member(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UpdateBackup sse_decode_update_backup(SseDeserializer deserializer);
+
+@protected UpdateBackupEntry sse_decode_update_backup_entry(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected Version sse_decode_version(SseDeserializer deserializer);
+
+@protected VersionDependency sse_decode_version_dependency(SseDeserializer deserializer);
+
+@protected VersionFile sse_decode_version_file(SseDeserializer deserializer);
+
+@protected WeeklyPlayStats sse_decode_weekly_play_stats(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(FutureOr<void> Function(bool, String?) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(FutureOr<void> Function(double, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_game_process_info_Output_unit_AnyhowException(FutureOr<void> Function(GameProcessInfo) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_version_None(Map<String, Version> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_game_log_event_Sse(RustStreamSink<GameLogEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_account(Account self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Chrono_Utc(DateTime self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_content_type(ContentType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_crash_diagnosis(CrashDiagnosis self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_embedded_metadata(EmbeddedMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_export_options(ExportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_game_process_info(GameProcessInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_import_candidate(ImportCandidate self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_install_plan(InstallPlan self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_instance_manifest(InstanceManifest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_java_runtime_version(JavaRuntimeVersion self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_launch_command(LaunchCommand self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_launch_options(LaunchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_mod_loader(ModLoader self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_mrpack_export_options(MrpackExportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_new_instance(NewInstance self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rate_limit_status(RateLimitStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_resolution(Resolution self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_query(SearchQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_category_tag(CategoryTag self, SseSerializer serializer);
+
+@protected void sse_encode_compat_issue(CompatIssue self, SseSerializer serializer);
+
+@protected void sse_encode_compat_issue_kind(CompatIssueKind self, SseSerializer serializer);
+
+@protected void sse_encode_compat_report(CompatReport self, SseSerializer serializer);
+
+@protected void sse_encode_compat_severity(CompatSeverity self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_kind(ConflictKind self, SseSerializer serializer);
+
+@protected void sse_encode_content_record(ContentRecord self, SseSerializer serializer);
+
+@protected void sse_encode_content_type(ContentType self, SseSerializer serializer);
+
+@protected void sse_encode_content_update(ContentUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_crash_cause(CrashCause self, SseSerializer serializer);
+
+@protected void sse_encode_crash_diagnosis(CrashDiagnosis self, SseSerializer serializer);
+
+@protected void sse_encode_crash_file_kind(CrashFileKind self, SseSerializer serializer);
+
+@protected void sse_encode_dependency_conflict(DependencyConflict self, SseSerializer serializer);
+
+@protected void sse_encode_embedded_metadata(EmbeddedMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_export_options(ExportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_file_hashes(FileHashes self, SseSerializer serializer);
+
+@protected void sse_encode_gallery_image(GalleryImage self, SseSerializer serializer);
+
+@protected void sse_encode_game_log_event(GameLogEvent self, SseSerializer serializer);
+
+@protected void sse_encode_game_process_info(GameProcessInfo self, SseSerializer serializer);
+
+@protected void sse_encode_game_version_tag(GameVersionTag self, SseSerializer serializer);
+
+@protected void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_import_candidate(ImportCandidate self, SseSerializer serializer);
+
+@protected void sse_encode_import_mode(ImportMode self, SseSerializer serializer);
+
+@protected void sse_encode_import_source(ImportSource self, SseSerializer serializer);
+
+@protected void sse_encode_install_plan(InstallPlan self, SseSerializer serializer);
+
+@protected void sse_encode_instance_manifest(InstanceManifest self, SseSerializer serializer);
+
+@protected void sse_encode_instance_sort(InstanceSort self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_java_runtime_version(JavaRuntimeVersion self, SseSerializer serializer);
+
+@protected void sse_encode_launch_command(LaunchCommand self, SseSerializer serializer);
+
+@protected void sse_encode_launch_options(LaunchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_license(License self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_category_tag(List<CategoryTag> self, SseSerializer serializer);
+
+@protected void sse_encode_list_compat_issue(List<CompatIssue> self, SseSerializer serializer);
+
+@protected void sse_encode_list_content_record(List<ContentRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_content_update(List<ContentUpdate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_dependency_conflict(List<DependencyConflict> self, SseSerializer serializer);
+
+@protected void sse_encode_list_gallery_image(List<GalleryImage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_game_process_info(List<GameProcessInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_game_version_tag(List<GameVersionTag> self, SseSerializer serializer);
+
+@protected void sse_encode_list_import_candidate(List<ImportCandidate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_instance_manifest(List<InstanceManifest> self, SseSerializer serializer);
+
+@protected void sse_encode_list_java_runtime_version(List<JavaRuntimeVersion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_loader_tag(List<LoaderTag> self, SseSerializer serializer);
+
+@protected void sse_encode_list_loader_version(List<LoaderVersion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_matched_cause(List<MatchedCause> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mrpack_optional_file(List<MrpackOptionalFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_planned_install(List<PlannedInstall> self, SseSerializer serializer);
+
+@protected void sse_encode_list_planned_upgrade(List<PlannedUpgrade> self, SseSerializer serializer);
+
+@protected void sse_encode_list_play_session(List<PlaySession> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_project(List<Project> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_version(List<(String,Version)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_scanned_content(List<ScannedContent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_team_member(List<TeamMember> self, SseSerializer serializer);
+
+@protected void sse_encode_list_update_backup(List<UpdateBackup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_update_backup_entry(List<UpdateBackupEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_version(List<Version> self, SseSerializer serializer);
+
+@protected void sse_encode_list_version_dependency(List<VersionDependency> self, SseSerializer serializer);
+
+@protected void sse_encode_list_version_file(List<VersionFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_weekly_play_stats(List<WeeklyPlayStats> self, SseSerializer serializer);
+
+@protected void sse_encode_loader_tag(LoaderTag self, SseSerializer serializer);
 
-  @protected
-  Object dco_decode_DartOpaque(dynamic raw);
+@protected void sse_encode_loader_version(LoaderVersion self, SseSerializer serializer);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected void sse_encode_log_source(LogSource self, SseSerializer serializer);
 
-  @protected
-  JavaRuntimeVersion dco_decode_box_autoadd_java_runtime_version(dynamic raw);
+@protected void sse_encode_matched_cause(MatchedCause self, SseSerializer serializer);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected void sse_encode_mod_loader(ModLoader self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected void sse_encode_modrinth_user(ModrinthUser self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected void sse_encode_mrpack_export_options(MrpackExportOptions self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 dco_decode_isize(dynamic raw);
+@protected void sse_encode_mrpack_info(MrpackInfo self, SseSerializer serializer);
 
-  @protected
-  JavaRuntimeVersion dco_decode_java_runtime_version(dynamic raw);
+@protected void sse_encode_mrpack_optional_file(MrpackOptionalFile self, SseSerializer serializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected void sse_encode_new_instance(NewInstance self, SseSerializer serializer);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
-  @protected
-  JavaRuntimeVersion? dco_decode_opt_box_autoadd_java_runtime_version(
-      dynamic raw);
+@protected void sse_encode_opt_box_autoadd_Chrono_Utc(DateTime? self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_content_type(ContentType? self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_crash_diagnosis(CrashDiagnosis? self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_embedded_metadata(EmbeddedMetadata? self, SseSerializer serializer);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_game_process_info(GameProcessInfo? self, SseSerializer serializer);
 
-  @protected
-  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_java_runtime_version(JavaRuntimeVersion? self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_mod_loader(ModLoader? self, SseSerializer serializer);
 
-  @protected
-  JavaRuntimeVersion sse_decode_box_autoadd_java_runtime_version(
-      SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_rate_limit_status(RateLimitStatus? self, SseSerializer serializer);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_resolution(Resolution? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
 
-  @protected
-  JavaRuntimeVersion sse_decode_java_runtime_version(
-      SseDeserializer deserializer);
+@protected void sse_encode_planned_install(PlannedInstall self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_planned_upgrade(PlannedUpgrade self, SseSerializer serializer);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected void sse_encode_play_session(PlaySession self, SseSerializer serializer);
 
-  @protected
-  JavaRuntimeVersion? sse_decode_opt_box_autoadd_java_runtime_version(
-      SseDeserializer deserializer);
+@protected void sse_encode_play_stats(PlayStats self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_project(Project self, SseSerializer serializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_rate_limit_status(RateLimitStatus self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected void sse_encode_record_string_version((String,Version) self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected void sse_encode_resolution(Resolution self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs_bool_opt_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(bool, String?) self, SseSerializer serializer);
+@protected void sse_encode_scanned_content(ScannedContent self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartFn_Inputs_f_64_String_Output_unit_AnyhowException(
-      FutureOr<void> Function(double, String) self, SseSerializer serializer);
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+@protected void sse_encode_search_query(SearchQuery self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_search_results(SearchResults self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected void sse_encode_team_member(TeamMember self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_box_autoadd_java_runtime_version(
-      JavaRuntimeVersion self, SseSerializer serializer);
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+@protected void sse_encode_unit(void self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+@protected void sse_encode_update_backup(UpdateBackup self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_java_runtime_version(
-      JavaRuntimeVersion self, SseSerializer serializer);
+@protected void sse_encode_update_backup_entry(UpdateBackupEntry self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected void sse_encode_version(Version self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_java_runtime_version(
-      JavaRuntimeVersion? self, SseSerializer serializer);
+@protected void sse_encode_version_dependency(VersionDependency self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_version_file(VersionFile self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected void sse_encode_weekly_play_stats(WeeklyPlayStats self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,65 @@
+-- 实例索引 (实例清单仍保存在各实例目录中，这里只用于搜索与排序)
+CREATE TABLE instances (
+    id              TEXT PRIMARY KEY NOT NULL,
+    name            TEXT NOT NULL,
+    game_version    TEXT NOT NULL,
+    loader          TEXT NOT NULL,
+    loader_version  TEXT,
+    path            TEXT NOT NULL,
+    icon            TEXT,
+    play_time_secs  INTEGER NOT NULL DEFAULT 0,
+    created         TEXT NOT NULL,
+    last_played     TEXT
+);
+
+CREATE INDEX idx_instances_name ON instances (name COLLATE NOCASE);
+CREATE INDEX idx_instances_last_played ON instances (last_played);
+
+-- 实例中已安装的内容 (模组、资源包、光影、数据包)
+CREATE TABLE instance_content (
+    instance_id     TEXT NOT NULL REFERENCES instances (id) ON DELETE CASCADE,
+    -- 相对实例目录的路径，如 mods/sodium.jar
+    path            TEXT NOT NULL,
+    project_type    TEXT NOT NULL,
+    project_id      TEXT,
+    version_id      TEXT,
+    sha1            TEXT NOT NULL,
+    sha512          TEXT,
+    file_size       INTEGER NOT NULL,
+    enabled         INTEGER NOT NULL DEFAULT 1,
+    installed_at    TEXT NOT NULL,
+    PRIMARY KEY (instance_id, path)
+);
+
+CREATE INDEX idx_instance_content_sha1 ON instance_content (sha1);
+
+-- 已发现的 Java 运行时
+CREATE TABLE java_runtimes (
+    path            TEXT PRIMARY KEY NOT NULL,
+    version         TEXT NOT NULL,
+    major_version   INTEGER NOT NULL,
+    discovered_at   TEXT NOT NULL
+);
+
+-- 下载缓存
+CREATE TABLE download_cache (
+    url             TEXT PRIMARY KEY NOT NULL,
+    path            TEXT NOT NULL,
+    sha1            TEXT,
+    size            INTEGER NOT NULL,
+    fetched_at      TEXT NOT NULL
+);
+
+-- 游戏会话
+CREATE TABLE play_sessions (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    instance_id     TEXT NOT NULL,
+    pid             INTEGER,
+    java_version    TEXT,
+    start_time      TEXT NOT NULL,
+    end_time        TEXT,
+    exit_code       INTEGER,
+    crashed         INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_play_sessions_instance ON play_sessions (instance_id, start_time);
@@ -0,0 +1,2 @@
+-- 游戏运行期间定期更新，启动器异常退出后用于估算会话结束时间
+ALTER TABLE play_sessions ADD COLUMN last_seen TEXT;
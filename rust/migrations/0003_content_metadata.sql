@@ -0,0 +1,16 @@
+-- 按文件哈希缓存的内容识别结果 (Modrinth 查询结果或文件内嵌的元数据)
+CREATE TABLE content_metadata (
+    sha1            TEXT PRIMARY KEY NOT NULL,
+    sha512          TEXT NOT NULL,
+    -- Modrinth 未收录时为空
+    project_id      TEXT,
+    version_id      TEXT,
+    title           TEXT,
+    version_number  TEXT,
+    icon_url        TEXT,
+    -- 文件内嵌的元数据 (JSON)
+    embedded        TEXT,
+    -- 文件内嵌的图标
+    icon            BLOB,
+    scanned_at      TEXT NOT NULL
+);
@@ -0,0 +1,2 @@
+-- 用户为内容设置的标签 (JSON 数组)，用于按标签批量启用 / 禁用
+ALTER TABLE instance_content ADD COLUMN tags TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1,15 @@
+-- Modrinth API 响应缓存，用于过期后重新验证与离线浏览
+CREATE TABLE modrinth_cache (
+    -- 请求地址与参数，单个项目 / 版本按 ID 保存
+    key             TEXT PRIMARY KEY NOT NULL,
+    -- 响应 JSON
+    body            TEXT NOT NULL,
+    fetched_at      TEXT NOT NULL
+);
+
+-- 项目图标缓存
+CREATE TABLE modrinth_icon_cache (
+    url             TEXT PRIMARY KEY NOT NULL,
+    data            BLOB NOT NULL,
+    fetched_at      TEXT NOT NULL
+);
@@ -0,0 +1,437 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::database::{self, ContentRecord};
+use crate::api::instance;
+use crate::api::mod_loader::ModLoader;
+use crate::api::modpack::safe_relative_path;
+use crate::api::modrinth::{ModrinthClient, Version, VersionFile};
+use crate::checksum;
+use crate::download;
+
+/// 配置常量
+mod config {
+    /// 存档目录
+    pub const SAVES_DIR: &str = "saves";
+    /// 禁用内容的文件名后缀 (与其他启动器一致)
+    pub const DISABLED_SUFFIX: &str = ".disabled";
+}
+
+/// 文件是否已被禁用
+pub(crate) fn is_disabled(path: &str) -> bool {
+    path.ends_with(config::DISABLED_SUFFIX)
+}
+
+/// 去掉禁用后缀的路径
+pub(crate) fn enabled_path(path: &str) -> &str {
+    path.strip_suffix(config::DISABLED_SUFFIX).unwrap_or(path)
+}
+
+/// 按启用状态得到文件路径
+pub(crate) fn path_with_state(path: &str, enabled: bool) -> String {
+    let path = enabled_path(path);
+    if enabled {
+        path.to_string()
+    } else {
+        format!("{}{}", path, config::DISABLED_SUFFIX)
+    }
+}
+
+/// 实例内容类型，与 Modrinth 的 `project_type` 一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentType {
+    Mod,
+    ResourcePack,
+    Shader,
+    DataPack,
+}
+
+impl ContentType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ContentType::Mod => "mod",
+            ContentType::ResourcePack => "resourcepack",
+            ContentType::Shader => "shader",
+            ContentType::DataPack => "datapack",
+        }
+    }
+
+    /// 由 Modrinth 项目类型与版本加载器推断，只支持数据包加载器的模组视为数据包
+    pub(crate) fn from_modrinth(project_type: &str, version: &Version) -> Result<Self> {
+        match project_type {
+            "mod" if !version.loaders.is_empty() && version.loaders.iter().all(|loader| loader == "datapack") => {
+                Ok(ContentType::DataPack)
+            }
+            "mod" => Ok(ContentType::Mod),
+            "resourcepack" => Ok(ContentType::ResourcePack),
+            "shader" => Ok(ContentType::Shader),
+            "datapack" => Ok(ContentType::DataPack),
+            other => Err(anyhow!("不支持安装到实例的项目类型: {}", other)),
+        }
+    }
+
+    /// 该类型内容在 Modrinth 上适用于实例的加载器
+    pub(crate) fn modrinth_loaders(&self, loader: ModLoader) -> Vec<String> {
+        let loaders: &[&str] = match self {
+            ContentType::Mod if loader == ModLoader::Quilt => &["quilt", "fabric"],
+            ContentType::Mod => &[loader.as_str()],
+            ContentType::ResourcePack => &["minecraft"],
+            ContentType::Shader => &["iris", "optifine", "canvas", "vanilla"],
+            ContentType::DataPack => &["datapack"],
+        };
+        loaders.iter().map(|loader| loader.to_string()).collect()
+    }
+
+    /// 相对实例目录的安装目录，数据包安装到指定存档中
+    pub(crate) fn folder(&self, world: Option<&str>) -> Result<String> {
+        Ok(match self {
+            ContentType::Mod => "mods".to_string(),
+            ContentType::ResourcePack => "resourcepacks".to_string(),
+            ContentType::Shader => "shaderpacks".to_string(),
+            ContentType::DataPack => {
+                let world = world.ok_or_else(|| anyhow!("安装数据包需要指定存档"))?;
+                if safe_relative_path(world).is_none_or(|path| path.components().count() != 1) {
+                    return Err(anyhow!("无效的存档名称: {}", world));
+                }
+                format!("{}/{}/datapacks", config::SAVES_DIR, world)
+            }
+        })
+    }
+}
+
+/// 下载文件并校验 SHA-1 与 SHA-512
+pub(crate) async fn download_version_file(file: &VersionFile, dest: &Path) -> Result<()> {
+    download::download_file(&file.url, dest, Some(&file.hashes.sha1)).await?;
+    if !file.hashes.sha512.is_empty() {
+        if let Err(e) = checksum::verify_file_sha512(dest, &file.hashes.sha512).await {
+            fs::remove_file(dest).await.ok();
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// 由 Modrinth 版本文件生成内容记录
+pub(crate) fn version_record(
+    instance_id: &str,
+    relative: String,
+    content_type: ContentType,
+    version: &Version,
+    file: &VersionFile,
+) -> ContentRecord {
+    ContentRecord {
+        instance_id: instance_id.to_string(),
+        path: relative,
+        project_type: content_type.as_str().to_string(),
+        project_id: Some(version.project_id.clone()),
+        version_id: Some(version.id.clone()),
+        sha1: file.hashes.sha1.clone(),
+        sha512: Some(file.hashes.sha512.clone()).filter(|sha512| !sha512.is_empty()),
+        file_size: file.size,
+        enabled: true,
+        installed_at: Utc::now(),
+        tags: Vec::new(),
+    }
+}
+
+/// 版本的主文件，文件名须为单个普通路径组件
+pub(crate) fn primary_file(version: &Version) -> Result<&VersionFile> {
+    let file = version
+        .primary_file()
+        .ok_or_else(|| anyhow!("版本 {} 没有可下载的文件", version.id))?;
+    if safe_relative_path(&file.filename).is_none_or(|path| path.components().count() != 1) {
+        return Err(anyhow!("无效的文件名: {}", file.filename));
+    }
+    Ok(file)
+}
+
+/// 把版本的主文件安装到实例目录并记录来源，同一项目在该目录下的旧文件会被替换
+pub(crate) async fn install_version(
+    instance_dir: &Path,
+    instance_id: &str,
+    version: &Version,
+    content_type: ContentType,
+    world: Option<&str>,
+) -> Result<ContentRecord> {
+    // 更新检查与依赖解析都依赖来源记录，无法记录时不安装
+    if !database::is_initialized() {
+        return Err(anyhow!("数据库尚未初始化，无法记录内容来源"));
+    }
+    let file = primary_file(version)?;
+    let folder = content_type.folder(world)?;
+    let relative = format!("{}/{}", folder, file.filename);
+    download_version_file(file, &instance_dir.join(&relative)).await?;
+
+    let record = version_record(instance_id, relative, content_type, version, file);
+    let previous = database::content_by_project(instance_id, &version.project_id).await?;
+    for old in previous
+        .iter()
+        .filter(|old| old.path != record.path && Path::new(&old.path).parent() == Path::new(&record.path).parent())
+    {
+        fs::remove_file(instance_dir.join(&old.path)).await.ok();
+        database::remove_content(instance_id, &old.path).await?;
+    }
+    database::upsert_content(&record).await?;
+    Ok(record)
+}
+
+/// 把 Modrinth 版本安装到实例。`content_type` 为空时按项目类型推断；
+/// 安装数据包时 `world` 为存档目录名
+pub async fn install_content(
+    resource_dir: String,
+    instance_id: String,
+    version_id: String,
+    content_type: Option<ContentType>,
+    world: Option<String>,
+) -> Result<ContentRecord> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let client = ModrinthClient::default();
+    let version = client.version(&version_id).await?;
+    let content_type = match content_type {
+        Some(content_type) => content_type,
+        None => {
+            let project = client.project(&version.project_id).await?;
+            ContentType::from_modrinth(&project.project_type, &version)?
+        }
+    };
+    install_version(
+        &PathBuf::from(&manifest.path),
+        &manifest.id,
+        &version,
+        content_type,
+        world.as_deref(),
+    )
+    .await
+}
+
+/// 重命名文件切换启用状态并同步内容记录，返回新的相对路径
+pub(crate) async fn set_enabled(instance_dir: &Path, instance_id: &str, path: &str, enabled: bool) -> Result<String> {
+    if safe_relative_path(path).is_none() {
+        return Err(anyhow!("无效的文件路径: {}", path));
+    }
+    let target = path_with_state(path, enabled);
+    if target == path {
+        return Ok(target);
+    }
+    if fs::try_exists(instance_dir.join(&target)).await? {
+        return Err(anyhow!("{} 已存在", target));
+    }
+    fs::rename(instance_dir.join(path), instance_dir.join(&target)).await?;
+
+    if database::is_initialized() {
+        let records = database::list_instance_content(instance_id.to_string(), None).await?;
+        if let Some(mut record) = records.into_iter().find(|record| record.path == path) {
+            record.path = target.clone();
+            record.enabled = enabled;
+            database::replace_content(instance_id, &[path.to_string()], &[record]).await?;
+        }
+    }
+    Ok(target)
+}
+
+/// 启用或禁用内容 (重命名为 / 去掉 `.disabled` 后缀)，返回新的相对路径
+pub async fn set_content_enabled(
+    resource_dir: String,
+    instance_id: String,
+    path: String,
+    enabled: bool,
+) -> Result<String> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    set_enabled(Path::new(&manifest.path), &manifest.id, &path, enabled).await
+}
+
+/// 批量启用或禁用带有指定标签的内容，返回变更后的路径
+pub async fn set_content_enabled_by_tag(
+    resource_dir: String,
+    instance_id: String,
+    tag: String,
+    enabled: bool,
+) -> Result<Vec<String>> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let records = database::list_instance_content(manifest.id.clone(), None).await?;
+    let mut changed = Vec::new();
+    for record in records.iter().filter(|record| record.tags.contains(&tag) && record.enabled != enabled) {
+        changed.push(set_enabled(Path::new(&manifest.path), &manifest.id, &record.path, enabled).await?);
+    }
+    Ok(changed)
+}
+
+/// 以标签作为配置方案：启用带有该标签的模组，禁用其余模组。返回变更后的路径
+pub async fn apply_content_profile(resource_dir: String, instance_id: String, tag: String) -> Result<Vec<String>> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let records = database::list_instance_content(manifest.id.clone(), Some(ContentType::Mod.as_str().to_string())).await?;
+    let mut changed = Vec::new();
+    for record in &records {
+        let enabled = record.tags.contains(&tag);
+        if record.enabled != enabled {
+            changed.push(set_enabled(Path::new(&manifest.path), &manifest.id, &record.path, enabled).await?);
+        }
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::database::tests::{record, with_pool};
+    use crate::api::instance::NewInstance;
+    use crate::api::mod_loader::ModLoader;
+
+    fn version(loaders: &[&str]) -> Version {
+        Version {
+            loaders: loaders.iter().map(|loader| loader.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn infers_content_type() {
+        let from = |project_type, loaders| ContentType::from_modrinth(project_type, &version(loaders)).unwrap();
+        assert_eq!(from("mod", &["datapack"]), ContentType::DataPack);
+        assert_eq!(from("mod", &["fabric", "datapack"]), ContentType::Mod);
+        // 没有加载器信息时按普通模组处理
+        assert_eq!(from("mod", &[]), ContentType::Mod);
+        assert_eq!(from("shader", &["iris"]), ContentType::Shader);
+        assert!(ContentType::from_modrinth("modpack", &version(&[])).is_err());
+    }
+
+    /// 创建实例并写入内容文件及记录，`files` 为 (路径, 标签)，带 `.disabled` 后缀的记为禁用
+    async fn instance_with(resource_dir: &Path, files: &[(&str, &[&str])]) -> instance::InstanceManifest {
+        let manifest = instance::create_instance(
+            resource_dir.to_string_lossy().to_string(),
+            NewInstance {
+                name: "Profiles".to_string(),
+                game_version: "1.20.1".to_string(),
+                loader: ModLoader::Fabric,
+                loader_version: None,
+                icon: None,
+            },
+        )
+        .await
+        .unwrap();
+        for (path, tags) in files {
+            let full = Path::new(&manifest.path).join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(&full, path).unwrap();
+            let mut content = record(&manifest.id, path, path);
+            content.project_type = if path.starts_with("mods/") { "mod" } else { "resourcepack" }.to_string();
+            content.enabled = !is_disabled(path);
+            content.tags = tags.iter().map(|tag| tag.to_string()).collect();
+            database::upsert_content(&content).await.unwrap();
+        }
+        manifest
+    }
+
+    /// 记录的 (路径, 启用状态, 标签)，按路径排序
+    async fn states(instance_id: &str) -> Vec<(String, bool, Vec<String>)> {
+        let mut records = database::list_instance_content(instance_id.to_string(), None).await.unwrap();
+        records.sort_by(|a, b| a.path.cmp(&b.path));
+        records
+            .into_iter()
+            .map(|record| (record.path, record.enabled, record.tags))
+            .collect()
+    }
+
+    fn state(path: &str, enabled: bool, tags: &[&str]) -> (String, bool, Vec<String>) {
+        (path.to_string(), enabled, tags.iter().map(|tag| tag.to_string()).collect())
+    }
+
+    #[tokio::test]
+    async fn toggles_content_by_tag() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let manifest = instance_with(
+                dir.path(),
+                &[("mods/a.jar", &["perf"]), ("mods/b.jar.disabled", &["perf"]), ("mods/c.jar", &[])],
+            )
+            .await;
+            let resource_dir = dir.path().to_string_lossy().to_string();
+            let root = Path::new(&manifest.path);
+
+            let changed = set_content_enabled_by_tag(resource_dir.clone(), manifest.id.clone(), "perf".to_string(), false)
+                .await
+                .unwrap();
+            assert_eq!(changed, ["mods/a.jar.disabled"]);
+            assert!(root.join("mods/a.jar.disabled").exists());
+            assert!(!root.join("mods/a.jar").exists());
+
+            let mut changed = set_content_enabled_by_tag(resource_dir, manifest.id.clone(), "perf".to_string(), true)
+                .await
+                .unwrap();
+            changed.sort();
+            assert_eq!(changed, ["mods/a.jar", "mods/b.jar"]);
+            assert_eq!(std::fs::read_to_string(root.join("mods/b.jar")).unwrap(), "mods/b.jar.disabled");
+            assert_eq!(
+                states(&manifest.id).await,
+                [
+                    state("mods/a.jar", true, &["perf"]),
+                    state("mods/b.jar", true, &["perf"]),
+                    state("mods/c.jar", true, &[]),
+                ]
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn applies_content_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let manifest = instance_with(
+                dir.path(),
+                &[
+                    ("mods/a.jar", &["pvp"]),
+                    ("mods/b.jar.disabled", &["pvp", "perf"]),
+                    ("mods/c.jar", &["perf"]),
+                    ("resourcepacks/d.zip", &[]),
+                ],
+            )
+            .await;
+            let resource_dir = dir.path().to_string_lossy().to_string();
+
+            let mut changed = apply_content_profile(resource_dir, manifest.id.clone(), "pvp".to_string())
+                .await
+                .unwrap();
+            changed.sort();
+            assert_eq!(changed, ["mods/b.jar", "mods/c.jar.disabled"]);
+            // 只切换模组，资源包不受配置方案影响
+            assert_eq!(
+                states(&manifest.id).await,
+                [
+                    state("mods/a.jar", true, &["pvp"]),
+                    state("mods/b.jar", true, &["pvp", "perf"]),
+                    state("mods/c.jar.disabled", false, &["perf"]),
+                    state("resourcepacks/d.zip", true, &[]),
+                ]
+            );
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn refuses_to_overwrite_existing_target() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let manifest = instance_with(dir.path(), &[("mods/a.jar", &["perf"])]).await;
+            let root = Path::new(&manifest.path);
+            std::fs::write(root.join("mods/a.jar.disabled"), "other").unwrap();
+
+            let result = set_content_enabled(
+                dir.path().to_string_lossy().to_string(),
+                manifest.id.clone(),
+                "mods/a.jar".to_string(),
+                false,
+            )
+            .await;
+            assert!(result.is_err());
+            assert_eq!(std::fs::read_to_string(root.join("mods/a.jar")).unwrap(), "mods/a.jar");
+            assert_eq!(std::fs::read_to_string(root.join("mods/a.jar.disabled")).unwrap(), "other");
+            assert_eq!(states(&manifest.id).await, [state("mods/a.jar", true, &["perf"])]);
+        })
+        .await;
+    }
+}
@@ -0,0 +1,514 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::content::{self, ContentType};
+use crate::api::database::{self, pool, ContentRecord};
+use crate::api::instance;
+use crate::api::mod_loader::ModLoader;
+use crate::api::modrinth::{HashAlgorithm, ModrinthClient};
+use crate::checksum;
+use crate::mod_metadata::{self, ModMetadata};
+
+/// 配置常量
+mod config {
+    /// 扫描的内容类型
+    pub const SCANNED_TYPES: &[super::ContentType] = &[
+        super::ContentType::Mod,
+        super::ContentType::ResourcePack,
+        super::ContentType::Shader,
+    ];
+    /// 识别为内容文件的扩展名
+    pub const CONTENT_EXTENSIONS: &[&str] = &["jar", "zip"];
+    /// Modrinth 未收录的文件，超过该天数后重新查询
+    pub const UNKNOWN_RECHECK_DAYS: i64 = 7;
+}
+
+/// 文件内嵌的模组元数据
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbeddedMetadata {
+    /// 元数据所属的加载器 (mcmod.info 视为 Forge)
+    pub loader: Option<ModLoader>,
+    pub mod_id: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub authors: Vec<String>,
+}
+
+/// 扫描到的内容文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScannedContent {
+    /// 相对实例目录的路径
+    pub path: String,
+    pub file_name: String,
+    pub content_type: ContentType,
+    /// 文件名带 `.disabled` 后缀时为 false
+    pub enabled: bool,
+    /// 用户设置的标签 (数据库未初始化时为空)
+    pub tags: Vec<String>,
+    pub size: u64,
+    pub sha1: String,
+    pub sha512: String,
+    /// Modrinth 未收录时为空
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    pub title: Option<String>,
+    pub version_number: Option<String>,
+    pub icon_url: Option<String>,
+    /// Modrinth 未收录时从文件中读取
+    pub embedded: Option<EmbeddedMetadata>,
+    /// 文件内嵌的图标
+    pub icon: Option<Vec<u8>>,
+}
+
+/// 按哈希缓存的识别结果
+#[derive(Debug, Clone, Default)]
+#[frb(ignore)]
+struct Identification {
+    project_id: Option<String>,
+    version_id: Option<String>,
+    title: Option<String>,
+    version_number: Option<String>,
+    icon_url: Option<String>,
+    embedded: Option<EmbeddedMetadata>,
+    icon: Option<Vec<u8>>,
+    scanned_at: Option<DateTime<Utc>>,
+}
+
+impl Identification {
+    fn from_row(row: &SqliteRow) -> Result<Self> {
+        let embedded: Option<String> = row.try_get("embedded")?;
+        Ok(Self {
+            project_id: row.try_get("project_id")?,
+            version_id: row.try_get("version_id")?,
+            title: row.try_get("title")?,
+            version_number: row.try_get("version_number")?,
+            icon_url: row.try_get("icon_url")?,
+            embedded: embedded.and_then(|text| serde_json::from_str(&text).ok()),
+            icon: row.try_get("icon")?,
+            scanned_at: Some(row.try_get("scanned_at")?),
+        })
+    }
+
+    /// 未被 Modrinth 收录且距上次查询已久，需要重新查询
+    fn needs_lookup(&self) -> bool {
+        self.project_id.is_none()
+            && self
+                .scanned_at
+                .is_none_or(|scanned_at| Utc::now() - scanned_at > Duration::days(config::UNKNOWN_RECHECK_DAYS))
+    }
+}
+
+impl EmbeddedMetadata {
+    fn from_metadata(metadata: ModMetadata) -> Self {
+        Self {
+            loader: Some(metadata.loader),
+            mod_id: Some(metadata.mod_id),
+            name: metadata.name,
+            version: metadata.version,
+            description: metadata.description,
+            authors: metadata.authors,
+        }
+    }
+}
+
+/// 读取 jar 内嵌的元数据与图标
+fn read_embedded(path: &Path) -> Result<Option<(EmbeddedMetadata, Option<Vec<u8>>)>> {
+    Ok(mod_metadata::read_primary_metadata(path)?
+        .map(|(metadata, icon)| (EmbeddedMetadata::from_metadata(metadata), icon)))
+}
+
+/// 列出目录中的内容文件 (相对实例目录的路径)
+async fn list_content_files(instance_dir: &Path, folder: &str) -> Result<Vec<(String, u64)>> {
+    let dir = instance_dir.join(folder);
+    if !fs::try_exists(&dir).await? {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    let mut entries = fs::read_dir(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let metadata = entry.metadata().await?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let known = Path::new(content::enabled_path(&name))
+            .extension()
+            .is_some_and(|extension| config::CONTENT_EXTENSIONS.contains(&extension.to_string_lossy().as_ref()));
+        if metadata.is_file() && known {
+            files.push((format!("{}/{}", folder, name), metadata.len()));
+        }
+    }
+    Ok(files)
+}
+
+async fn cached_identification(sha1: &str) -> Result<Option<Identification>> {
+    let row = sqlx::query("SELECT * FROM content_metadata WHERE sha1 = ?")
+        .bind(sha1)
+        .fetch_optional(pool()?)
+        .await?;
+    row.as_ref().map(Identification::from_row).transpose()
+}
+
+async fn save_identification(sha1: &str, sha512: &str, identification: &Identification) -> Result<()> {
+    let embedded = identification
+        .embedded
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()?;
+    sqlx::query(
+        "INSERT INTO content_metadata
+            (sha1, sha512, project_id, version_id, title, version_number, icon_url, embedded, icon, scanned_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT (sha1) DO UPDATE SET
+            sha512 = excluded.sha512,
+            project_id = excluded.project_id,
+            version_id = excluded.version_id,
+            title = excluded.title,
+            version_number = excluded.version_number,
+            icon_url = excluded.icon_url,
+            embedded = excluded.embedded,
+            icon = excluded.icon,
+            scanned_at = excluded.scanned_at",
+    )
+    .bind(sha1)
+    .bind(sha512)
+    .bind(&identification.project_id)
+    .bind(&identification.version_id)
+    .bind(&identification.title)
+    .bind(&identification.version_number)
+    .bind(&identification.icon_url)
+    .bind(embedded)
+    .bind(&identification.icon)
+    .bind(Utc::now())
+    .execute(pool()?)
+    .await?;
+    Ok(())
+}
+
+/// 按哈希批量查询 Modrinth，离线或请求失败时返回 None
+async fn lookup_modrinth(client: &ModrinthClient, hashes: &[String]) -> Option<HashMap<String, Identification>> {
+    if hashes.is_empty() {
+        return Some(HashMap::new());
+    }
+    let versions = client.versions_from_hashes(hashes, HashAlgorithm::Sha1).await.ok()?;
+    let mut project_ids: Vec<String> = versions.values().map(|version| version.project_id.clone()).collect();
+    project_ids.sort();
+    project_ids.dedup();
+    let projects: HashMap<String, _> = client
+        .projects(&project_ids)
+        .await
+        .ok()?
+        .into_iter()
+        .map(|project| (project.id.clone(), project))
+        .collect();
+
+    Some(
+        versions
+            .into_iter()
+            .map(|(sha1, version)| {
+                let project = projects.get(&version.project_id);
+                let identification = Identification {
+                    title: project.map(|project| project.title.clone()),
+                    icon_url: project.and_then(|project| project.icon_url.clone()),
+                    project_id: Some(version.project_id),
+                    version_id: Some(version.id),
+                    version_number: Some(version.version_number),
+                    ..Default::default()
+                };
+                (sha1, identification)
+            })
+            .collect(),
+    )
+}
+
+/// 扫描实例的模组、资源包与光影包，按哈希到 Modrinth 识别，未收录的读取文件内嵌元数据。
+/// 结果按哈希缓存，并同步到实例内容记录
+pub async fn scan_instance_content(resource_dir: String, instance_id: String) -> Result<Vec<ScannedContent>> {
+    scan_instance_content_with(&ModrinthClient::default(), resource_dir, instance_id).await
+}
+
+pub(crate) async fn scan_instance_content_with(
+    client: &ModrinthClient,
+    resource_dir: String,
+    instance_id: String,
+) -> Result<Vec<ScannedContent>> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let instance_dir = PathBuf::from(&manifest.path);
+    let use_cache = database::is_initialized();
+
+    let mut scanned = Vec::new();
+    let mut identifications = Vec::new();
+    for content_type in config::SCANNED_TYPES {
+        let folder = content_type.folder(None)?;
+        for (relative, size) in list_content_files(&instance_dir, &folder).await? {
+            let path = instance_dir.join(&relative);
+            let sha1 = checksum::file_sha1(&path).await?;
+            let sha512 = checksum::file_sha512(&path).await?;
+            let cached = if use_cache { cached_identification(&sha1).await? } else { None };
+            identifications.push(cached);
+            let enabled = !content::is_disabled(&relative);
+            scanned.push(ScannedContent {
+                file_name: relative.rsplit('/').next().unwrap_or_default().to_string(),
+                path: relative,
+                content_type: *content_type,
+                enabled,
+                tags: Vec::new(),
+                size,
+                sha1,
+                sha512,
+                project_id: None,
+                version_id: None,
+                title: None,
+                version_number: None,
+                icon_url: None,
+                embedded: None,
+                icon: None,
+            });
+        }
+    }
+
+    let mut pending: Vec<String> = scanned
+        .iter()
+        .zip(&identifications)
+        .filter(|(_, cached)| cached.as_ref().is_none_or(Identification::needs_lookup))
+        .map(|(content, _)| content.sha1.clone())
+        .collect();
+    pending.sort();
+    pending.dedup();
+    let found = lookup_modrinth(client, &pending).await;
+
+    for (content, cached) in scanned.iter_mut().zip(identifications) {
+        let looked_up = pending.binary_search(&content.sha1).is_ok();
+        let mut identification = match (&found, looked_up) {
+            (Some(found), true) => found.get(&content.sha1).cloned(),
+            _ => None,
+        }
+        .or(cached.clone())
+        .unwrap_or_default();
+
+        if identification.project_id.is_none() && identification.embedded.is_none() && content.content_type == ContentType::Mod {
+            let path = instance_dir.join(&content.path);
+            if let Ok(Some((embedded, icon))) = tokio::task::spawn_blocking(move || read_embedded(&path)).await? {
+                identification.embedded = Some(embedded);
+                identification.icon = icon;
+            }
+        }
+        // 查询失败时不更新缓存，避免把未查询到的文件记为未收录
+        if use_cache && looked_up && found.is_some() {
+            save_identification(&content.sha1, &content.sha512, &identification).await?;
+        }
+
+        content.project_id = identification.project_id;
+        content.version_id = identification.version_id;
+        content.title = identification.title;
+        content.version_number = identification.version_number;
+        content.icon_url = identification.icon_url;
+        content.embedded = identification.embedded;
+        content.icon = identification.icon;
+    }
+
+    if use_cache {
+        sync_content_records(&manifest.id, &mut scanned).await?;
+    }
+    scanned.sort_by_key(|content| content.path.to_lowercase());
+    Ok(scanned)
+}
+
+/// 以磁盘为准同步扫描目录下的内容记录，保留已有的安装时间、来源与标签
+/// (手动添加或去掉 `.disabled` 后缀的文件按去掉后缀的路径对应)
+async fn sync_content_records(instance_id: &str, scanned: &mut [ScannedContent]) -> Result<()> {
+    let existing = database::list_instance_content(instance_id.to_string(), None).await?;
+
+    let mut folders = Vec::new();
+    for content_type in config::SCANNED_TYPES {
+        folders.push(format!("{}/", content_type.folder(None)?));
+    }
+    for record in &existing {
+        let in_scanned_folder = folders.iter().any(|folder| record.path.starts_with(folder.as_str()));
+        if in_scanned_folder && !scanned.iter().any(|content| content.path == record.path) {
+            database::remove_content(instance_id, &record.path).await?;
+        }
+    }
+
+    let existing: HashMap<&str, &ContentRecord> = existing
+        .iter()
+        .map(|record| (content::enabled_path(&record.path), record))
+        .collect();
+    for content in scanned.iter_mut() {
+        let matched = existing.get(content::enabled_path(&content.path)).copied();
+        let previous = matched.filter(|record| record.sha1 == content.sha1);
+        content.tags = matched.map(|record| record.tags.clone()).unwrap_or_default();
+        let record = ContentRecord {
+            instance_id: instance_id.to_string(),
+            path: content.path.clone(),
+            project_type: content.content_type.as_str().to_string(),
+            project_id: content
+                .project_id
+                .clone()
+                .or_else(|| previous.and_then(|record| record.project_id.clone())),
+            version_id: content
+                .version_id
+                .clone()
+                .or_else(|| previous.and_then(|record| record.version_id.clone())),
+            sha1: content.sha1.clone(),
+            sha512: Some(content.sha512.clone()),
+            file_size: content.size,
+            enabled: content.enabled,
+            installed_at: previous.map_or_else(Utc::now, |record| record.installed_at),
+            tags: content.tags.clone(),
+        };
+        database::upsert_content(&record).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::database::tests::with_pool;
+    use crate::api::instance::NewInstance;
+    use crate::api::modrinth::tests::unreachable_url;
+    use crate::mod_metadata::tests::jar;
+    use sha1::Digest;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// 创建带有一个已收录模组、一个自制 Fabric 模组与一个资源包的实例
+    async fn instance_with_content(resource_dir: &Path) -> instance::InstanceManifest {
+        let manifest = instance::create_instance(
+            resource_dir.to_string_lossy().to_string(),
+            NewInstance {
+                name: "Scan".to_string(),
+                game_version: "1.20.1".to_string(),
+                loader: ModLoader::Fabric,
+                loader_version: None,
+                icon: None,
+            },
+        )
+        .await
+        .unwrap();
+        let root = Path::new(&manifest.path);
+        std::fs::create_dir_all(root.join("mods")).unwrap();
+        std::fs::create_dir_all(root.join("resourcepacks")).unwrap();
+        std::fs::write(root.join("mods/known.jar"), "known").unwrap();
+        let fabric = br#"{ "id": "custom", "name": "Custom", "version": "1.0.0", "icon": "icon.png" }"#;
+        std::fs::write(
+            root.join("mods/custom.jar.disabled"),
+            jar(&[("fabric.mod.json", fabric), ("icon.png", b"png")]),
+        )
+        .unwrap();
+        std::fs::write(root.join("resourcepacks/pack.zip"), "pack").unwrap();
+        std::fs::write(root.join("mods/notes.txt"), "not content").unwrap();
+        manifest
+    }
+
+    async fn cached_count() -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM content_metadata")
+            .fetch_one(pool().unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn rechecks_unknown_files_after_a_while() {
+        let scanned = |days: i64| Identification {
+            scanned_at: Some(Utc::now() - Duration::days(days)),
+            ..Default::default()
+        };
+        assert!(Identification::default().needs_lookup());
+        assert!(!scanned(1).needs_lookup());
+        assert!(scanned(config::UNKNOWN_RECHECK_DAYS + 1).needs_lookup());
+        let known = Identification {
+            project_id: Some("p1".to_string()),
+            ..scanned(30)
+        };
+        assert!(!known.needs_lookup());
+    }
+
+    #[tokio::test]
+    async fn identifies_and_caches_by_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        let sha1 = checksum::hex_string(&sha1::Sha1::digest(b"known"));
+        Mock::given(method("POST"))
+            .and(path("/version_files"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                sha1.clone(): { "id": "v1", "project_id": "p1", "version_number": "2.0.0" },
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/projects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "p1", "title": "Known", "icon_url": "https://cdn/icon.png" },
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = ModrinthClient::new(&format!("{}/", server.uri()));
+
+        with_pool(dir.path(), async {
+            let manifest = instance_with_content(dir.path()).await;
+            let resource_dir = dir.path().to_string_lossy().to_string();
+            // 第二次扫描全部命中缓存，不再请求 Modrinth
+            for _ in 0..2 {
+                let scanned = scan_instance_content_with(&client, resource_dir.clone(), manifest.id.clone())
+                    .await
+                    .unwrap();
+                let paths: Vec<&str> = scanned.iter().map(|content| content.path.as_str()).collect();
+                assert_eq!(paths, ["mods/custom.jar.disabled", "mods/known.jar", "resourcepacks/pack.zip"]);
+
+                let custom = &scanned[0];
+                assert!(!custom.enabled);
+                assert_eq!(custom.project_id, None);
+                let embedded = custom.embedded.as_ref().unwrap();
+                assert_eq!(embedded.loader, Some(ModLoader::Fabric));
+                assert_eq!(embedded.mod_id.as_deref(), Some("custom"));
+                assert_eq!(embedded.name.as_deref(), Some("Custom"));
+                assert_eq!(custom.icon.as_deref(), Some(&b"png"[..]));
+
+                let known = &scanned[1];
+                assert_eq!(known.sha1, sha1);
+                assert_eq!(known.project_id.as_deref(), Some("p1"));
+                assert_eq!(known.version_id.as_deref(), Some("v1"));
+                assert_eq!(known.title.as_deref(), Some("Known"));
+                assert_eq!(known.version_number.as_deref(), Some("2.0.0"));
+                assert!(known.embedded.is_none());
+
+                assert!(scanned[2].embedded.is_none());
+                assert_eq!(cached_count().await, 3);
+            }
+
+            let records = database::list_instance_content(manifest.id.clone(), None).await.unwrap();
+            assert_eq!(records.len(), 3);
+            let known = records.iter().find(|record| record.path == "mods/known.jar").unwrap();
+            assert_eq!(known.project_id.as_deref(), Some("p1"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn does_not_cache_failed_lookups() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = ModrinthClient::new(&format!("{}/", unreachable_url()));
+
+        with_pool(dir.path(), async {
+            let manifest = instance_with_content(dir.path()).await;
+            let scanned = scan_instance_content_with(&client, dir.path().to_string_lossy().to_string(), manifest.id.clone())
+                .await
+                .unwrap();
+            assert_eq!(scanned.len(), 3);
+            assert!(scanned.iter().all(|content| content.project_id.is_none()));
+            assert!(scanned[0].embedded.is_some());
+            // 查询失败的文件下次仍会查询
+            assert_eq!(cached_count().await, 0);
+            assert_eq!(database::list_instance_content(manifest.id, None).await.unwrap().len(), 3);
+        })
+        .await;
+    }
+}
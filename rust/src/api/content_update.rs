@@ -0,0 +1,616 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::content::{self, ContentType};
+use crate::api::content_scan;
+use crate::api::database::{self, ContentRecord};
+use crate::api::instance;
+use crate::api::modpack::safe_relative_path;
+use crate::api::modrinth::{HashAlgorithm, ModrinthClient, Version};
+use crate::checksum;
+
+/// 配置常量
+mod config {
+    use super::ContentType;
+    /// 检查更新的内容类型
+    pub const UPDATED_TYPES: &[ContentType] = &[ContentType::Mod, ContentType::ResourcePack, ContentType::Shader];
+    /// 实例目录下存放待替换新文件的目录
+    pub const STAGING_DIR: &str = ".update-staging";
+    /// 实例目录下存放被替换旧文件的目录，用于回滚
+    pub const BACKUP_DIR: &str = ".update-backups";
+    /// 备份目录中的清单
+    pub const BACKUP_MANIFEST: &str = "backup.json";
+    /// 更新文件的并发下载数
+    pub const CONCURRENT_DOWNLOADS: usize = 4;
+}
+
+/// 可用的更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentUpdate {
+    /// 当前文件相对实例目录的路径
+    pub path: String,
+    pub content_type: ContentType,
+    pub project_id: String,
+    pub title: Option<String>,
+    pub current_version_id: Option<String>,
+    pub current_version_number: Option<String>,
+    pub new_version_id: String,
+    pub new_version_number: String,
+    /// release / beta / alpha
+    pub version_type: String,
+    pub changelog: Option<String>,
+    pub date_published: Option<DateTime<Utc>>,
+}
+
+/// 一次更新中被替换的文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateBackupEntry {
+    pub old: ContentRecord,
+    pub new: ContentRecord,
+}
+
+/// 一次批量更新的备份，可用于回滚
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateBackup {
+    pub id: String,
+    pub created: DateTime<Utc>,
+    pub entries: Vec<UpdateBackupEntry>,
+}
+
+/// 检查实例中模组、资源包与光影包的更新 (适用于实例的游戏版本与加载器)
+pub async fn check_content_updates(resource_dir: String, instance_id: String) -> Result<Vec<ContentUpdate>> {
+    check_content_updates_with(&ModrinthClient::default(), resource_dir, instance_id).await
+}
+
+async fn check_content_updates_with(
+    client: &ModrinthClient,
+    resource_dir: String,
+    instance_id: String,
+) -> Result<Vec<ContentUpdate>> {
+    let manifest = instance::get_instance(resource_dir.clone(), instance_id.clone()).await?;
+    let scanned = content_scan::scan_instance_content_with(client, resource_dir, instance_id).await?;
+    let game_versions = [manifest.game_version.clone()];
+
+    let mut updates = Vec::new();
+    for content_type in config::UPDATED_TYPES {
+        let contents: Vec<_> = scanned
+            .iter()
+            .filter(|content| content.content_type == *content_type && content.project_id.is_some())
+            .collect();
+        let hashes: Vec<String> = contents.iter().map(|content| content.sha1.clone()).collect();
+        let latest = client
+            .latest_versions_from_hashes(
+                &hashes,
+                HashAlgorithm::Sha1,
+                &content_type.modrinth_loaders(manifest.loader),
+                &game_versions,
+            )
+            .await?;
+
+        for content in contents {
+            let Some(version) = latest.get(&content.sha1) else {
+                continue;
+            };
+            if version.files.iter().any(|file| file.hashes.sha1 == content.sha1) {
+                continue;
+            }
+            updates.push(ContentUpdate {
+                path: content.path.clone(),
+                content_type: *content_type,
+                project_id: version.project_id.clone(),
+                title: content.title.clone(),
+                current_version_id: content.version_id.clone(),
+                current_version_number: content.version_number.clone(),
+                new_version_id: version.id.clone(),
+                new_version_number: version.version_number.clone(),
+                version_type: version.version_type.clone(),
+                changelog: version.changelog.clone(),
+                date_published: version.date_published,
+            });
+        }
+    }
+    Ok(updates)
+}
+
+/// 下载所有新文件到暂存目录并校验，任一失败则整体失败
+async fn stage_updates(
+    staging: &Path,
+    updates: &[ContentUpdate],
+    versions: &HashMap<String, Version>,
+) -> Result<Vec<PathBuf>> {
+    use futures::StreamExt;
+
+    // 先收集为 Vec 再并发：流的类型中带有借用参数的闭包时，编译器无法证明桥接层要求的 Send
+    let downloads: Vec<_> = updates
+        .iter()
+        .enumerate()
+        .map(|(index, update)| async move {
+            let version = versions
+                .get(&update.new_version_id)
+                .ok_or_else(|| anyhow!("找不到版本 {}", update.new_version_id))?;
+            let file = content::primary_file(version)?;
+            // 以序号区分目录，避免不同项目的同名文件互相覆盖
+            let dest = staging.join(index.to_string()).join(&file.filename);
+            content::download_version_file(file, &dest).await?;
+            Ok::<_, anyhow::Error>(dest)
+        })
+        .collect();
+    let staged = futures::stream::iter(downloads)
+        .buffered(config::CONCURRENT_DOWNLOADS)
+        .collect::<Vec<_>>()
+        .await;
+    staged.into_iter().collect()
+}
+
+/// 移动文件，必要时创建目标目录
+pub(crate) async fn move_file(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::rename(from, to).await?;
+    Ok(())
+}
+
+/// 撤销已完成的替换：删除新文件并放回旧文件。旧文件还没移入备份的条目保持原样
+async fn restore_entries(instance_dir: &Path, backup_dir: &Path, entries: &[UpdateBackupEntry]) -> Result<()> {
+    for entry in entries.iter().rev() {
+        let backup = backup_dir.join(&entry.old.path);
+        if !fs::try_exists(&backup).await? {
+            continue;
+        }
+        fs::remove_file(instance_dir.join(&entry.new.path)).await.ok();
+        move_file(&backup, &instance_dir.join(&entry.old.path)).await?;
+    }
+    Ok(())
+}
+
+/// 已安装文件的内容记录，数据库中没有时按更新信息生成
+async fn current_record(instance_dir: &Path, instance_id: &str, update: &ContentUpdate) -> Result<ContentRecord> {
+    if database::is_initialized() {
+        let records = database::list_instance_content(instance_id.to_string(), None).await?;
+        if let Some(record) = records.into_iter().find(|record| record.path == update.path) {
+            return Ok(record);
+        }
+    }
+    let path = instance_dir.join(&update.path);
+    Ok(ContentRecord {
+        instance_id: instance_id.to_string(),
+        path: update.path.clone(),
+        project_type: update.content_type.as_str().to_string(),
+        project_id: Some(update.project_id.clone()),
+        version_id: update.current_version_id.clone(),
+        sha1: checksum::file_sha1(&path).await?,
+        sha512: None,
+        file_size: fs::metadata(&path).await?.len(),
+        enabled: !content::is_disabled(&update.path),
+        installed_at: Utc::now(),
+        tags: Vec::new(),
+    })
+}
+
+fn backups_dir(instance_dir: &Path) -> PathBuf {
+    instance_dir.join(config::BACKUP_DIR)
+}
+
+/// 备份目录，拒绝非法的备份 ID
+fn backup_dir(instance_dir: &Path, backup_id: &str) -> Result<PathBuf> {
+    if safe_relative_path(backup_id).is_none_or(|path| path.components().count() != 1) {
+        return Err(anyhow!("无效的备份 ID: {}", backup_id));
+    }
+    Ok(backups_dir(instance_dir).join(backup_id))
+}
+
+/// 批量更新：先下载并校验全部新文件，再逐个替换，旧文件移入备份目录以便回滚。
+/// 任一步失败时恢复到更新前的状态
+pub async fn apply_content_updates(
+    resource_dir: String,
+    instance_id: String,
+    updates: Vec<ContentUpdate>,
+) -> Result<UpdateBackup> {
+    apply_content_updates_with(&ModrinthClient::default(), resource_dir, instance_id, updates).await
+}
+
+async fn apply_content_updates_with(
+    client: &ModrinthClient,
+    resource_dir: String,
+    instance_id: String,
+    updates: Vec<ContentUpdate>,
+) -> Result<UpdateBackup> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let instance_dir = PathBuf::from(&manifest.path);
+    for update in &updates {
+        if safe_relative_path(&update.path).is_none() {
+            return Err(anyhow!("无效的文件路径: {}", update.path));
+        }
+    }
+
+    let version_ids: Vec<String> = updates.iter().map(|update| update.new_version_id.clone()).collect();
+    let versions: HashMap<String, Version> = client
+        .versions(&version_ids)
+        .await?
+        .into_iter()
+        .map(|version| (version.id.clone(), version))
+        .collect();
+
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    let staging = instance_dir.join(config::STAGING_DIR).join(&id);
+    let staged = match stage_updates(&staging, &updates, &versions).await {
+        Ok(staged) => staged,
+        Err(e) => {
+            fs::remove_dir_all(&staging).await.ok();
+            return Err(e);
+        }
+    };
+
+    // 每移动一个旧文件前先把条目写入备份清单，中途中断时也能回滚
+    let backup_dir = backup_dir(&instance_dir, &id)?;
+    let mut backup = UpdateBackup {
+        id,
+        created: Utc::now(),
+        entries: Vec::new(),
+    };
+    let result = async {
+        write_backup(&backup_dir, &backup).await?;
+        for (update, staged) in updates.iter().zip(&staged) {
+            let version = &versions[&update.new_version_id];
+            let file = content::primary_file(version)?;
+            let old = current_record(&instance_dir, &manifest.id, update).await?;
+            // 新文件放在旧文件所在目录
+            let folder = Path::new(&update.path)
+                .parent()
+                .map(|parent| parent.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_default();
+            // 新文件沿用旧文件的启用状态与标签
+            let mut new = content::version_record(
+                &manifest.id,
+                content::path_with_state(&format!("{}/{}", folder, file.filename), old.enabled),
+                update.content_type,
+                version,
+                file,
+            );
+            new.enabled = old.enabled;
+            new.tags = old.tags.clone();
+
+            let (old_path, new_path) = (old.path.clone(), new.path.clone());
+            backup.entries.push(UpdateBackupEntry { old, new });
+            write_backup(&backup_dir, &backup).await?;
+            move_file(&instance_dir.join(&old_path), &backup_dir.join(&old_path)).await?;
+            move_file(staged, &instance_dir.join(&new_path)).await?;
+        }
+
+        if database::is_initialized() {
+            let removed: Vec<String> = backup.entries.iter().map(|entry| entry.old.path.clone()).collect();
+            let records: Vec<ContentRecord> = backup.entries.iter().map(|entry| entry.new.clone()).collect();
+            database::replace_content(&manifest.id, &removed, &records).await?;
+        }
+        Ok(())
+    }
+    .await;
+    fs::remove_dir_all(&staging).await.ok();
+
+    if let Err(e) = result {
+        restore_entries(&instance_dir, &backup_dir, &backup.entries).await?;
+        fs::remove_dir_all(&backup_dir).await.ok();
+        return Err(e);
+    }
+    Ok(backup)
+}
+
+/// 写入 (覆盖) 备份清单
+async fn write_backup(backup_dir: &Path, backup: &UpdateBackup) -> Result<()> {
+    fs::create_dir_all(backup_dir).await?;
+    fs::write(
+        backup_dir.join(config::BACKUP_MANIFEST),
+        serde_json::to_string_pretty(backup)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// 列出实例的更新备份，新的在前
+pub async fn list_update_backups(resource_dir: String, instance_id: String) -> Result<Vec<UpdateBackup>> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let dir = backups_dir(Path::new(&manifest.path));
+    if !fs::try_exists(&dir).await? {
+        return Ok(Vec::new());
+    }
+    let mut backups = Vec::new();
+    let mut entries = fs::read_dir(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let Ok(text) = fs::read_to_string(entry.path().join(config::BACKUP_MANIFEST)).await else {
+            continue;
+        };
+        if let Ok(backup) = serde_json::from_str::<UpdateBackup>(&text) {
+            backups.push(backup);
+        }
+    }
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.created));
+    Ok(backups)
+}
+
+/// 回滚一次批量更新：删除新文件、恢复旧文件及其内容记录，然后删除备份
+pub async fn rollback_content_update(resource_dir: String, instance_id: String, backup_id: String) -> Result<()> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let instance_dir = PathBuf::from(&manifest.path);
+    let backup_dir = backup_dir(&instance_dir, &backup_id)?;
+    let text = fs::read_to_string(backup_dir.join(config::BACKUP_MANIFEST))
+        .await
+        .map_err(|_| anyhow!("找不到更新备份 {}", backup_id))?;
+    let backup: UpdateBackup = serde_json::from_str(&text)?;
+    for entry in &backup.entries {
+        if safe_relative_path(&entry.old.path).is_none() || safe_relative_path(&entry.new.path).is_none() {
+            return Err(anyhow!("备份清单包含无效路径"));
+        }
+    }
+
+    restore_entries(&instance_dir, &backup_dir, &backup.entries).await?;
+    if database::is_initialized() {
+        let removed: Vec<String> = backup.entries.iter().map(|entry| entry.new.path.clone()).collect();
+        let records: Vec<ContentRecord> = backup.entries.iter().map(|entry| entry.old.clone()).collect();
+        database::replace_content(&manifest.id, &removed, &records).await?;
+    }
+    fs::remove_dir_all(&backup_dir).await?;
+    Ok(())
+}
+
+/// 删除更新备份 (确认不再需要回滚时释放空间)
+pub async fn delete_update_backup(resource_dir: String, instance_id: String, backup_id: String) -> Result<()> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let backup_dir = backup_dir(Path::new(&manifest.path), &backup_id)?;
+    if fs::try_exists(&backup_dir).await? {
+        fs::remove_dir_all(&backup_dir).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::database::tests::{record, with_pool};
+    use crate::api::instance::NewInstance;
+    use crate::api::mod_loader::ModLoader;
+    use sha1::Digest;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn sha1_of(content: &str) -> String {
+        checksum::hex_string(&sha1::Sha1::digest(content))
+    }
+
+    fn sha512_of(content: &str) -> String {
+        checksum::hex_string(&sha2::Sha512::digest(content))
+    }
+
+    /// 创建带有 `mods/a.jar` (内容 `a-old`，已记录来源与标签) 的实例
+    async fn instance_with_mod(resource_dir: &Path) -> instance::InstanceManifest {
+        let manifest = instance::create_instance(
+            resource_dir.to_string_lossy().to_string(),
+            NewInstance {
+                name: "Update".to_string(),
+                game_version: "1.20.1".to_string(),
+                loader: ModLoader::Fabric,
+                loader_version: None,
+                icon: None,
+            },
+        )
+        .await
+        .unwrap();
+        let mods = Path::new(&manifest.path).join("mods");
+        std::fs::create_dir_all(&mods).unwrap();
+        std::fs::write(mods.join("a.jar"), "a-old").unwrap();
+        let mut old = record(&manifest.id, "mods/a.jar", &sha1_of("a-old"));
+        old.project_id = Some("pa".to_string());
+        old.version_id = Some("va1".to_string());
+        old.tags = vec!["perf".to_string()];
+        database::upsert_content(&old).await.unwrap();
+        manifest
+    }
+
+    fn update() -> ContentUpdate {
+        ContentUpdate {
+            path: "mods/a.jar".to_string(),
+            content_type: ContentType::Mod,
+            project_id: "pa".to_string(),
+            title: None,
+            current_version_id: Some("va1".to_string()),
+            current_version_number: None,
+            new_version_id: "va2".to_string(),
+            new_version_number: "2.0".to_string(),
+            version_type: "release".to_string(),
+            changelog: None,
+            date_published: None,
+        }
+    }
+
+    /// 提供版本 `va2` 的信息，其主文件 `a-2.0.jar` 的内容为 `a-new`，声明的 SHA-512 按 `declared` 计算
+    async fn serve_new_version(server: &MockServer, declared: &str) {
+        Mock::given(path("/versions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": "va2",
+                "project_id": "pa",
+                "version_number": "2.0",
+                "files": [{
+                    "url": format!("{}/files/a-2.0.jar", server.uri()),
+                    "filename": "a-2.0.jar",
+                    "primary": true,
+                    "size": 5,
+                    "hashes": { "sha1": sha1_of("a-new"), "sha512": sha512_of(declared) },
+                }],
+            }])))
+            .mount(server)
+            .await;
+        Mock::given(path("/files/a-2.0.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes("a-new"))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn checks_updates_and_skips_current_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        let (a, b) = (sha1_of("a-old"), sha1_of("b-current"));
+        Mock::given(method("POST"))
+            .and(path("/version_files"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                a.clone(): { "id": "va1", "project_id": "pa", "version_number": "1.0" },
+                b.clone(): { "id": "vb1", "project_id": "pb", "version_number": "1.0" },
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(path("/projects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "pa", "title": "A" },
+                { "id": "pb", "title": "B" },
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/version_files/update"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                a.clone(): {
+                    "id": "va2",
+                    "project_id": "pa",
+                    "version_number": "2.0",
+                    "version_type": "beta",
+                    "changelog": "Fixed crash",
+                    "files": [{ "filename": "a-2.0.jar", "hashes": { "sha1": sha1_of("a-new") } }],
+                },
+                b.clone(): {
+                    "id": "vb1",
+                    "project_id": "pb",
+                    "version_number": "1.0",
+                    "files": [{ "filename": "b.jar", "hashes": { "sha1": b } }],
+                },
+            })))
+            .mount(&server)
+            .await;
+        let client = ModrinthClient::new(&format!("{}/", server.uri()));
+
+        with_pool(dir.path(), async {
+            let manifest = instance_with_mod(dir.path()).await;
+            std::fs::write(Path::new(&manifest.path).join("mods/b.jar"), "b-current").unwrap();
+
+            let updates = check_content_updates_with(&client, dir.path().to_string_lossy().to_string(), manifest.id)
+                .await
+                .unwrap();
+            assert_eq!(updates.len(), 1);
+            let update = &updates[0];
+            assert_eq!(update.path, "mods/a.jar");
+            assert_eq!(update.title.as_deref(), Some("A"));
+            assert_eq!(update.current_version_id.as_deref(), Some("va1"));
+            assert_eq!(update.current_version_number.as_deref(), Some("1.0"));
+            assert_eq!(update.new_version_id, "va2");
+            assert_eq!(update.version_type, "beta");
+            assert_eq!(update.changelog.as_deref(), Some("Fixed crash"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn applies_and_rolls_back_updates() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        serve_new_version(&server, "a-new").await;
+        let client = ModrinthClient::new(&format!("{}/", server.uri()));
+
+        with_pool(dir.path(), async {
+            let manifest = instance_with_mod(dir.path()).await;
+            let resource_dir = dir.path().to_string_lossy().to_string();
+            let root = PathBuf::from(&manifest.path);
+
+            let backup = apply_content_updates_with(&client, resource_dir.clone(), manifest.id.clone(), vec![update()])
+                .await
+                .unwrap();
+            assert!(!root.join("mods/a.jar").exists());
+            assert_eq!(std::fs::read_to_string(root.join("mods/a-2.0.jar")).unwrap(), "a-new");
+            let backup_dir = root.join(config::BACKUP_DIR).join(&backup.id);
+            assert_eq!(std::fs::read_to_string(backup_dir.join("mods/a.jar")).unwrap(), "a-old");
+            assert!(!root.join(config::STAGING_DIR).join(&backup.id).exists());
+            let records = database::list_instance_content(manifest.id.clone(), None).await.unwrap();
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].path, "mods/a-2.0.jar");
+            assert_eq!(records[0].version_id.as_deref(), Some("va2"));
+            assert_eq!(records[0].tags, ["perf"]);
+            let backups = list_update_backups(resource_dir.clone(), manifest.id.clone()).await.unwrap();
+            assert_eq!(backups.len(), 1);
+            assert_eq!(backups[0].entries[0].old.path, "mods/a.jar");
+
+            rollback_content_update(resource_dir.clone(), manifest.id.clone(), backup.id.clone())
+                .await
+                .unwrap();
+            assert_eq!(std::fs::read_to_string(root.join("mods/a.jar")).unwrap(), "a-old");
+            assert!(!root.join("mods/a-2.0.jar").exists());
+            assert!(!backup_dir.exists());
+            let records = database::list_instance_content(manifest.id.clone(), None).await.unwrap();
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].path, "mods/a.jar");
+            assert_eq!(records[0].version_id.as_deref(), Some("va1"));
+            assert_eq!(records[0].tags, ["perf"]);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn hash_mismatch_leaves_instance_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        // SHA-1 与声明一致但 SHA-512 不一致
+        serve_new_version(&server, "tampered").await;
+        let client = ModrinthClient::new(&format!("{}/", server.uri()));
+
+        with_pool(dir.path(), async {
+            let manifest = instance_with_mod(dir.path()).await;
+            let resource_dir = dir.path().to_string_lossy().to_string();
+            let root = PathBuf::from(&manifest.path);
+
+            let result = apply_content_updates_with(&client, resource_dir.clone(), manifest.id.clone(), vec![update()]).await;
+            assert!(result.is_err());
+            assert_eq!(std::fs::read_to_string(root.join("mods/a.jar")).unwrap(), "a-old");
+            assert!(!root.join("mods/a-2.0.jar").exists());
+            assert_eq!(std::fs::read_dir(root.join(config::STAGING_DIR)).unwrap().count(), 0);
+            assert!(list_update_backups(resource_dir, manifest.id.clone()).await.unwrap().is_empty());
+            let records = database::list_instance_content(manifest.id, None).await.unwrap();
+            assert_eq!(records[0].path, "mods/a.jar");
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn rolls_back_interrupted_update() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let manifest = instance_with_mod(dir.path()).await;
+            let resource_dir = dir.path().to_string_lossy().to_string();
+            let root = PathBuf::from(&manifest.path);
+            std::fs::write(root.join("mods/b.jar"), "b-old").unwrap();
+
+            // a 已替换完成；b 的条目已写入清单，但旧文件尚未移入备份
+            let backup_dir = backup_dir(&root, "interrupted").unwrap();
+            std::fs::create_dir_all(backup_dir.join("mods")).unwrap();
+            std::fs::rename(root.join("mods/a.jar"), backup_dir.join("mods/a.jar")).unwrap();
+            std::fs::write(root.join("mods/a-2.0.jar"), "a-new").unwrap();
+            let entry = |old: &str, new: &str| UpdateBackupEntry {
+                old: record(&manifest.id, old, "old"),
+                new: record(&manifest.id, new, "new"),
+            };
+            let backup = UpdateBackup {
+                id: "interrupted".to_string(),
+                created: Utc::now(),
+                entries: vec![entry("mods/a.jar", "mods/a-2.0.jar"), entry("mods/b.jar", "mods/b.jar")],
+            };
+            write_backup(&backup_dir, &backup).await.unwrap();
+
+            rollback_content_update(resource_dir, manifest.id, backup.id).await.unwrap();
+            assert_eq!(std::fs::read_to_string(root.join("mods/a.jar")).unwrap(), "a-old");
+            assert_eq!(std::fs::read_to_string(root.join("mods/b.jar")).unwrap(), "b-old");
+            assert!(!root.join("mods/a-2.0.jar").exists());
+            assert!(!backup_dir.exists());
+        })
+        .await;
+    }
+}
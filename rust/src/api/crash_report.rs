@@ -0,0 +1,428 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::fs;
+
+/// 崩溃文件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrashFileKind {
+    /// crash-reports/*.txt
+    CrashReport,
+    /// JVM 致命错误日志 hs_err_pid*.log
+    HsErr,
+    /// 直接传入的文本 (如游戏输出)
+    Text,
+}
+
+/// 已知的崩溃原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrashCause {
+    /// Java 主版本不匹配
+    WrongJavaVersion,
+    /// 使用了 32 位 JVM
+    Jvm32Bit,
+    /// 内存不足
+    OutOfMemory,
+    /// 缺少前置依赖
+    MissingDependency,
+    /// Mixin 注入冲突
+    MixinConflict,
+    /// 显卡驱动 / OpenGL 问题
+    OpenGl,
+}
+
+/// 命中的崩溃原因
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchedCause {
+    pub cause: CrashCause,
+    /// 命中的规则 ID
+    pub rule_id: String,
+    /// 命中规则的原文
+    pub evidence: String,
+    /// 给用户的建议
+    pub suggestion: String,
+}
+
+/// 崩溃诊断结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashDiagnosis {
+    pub kind: CrashFileKind,
+    pub file_path: Option<String>,
+    pub description: Option<String>,
+    pub suspected_mods: Vec<String>,
+    pub stack_trace: Option<String>,
+    pub causes: Vec<MatchedCause>,
+}
+
+/// 已知崩溃原因规则表
+mod rules {
+    use super::CrashCause;
+    use flutter_rust_bridge::frb;
+    use regex::Regex;
+    use std::sync::LazyLock;
+
+    /// 单条规则
+    #[frb(ignore)]
+    pub struct CrashRule {
+        pub id: &'static str,
+        pub cause: CrashCause,
+        /// 按行匹配的正则 (不区分大小写)
+        pub pattern: &'static str,
+        pub suggestion: &'static str,
+    }
+
+    /// 规则表，按优先级排列
+    pub const CRASH_RULES: &[CrashRule] = &[
+        CrashRule {
+            id: "unsupported-class-version",
+            cause: CrashCause::WrongJavaVersion,
+            pattern: r"UnsupportedClassVersionError|class file version \d+",
+            suggestion: "游戏或模组需要更高版本的 Java，请在实例设置中切换 Java",
+        },
+        CrashRule {
+            id: "java-version-required",
+            cause: CrashCause::WrongJavaVersion,
+            pattern: r"requires (?:java|jre) (?:version )?\d+|Java \d+ or (?:above|newer) is required",
+            suggestion: "请按提示安装并选择对应主版本的 Java",
+        },
+        CrashRule {
+            id: "legacy-java-incompatible",
+            cause: CrashCause::WrongJavaVersion,
+            pattern: r"jdk\.internal\.loader\.ClassLoaders\$AppClassLoader cannot be cast to (?:class )?java\.net\.URLClassLoader",
+            suggestion: "旧版本 Forge 仅支持 Java 8，请切换到 Java 8",
+        },
+        CrashRule {
+            id: "jvm-32bit-heap",
+            cause: CrashCause::Jvm32Bit,
+            pattern: r"Could not reserve enough space for .*object heap|Invalid maximum heap size",
+            suggestion: "当前 Java 可能是 32 位版本或内存设置过大，请安装 64 位 Java 或降低最大内存",
+        },
+        CrashRule {
+            id: "jvm-32bit-arch",
+            cause: CrashCause::Jvm32Bit,
+            pattern: r"Java HotSpot\(TM\) (?:Client|Server) VM .*x86|os\.arch: x86$|\(32-bit\)",
+            suggestion: "检测到 32 位 Java，请安装 64 位 Java",
+        },
+        CrashRule {
+            id: "out-of-memory",
+            cause: CrashCause::OutOfMemory,
+            pattern: r"java\.lang\.OutOfMemoryError|Out of Memory Error|insufficient memory for the Java Runtime Environment",
+            suggestion: "游戏内存不足，请提高最大内存或减少模组数量",
+        },
+        CrashRule {
+            id: "fabric-missing-dependency",
+            cause: CrashCause::MissingDependency,
+            pattern: r"Mod resolution encountered an incompatible mod set|requires (?:any )?version .* of (?:mod )?\S+, which is missing",
+            suggestion: "缺少前置模组，请根据提示安装对应的依赖",
+        },
+        CrashRule {
+            id: "forge-missing-dependency",
+            cause: CrashCause::MissingDependency,
+            pattern: r"Missing or unsupported mandatory dependencies|MissingModsException|Missing Mods:",
+            suggestion: "缺少前置模组，请根据提示安装对应的依赖",
+        },
+        CrashRule {
+            id: "no-class-def",
+            cause: CrashCause::MissingDependency,
+            pattern: r"java\.lang\.NoClassDefFoundError|java\.lang\.ClassNotFoundException",
+            suggestion: "找不到类，可能缺少前置模组或模组与当前版本不兼容",
+        },
+        CrashRule {
+            id: "mixin-apply-failed",
+            cause: CrashCause::MixinConflict,
+            pattern: r"Mixin apply (?:for mod \S+ )?failed|MixinApplyError|InvalidInjectionException|Mixin transformation of \S+ failed",
+            suggestion: "模组之间存在 Mixin 冲突，请根据可疑模组逐个排查",
+        },
+        CrashRule {
+            id: "opengl-pixel-format",
+            cause: CrashCause::OpenGl,
+            pattern: r"Pixel format not accelerated|GLFW error 65542|WGL: The driver does not appear to support OpenGL",
+            suggestion: "显卡驱动不支持 OpenGL，请更新显卡驱动或使用独立显卡启动",
+        },
+        CrashRule {
+            id: "opengl-driver-crash",
+            cause: CrashCause::OpenGl,
+            pattern: r"\[(?:ig\w*icd\d*|atio6axx|atioglxx|nvoglv\d*|amdxc\d*)\.dll\+|Problematic frame:.*(?:libGL|iris_dri|radeonsi_dri|libnvidia-glcore)",
+            suggestion: "显卡驱动崩溃，请更新显卡驱动",
+        },
+    ];
+
+    /// 编译后的规则正则，与 CRASH_RULES 一一对应
+    pub static COMPILED_RULES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+        CRASH_RULES
+            .iter()
+            .map(|rule| Regex::new(&format!("(?i){}", rule.pattern)).unwrap())
+            .collect()
+    });
+}
+
+/// 崩溃报告中可疑模组段落的标题
+const SUSPECTED_MODS_HEADERS: &[&str] = &["Suspected Mod:", "Suspected Mods:", "Suspected Mod(s):"];
+
+/// 用规则表匹配文本，每条规则只记录第一次命中
+pub(crate) fn match_known_causes(text: &str) -> Vec<MatchedCause> {
+    let mut causes = Vec::new();
+    for (rule, regex) in rules::CRASH_RULES.iter().zip(rules::COMPILED_RULES.iter()) {
+        if let Some(line) = text.lines().find(|line| regex.is_match(line)) {
+            causes.push(MatchedCause {
+                cause: rule.cause,
+                rule_id: rule.id.to_string(),
+                evidence: line.trim().to_string(),
+                suggestion: rule.suggestion.to_string(),
+            });
+        }
+    }
+    causes
+}
+
+/// 解析 crash-reports/*.txt
+pub(crate) fn parse_crash_report(text: &str) -> CrashDiagnosis {
+    let lines: Vec<&str> = text.lines().collect();
+
+    let description_index = lines
+        .iter()
+        .position(|line| line.starts_with("Description:"));
+    let description = description_index
+        .map(|index| lines[index].trim_start_matches("Description:").trim().to_string());
+
+    // 描述之后的空行开始是异常堆栈，直到下一个空行
+    let stack_trace = description_index.and_then(|index| {
+        let trace: Vec<&str> = lines[index + 1..]
+            .iter()
+            .skip_while(|line| line.trim().is_empty())
+            .take_while(|line| !line.trim().is_empty())
+            .copied()
+            .collect();
+        (!trace.is_empty()).then(|| trace.join("\n"))
+    });
+
+    let mut suspected_mods = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let Some(header) = SUSPECTED_MODS_HEADERS
+            .iter()
+            .find(|header| line.trim_start().starts_with(**header))
+        else {
+            continue;
+        };
+        let inline = line.trim_start()[header.len()..].trim();
+        if !inline.is_empty() && !inline.eq_ignore_ascii_case("NONE") {
+            suspected_mods.push(inline.to_string());
+        }
+        let entries: Vec<&str> = lines[index + 1..]
+            .iter()
+            .take_while(|line| line.starts_with('\t') || line.starts_with("  "))
+            .copied()
+            .collect();
+        // 更深缩进的行是模组的附加信息 (如 Fabric 的 Issue tracker URL)，只取第一层
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let Some(depth) = entries.iter().map(|line| indent(line)).min() else {
+            continue;
+        };
+        suspected_mods.extend(
+            entries
+                .iter()
+                .filter(|line| indent(line) == depth)
+                .map(|line| line.trim().to_string())
+                .filter(|line| {
+                    !line.is_empty() && !line.starts_with("at ") && !line.starts_with("Stacktrace")
+                }),
+        );
+    }
+
+    CrashDiagnosis {
+        kind: CrashFileKind::CrashReport,
+        file_path: None,
+        description,
+        suspected_mods,
+        stack_trace,
+        causes: match_known_causes(text),
+    }
+}
+
+/// 解析 hs_err_pid*.log
+pub(crate) fn parse_hs_err(text: &str) -> CrashDiagnosis {
+    let lines: Vec<&str> = text.lines().collect();
+
+    // 文件头部以 `#` 开头的段落描述了错误类型
+    let description = lines
+        .iter()
+        .map(|line| line.trim_start_matches('#').trim())
+        .find(|line| line.starts_with("EXCEPTION_") || line.starts_with("SIG") || line.contains("insufficient memory"))
+        .map(str::to_string);
+
+    let stack_trace = lines
+        .iter()
+        .position(|line| line.starts_with("Native frames:") || line.starts_with("Java frames:"))
+        .map(|index| {
+            lines[index..]
+                .iter()
+                .take_while(|line| !line.trim().is_empty())
+                .copied()
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|line| line.contains("Problematic frame:"))
+                .and_then(|index| lines.get(index + 1))
+                .map(|line| line.trim_start_matches('#').trim().to_string())
+        });
+
+    CrashDiagnosis {
+        kind: CrashFileKind::HsErr,
+        file_path: None,
+        description,
+        suspected_mods: Vec::new(),
+        stack_trace,
+        causes: match_known_causes(text),
+    }
+}
+
+/// 读取目录下符合条件的文件及其修改时间
+async fn collect_candidates(
+    dir: &Path,
+    matches: impl Fn(&str) -> bool,
+) -> Result<Vec<(PathBuf, SystemTime)>> {
+    let mut candidates = Vec::new();
+    if !fs::try_exists(dir).await? {
+        return Ok(candidates);
+    }
+    let mut entries = fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !matches(&name) {
+            continue;
+        }
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() {
+            candidates.push((entry.path(), metadata.modified()?));
+        }
+    }
+    Ok(candidates)
+}
+
+/// 查找实例目录下最新的崩溃报告或 hs_err 日志
+pub(crate) async fn find_latest_crash_file(
+    game_dir: &Path,
+    since: Option<SystemTime>,
+) -> Result<Option<(PathBuf, CrashFileKind)>> {
+    let mut candidates: Vec<(PathBuf, SystemTime, CrashFileKind)> = Vec::new();
+
+    for (path, modified) in
+        collect_candidates(&game_dir.join("crash-reports"), |name| name.ends_with(".txt")).await?
+    {
+        candidates.push((path, modified, CrashFileKind::CrashReport));
+    }
+    // JVM 在工作目录 (即实例目录) 写出 hs_err 日志
+    for (path, modified) in collect_candidates(game_dir, |name| {
+        name.starts_with("hs_err_pid") && name.ends_with(".log")
+    })
+    .await?
+    {
+        candidates.push((path, modified, CrashFileKind::HsErr));
+    }
+
+    Ok(candidates
+        .into_iter()
+        .filter(|(_, modified, _)| since.is_none_or(|since| *modified >= since))
+        .max_by_key(|(_, modified, _)| *modified)
+        .map(|(path, _, kind)| (path, kind)))
+}
+
+/// 诊断实例最近一次崩溃，`since` 为游戏启动时间 (只查找之后生成的文件)
+pub async fn diagnose_latest_crash(
+    game_dir: String,
+    since: Option<DateTime<Utc>>,
+) -> Result<Option<CrashDiagnosis>> {
+    let since = since.map(SystemTime::from);
+    let Some((path, kind)) = find_latest_crash_file(Path::new(&game_dir), since).await? else {
+        return Ok(None);
+    };
+
+    let bytes = fs::read(&path).await?;
+    let text = String::from_utf8_lossy(&bytes);
+    let mut diagnosis = match kind {
+        CrashFileKind::HsErr => parse_hs_err(&text),
+        _ => parse_crash_report(&text),
+    };
+    diagnosis.file_path = Some(path.to_string_lossy().to_string());
+    Ok(Some(diagnosis))
+}
+
+/// 诊断任意文本 (如崩溃时的游戏输出)
+pub fn diagnose_crash_text(text: String) -> CrashDiagnosis {
+    CrashDiagnosis {
+        kind: CrashFileKind::Text,
+        file_path: None,
+        description: None,
+        suspected_mods: Vec::new(),
+        stack_trace: None,
+        causes: match_known_causes(&text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule_ids(diagnosis: &CrashDiagnosis) -> Vec<&str> {
+        diagnosis.causes.iter().map(|cause| cause.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn fabric_mixin_crash_report() {
+        let diagnosis = parse_crash_report(include_str!("../../tests/fixtures/crash/fabric-mixin-crash.txt"));
+        assert_eq!(diagnosis.description.as_deref(), Some("Initializing game"));
+        assert!(diagnosis
+            .stack_trace
+            .as_deref()
+            .is_some_and(|trace| trace.starts_with("java.lang.RuntimeException: Mixin transformation")));
+        assert_eq!(rule_ids(&diagnosis), ["mixin-apply-failed"]);
+        assert_eq!(diagnosis.causes[0].cause, CrashCause::MixinConflict);
+        assert_eq!(diagnosis.suspected_mods, ["Sodium (sodium)", "Fabric Loader (fabricloader)"]);
+    }
+
+    #[test]
+    fn forge_missing_dependency_report() {
+        let diagnosis = parse_crash_report(include_str!("../../tests/fixtures/crash/forge-missing-dependency.txt"));
+        assert_eq!(diagnosis.description.as_deref(), Some("Mod loading error has occurred"));
+        assert_eq!(rule_ids(&diagnosis), ["forge-missing-dependency"]);
+        assert!(diagnosis.suspected_mods.is_empty());
+    }
+
+    #[test]
+    fn wrong_java_report() {
+        let diagnosis = parse_crash_report(include_str!("../../tests/fixtures/crash/wrong-java.txt"));
+        assert_eq!(rule_ids(&diagnosis), ["unsupported-class-version"]);
+        assert_eq!(diagnosis.causes[0].cause, CrashCause::WrongJavaVersion);
+    }
+
+    #[test]
+    fn hs_err_out_of_memory() {
+        let diagnosis = parse_hs_err(include_str!("../../tests/fixtures/crash/hs_err_pid1234-oom.log"));
+        assert_eq!(diagnosis.kind, CrashFileKind::HsErr);
+        assert_eq!(
+            diagnosis.description.as_deref(),
+            Some("There is insufficient memory for the Java Runtime Environment to continue.")
+        );
+        assert_eq!(rule_ids(&diagnosis), ["out-of-memory"]);
+    }
+
+    #[test]
+    fn hs_err_graphics_driver() {
+        let diagnosis = parse_hs_err(include_str!("../../tests/fixtures/crash/hs_err_pid4321-driver.log"));
+        assert!(diagnosis
+            .description
+            .as_deref()
+            .is_some_and(|description| description.starts_with("EXCEPTION_ACCESS_VIOLATION")));
+        assert!(diagnosis
+            .stack_trace
+            .as_deref()
+            .is_some_and(|trace| trace.starts_with("Native frames:") && trace.contains("atio6axx.dll")));
+        assert_eq!(rule_ids(&diagnosis), ["opengl-driver-crash"]);
+        assert_eq!(diagnosis.causes[0].cause, CrashCause::OpenGl);
+    }
+}
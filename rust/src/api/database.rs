@@ -0,0 +1,695 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteRow};
+use sqlx::{Row, SqliteExecutor, SqlitePool};
+use std::path::Path;
+use tokio::sync::OnceCell;
+
+use crate::api::instance::{self, InstanceManifest};
+use crate::api::java_download::JavaRuntimeVersion;
+
+/// 配置常量
+mod config {
+    /// 资源目录下的数据库文件名
+    pub const DATABASE_FILE: &str = "aml.db";
+    /// 连接池大小
+    pub const MAX_CONNECTIONS: u32 = 4;
+}
+
+/// 全局连接池，由 `init_database` 初始化
+static POOL: OnceCell<SqlitePool> = OnceCell::const_new();
+
+/// 内嵌的版本化迁移脚本 (rust/migrations)
+static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+#[cfg(test)]
+tokio::task_local! {
+    /// 测试中代替全局连接池，见 `tests::with_pool`
+    static TEST_POOL: &'static SqlitePool;
+}
+
+/// 获取连接池，数据库未初始化时返回错误
+pub(crate) fn pool() -> Result<&'static SqlitePool> {
+    #[cfg(test)]
+    if let Ok(pool) = TEST_POOL.try_with(|pool| *pool) {
+        return Ok(pool);
+    }
+    POOL.get().ok_or_else(|| anyhow!("数据库尚未初始化"))
+}
+
+/// 数据库是否已初始化 (未初始化时缓存、索引等功能自动跳过)
+pub(crate) fn is_initialized() -> bool {
+    pool().is_ok()
+}
+
+/// 打开 (必要时创建) 资源目录下的数据库并执行迁移
+pub(crate) async fn open_pool(resource_dir: &str) -> Result<SqlitePool> {
+    tokio::fs::create_dir_all(resource_dir).await?;
+    let options = SqliteConnectOptions::new()
+        .filename(Path::new(resource_dir).join(config::DATABASE_FILE))
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .foreign_keys(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(config::MAX_CONNECTIONS)
+        .connect_with(options)
+        .await?;
+    MIGRATOR.run(&pool).await?;
+    Ok(pool)
+}
+
+/// 打开 (必要时创建) 资源目录下的数据库并执行迁移，重复调用无副作用
+pub async fn init_database(resource_dir: String) -> Result<()> {
+    POOL.get_or_try_init(|| open_pool(&resource_dir)).await?;
+    Ok(())
+}
+
+// 实例索引
+
+/// 实例排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstanceSort {
+    Name,
+    LastPlayed,
+    PlayTime,
+    Created,
+    GameVersion,
+}
+
+impl InstanceSort {
+    fn column(&self) -> &'static str {
+        match self {
+            InstanceSort::Name => "name COLLATE NOCASE",
+            InstanceSort::LastPlayed => "last_played",
+            InstanceSort::PlayTime => "play_time_secs",
+            InstanceSort::Created => "created",
+            InstanceSort::GameVersion => "game_version",
+        }
+    }
+}
+
+/// 写入或更新实例索引
+pub(crate) async fn index_instance(manifest: &InstanceManifest) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO instances
+            (id, name, game_version, loader, loader_version, path, icon, play_time_secs, created, last_played)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT (id) DO UPDATE SET
+            name = excluded.name,
+            game_version = excluded.game_version,
+            loader = excluded.loader,
+            loader_version = excluded.loader_version,
+            path = excluded.path,
+            icon = excluded.icon,
+            play_time_secs = excluded.play_time_secs,
+            created = excluded.created,
+            last_played = excluded.last_played",
+    )
+    .bind(&manifest.id)
+    .bind(&manifest.name)
+    .bind(&manifest.game_version)
+    .bind(manifest.loader.as_str())
+    .bind(&manifest.loader_version)
+    .bind(&manifest.path)
+    .bind(&manifest.icon)
+    .bind(manifest.play_time_secs as i64)
+    .bind(manifest.created)
+    .bind(manifest.last_played)
+    .execute(pool()?)
+    .await?;
+    Ok(())
+}
+
+/// 删除实例索引及其内容记录
+pub(crate) async fn remove_instance(id: &str) -> Result<()> {
+    sqlx::query("DELETE FROM instances WHERE id = ?")
+        .bind(id)
+        .execute(pool()?)
+        .await?;
+    Ok(())
+}
+
+/// 数据库已初始化时同步实例索引；索引失败不影响实例文件本身
+pub(crate) async fn sync_instance_index(manifest: &InstanceManifest) {
+    if is_initialized() {
+        index_instance(manifest).await.ok();
+    }
+}
+
+/// 以磁盘上的实例清单为准重建实例索引，返回索引的实例数
+pub async fn rebuild_instance_index(resource_dir: String) -> Result<u32> {
+    let manifests = instance::list_instances(resource_dir).await?;
+    let mut transaction = pool()?.begin().await?;
+    let ids: Vec<&str> = manifests.iter().map(|manifest| manifest.id.as_str()).collect();
+    let existing: Vec<String> = sqlx::query_scalar("SELECT id FROM instances")
+        .fetch_all(&mut *transaction)
+        .await?;
+    for id in existing.iter().filter(|id| !ids.contains(&id.as_str())) {
+        sqlx::query("DELETE FROM instances WHERE id = ?")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    transaction.commit().await?;
+
+    for manifest in &manifests {
+        index_instance(manifest).await?;
+    }
+    Ok(manifests.len() as u32)
+}
+
+/// 按名称或游戏版本搜索实例并排序，返回实例 ID 列表 (可再用 `get_instance` 读取完整清单)
+pub async fn search_instances(query: String, sort: InstanceSort, descending: bool) -> Result<Vec<String>> {
+    let sql = format!(
+        "SELECT id FROM instances
+         WHERE name LIKE ?1 ESCAPE '\\' OR game_version LIKE ?1 ESCAPE '\\' OR loader LIKE ?1 ESCAPE '\\'
+         ORDER BY {} {} NULLS LAST, name COLLATE NOCASE",
+        sort.column(),
+        if descending { "DESC" } else { "ASC" }
+    );
+    let pattern = format!(
+        "%{}%",
+        query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+    );
+    Ok(sqlx::query_scalar(&sql).bind(pattern).fetch_all(pool()?).await?)
+}
+
+// 实例内容
+
+/// 实例中已安装的内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentRecord {
+    pub instance_id: String,
+    /// 相对实例目录的路径
+    pub path: String,
+    /// mod / resourcepack / shader / datapack
+    pub project_type: String,
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    pub sha1: String,
+    pub sha512: Option<String>,
+    pub file_size: u64,
+    pub enabled: bool,
+    pub installed_at: DateTime<Utc>,
+    /// 用户设置的标签
+    pub tags: Vec<String>,
+}
+
+fn content_from_row(row: &SqliteRow) -> Result<ContentRecord> {
+    Ok(ContentRecord {
+        instance_id: row.try_get("instance_id")?,
+        path: row.try_get("path")?,
+        project_type: row.try_get("project_type")?,
+        project_id: row.try_get("project_id")?,
+        version_id: row.try_get("version_id")?,
+        sha1: row.try_get("sha1")?,
+        sha512: row.try_get("sha512")?,
+        file_size: row.try_get::<i64, _>("file_size")? as u64,
+        enabled: row.try_get("enabled")?,
+        installed_at: row.try_get("installed_at")?,
+        tags: serde_json::from_str(&row.try_get::<String, _>("tags")?).unwrap_or_default(),
+    })
+}
+
+/// 写入或更新内容记录
+pub(crate) async fn upsert_content(record: &ContentRecord) -> Result<()> {
+    upsert_content_with(pool()?, record).await
+}
+
+async fn upsert_content_with<'e>(executor: impl SqliteExecutor<'e>, record: &ContentRecord) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO instance_content
+            (instance_id, path, project_type, project_id, version_id, sha1, sha512, file_size, enabled, installed_at, tags)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT (instance_id, path) DO UPDATE SET
+            project_type = excluded.project_type,
+            project_id = excluded.project_id,
+            version_id = excluded.version_id,
+            sha1 = excluded.sha1,
+            sha512 = excluded.sha512,
+            file_size = excluded.file_size,
+            enabled = excluded.enabled,
+            installed_at = excluded.installed_at,
+            tags = excluded.tags",
+    )
+    .bind(&record.instance_id)
+    .bind(&record.path)
+    .bind(&record.project_type)
+    .bind(&record.project_id)
+    .bind(&record.version_id)
+    .bind(&record.sha1)
+    .bind(&record.sha512)
+    .bind(record.file_size as i64)
+    .bind(record.enabled)
+    .bind(record.installed_at)
+    .bind(serde_json::to_string(&record.tags)?)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// 删除内容记录
+pub(crate) async fn remove_content(instance_id: &str, path: &str) -> Result<()> {
+    remove_content_with(pool()?, instance_id, path).await
+}
+
+async fn remove_content_with<'e>(executor: impl SqliteExecutor<'e>, instance_id: &str, path: &str) -> Result<()> {
+    sqlx::query("DELETE FROM instance_content WHERE instance_id = ? AND path = ?")
+        .bind(instance_id)
+        .bind(path)
+        .execute(executor)
+        .await?;
+    Ok(())
+}
+
+/// 在一个事务中删除并写入内容记录，替换文件后同步记录时不会只完成一半
+pub(crate) async fn replace_content(instance_id: &str, removed: &[String], upserted: &[ContentRecord]) -> Result<()> {
+    let mut transaction = pool()?.begin().await?;
+    for path in removed {
+        remove_content_with(&mut *transaction, instance_id, path).await?;
+    }
+    for record in upserted {
+        upsert_content_with(&mut *transaction, record).await?;
+    }
+    transaction.commit().await?;
+    Ok(())
+}
+
+/// 设置内容的标签
+pub async fn set_content_tags(instance_id: String, path: String, tags: Vec<String>) -> Result<()> {
+    let updated = sqlx::query("UPDATE instance_content SET tags = ? WHERE instance_id = ? AND path = ?")
+        .bind(serde_json::to_string(&tags)?)
+        .bind(&instance_id)
+        .bind(&path)
+        .execute(pool()?)
+        .await?
+        .rows_affected();
+    if updated == 0 {
+        return Err(anyhow!("实例 {} 中没有 {}", instance_id, path));
+    }
+    Ok(())
+}
+
+/// 查找实例中来自指定项目的内容
+pub(crate) async fn content_by_project(instance_id: &str, project_id: &str) -> Result<Vec<ContentRecord>> {
+    let rows = sqlx::query("SELECT * FROM instance_content WHERE instance_id = ? AND project_id = ?")
+        .bind(instance_id)
+        .bind(project_id)
+        .fetch_all(pool()?)
+        .await?;
+    rows.iter().map(content_from_row).collect()
+}
+
+/// 列出实例中的内容，可按项目类型过滤
+pub async fn list_instance_content(instance_id: String, project_type: Option<String>) -> Result<Vec<ContentRecord>> {
+    let rows = sqlx::query(
+        "SELECT * FROM instance_content
+         WHERE instance_id = ? AND (?2 IS NULL OR project_type = ?2)
+         ORDER BY path COLLATE NOCASE",
+    )
+    .bind(instance_id)
+    .bind(project_type)
+    .fetch_all(pool()?)
+    .await?;
+    rows.iter().map(content_from_row).collect()
+}
+
+// Java 运行时
+
+/// 保存检测到的 Java 运行时 (按路径去重)
+pub async fn save_java_runtimes(runtimes: Vec<JavaRuntimeVersion>) -> Result<()> {
+    let mut transaction = pool()?.begin().await?;
+    for runtime in &runtimes {
+        sqlx::query(
+            "INSERT INTO java_runtimes (path, version, major_version, discovered_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT (path) DO UPDATE SET
+                version = excluded.version,
+                major_version = excluded.major_version",
+        )
+        .bind(&runtime.path)
+        .bind(&runtime.version)
+        .bind(runtime.major_version)
+        .bind(Utc::now())
+        .execute(&mut *transaction)
+        .await?;
+    }
+    transaction.commit().await?;
+    Ok(())
+}
+
+/// 列出已保存的 Java 运行时 (新版本在前)，`major_version` 不为空时只返回该主版本
+pub async fn list_java_runtimes(major_version: Option<i32>) -> Result<Vec<JavaRuntimeVersion>> {
+    let rows = sqlx::query(
+        "SELECT path, version, major_version FROM java_runtimes
+         WHERE ?1 IS NULL OR major_version = ?1
+         ORDER BY major_version DESC, version DESC",
+    )
+    .bind(major_version)
+    .fetch_all(pool()?)
+    .await?;
+    rows.iter()
+        .map(|row| {
+            Ok(JavaRuntimeVersion {
+                path: row.try_get("path")?,
+                version: row.try_get("version")?,
+                major_version: row.try_get("major_version")?,
+            })
+        })
+        .collect()
+}
+
+/// 删除 Java 运行时记录
+pub async fn remove_java_runtime(path: String) -> Result<()> {
+    sqlx::query("DELETE FROM java_runtimes WHERE path = ?")
+        .bind(path)
+        .execute(pool()?)
+        .await?;
+    Ok(())
+}
+
+// 下载缓存
+
+/// 下载缓存条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedDownload {
+    pub url: String,
+    pub path: String,
+    pub sha1: Option<String>,
+    pub size: u64,
+    pub fetched_at: DateTime<Utc>,
+}
+
+fn cached_download_from_row(row: &SqliteRow) -> Result<CachedDownload> {
+    Ok(CachedDownload {
+        url: row.try_get("url")?,
+        path: row.try_get("path")?,
+        sha1: row.try_get("sha1")?,
+        size: row.try_get::<i64, _>("size")? as u64,
+        fetched_at: row.try_get("fetched_at")?,
+    })
+}
+
+/// 查询下载缓存，文件已不存在时删除记录并返回 None
+pub(crate) async fn cached_download(url: &str) -> Result<Option<CachedDownload>> {
+    let row = sqlx::query("SELECT * FROM download_cache WHERE url = ?")
+        .bind(url)
+        .fetch_optional(pool()?)
+        .await?;
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    let entry = cached_download_from_row(&row)?;
+    if !tokio::fs::try_exists(&entry.path).await? {
+        sqlx::query("DELETE FROM download_cache WHERE url = ?")
+            .bind(url)
+            .execute(pool()?)
+            .await?;
+        return Ok(None);
+    }
+    Ok(Some(entry))
+}
+
+/// 查询下载到指定路径的文件来源 (最近一次)
+pub(crate) async fn download_source(path: &Path) -> Result<Option<CachedDownload>> {
+    let row = sqlx::query("SELECT * FROM download_cache WHERE path = ? ORDER BY fetched_at DESC LIMIT 1")
+        .bind(path.to_string_lossy().to_string())
+        .fetch_optional(pool()?)
+        .await?;
+    row.as_ref().map(cached_download_from_row).transpose()
+}
+
+/// 记录下载缓存
+pub(crate) async fn record_download(entry: &CachedDownload) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO download_cache (url, path, sha1, size, fetched_at)
+         VALUES (?, ?, ?, ?, ?)
+         ON CONFLICT (url) DO UPDATE SET
+            path = excluded.path,
+            sha1 = excluded.sha1,
+            size = excluded.size,
+            fetched_at = excluded.fetched_at",
+    )
+    .bind(&entry.url)
+    .bind(&entry.path)
+    .bind(&entry.sha1)
+    .bind(entry.size as i64)
+    .bind(entry.fetched_at)
+    .execute(pool()?)
+    .await?;
+    Ok(())
+}
+
+/// 清空下载缓存记录 (不删除文件)，返回删除的条目数
+pub async fn clear_download_cache() -> Result<u64> {
+    Ok(sqlx::query("DELETE FROM download_cache")
+        .execute(pool()?)
+        .await?
+        .rows_affected())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::mod_loader::ModLoader;
+    use chrono::Duration;
+    use std::future::Future;
+
+    /// 在临时目录的数据库中运行 `future`，其中的 `pool()` 都指向该数据库
+    pub(crate) async fn with_pool<F: Future>(dir: &Path, future: F) -> F::Output {
+        let pool = open_pool(&dir.to_string_lossy()).await.unwrap();
+        TEST_POOL.scope(Box::leak(Box::new(pool)), future).await
+    }
+
+    pub(crate) fn manifest(id: &str, name: &str, game_version: &str, loader: ModLoader) -> InstanceManifest {
+        InstanceManifest {
+            format_version: 0,
+            id: id.to_string(),
+            name: name.to_string(),
+            game_version: game_version.to_string(),
+            loader,
+            loader_version: None,
+            java_path: None,
+            max_memory_mb: None,
+            min_memory_mb: None,
+            jvm_args: Vec::new(),
+            resolution: None,
+            icon: None,
+            play_time_secs: 0,
+            created: Utc::now(),
+            last_played: None,
+            path: format!("/instances/{}", id),
+        }
+    }
+
+    pub(crate) fn record(instance_id: &str, path: &str, sha1: &str) -> ContentRecord {
+        ContentRecord {
+            instance_id: instance_id.to_string(),
+            path: path.to_string(),
+            project_type: "mod".to_string(),
+            project_id: None,
+            version_id: None,
+            sha1: sha1.to_string(),
+            sha512: None,
+            file_size: 1,
+            enabled: true,
+            installed_at: Utc::now(),
+            tags: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn runs_migrations_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        open_pool(&resource_dir).await.unwrap().close().await;
+        // 重新打开时不会重复执行迁移
+        let pool = open_pool(&resource_dir).await.unwrap();
+        let applied: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_migrations WHERE success")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(applied as usize, MIGRATOR.iter().count());
+        let tables: Vec<String> = sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type = 'table'")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        for table in ["instances", "instance_content", "java_runtimes", "download_cache", "play_sessions"] {
+            assert!(tables.iter().any(|name| name == table), "缺少表 {}", table);
+        }
+    }
+
+    #[tokio::test]
+    async fn stores_content_records() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            // 内容记录依赖实例索引
+            assert!(upsert_content(&record("pack", "mods/a.jar", "a")).await.is_err());
+            index_instance(&manifest("pack", "Pack", "1.20.1", ModLoader::Fabric)).await.unwrap();
+
+            let mut sodium = record("pack", "mods/sodium.jar", "1");
+            sodium.project_id = Some("AANobbMI".to_string());
+            upsert_content(&sodium).await.unwrap();
+            let mut pack = record("pack", "resourcepacks/faithful.zip", "2");
+            pack.project_type = "resourcepack".to_string();
+            upsert_content(&pack).await.unwrap();
+            sodium.sha1 = "3".to_string();
+            upsert_content(&sodium).await.unwrap();
+
+            let all = list_instance_content("pack".to_string(), None).await.unwrap();
+            assert_eq!(all.len(), 2);
+            assert_eq!(all[0].path, "mods/sodium.jar");
+            assert_eq!(all[0].sha1, "3");
+            let packs = list_instance_content("pack".to_string(), Some("resourcepack".to_string())).await.unwrap();
+            assert_eq!(packs.len(), 1);
+            assert_eq!(content_by_project("pack", "AANobbMI").await.unwrap().len(), 1);
+
+            set_content_tags("pack".to_string(), "mods/sodium.jar".to_string(), vec!["performance".to_string()])
+                .await
+                .unwrap();
+            assert!(set_content_tags("pack".to_string(), "mods/missing.jar".to_string(), Vec::new()).await.is_err());
+            assert_eq!(content_by_project("pack", "AANobbMI").await.unwrap()[0].tags, ["performance"]);
+
+            remove_content("pack", "resourcepacks/faithful.zip").await.unwrap();
+            assert_eq!(list_instance_content("pack".to_string(), None).await.unwrap().len(), 1);
+
+            // 删除实例索引时级联删除内容记录
+            remove_instance("pack").await.unwrap();
+            assert!(list_instance_content("pack".to_string(), None).await.unwrap().is_empty());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn replaces_content_in_one_transaction() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            index_instance(&manifest("pack", "Pack", "1.20.1", ModLoader::Fabric)).await.unwrap();
+            upsert_content(&record("pack", "mods/old.jar", "old")).await.unwrap();
+
+            replace_content("pack", &["mods/old.jar".to_string()], &[record("pack", "mods/new.jar", "new")])
+                .await
+                .unwrap();
+            let paths: Vec<String> = list_instance_content("pack".to_string(), None)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|record| record.path)
+                .collect();
+            assert_eq!(paths, ["mods/new.jar"]);
+
+            // 任一记录写入失败时整体回滚
+            let result = replace_content(
+                "pack",
+                &["mods/new.jar".to_string()],
+                &[record("pack", "mods/newer.jar", "newer"), record("missing", "mods/x.jar", "x")],
+            )
+            .await;
+            assert!(result.is_err());
+            let records = list_instance_content("pack".to_string(), None).await.unwrap();
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].path, "mods/new.jar");
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn searches_and_sorts_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let mut survival = manifest("survival", "Survival", "1.20.1", ModLoader::Vanilla);
+            survival.play_time_secs = 100;
+            survival.last_played = Some(Utc::now() - Duration::days(1));
+            let mut modded = manifest("modded", "modded_100%", "1.20.1", ModLoader::Fabric);
+            modded.play_time_secs = 500;
+            modded.last_played = Some(Utc::now());
+            let classic = manifest("classic", "Classic", "1.8.9", ModLoader::Forge);
+            for manifest in [&survival, &modded, &classic] {
+                index_instance(manifest).await.unwrap();
+            }
+
+            let search = |query: &str, sort, descending| search_instances(query.to_string(), sort, descending);
+            assert_eq!(search("", InstanceSort::Name, false).await.unwrap(), ["classic", "modded", "survival"]);
+            assert_eq!(search("1.20", InstanceSort::PlayTime, true).await.unwrap(), ["modded", "survival"]);
+            assert_eq!(search("forge", InstanceSort::Name, false).await.unwrap(), ["classic"]);
+            // LIKE 通配符按字面匹配
+            assert_eq!(search("_100%", InstanceSort::Name, false).await.unwrap(), ["modded"]);
+            assert!(search("%%", InstanceSort::Name, false).await.unwrap().is_empty());
+            // 从未游玩的实例排在最后
+            assert_eq!(
+                search("", InstanceSort::LastPlayed, true).await.unwrap(),
+                ["modded", "survival", "classic"]
+            );
+
+            remove_instance("classic").await.unwrap();
+            assert_eq!(search("", InstanceSort::Name, false).await.unwrap(), ["modded", "survival"]);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn stores_java_runtimes() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let runtime = |path: &str, version: &str, major_version| JavaRuntimeVersion {
+                path: path.to_string(),
+                version: version.to_string(),
+                major_version,
+            };
+            save_java_runtimes(vec![
+                runtime("/jdk8/bin/java", "1.8.0_392", 8),
+                runtime("/jdk17/bin/java", "17.0.8", 17),
+                runtime("/jdk21/bin/java", "21.0.1", 21),
+            ])
+            .await
+            .unwrap();
+            // 同一路径更新版本
+            save_java_runtimes(vec![runtime("/jdk17/bin/java", "17.0.9", 17)]).await.unwrap();
+
+            let all = list_java_runtimes(None).await.unwrap();
+            let majors: Vec<i32> = all.iter().map(|runtime| runtime.major_version).collect();
+            assert_eq!(majors, [21, 17, 8]);
+            let java17 = list_java_runtimes(Some(17)).await.unwrap();
+            assert_eq!(java17.len(), 1);
+            assert_eq!(java17[0].version, "17.0.9");
+
+            remove_java_runtime("/jdk8/bin/java".to_string()).await.unwrap();
+            assert_eq!(list_java_runtimes(None).await.unwrap().len(), 2);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn caches_downloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("client.jar");
+        tokio::fs::write(&file, b"client").await.unwrap();
+        with_pool(dir.path(), async {
+            let entry = |url: &str, path: &Path, fetched_at| CachedDownload {
+                url: url.to_string(),
+                path: path.to_string_lossy().to_string(),
+                sha1: Some("sha1".to_string()),
+                size: 6,
+                fetched_at,
+            };
+            let earlier = Utc::now() - Duration::hours(1);
+            record_download(&entry("https://a.example/client.jar", &file, earlier)).await.unwrap();
+            record_download(&entry("https://b.example/client.jar", &file, Utc::now())).await.unwrap();
+            record_download(&entry("https://a.example/gone.jar", &dir.path().join("gone.jar"), earlier))
+                .await
+                .unwrap();
+
+            let cached = cached_download("https://a.example/client.jar").await.unwrap().unwrap();
+            assert_eq!(cached.size, 6);
+            assert_eq!(download_source(&file).await.unwrap().unwrap().url, "https://b.example/client.jar");
+            // 文件已不存在时删除记录
+            assert!(cached_download("https://a.example/gone.jar").await.unwrap().is_none());
+            assert!(download_source(&dir.path().join("gone.jar")).await.unwrap().is_none());
+
+            assert_eq!(clear_download_cache().await.unwrap(), 2);
+            assert!(cached_download("https://a.example/client.jar").await.unwrap().is_none());
+        })
+        .await;
+    }
+}
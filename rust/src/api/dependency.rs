@@ -0,0 +1,722 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::content::{self, ContentType};
+use crate::api::content_update::move_file;
+use crate::api::database::{self, ContentRecord};
+use crate::api::instance;
+use crate::api::mod_loader::ModLoader;
+use crate::api::modrinth::{ModrinthClient, Project, Version, VersionFile};
+
+/// 配置常量
+mod config {
+    /// 实例目录下存放待安装文件的目录
+    pub const STAGING_DIR: &str = ".install-staging";
+    /// 暂存目录中存放被替换旧文件的子目录
+    pub const REPLACED_DIR: &str = "replaced";
+    /// 并发下载数
+    pub const CONCURRENT_DOWNLOADS: usize = 4;
+}
+
+/// 计划安装的项目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedInstall {
+    pub project_id: String,
+    pub version_id: String,
+    pub title: String,
+    pub project_type: String,
+    pub version_number: String,
+    /// 引入该依赖的项目，用户选择的项目为 None
+    pub required_by: Option<String>,
+}
+
+/// 计划升级的已安装项目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedUpgrade {
+    pub project_id: String,
+    pub from_version_id: String,
+    pub to_version_id: String,
+    pub title: String,
+    pub project_type: String,
+    pub version_number: String,
+    pub required_by: Option<String>,
+}
+
+/// 冲突类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictKind {
+    /// 声明了不兼容
+    Incompatible,
+    /// 没有适用于实例游戏版本与加载器的版本
+    NoCompatibleVersion,
+    /// 不同项目要求了同一依赖的不同版本
+    VersionMismatch,
+}
+
+/// 依赖冲突
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyConflict {
+    pub kind: ConflictKind,
+    /// 出现问题的项目
+    pub project_id: String,
+    /// 引起冲突的项目
+    pub source_project_id: String,
+    pub message: String,
+}
+
+/// 安装计划，由界面确认后交给 `apply_install_plan` 执行
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstallPlan {
+    pub install: Vec<PlannedInstall>,
+    pub upgrade: Vec<PlannedUpgrade>,
+    pub conflicts: Vec<DependencyConflict>,
+    /// 未安装的可选依赖 (项目 ID)
+    pub optional: Vec<String>,
+}
+
+/// 解析依赖所需的数据来源
+#[frb(ignore)]
+pub(crate) trait VersionSource {
+    async fn version(&self, id: &str) -> Result<Version>;
+    async fn versions(&self, ids: &[String]) -> Result<Vec<Version>>;
+    /// 项目适用于指定加载器与游戏版本的版本，新版本在前
+    async fn project_versions(&self, project_id: &str, loaders: &[String], game_versions: &[String])
+        -> Result<Vec<Version>>;
+    async fn projects(&self, ids: &[String]) -> Result<Vec<Project>>;
+}
+
+impl VersionSource for ModrinthClient {
+    async fn version(&self, id: &str) -> Result<Version> {
+        ModrinthClient::version(self, id).await
+    }
+
+    async fn versions(&self, ids: &[String]) -> Result<Vec<Version>> {
+        ModrinthClient::versions(self, ids).await
+    }
+
+    async fn project_versions(
+        &self,
+        project_id: &str,
+        loaders: &[String],
+        game_versions: &[String],
+    ) -> Result<Vec<Version>> {
+        ModrinthClient::project_versions(self, project_id, loaders, game_versions).await
+    }
+
+    async fn projects(&self, ids: &[String]) -> Result<Vec<Project>> {
+        ModrinthClient::projects(self, ids).await
+    }
+}
+
+/// 实例可接受的 Modrinth 加载器 (Quilt 兼容 Fabric 模组，资源包等使用 minecraft)
+fn compatible_loaders(loader: ModLoader) -> Vec<String> {
+    let mut loaders = vec![loader.as_str().to_string()];
+    if loader == ModLoader::Quilt {
+        loaders.push(ModLoader::Fabric.as_str().to_string());
+    }
+    if loader != ModLoader::Vanilla {
+        loaders.push(ModLoader::Vanilla.as_str().to_string());
+    }
+    loaders
+}
+
+/// 版本是否适用于实例
+fn is_compatible(version: &Version, game_version: &str, loaders: &[String]) -> bool {
+    version.game_versions.iter().any(|v| v == game_version)
+        && version.loaders.iter().any(|loader| loaders.contains(loader))
+}
+
+/// 从候选版本中选择：优先正式版，其次最新版本
+fn pick_version(mut candidates: Vec<Version>) -> Option<Version> {
+    let release = candidates.iter().position(|version| version.version_type == "release");
+    match release {
+        Some(index) => Some(candidates.swap_remove(index)),
+        None if !candidates.is_empty() => Some(candidates.swap_remove(0)),
+        None => None,
+    }
+}
+
+/// 解析过程中选定的版本
+struct Chosen {
+    version: Version,
+    required_by: Option<String>,
+    /// 被依赖固定为该版本
+    pinned: bool,
+}
+
+/// 遍历依赖图，生成安装计划。`installed` 为实例中已安装的内容
+pub(crate) async fn resolve<S: VersionSource>(
+    source: &S,
+    root: Version,
+    game_version: &str,
+    loader: ModLoader,
+    installed: &[ContentRecord],
+) -> Result<InstallPlan> {
+    let loaders = compatible_loaders(loader);
+    let game_versions = [game_version.to_string()];
+    let installed: HashMap<&str, &str> = installed
+        .iter()
+        .filter_map(|record| Some((record.project_id.as_deref()?, record.version_id.as_deref().unwrap_or_default())))
+        .collect();
+
+    let mut plan = InstallPlan::default();
+    let mut chosen: HashMap<String, Chosen> = HashMap::new();
+    // (不兼容的项目, 限定的版本, 声明不兼容的项目)
+    let mut incompatibilities: Vec<(String, Option<String>, String)> = Vec::new();
+    let mut queue = VecDeque::new();
+
+    if !is_compatible(&root, game_version, &loaders) {
+        plan.conflicts.push(DependencyConflict {
+            kind: ConflictKind::NoCompatibleVersion,
+            project_id: root.project_id.clone(),
+            source_project_id: root.project_id.clone(),
+            message: format!("{} 不支持 {} {}", root.version_number, loader.as_str(), game_version),
+        });
+    }
+    queue.push_back(root.project_id.clone());
+    chosen.insert(
+        root.project_id.clone(),
+        Chosen {
+            version: root,
+            required_by: None,
+            pinned: true,
+        },
+    );
+
+    while let Some(project_id) = queue.pop_front() {
+        let dependencies = chosen[&project_id].version.dependencies.clone();
+        for dependency in dependencies {
+            match dependency.dependency_type.as_str() {
+                "required" => {}
+                "incompatible" => {
+                    if let Some(target) = &dependency.project_id {
+                        incompatibilities.push((target.clone(), dependency.version_id.clone(), project_id.clone()));
+                    } else if let Some(version_id) = &dependency.version_id {
+                        let version = source.version(version_id).await?;
+                        incompatibilities.push((version.project_id, Some(version.id), project_id.clone()));
+                    }
+                    continue;
+                }
+                "optional" => {
+                    if let Some(target) = &dependency.project_id {
+                        if !installed.contains_key(target.as_str()) && !plan.optional.contains(target) {
+                            plan.optional.push(target.clone());
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            }
+
+            // 固定版本的依赖直接读取版本，否则按实例筛选项目版本
+            let pinned = dependency.version_id.is_some();
+            let target_project = match (&dependency.version_id, &dependency.project_id) {
+                (Some(_), Some(target)) | (None, Some(target)) => target.clone(),
+                (Some(version_id), None) => source.version(version_id).await?.project_id,
+                (None, None) => continue,
+            };
+
+            if let Some(existing) = chosen.get(&target_project) {
+                let mismatch = dependency
+                    .version_id
+                    .as_ref()
+                    .is_some_and(|version_id| existing.pinned && *version_id != existing.version.id);
+                if mismatch {
+                    plan.conflicts.push(DependencyConflict {
+                        kind: ConflictKind::VersionMismatch,
+                        project_id: target_project.clone(),
+                        source_project_id: project_id.clone(),
+                        message: format!(
+                            "需要版本 {}，但已选择 {}",
+                            dependency.version_id.clone().unwrap_or_default(),
+                            existing.version.version_number
+                        ),
+                    });
+                }
+                continue;
+            }
+
+            if let Some(installed_version) = installed.get(target_project.as_str()) {
+                let satisfied = dependency
+                    .version_id
+                    .as_deref()
+                    .is_none_or(|version_id| version_id == *installed_version);
+                if satisfied {
+                    continue;
+                }
+            }
+
+            let version = match &dependency.version_id {
+                Some(version_id) => Some(source.version(version_id).await?)
+                    .filter(|version| is_compatible(version, game_version, &loaders)),
+                None => pick_version(
+                    source
+                        .project_versions(&target_project, &loaders, &game_versions)
+                        .await?
+                        .into_iter()
+                        .filter(|version| is_compatible(version, game_version, &loaders))
+                        .collect(),
+                ),
+            };
+            let Some(version) = version else {
+                plan.conflicts.push(DependencyConflict {
+                    kind: ConflictKind::NoCompatibleVersion,
+                    project_id: target_project.clone(),
+                    source_project_id: project_id.clone(),
+                    message: format!("没有适用于 {} {} 的版本", loader.as_str(), game_version),
+                });
+                continue;
+            };
+            chosen.insert(
+                target_project.clone(),
+                Chosen {
+                    version,
+                    required_by: Some(project_id.clone()),
+                    pinned,
+                },
+            );
+            queue.push_back(target_project);
+        }
+    }
+
+    // 已安装的内容声明与计划中的项目不兼容
+    let installed_ids: Vec<String> = installed
+        .iter()
+        .filter(|(project_id, version_id)| !version_id.is_empty() && !chosen.contains_key(**project_id))
+        .map(|(_, version_id)| version_id.to_string())
+        .collect();
+    if !installed_ids.is_empty() {
+        for version in source.versions(&installed_ids).await? {
+            for dependency in version.dependencies.iter().filter(|d| d.dependency_type == "incompatible") {
+                if let Some(target) = &dependency.project_id {
+                    incompatibilities.push((target.clone(), dependency.version_id.clone(), version.project_id.clone()));
+                }
+            }
+        }
+    }
+
+    let mut reported = HashSet::new();
+    for (target, version_id, source_project) in incompatibilities {
+        let present = match chosen.get(&target) {
+            Some(chosen) => version_id.as_ref().is_none_or(|id| *id == chosen.version.id),
+            None => installed
+                .get(target.as_str())
+                .is_some_and(|installed| version_id.as_deref().is_none_or(|id| id == *installed)),
+        };
+        // 两端都声明不兼容时只报告一次
+        let key = if target < source_project {
+            (target.clone(), source_project.clone())
+        } else {
+            (source_project.clone(), target.clone())
+        };
+        if present && reported.insert(key) {
+            plan.conflicts.push(DependencyConflict {
+                kind: ConflictKind::Incompatible,
+                project_id: target,
+                source_project_id: source_project,
+                message: "声明不兼容".to_string(),
+            });
+        }
+    }
+
+    let project_ids: Vec<String> = chosen.keys().cloned().collect();
+    let projects: HashMap<String, Project> = source
+        .projects(&project_ids)
+        .await?
+        .into_iter()
+        .map(|project| (project.id.clone(), project))
+        .collect();
+    let mut chosen: Vec<Chosen> = chosen.into_values().collect();
+    chosen.sort_by(|a, b| a.version.project_id.cmp(&b.version.project_id));
+    for Chosen {
+        version, required_by, ..
+    } in chosen
+    {
+        let (title, project_type) = projects
+            .get(&version.project_id)
+            .map(|project| (project.title.clone(), project.project_type.clone()))
+            .unwrap_or_else(|| (version.project_id.clone(), "mod".to_string()));
+        match installed.get(version.project_id.as_str()) {
+            Some(installed_version) if *installed_version == version.id => {}
+            Some(installed_version) => plan.upgrade.push(PlannedUpgrade {
+                project_id: version.project_id,
+                from_version_id: installed_version.to_string(),
+                to_version_id: version.id,
+                title,
+                project_type,
+                version_number: version.version_number,
+                required_by,
+            }),
+            None => plan.install.push(PlannedInstall {
+                project_id: version.project_id,
+                version_id: version.id,
+                title,
+                project_type,
+                version_number: version.version_number,
+                required_by,
+            }),
+        }
+    }
+    Ok(plan)
+}
+
+/// 已安装内容，数据库未初始化时为空
+async fn installed_content(instance_id: &str) -> Result<Vec<ContentRecord>> {
+    if !database::is_initialized() {
+        return Ok(Vec::new());
+    }
+    database::list_instance_content(instance_id.to_string(), None).await
+}
+
+/// 为安装 Modrinth 版本生成计划 (含必需依赖、升级与冲突)，不下载任何文件
+pub async fn resolve_content_install(
+    resource_dir: String,
+    instance_id: String,
+    version_id: String,
+) -> Result<InstallPlan> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let client = ModrinthClient::default();
+    let root = client.version(&version_id).await?;
+    let installed = installed_content(&manifest.id).await?;
+    resolve(&client, root, &manifest.game_version, manifest.loader, &installed).await
+}
+
+/// 下载所有文件到暂存目录并校验，任一失败则整体失败
+async fn stage_files(staging: &Path, targets: &[(ContentRecord, &VersionFile)]) -> Result<Vec<PathBuf>> {
+    use futures::StreamExt;
+
+    // 同 content_update::stage_updates，先收集再并发
+    let downloads: Vec<_> = targets
+        .iter()
+        .enumerate()
+        .map(|(index, (_, file))| async move {
+            // 以序号区分目录，避免不同项目的同名文件互相覆盖
+            let dest = staging.join(index.to_string()).join(&file.filename);
+            content::download_version_file(file, &dest).await?;
+            Ok::<_, anyhow::Error>(dest)
+        })
+        .collect();
+    let staged = futures::stream::iter(downloads)
+        .buffered(config::CONCURRENT_DOWNLOADS)
+        .collect::<Vec<_>>()
+        .await;
+    staged.into_iter().collect()
+}
+
+/// 按相反顺序撤销已完成的移动，返回是否全部恢复
+async fn undo_moves(moves: &[(PathBuf, PathBuf)]) -> bool {
+    let mut restored = true;
+    for (from, to) in moves.iter().rev() {
+        if let Err(e) = move_file(to, from).await {
+            tracing::warn!("无法恢复 {}: {}", from.display(), e);
+            restored = false;
+        }
+    }
+    restored
+}
+
+/// 执行确认后的安装计划，有冲突时拒绝执行。`world` 用于安装数据包。
+/// 先下载并校验全部文件，再统一替换并在一个事务中写入记录，任一步失败时实例保持原样
+pub async fn apply_install_plan(
+    resource_dir: String,
+    instance_id: String,
+    plan: InstallPlan,
+    world: Option<String>,
+) -> Result<Vec<ContentRecord>> {
+    if !plan.conflicts.is_empty() {
+        return Err(anyhow!("安装计划存在 {} 个冲突", plan.conflicts.len()));
+    }
+    if !database::is_initialized() {
+        return Err(anyhow!("数据库尚未初始化，无法记录内容来源"));
+    }
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let instance_dir = PathBuf::from(&manifest.path);
+
+    let requested: Vec<(&String, &String)> = plan
+        .install
+        .iter()
+        .map(|item| (&item.version_id, &item.project_type))
+        .chain(plan.upgrade.iter().map(|item| (&item.to_version_id, &item.project_type)))
+        .collect();
+    let version_ids: Vec<String> = requested.iter().map(|(version_id, _)| version_id.to_string()).collect();
+    let versions: HashMap<String, Version> = ModrinthClient::default()
+        .versions(&version_ids)
+        .await?
+        .into_iter()
+        .map(|version| (version.id.clone(), version))
+        .collect();
+
+    let mut targets = Vec::new();
+    for (version_id, project_type) in requested {
+        let version = versions
+            .get(version_id)
+            .ok_or_else(|| anyhow!("找不到版本 {}", version_id))?;
+        let content_type = ContentType::from_modrinth(project_type, version)?;
+        let file = content::primary_file(version)?;
+        let relative = format!("{}/{}", content_type.folder(world.as_deref())?, file.filename);
+        targets.push((
+            content::version_record(&manifest.id, relative, content_type, version, file),
+            file,
+        ));
+    }
+
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    let staging = instance_dir.join(config::STAGING_DIR).join(&id);
+    let mut keep_staging = false;
+    let result = async {
+        let staged = stage_files(&staging, &targets).await?;
+
+        // 同一项目在同一目录下的旧文件与同名文件先移到暂存目录，失败时可以放回
+        let backup_dir = staging.join(config::REPLACED_DIR);
+        let mut moves = Vec::new();
+        let mut removed = Vec::new();
+        for ((record, _), staged) in targets.iter().zip(staged) {
+            let folder = Path::new(&record.path).parent();
+            let previous = database::content_by_project(&manifest.id, record.project_id.as_deref().unwrap_or_default()).await?;
+            for old in previous
+                .iter()
+                .filter(|old| old.path != record.path && Path::new(&old.path).parent() == folder)
+            {
+                removed.push(old.path.clone());
+                if fs::try_exists(instance_dir.join(&old.path)).await? {
+                    moves.push((instance_dir.join(&old.path), backup_dir.join(&old.path)));
+                }
+            }
+            let dest = instance_dir.join(&record.path);
+            if fs::try_exists(&dest).await? {
+                moves.push((dest.clone(), backup_dir.join(&record.path)));
+            }
+            moves.push((staged, dest));
+        }
+
+        let records: Vec<ContentRecord> = targets.iter().map(|(record, _)| record.clone()).collect();
+        let mut completed = 0;
+        let mut failure = None;
+        for (from, to) in &moves {
+            if let Err(e) = move_file(from, to).await {
+                failure = Some(e);
+                break;
+            }
+            completed += 1;
+        }
+        if failure.is_none() {
+            failure = database::replace_content(&manifest.id, &removed, &records).await.err();
+        }
+        if let Some(e) = failure {
+            if !undo_moves(&moves[..completed]).await {
+                keep_staging = true;
+                return Err(e.context(format!("部分文件未能恢复，旧文件保留在 {}", backup_dir.display())));
+            }
+            return Err(e);
+        }
+        Ok(records)
+    }
+    .await;
+    if !keep_staging {
+        fs::remove_dir_all(&staging).await.ok();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::modrinth::VersionDependency;
+
+    /// 内存中的版本数据，`project_versions` 按加入的相反顺序 (新版本在前) 返回
+    #[derive(Default)]
+    struct MemorySource {
+        versions: Vec<Version>,
+    }
+
+    impl MemorySource {
+        fn add(mut self, version: Version) -> Self {
+            self.versions.push(version);
+            self
+        }
+    }
+
+    impl VersionSource for MemorySource {
+        async fn version(&self, id: &str) -> Result<Version> {
+            self.versions
+                .iter()
+                .find(|version| version.id == id)
+                .cloned()
+                .ok_or_else(|| anyhow!("找不到版本 {}", id))
+        }
+
+        async fn versions(&self, ids: &[String]) -> Result<Vec<Version>> {
+            Ok(self.versions.iter().filter(|version| ids.contains(&version.id)).cloned().collect())
+        }
+
+        async fn project_versions(
+            &self,
+            project_id: &str,
+            loaders: &[String],
+            game_versions: &[String],
+        ) -> Result<Vec<Version>> {
+            Ok(self
+                .versions
+                .iter()
+                .rev()
+                .filter(|version| version.project_id == project_id)
+                .filter(|version| version.loaders.iter().any(|loader| loaders.contains(loader)))
+                .filter(|version| version.game_versions.iter().any(|v| game_versions.contains(v)))
+                .cloned()
+                .collect())
+        }
+
+        async fn projects(&self, ids: &[String]) -> Result<Vec<Project>> {
+            Ok(ids
+                .iter()
+                .map(|id| Project {
+                    id: id.clone(),
+                    title: id.to_uppercase(),
+                    project_type: "mod".to_string(),
+                    ..Default::default()
+                })
+                .collect())
+        }
+    }
+
+    /// `id` 形如 `a1`，项目为首字母
+    fn version(id: &str, dependencies: &[(&str, Option<&str>, &str)]) -> Version {
+        Version {
+            id: id.to_string(),
+            project_id: id[..1].to_string(),
+            version_number: id.to_string(),
+            version_type: "release".to_string(),
+            game_versions: vec!["1.20.1".to_string()],
+            loaders: vec!["fabric".to_string()],
+            dependencies: dependencies
+                .iter()
+                .map(|(project_id, version_id, dependency_type)| VersionDependency {
+                    project_id: Some(project_id.to_string()),
+                    version_id: version_id.map(str::to_string),
+                    dependency_type: dependency_type.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn installed(version: &str) -> ContentRecord {
+        ContentRecord {
+            instance_id: "instance".to_string(),
+            path: format!("mods/{}.jar", version),
+            project_type: "mod".to_string(),
+            project_id: Some(version[..1].to_string()),
+            version_id: Some(version.to_string()),
+            sha1: String::new(),
+            sha512: None,
+            file_size: 0,
+            enabled: true,
+            installed_at: chrono::Utc::now(),
+            tags: Vec::new(),
+        }
+    }
+
+    async fn plan(source: &MemorySource, root: &str, installed: &[ContentRecord]) -> InstallPlan {
+        let root = source.version(root).await.unwrap();
+        resolve(source, root, "1.20.1", ModLoader::Fabric, installed).await.unwrap()
+    }
+
+    fn installs(plan: &InstallPlan) -> Vec<(&str, Option<&str>)> {
+        plan.install
+            .iter()
+            .map(|item| (item.version_id.as_str(), item.required_by.as_deref()))
+            .collect()
+    }
+
+    fn conflicts(plan: &InstallPlan) -> Vec<(ConflictKind, &str, &str)> {
+        plan.conflicts
+            .iter()
+            .map(|conflict| (conflict.kind, conflict.project_id.as_str(), conflict.source_project_id.as_str()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn resolves_required_chain() {
+        let source = MemorySource::default()
+            .add(version("a1", &[("b", None, "required"), ("o", None, "optional")]))
+            .add(version("b1", &[("c", None, "required")]))
+            .add(version("c1", &[]));
+        let plan = plan(&source, "a1", &[]).await;
+        assert_eq!(installs(&plan), [("a1", None), ("b1", Some("a")), ("c1", Some("b"))]);
+        assert_eq!(plan.install[1].title, "B");
+        assert_eq!(plan.optional, ["o"]);
+        assert!(plan.conflicts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn resolves_diamond_once() {
+        let source = MemorySource::default()
+            .add(version("a1", &[("b", None, "required"), ("c", None, "required")]))
+            .add(version("b1", &[("d", None, "required")]))
+            .add(version("c1", &[("d", None, "required")]))
+            .add(version("d1", &[]))
+            .add(version("d2", &[]));
+        let plan = plan(&source, "a1", &[]).await;
+        assert_eq!(
+            installs(&plan),
+            [("a1", None), ("b1", Some("a")), ("c1", Some("a")), ("d2", Some("b"))]
+        );
+        assert!(plan.conflicts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_pinned_version_mismatch() {
+        let source = MemorySource::default()
+            .add(version("a1", &[("b", Some("b1"), "required"), ("c", None, "required")]))
+            .add(version("b1", &[]))
+            .add(version("b2", &[]))
+            .add(version("c1", &[("b", Some("b2"), "required")]));
+        let plan = plan(&source, "a1", &[]).await;
+        assert_eq!(conflicts(&plan), [(ConflictKind::VersionMismatch, "b", "c")]);
+    }
+
+    #[tokio::test]
+    async fn reports_two_sided_incompatibility_once() {
+        let source = MemorySource::default()
+            .add(version("a1", &[("x", None, "incompatible")]))
+            .add(version("x1", &[("a", None, "incompatible")]));
+        let plan = plan(&source, "a1", &[installed("x1")]).await;
+        assert_eq!(conflicts(&plan), [(ConflictKind::Incompatible, "x", "a")]);
+    }
+
+    #[tokio::test]
+    async fn upgrades_installed_dependency() {
+        let source = MemorySource::default()
+            .add(version("a1", &[("b", Some("b2"), "required"), ("c", None, "required")]))
+            .add(version("b1", &[]))
+            .add(version("b2", &[]))
+            .add(version("c1", &[]));
+        let plan = plan(&source, "a1", &[installed("b1"), installed("c1")]).await;
+        // 未固定版本的已安装依赖保持不变
+        assert_eq!(installs(&plan), [("a1", None)]);
+        let upgrades: Vec<_> = plan
+            .upgrade
+            .iter()
+            .map(|item| (item.project_id.as_str(), item.from_version_id.as_str(), item.to_version_id.as_str()))
+            .collect();
+        assert_eq!(upgrades, [("b", "b1", "b2")]);
+        assert!(plan.conflicts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_missing_compatible_version() {
+        let mut forge_only = version("b1", &[]);
+        forge_only.loaders = vec!["forge".to_string()];
+        let source = MemorySource::default()
+            .add(version("a1", &[("b", None, "required")]))
+            .add(forge_only);
+        let plan = plan(&source, "a1", &[]).await;
+        assert_eq!(conflicts(&plan), [(ConflictKind::NoCompatibleVersion, "b", "a")]);
+        assert_eq!(installs(&plan), [("a1", None)]);
+    }
+}
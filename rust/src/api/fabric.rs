@@ -0,0 +1,161 @@
+use anyhow::Result;
+use flutter_rust_bridge::DartFnFuture;
+use std::path::Path;
+
+use crate::api::java_download::ProgressCallback;
+use crate::api::loader_meta::{FabricLikeSource, LoaderVersion};
+use crate::config::{CURRENT_FABRIC_FORMAT_VERSION, FABRIC_META_URL, META_URL};
+
+/// Fabric 元数据来源
+pub(crate) const FABRIC_SOURCE: FabricLikeSource<'static> = fabric_source(META_URL, FABRIC_META_URL);
+
+/// 使用指定 Modrinth 元数据地址与 Fabric 官方元数据地址的来源
+pub(crate) const fn fabric_source<'a>(meta_url: &'a str, upstream_url: &'a str) -> FabricLikeSource<'a> {
+    FabricLikeSource {
+        name: "Fabric",
+        meta_loader: "fabric",
+        format_version: CURRENT_FABRIC_FORMAT_VERSION,
+        meta_url,
+        upstream_url,
+    }
+}
+
+/// 列出指定游戏版本可用的 Fabric 加载器，Modrinth 元数据不可用时回退到 meta.fabricmc.net
+pub async fn list_fabric_loaders(game_version: String) -> Result<Vec<LoaderVersion>> {
+    FABRIC_SOURCE.list_loaders(&game_version).await
+}
+
+/// 安装 Fabric 加载器，返回新版本 ID
+pub async fn install_fabric(
+    minecraft_dir: String,
+    game_version: String,
+    loader_version: String,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<String> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    FABRIC_SOURCE
+        .install(Path::new(&minecraft_dir), &game_version, &loader_version, &on_progress)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_json(server: &MockServer, route: &str, body: serde_json::Value) {
+        Mock::given(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(server)
+            .await;
+    }
+
+    fn urls(server: &MockServer) -> (String, String) {
+        (format!("{}/meta/", server.uri()), format!("{}/upstream/", server.uri()))
+    }
+
+    #[tokio::test]
+    async fn lists_loaders_from_meta() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "/meta/fabric/v0/manifest.json",
+            json!({
+                "gameVersions": [
+                    { "id": "1.20.1", "stable": true, "loaders": [] },
+                    {
+                        "id": "${modrinth.gameVersion}",
+                        "stable": true,
+                        "loaders": [
+                            { "id": "0.15.0", "url": "unused", "stable": true },
+                            { "id": "0.15.1-beta.1", "url": "unused", "stable": false }
+                        ]
+                    }
+                ]
+            }),
+        )
+        .await;
+        let (meta, upstream) = urls(&server);
+        let loaders = fabric_source(&meta, &upstream).list_loaders("1.20.1").await.unwrap();
+        let loaders: Vec<_> = loaders.iter().map(|loader| (loader.id.as_str(), loader.stable)).collect();
+        assert_eq!(loaders, [("0.15.0", true), ("0.15.1-beta.1", false)]);
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_upstream() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "/upstream/versions/loader/1.20.1",
+            json!([
+                { "loader": { "version": "0.15.0", "stable": true } },
+                { "loader": { "version": "0.14.25", "stable": false } }
+            ]),
+        )
+        .await;
+        let (meta, upstream) = urls(&server);
+        let loaders = fabric_source(&meta, &upstream).list_loaders("1.20.1").await.unwrap();
+        let loaders: Vec<_> = loaders.iter().map(|loader| (loader.id.as_str(), loader.stable)).collect();
+        assert_eq!(loaders, [("0.15.0", true), ("0.14.25", false)]);
+    }
+
+    #[tokio::test]
+    async fn installs_profile_from_meta() {
+        let server = MockServer::start().await;
+        let (meta, upstream) = urls(&server);
+        mount_json(
+            &server,
+            "/meta/fabric/v0/manifest.json",
+            json!({
+                "gameVersions": [
+                    { "id": "1.20.1", "loaders": [] },
+                    {
+                        "id": "${modrinth.gameVersion}",
+                        "loaders": [{ "id": "0.15.0", "url": format!("{}/profile.json", server.uri()), "stable": true }]
+                    }
+                ]
+            }),
+        )
+        .await;
+        mount_json(
+            &server,
+            "/profile.json",
+            json!({
+                "id": "fabric-loader-0.15.0-${modrinth.gameVersion}",
+                "inheritsFrom": "${modrinth.gameVersion}",
+                "mainClass": "net.fabricmc.loader.impl.launch.knot.KnotClient",
+                "libraries": [{ "name": "net.fabricmc:fabric-loader:0.15.0", "url": format!("{}/maven/", server.uri()) }]
+            }),
+        )
+        .await;
+        Mock::given(path("/maven/net/fabricmc/fabric-loader/0.15.0/fabric-loader-0.15.0.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"loader".as_slice()))
+            .mount(&server)
+            .await;
+
+        // 原版 JSON 已存在时不会请求版本清单
+        let dir = tempfile::tempdir().unwrap();
+        let vanilla_path = crate::minecraft::version_json_path(&dir.path().join("versions"), "1.20.1");
+        tokio::fs::create_dir_all(vanilla_path.parent().unwrap()).await.unwrap();
+        tokio::fs::write(&vanilla_path, r#"{"id":"1.20.1","mainClass":"net.minecraft.client.main.Main"}"#)
+            .await
+            .unwrap();
+
+        let on_progress: ProgressCallback = Box::new(|_, _| Box::pin(async {}) as DartFnFuture<()>);
+        let id = fabric_source(&meta, &upstream)
+            .install(dir.path(), "1.20.1", "0.15.0", &on_progress)
+            .await
+            .unwrap();
+        assert_eq!(id, "fabric-loader-0.15.0-1.20.1");
+
+        let version_path = crate::minecraft::version_json_path(&dir.path().join("versions"), &id);
+        let version = crate::minecraft::read_version_info(&version_path).await.unwrap();
+        assert_eq!(version.inherits_from.as_deref(), Some("1.20.1"));
+        let library = dir
+            .path()
+            .join("libraries/net/fabricmc/fabric-loader/0.15.0/fabric-loader-0.15.0.jar");
+        assert_eq!(tokio::fs::read(library).await.unwrap(), b"loader");
+    }
+}
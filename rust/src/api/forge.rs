@@ -0,0 +1,903 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::DartFnFuture;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::process::Command;
+use zip::ZipArchive;
+
+use crate::api::java_download::ProgressCallback;
+use crate::api::launch::classpath_separator;
+use crate::api::loader_meta::{self, LoaderVersion};
+use crate::api::{libraries, vanilla};
+use crate::archive;
+use crate::checksum;
+use crate::config::{CURRENT_FORGE_FORMAT_VERSION, META_URL};
+use crate::download;
+use crate::minecraft::{self, Library, VersionInfo};
+
+/// 配置常量
+mod config {
+    /// Forge Maven 仓库
+    pub const FORGE_MAVEN_URL: &str = "https://maven.minecraftforge.net/";
+    /// 旧版 Forge 版本 JSON 中已失效的仓库地址
+    pub const LEGACY_FORGE_MAVEN_URLS: &[&str] = &[
+        "http://files.minecraftforge.net/maven/",
+        "https://files.minecraftforge.net/maven/",
+    ];
+    /// Forge 的 Maven 坐标
+    pub const FORGE_ARTIFACT: &str = "net.minecraftforge:forge";
+    /// Modrinth 元数据中的加载器名称
+    pub const META_LOADER: &str = "forge";
+    /// 处理器失败时附带的输出行数
+    pub const PROCESSOR_LOG_TAIL: usize = 20;
+}
+
+/// 正则表达式缓存
+mod regex_cache {
+    use regex::Regex;
+    use std::sync::LazyLock;
+
+    /// 处理器参数中的 `{KEY}` 变量
+    pub static DATA_TOKEN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"\{([A-Za-z0-9_]+)\}").unwrap()
+    });
+}
+
+/// 依赖于安装端的数据信息条目
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct SidedDataEntry {
+    pub client: String,
+}
+
+/// 下载文件后要运行的处理器
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct Processor {
+    /// 处理器 jar 的 Maven 坐标
+    pub jar: String,
+    #[serde(default)]
+    pub classpath: Vec<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// 输出文件 -> SHA-1
+    pub outputs: Option<HashMap<String, String>>,
+    /// client / server / extract
+    pub sides: Option<Vec<String>>,
+}
+
+/// 旧版 (1.13 之前) 安装配置中的 install 段
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LegacyInstall {
+    /// universal jar 的 Maven 坐标
+    pub path: String,
+    /// universal jar 在安装器中的文件名
+    pub file_path: String,
+    pub minecraft: String,
+}
+
+/// 安装器中的 install_profile.json
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InstallProfile {
+    /// 新版：版本 JSON 在安装器中的路径 (如 `/version.json`)
+    pub json: Option<String>,
+    pub minecraft: Option<String>,
+    #[serde(default)]
+    pub data: HashMap<String, SidedDataEntry>,
+    #[serde(default)]
+    pub processors: Vec<Processor>,
+    #[serde(default)]
+    pub libraries: Vec<Library>,
+    /// 旧版：安装信息
+    pub install: Option<LegacyInstall>,
+    /// 旧版：内嵌的版本 JSON
+    pub version_info: Option<VersionInfo>,
+}
+
+/// 读取 zip 中的单个文件
+fn read_zip_entry(zip_path: &Path, name: &str) -> Result<Vec<u8>> {
+    let mut archive = ZipArchive::new(File::open(zip_path)?)?;
+    let mut entry = archive
+        .by_name(name.trim_start_matches('/'))
+        .map_err(|_| anyhow!("安装器中缺少 {}", name))?;
+    let mut buffer = Vec::new();
+    entry.read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// 将 zip 中指定前缀下的文件解压到目标目录 (去掉前缀)
+fn extract_zip_prefix(zip_path: &Path, prefix: &str, dest: &Path) -> Result<()> {
+    let mut archive = ZipArchive::new(File::open(zip_path)?)?;
+    archive::extract_zip(
+        &mut archive,
+        dest,
+        |name| name.strip_prefix(prefix).ok().map(Path::to_path_buf),
+        |_, _| {},
+    )?;
+    Ok(())
+}
+
+/// 读取 jar 清单中的 Main-Class
+fn jar_main_class(jar_path: &Path) -> Result<String> {
+    let manifest = read_zip_entry(jar_path, "META-INF/MANIFEST.MF")?;
+    String::from_utf8_lossy(&manifest)
+        .lines()
+        .find_map(|line| line.strip_prefix("Main-Class:"))
+        .map(|main_class| main_class.trim().to_string())
+        .ok_or_else(|| anyhow!("{} 缺少 Main-Class", jar_path.display()))
+}
+
+/// 处理器运行环境
+struct ProcessorContext {
+    libraries_dir: PathBuf,
+    /// data 变量 (已解析为客户端取值)
+    variables: HashMap<String, String>,
+}
+
+impl ProcessorContext {
+    fn library_path(&self, coords: &str) -> Result<PathBuf> {
+        Ok(self.libraries_dir.join(minecraft::maven_path(coords, None)?))
+    }
+
+    /// 解析处理器参数：`[maven坐标]` 为库路径，`{KEY}` 替换为 data 变量
+    fn resolve_arg(&self, arg: &str) -> Result<String> {
+        if let Some(coords) = arg.strip_prefix('[').and_then(|arg| arg.strip_suffix(']')) {
+            return Ok(self.library_path(coords)?.to_string_lossy().to_string());
+        }
+        let mut missing = None;
+        let resolved = regex_cache::DATA_TOKEN_REGEX.replace_all(arg, |captures: &regex::Captures| {
+            match self.variables.get(&captures[1]) {
+                Some(value) => value.clone(),
+                None => {
+                    missing = Some(captures[1].to_string());
+                    captures[0].to_string()
+                }
+            }
+        });
+        match missing {
+            Some(key) => Err(anyhow!("处理器参数引用了未知变量: {}", key)),
+            None => Ok(resolved.into_owned()),
+        }
+    }
+}
+
+/// 解析 data 条目的客户端取值：`[坐标]`、`'字面量'` 或安装器内的文件路径
+fn resolve_data_value(
+    value: &str,
+    installer: &Path,
+    libraries_dir: &Path,
+    work_dir: &Path,
+) -> Result<String> {
+    if let Some(coords) = value.strip_prefix('[').and_then(|value| value.strip_suffix(']')) {
+        return Ok(libraries_dir
+            .join(minecraft::maven_path(coords, None)?)
+            .to_string_lossy()
+            .to_string());
+    }
+    if let Some(literal) = value.strip_prefix('\'').and_then(|value| value.strip_suffix('\'')) {
+        return Ok(literal.to_string());
+    }
+    if value.starts_with('/') {
+        let relative = Path::new(value.trim_start_matches('/'));
+        if relative.components().any(|component| !matches!(component, std::path::Component::Normal(_))) {
+            return Err(anyhow!("无效的安装器路径: {}", value));
+        }
+        let target = work_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, read_zip_entry(installer, value)?)?;
+        return Ok(target.to_string_lossy().to_string());
+    }
+    Ok(value.to_string())
+}
+
+/// 判断处理器输出是否都已存在且校验通过
+async fn outputs_valid(outputs: &[(PathBuf, String)]) -> bool {
+    if outputs.is_empty() {
+        return false;
+    }
+    for (path, sha1) in outputs {
+        if checksum::verify_file_sha1(path, sha1).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
+/// 运行单个处理器并校验输出
+async fn run_processor(processor: &Processor, context: &ProcessorContext, java_path: &str) -> Result<()> {
+    let jar_path = context.library_path(&processor.jar)?;
+    let main_class = jar_main_class(&jar_path)?;
+
+    let mut classpath = vec![jar_path.to_string_lossy().to_string()];
+    for coords in &processor.classpath {
+        classpath.push(context.library_path(coords)?.to_string_lossy().to_string());
+    }
+
+    let args = processor
+        .args
+        .iter()
+        .map(|arg| context.resolve_arg(arg))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut outputs = Vec::new();
+    for (path, sha1) in processor.outputs.iter().flatten() {
+        outputs.push((PathBuf::from(context.resolve_arg(path)?), context.resolve_arg(sha1)?));
+    }
+    if outputs_valid(&outputs).await {
+        return Ok(());
+    }
+
+    let output = Command::new(java_path)
+        .arg("-cp")
+        .arg(classpath.join(classpath_separator()))
+        .arg(&main_class)
+        .args(&args)
+        .output()
+        .await
+        .map_err(|e| anyhow!("无法运行处理器 {}: {}", processor.jar, e))?;
+
+    if !output.status.success() {
+        let log = String::from_utf8_lossy(&output.stderr);
+        let log = if log.trim().is_empty() {
+            String::from_utf8_lossy(&output.stdout)
+        } else {
+            log
+        };
+        let lines: Vec<&str> = log.lines().collect();
+        let tail = lines[lines.len().saturating_sub(config::PROCESSOR_LOG_TAIL)..].join("\n");
+        return Err(anyhow!("处理器 {} 运行失败:\n{}", processor.jar, tail));
+    }
+
+    for (path, sha1) in &outputs {
+        checksum::verify_file_sha1(path, sha1).await?;
+    }
+    Ok(())
+}
+
+/// 一次安装过程的环境
+struct InstallerEnv<'a> {
+    /// 已下载的安装器 jar
+    installer: &'a Path,
+    minecraft_dir: &'a Path,
+    /// 原版客户端 jar
+    minecraft_jar: &'a Path,
+    game_version: &'a str,
+    /// 运行处理器使用的 Java
+    java_path: &'a str,
+    /// 临时工作目录
+    work_dir: &'a Path,
+}
+
+/// 新版安装器 (1.13+)：写入版本 JSON、下载依赖库并运行处理器
+async fn install_modern(
+    profile: &InstallProfile,
+    env: &InstallerEnv<'_>,
+    on_progress: &ProgressCallback,
+) -> Result<String> {
+    let InstallerEnv {
+        installer,
+        minecraft_dir,
+        minecraft_jar,
+        game_version,
+        java_path,
+        work_dir,
+    } = *env;
+    let libraries_dir = minecraft_dir.join("libraries");
+    let json_path = profile
+        .json
+        .as_deref()
+        .ok_or_else(|| anyhow!("安装配置缺少 json 字段"))?;
+    let version: VersionInfo = serde_json::from_slice(&read_zip_entry(installer, json_path)?)?;
+    loader_meta::write_loader_version(minecraft_dir, &version).await?;
+
+    // 安装器内的 maven 目录包含无法从仓库下载的构件
+    {
+        let installer = installer.to_path_buf();
+        let libraries_dir = libraries_dir.clone();
+        tokio::task::spawn_blocking(move || extract_zip_prefix(&installer, "maven", &libraries_dir)).await??;
+    }
+
+    let mut all_libraries = profile.libraries.clone();
+    all_libraries.extend(version.libraries.iter().cloned());
+    libraries::install_libraries(&all_libraries, &libraries_dir, Some(on_progress), (0.25, 0.6)).await?;
+
+    let mut variables = HashMap::new();
+    for (key, entry) in &profile.data {
+        variables.insert(
+            key.clone(),
+            resolve_data_value(&entry.client, installer, &libraries_dir, work_dir)?,
+        );
+    }
+    variables.insert("SIDE".to_string(), "client".to_string());
+    variables.insert("MINECRAFT_JAR".to_string(), minecraft_jar.to_string_lossy().to_string());
+    variables.insert("MINECRAFT_VERSION".to_string(), game_version.to_string());
+    variables.insert("ROOT".to_string(), minecraft_dir.to_string_lossy().to_string());
+    variables.insert("INSTALLER".to_string(), installer.to_string_lossy().to_string());
+    variables.insert("LIBRARY_DIR".to_string(), libraries_dir.to_string_lossy().to_string());
+    let context = ProcessorContext {
+        libraries_dir,
+        variables,
+    };
+
+    let processors: Vec<&Processor> = profile
+        .processors
+        .iter()
+        .filter(|processor| {
+            processor
+                .sides
+                .as_ref()
+                .is_none_or(|sides| sides.iter().any(|side| side == "client"))
+        })
+        .collect();
+    for (i, processor) in processors.iter().enumerate() {
+        let progress = 0.6 + 0.4 * i as f64 / processors.len() as f64;
+        on_progress(progress, format!("运行安装处理器... {}/{}", i + 1, processors.len())).await;
+        run_processor(processor, &context, java_path).await?;
+    }
+
+    Ok(version.id)
+}
+
+/// 旧版安装器 (1.13 之前)：写入内嵌版本 JSON 并放置 universal jar
+async fn install_legacy(
+    install: &LegacyInstall,
+    version_info: &VersionInfo,
+    installer: &Path,
+    minecraft_dir: &Path,
+    on_progress: &ProgressCallback,
+) -> Result<String> {
+    let libraries_dir = minecraft_dir.join("libraries");
+
+    let mut version = version_info.clone();
+    if version.inherits_from.is_none() {
+        version.inherits_from = Some(install.minecraft.clone());
+        version.jar = Some(install.minecraft.clone());
+    }
+    for library in &mut version.libraries {
+        if let Some(url) = &library.url {
+            if config::LEGACY_FORGE_MAVEN_URLS.contains(&url.as_str()) {
+                library.url = Some(config::FORGE_MAVEN_URL.to_string());
+            }
+        }
+    }
+    loader_meta::write_loader_version(minecraft_dir, &version).await?;
+
+    let universal = read_zip_entry(installer, &install.file_path)?;
+    let universal_path = libraries_dir.join(minecraft::maven_path(&install.path, None)?);
+    if let Some(parent) = universal_path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(&universal_path, universal).await?;
+
+    libraries::install_libraries(&version.libraries, &libraries_dir, Some(on_progress), (0.3, 1.0)).await?;
+    Ok(version.id)
+}
+
+/// 通过安装器安装 Forge 系加载器 (Forge / NeoForge 共用)，返回新版本 ID
+pub(crate) async fn install_with_installer(
+    name: &str,
+    installer_url: &str,
+    minecraft_dir: &Path,
+    game_version: &str,
+    java_path: &str,
+    on_progress: &ProgressCallback,
+) -> Result<String> {
+    let work_dir = std::env::temp_dir().join(format!("aml-installer-{}", uuid::Uuid::new_v4().simple()));
+    fs::create_dir_all(&work_dir).await?;
+
+    let result = async {
+        on_progress(0.02, format!("准备 Minecraft {}", game_version)).await;
+        let vanilla_version = vanilla::ensure_version_json(minecraft_dir, game_version, META_URL).await?;
+        let minecraft_jar = vanilla::ensure_client_jar(minecraft_dir, &vanilla_version).await?;
+
+        on_progress(0.1, format!("下载 {} 安装器", name)).await;
+        let installer = work_dir.join("installer.jar");
+        let sha1 = libraries::fetch_maven_sha1(installer_url).await;
+        if sha1.is_none() {
+            if libraries::is_strict_verification() {
+                return Err(anyhow!("{} 安装器没有可用的校验值，严格校验模式下拒绝下载", name));
+            }
+            on_progress(0.1, format!("警告: {} 安装器没有校验值，未经校验", name)).await;
+        }
+        download::download_file(installer_url, &installer, sha1.as_deref()).await?;
+
+        on_progress(0.2, "读取安装配置".to_string()).await;
+        let profile: InstallProfile =
+            serde_json::from_slice(&read_zip_entry(&installer, "install_profile.json")?)?;
+        if let Some(minecraft) = &profile.minecraft {
+            if minecraft != game_version {
+                return Err(anyhow!("安装器对应 Minecraft {}，而不是 {}", minecraft, game_version));
+            }
+        }
+
+        let version_id = match (&profile.install, &profile.version_info) {
+            (Some(install), Some(version_info)) => {
+                install_legacy(install, version_info, &installer, minecraft_dir, on_progress).await?
+            }
+            _ => {
+                let env = InstallerEnv {
+                    installer: &installer,
+                    minecraft_dir,
+                    minecraft_jar: &minecraft_jar,
+                    game_version,
+                    java_path,
+                    work_dir: &work_dir,
+                };
+                install_modern(&profile, &env, on_progress).await?
+            }
+        };
+
+        on_progress(1.0, format!("{} 安装完成", name)).await;
+        Ok(version_id)
+    }
+    .await;
+
+    fs::remove_dir_all(&work_dir).await.ok();
+    result
+}
+
+/// Forge 安装器下载地址，`forge_version` 为完整的 Maven 版本 (如 `1.20.1-47.1.0`)
+pub(crate) fn forge_installer_url(forge_version: &str) -> Result<String> {
+    installer_url_in(config::FORGE_MAVEN_URL, forge_version)
+}
+
+/// 指定 Maven 仓库中的 Forge 安装器地址
+fn installer_url_in(maven_url: &str, forge_version: &str) -> Result<String> {
+    let path = minecraft::maven_url_path(
+        &format!("{}:{}", config::FORGE_ARTIFACT, forge_version),
+        Some("installer"),
+    )?;
+    Ok(format!("{}{}", maven_url, path))
+}
+
+/// 从指定 Maven 仓库列出游戏版本对应的 Forge 版本
+async fn list_maven_versions(maven_url: &str, game_version: &str) -> Result<Vec<LoaderVersion>> {
+    let metadata_url = format!(
+        "{}{}/maven-metadata.xml",
+        maven_url,
+        config::FORGE_ARTIFACT.replace([':', '.'], "/")
+    );
+    let prefix = format!("{}-", game_version);
+    Ok(loader_meta::fetch_maven_versions(&metadata_url)
+        .await?
+        .into_iter()
+        .rev()
+        .filter(|version| version.starts_with(&prefix))
+        .map(|version| LoaderVersion {
+            stable: !version.contains("-pre"),
+            id: version,
+        })
+        .collect())
+}
+
+/// 先查 Modrinth 元数据，不可用时回退到 Maven 仓库
+async fn list_versions_in(meta_url: &str, maven_url: &str, game_version: &str) -> Result<Vec<LoaderVersion>> {
+    match loader_meta::list_meta_loaders(meta_url, config::META_LOADER, CURRENT_FORGE_FORMAT_VERSION, game_version)
+        .await
+    {
+        Ok(loaders) if !loaders.is_empty() => Ok(loaders),
+        _ => list_maven_versions(maven_url, game_version).await,
+    }
+}
+
+/// 列出指定游戏版本可用的 Forge 版本 (完整 Maven 版本号，新版本在前)，Modrinth 元数据不可用时回退到 Forge Maven
+pub async fn list_forge_versions(game_version: String) -> Result<Vec<LoaderVersion>> {
+    list_versions_in(META_URL, config::FORGE_MAVEN_URL, &game_version).await
+}
+
+/// 安装 Forge，`java_path` 用于运行安装处理器，返回新版本 ID
+pub async fn install_forge(
+    minecraft_dir: String,
+    game_version: String,
+    forge_version: String,
+    java_path: String,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<String> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    install_with_installer(
+        "Forge",
+        &forge_installer_url(&forge_version)?,
+        Path::new(&minecraft_dir),
+        &game_version,
+        &java_path,
+        &on_progress,
+    )
+    .await
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use flutter_rust_bridge::DartFnFuture;
+    use serde_json::json;
+    use std::io::{Cursor, Write};
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    /// 生成 maven-metadata.xml
+    pub(crate) fn maven_metadata(versions: &[&str]) -> String {
+        let versions: String = versions
+            .iter()
+            .map(|version| format!("<version>{}</version>", version))
+            .collect();
+        format!(
+            "<metadata><versioning><versions>{}</versions></versioning></metadata>",
+            versions
+        )
+    }
+
+    /// 生成只含仅服务端处理器的新版安装器，安装时不需要运行 Java
+    pub(crate) fn installer_jar(game_version: &str, version_id: &str, bundled: &str) -> Vec<u8> {
+        let profile = json!({
+            "json": "/version.json",
+            "minecraft": game_version,
+            "data": {},
+            "processors": [{ "jar": "net.example:server-only:1.0", "sides": ["server"] }],
+            "libraries": []
+        });
+        let version = json!({
+            "id": version_id,
+            "inheritsFrom": game_version,
+            "mainClass": "cpw.mods.bootstraplauncher.BootstrapLauncher",
+            "libraries": []
+        });
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, data) in [
+            ("install_profile.json", profile.to_string().into_bytes()),
+            ("version.json", version.to_string().into_bytes()),
+            (bundled, b"bundled".to_vec()),
+        ] {
+            zip.start_file(name, FileOptions::default()).unwrap();
+            zip.write_all(&data).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    /// 在 `.minecraft` 中放置原版 JSON 与客户端 jar，安装时无需访问版本清单
+    pub(crate) async fn prepare_vanilla(minecraft_dir: &Path, game_version: &str) {
+        let version_dir = minecraft_dir.join("versions").join(game_version);
+        fs::create_dir_all(&version_dir).await.unwrap();
+        let jar = version_dir.join(format!("{}.jar", game_version));
+        fs::write(&jar, b"client").await.unwrap();
+        let version = json!({
+            "id": game_version,
+            "mainClass": "net.minecraft.client.main.Main",
+            "downloads": {
+                "client": { "sha1": checksum::file_sha1(&jar).await.unwrap(), "size": 6, "url": "http://127.0.0.1:9/client.jar" }
+            }
+        });
+        fs::write(version_dir.join(format!("{}.json", game_version)), version.to_string())
+            .await
+            .unwrap();
+    }
+
+    pub(crate) fn no_progress() -> ProgressCallback {
+        Box::new(|_, _| Box::pin(async {}) as DartFnFuture<()>)
+    }
+
+    #[tokio::test]
+    async fn lists_versions_from_maven() {
+        let server = MockServer::start().await;
+        Mock::given(path("/net/minecraftforge/forge/maven-metadata.xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(maven_metadata(&[
+                "1.20-46.0.1",
+                "1.20.1-47.0.1",
+                "1.20.1-47.1.0",
+                "1.20.2-48.0.0-pre",
+            ])))
+            .mount(&server)
+            .await;
+        let meta = format!("{}/meta/", server.uri());
+        let maven = format!("{}/", server.uri());
+        let versions = list_versions_in(&meta, &maven, "1.20.1").await.unwrap();
+        let ids: Vec<&str> = versions.iter().map(|version| version.id.as_str()).collect();
+        assert_eq!(ids, ["1.20.1-47.1.0", "1.20.1-47.0.1"]);
+        let versions = list_versions_in(&meta, &maven, "1.20.2").await.unwrap();
+        assert!(!versions[0].stable);
+    }
+
+    #[tokio::test]
+    async fn lists_versions_from_meta() {
+        let server = MockServer::start().await;
+        Mock::given(path("/meta/forge/v0/manifest.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "gameVersions": [
+                    {
+                        "id": "1.20.1",
+                        "loaders": [
+                            { "id": "1.20.1-47.1.3", "url": "unused", "stable": true },
+                            { "id": "1.20.1-47.1.0", "url": "unused", "stable": false }
+                        ]
+                    },
+                    { "id": "1.20.2", "loaders": [] }
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(path("/net/minecraftforge/forge/maven-metadata.xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(maven_metadata(&["1.20.2-48.0.0"])))
+            .mount(&server)
+            .await;
+        let meta = format!("{}/meta/", server.uri());
+        let maven = format!("{}/", server.uri());
+
+        let versions = list_versions_in(&meta, &maven, "1.20.1").await.unwrap();
+        let versions: Vec<_> = versions.iter().map(|version| (version.id.as_str(), version.stable)).collect();
+        assert_eq!(versions, [("1.20.1-47.1.3", true), ("1.20.1-47.1.0", false)]);
+        // 元数据中没有该游戏版本的条目时查询 Maven
+        let versions = list_versions_in(&meta, &maven, "1.20.2").await.unwrap();
+        assert_eq!(versions[0].id, "1.20.2-48.0.0");
+    }
+
+    #[tokio::test]
+    async fn installs_modern_installer() {
+        let server = MockServer::start().await;
+        let maven = format!("{}/", server.uri());
+        let bundled = "maven/net/minecraftforge/forge/1.20.1-47.1.0/forge-1.20.1-47.1.0-universal.jar";
+        let url = installer_url_in(&maven, "1.20.1-47.1.0").unwrap();
+        assert!(url.ends_with("net/minecraftforge/forge/1.20.1-47.1.0/forge-1.20.1-47.1.0-installer.jar"));
+        Mock::given(path("/net/minecraftforge/forge/1.20.1-47.1.0/forge-1.20.1-47.1.0-installer.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(installer_jar(
+                "1.20.1",
+                "1.20.1-forge-47.1.0",
+                bundled,
+            )))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        prepare_vanilla(dir.path(), "1.20.1").await;
+        let id = install_with_installer("Forge", &url, dir.path(), "1.20.1", "java", &no_progress())
+            .await
+            .unwrap();
+        assert_eq!(id, "1.20.1-forge-47.1.0");
+
+        let version_path = minecraft::version_json_path(&dir.path().join("versions"), &id);
+        let version = minecraft::read_version_info(&version_path).await.unwrap();
+        assert_eq!(version.inherits_from.as_deref(), Some("1.20.1"));
+        // 安装器 maven 目录中的构件被解压到 libraries
+        let extracted = dir.path().join("libraries").join(bundled.trim_start_matches("maven/"));
+        assert_eq!(fs::read(extracted).await.unwrap(), b"bundled");
+    }
+
+    #[tokio::test]
+    async fn rejects_installer_for_other_game_version() {
+        let server = MockServer::start().await;
+        let maven = format!("{}/", server.uri());
+        let url = installer_url_in(&maven, "1.20.1-47.1.0").unwrap();
+        Mock::given(path("/net/minecraftforge/forge/1.20.1-47.1.0/forge-1.20.1-47.1.0-installer.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(installer_jar(
+                "1.20.1",
+                "1.20.1-forge-47.1.0",
+                "maven/placeholder.jar",
+            )))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        prepare_vanilla(dir.path(), "1.20.2").await;
+        let result = install_with_installer("Forge", &url, dir.path(), "1.20.2", "java", &no_progress()).await;
+        assert!(result.is_err());
+    }
+
+    fn zip_bytes(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, data) in entries {
+            zip.start_file(*name, FileOptions::default()).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    /// 生成带客户端处理器的安装器，`patched_sha1` 为处理器输出的期望校验值
+    fn processor_installer_jar(patched_sha1: &str) -> Vec<u8> {
+        let profile = json!({
+            "json": "/version.json",
+            "minecraft": "1.20.1",
+            "data": {
+                "MAPPINGS": { "client": "[net.example:mappings:1.0:mappings@txt]", "server": "unused" },
+                "BINPATCH": { "client": "/data/client.lzma", "server": "/data/server.lzma" },
+                "PATCHED": { "client": "[net.example:patched:1.0]", "server": "unused" },
+                "PATCHED_SHA": { "client": format!("'{}'", patched_sha1), "server": "unused" }
+            },
+            "processors": [
+                { "jar": "net.example:server-only:1.0", "sides": ["server"] },
+                {
+                    "jar": "net.example:processor:1.0",
+                    "classpath": ["net.example:processor-lib:1.0"],
+                    "args": [
+                        "--input", "{MINECRAFT_JAR}",
+                        "--mappings", "{MAPPINGS}",
+                        "--patch", "{BINPATCH}",
+                        "--lib", "[net.example:processor-lib:1.0]",
+                        "--side", "{SIDE}",
+                        "--output", "{PATCHED}"
+                    ],
+                    "outputs": { "{PATCHED}": "{PATCHED_SHA}" }
+                }
+            ],
+            "libraries": []
+        });
+        let version = json!({
+            "id": "1.20.1-forge-47.1.0",
+            "inheritsFrom": "1.20.1",
+            "mainClass": "cpw.mods.bootstraplauncher.BootstrapLauncher",
+            "libraries": []
+        });
+        let processor = zip_bytes(&[("META-INF/MANIFEST.MF", b"Manifest-Version: 1.0\r\nMain-Class: net.example.Processor\r\n")]);
+        zip_bytes(&[
+            ("install_profile.json", profile.to_string().as_bytes()),
+            ("version.json", version.to_string().as_bytes()),
+            ("data/client.lzma", b"binpatch"),
+            ("maven/net/example/processor/1.0/processor-1.0.jar", &processor),
+            ("maven/net/example/processor-lib/1.0/processor-lib-1.0.jar", b"lib"),
+            ("maven/net/example/mappings/1.0/mappings-1.0-mappings.txt", b"mappings"),
+        ])
+    }
+
+    /// 代替 Java 的脚本：记录参数与补丁内容，并写出 `--output` 指定的文件
+    #[cfg(unix)]
+    fn fake_java(dir: &Path) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let script = dir.join("java");
+        let log = dir.join("args.txt");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\nprintf '%s\\n' \"$@\" > '{log}'\nwhile [ $# -gt 0 ]; do\n  case \"$1\" in\n    --patch) cat \"$2\" >> '{log}' ;;\n    --output) mkdir -p \"$(dirname \"$2\")\" && printf patched > \"$2\" ;;\n  esac\n  shift\ndone\n",
+                log = log.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn runs_client_processors() {
+        use sha1::{Digest, Sha1};
+        let patched_sha1 = checksum::hex_string(&Sha1::digest(b"patched"));
+        let server = MockServer::start().await;
+        let maven = format!("{}/", server.uri());
+        let url = installer_url_in(&maven, "1.20.1-47.1.0").unwrap();
+        Mock::given(path("/net/minecraftforge/forge/1.20.1-47.1.0/forge-1.20.1-47.1.0-installer.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(processor_installer_jar(&patched_sha1)))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let minecraft_dir = dir.path().join(".minecraft");
+        prepare_vanilla(&minecraft_dir, "1.20.1").await;
+        let java = fake_java(dir.path());
+        let id = install_with_installer(
+            "Forge",
+            &url,
+            &minecraft_dir,
+            "1.20.1",
+            &java.to_string_lossy(),
+            &no_progress(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(id, "1.20.1-forge-47.1.0");
+
+        let libraries = minecraft_dir.join("libraries");
+        let library = |coords: &str| libraries.join(minecraft::maven_path(coords, None).unwrap());
+        let args = std::fs::read_to_string(dir.path().join("args.txt")).unwrap();
+        let args: Vec<&str> = args.lines().collect();
+        let classpath = [library("net.example:processor:1.0"), library("net.example:processor-lib:1.0")]
+            .map(|path| path.to_string_lossy().to_string())
+            .join(classpath_separator());
+        let minecraft_jar = minecraft_dir.join("versions/1.20.1/1.20.1.jar");
+        assert_eq!(args[..3], ["-cp", classpath.as_str(), "net.example.Processor"]);
+        assert_eq!(args[3..5], ["--input", minecraft_jar.to_string_lossy().as_ref()]);
+        assert_eq!(
+            args[5..7],
+            ["--mappings", library("net.example:mappings:1.0:mappings@txt").to_string_lossy().as_ref()]
+        );
+        // 安装器内的文件被解压到临时目录，处理器运行时可以读取
+        assert_eq!(args[7], "--patch");
+        assert!(args[8].ends_with("data/client.lzma"));
+        assert_eq!(args[9..11], ["--lib", library("net.example:processor-lib:1.0").to_string_lossy().as_ref()]);
+        assert_eq!(args[11..13], ["--side", "client"]);
+        assert_eq!(args[13..15], ["--output", library("net.example:patched:1.0").to_string_lossy().as_ref()]);
+        assert_eq!(args[15], "binpatch");
+        assert_eq!(fs::read(library("net.example:patched:1.0")).await.unwrap(), b"patched");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn rejects_processor_output_with_wrong_sha1() {
+        let server = MockServer::start().await;
+        let maven = format!("{}/", server.uri());
+        let url = installer_url_in(&maven, "1.20.1-47.1.0").unwrap();
+        Mock::given(path("/net/minecraftforge/forge/1.20.1-47.1.0/forge-1.20.1-47.1.0-installer.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(processor_installer_jar(&"0".repeat(40))))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let minecraft_dir = dir.path().join(".minecraft");
+        prepare_vanilla(&minecraft_dir, "1.20.1").await;
+        let java = fake_java(dir.path());
+        let result = install_with_installer(
+            "Forge",
+            &url,
+            &minecraft_dir,
+            "1.20.1",
+            &java.to_string_lossy(),
+            &no_progress(),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn installs_legacy_universal_jar() {
+        let server = MockServer::start().await;
+        let maven = format!("{}/", server.uri());
+        let url = installer_url_in(&maven, "1.12.2-14.23.5.2860").unwrap();
+        let profile = json!({
+            "install": {
+                "path": "net.minecraftforge:forge:1.12.2-14.23.5.2860",
+                "filePath": "forge-1.12.2-14.23.5.2860-universal.jar",
+                "minecraft": "1.12.2"
+            },
+            "versionInfo": {
+                "id": "1.12.2-forge1.12.2-14.23.5.2860",
+                "mainClass": "net.minecraft.launchwrapper.Launch",
+                "minecraftArguments": "--tweakClass net.minecraftforge.fml.common.launcher.FMLTweaker",
+                "libraries": [
+                    { "name": "net.minecraftforge:forge:1.12.2-14.23.5.2860" },
+                    { "name": "net.minecraft:launchwrapper:1.12", "url": maven },
+                    { "name": "org.scala-lang:scala-library:2.11.1", "url": "http://files.minecraftforge.net/maven/" }
+                ]
+            }
+        });
+        Mock::given(path("/net/minecraftforge/forge/1.12.2-14.23.5.2860/forge-1.12.2-14.23.5.2860-installer.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(zip_bytes(&[
+                ("install_profile.json", profile.to_string().as_bytes()),
+                ("forge-1.12.2-14.23.5.2860-universal.jar", b"universal"),
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(path("/net/minecraft/launchwrapper/1.12/launchwrapper-1.12.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"launchwrapper".as_slice()))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        prepare_vanilla(dir.path(), "1.12.2").await;
+        let libraries = dir.path().join("libraries");
+        // 已存在且没有校验值的库不会重新下载
+        let scala = libraries.join(minecraft::maven_path("org.scala-lang:scala-library:2.11.1", None).unwrap());
+        fs::create_dir_all(scala.parent().unwrap()).await.unwrap();
+        fs::write(&scala, b"scala").await.unwrap();
+
+        let id = install_with_installer("Forge", &url, dir.path(), "1.12.2", "java", &no_progress())
+            .await
+            .unwrap();
+        assert_eq!(id, "1.12.2-forge1.12.2-14.23.5.2860");
+
+        let universal = libraries.join(minecraft::maven_path("net.minecraftforge:forge:1.12.2-14.23.5.2860", None).unwrap());
+        assert_eq!(fs::read(universal).await.unwrap(), b"universal");
+        let launchwrapper = libraries.join(minecraft::maven_path("net.minecraft:launchwrapper:1.12", None).unwrap());
+        assert_eq!(fs::read(launchwrapper).await.unwrap(), b"launchwrapper");
+
+        let version_path = minecraft::version_json_path(&dir.path().join("versions"), &id);
+        let version = minecraft::read_version_info(&version_path).await.unwrap();
+        assert_eq!(version.inherits_from.as_deref(), Some("1.12.2"));
+        assert_eq!(version.jar.as_deref(), Some("1.12.2"));
+        // 已失效的旧仓库地址被替换
+        assert_eq!(version.libraries[2].url.as_deref(), Some(config::FORGE_MAVEN_URL));
+    }
+}
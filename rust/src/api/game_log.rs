@@ -0,0 +1,333 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::api::game_process::LogSource;
+use crate::download;
+use crate::minecraft::VersionInfo;
+
+/// 正则表达式缓存
+mod regex_cache {
+    use regex::Regex;
+    use std::sync::LazyLock;
+
+    /// 纯文本日志格式: `[12:34:56] [Render thread/INFO] [logger]: message`
+    pub static PLAIN_LOG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^\[[^\]]*\] \[([^\]]+)/([A-Za-z]+)\](?: \[([^\]]+)\])?: ?(.*)$").unwrap()
+    });
+}
+
+/// 日志事件开始标签
+const EVENT_START: &str = "<log4j:Event";
+/// 日志事件结束标签
+const EVENT_END: &str = "</log4j:Event>";
+
+/// 日志级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+    Unknown,
+}
+
+impl LogLevel {
+    fn parse(level: &str) -> Self {
+        match level.to_ascii_uppercase().as_str() {
+            "TRACE" => LogLevel::Trace,
+            "DEBUG" => LogLevel::Debug,
+            "INFO" => LogLevel::Info,
+            "WARN" | "WARNING" => LogLevel::Warn,
+            "ERROR" => LogLevel::Error,
+            "FATAL" => LogLevel::Fatal,
+            _ => LogLevel::Unknown,
+        }
+    }
+}
+
+/// 结构化的游戏日志事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameLogEvent {
+    pub process_id: String,
+    pub source: LogSource,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub level: LogLevel,
+    pub thread: Option<String>,
+    pub logger: Option<String>,
+    pub message: String,
+    pub throwable: Option<String>,
+    /// 是否来自 log4j XML 事件 (否则为纯文本回退)
+    pub structured: bool,
+}
+
+/// 按行解析游戏输出，XML 事件跨多行时会缓冲到事件结束
+pub(crate) struct Log4jStreamParser {
+    process_id: String,
+    source: LogSource,
+    buffer: Option<String>,
+}
+
+impl Log4jStreamParser {
+    pub(crate) fn new(process_id: String, source: LogSource) -> Self {
+        Self {
+            process_id,
+            source,
+            buffer: None,
+        }
+    }
+
+    /// 输入一行输出，得到完整事件时返回
+    pub(crate) fn push_line(&mut self, line: String) -> Option<GameLogEvent> {
+        match self.buffer.as_mut() {
+            Some(buffer) => {
+                buffer.push('\n');
+                buffer.push_str(&line);
+            }
+            None if line.trim_start().starts_with(EVENT_START) => self.buffer = Some(line),
+            None => return Some(self.plain_event(&line)),
+        }
+
+        if self.buffer.as_deref().is_some_and(|buffer| buffer.contains(EVENT_END)) {
+            let xml = self.buffer.take().unwrap_or_default();
+            return Some(
+                self.parse_xml_event(&xml)
+                    .unwrap_or_else(|_| self.plain_event(&xml)),
+            );
+        }
+        None
+    }
+
+    /// 输出结束时刷新未闭合的事件
+    pub(crate) fn finish(&mut self) -> Option<GameLogEvent> {
+        self.buffer.take().map(|buffer| self.plain_event(&buffer))
+    }
+
+    /// 纯文本回退：尽量识别 `[time] [thread/LEVEL]: message` 格式
+    fn plain_event(&self, line: &str) -> GameLogEvent {
+        let mut event = GameLogEvent {
+            process_id: self.process_id.clone(),
+            source: self.source,
+            timestamp: None,
+            level: LogLevel::Unknown,
+            thread: None,
+            logger: None,
+            message: line.to_string(),
+            throwable: None,
+            structured: false,
+        };
+        if let Some(captures) = regex_cache::PLAIN_LOG_REGEX.captures(line) {
+            event.thread = Some(captures[1].to_string());
+            event.level = LogLevel::parse(&captures[2]);
+            event.logger = captures.get(3).map(|logger| logger.as_str().to_string());
+            event.message = captures[4].to_string();
+        }
+        event
+    }
+
+    /// 解析一个完整的 `<log4j:Event>` 元素
+    fn parse_xml_event(&self, xml: &str) -> Result<GameLogEvent> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut event = GameLogEvent {
+            process_id: self.process_id.clone(),
+            source: self.source,
+            timestamp: None,
+            level: LogLevel::Unknown,
+            thread: None,
+            logger: None,
+            message: String::new(),
+            throwable: None,
+            structured: true,
+        };
+        let mut current: Option<Vec<u8>> = None;
+        let mut saw_event = false;
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) | Event::Empty(element)
+                    if element.name().as_ref() == b"log4j:Event" =>
+                {
+                    saw_event = true;
+                    for attribute in element.attributes() {
+                        let attribute = attribute?;
+                        let value = attribute.unescape_value()?.into_owned();
+                        match attribute.key.as_ref() {
+                            b"logger" => event.logger = Some(value),
+                            b"thread" => event.thread = Some(value),
+                            b"level" => event.level = LogLevel::parse(&value),
+                            b"timestamp" => {
+                                event.timestamp = value
+                                    .parse::<i64>()
+                                    .ok()
+                                    .and_then(|millis| Utc.timestamp_millis_opt(millis).single());
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Event::Start(element) => current = Some(element.name().as_ref().to_vec()),
+                Event::End(_) => current = None,
+                Event::Text(text) => {
+                    let text = text.unescape()?.into_owned();
+                    self.append_content(&mut event, current.as_deref(), &text);
+                }
+                Event::CData(data) => {
+                    let text = String::from_utf8_lossy(&data.into_inner()).into_owned();
+                    self.append_content(&mut event, current.as_deref(), &text);
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if !saw_event {
+            return Err(anyhow!("不是 log4j 事件"));
+        }
+        Ok(event)
+    }
+
+    fn append_content(&self, event: &mut GameLogEvent, element: Option<&[u8]>, text: &str) {
+        match element {
+            Some(b"log4j:Message") => event.message.push_str(text),
+            Some(b"log4j:Throwable") => event
+                .throwable
+                .get_or_insert_with(String::new)
+                .push_str(text),
+            _ => {}
+        }
+    }
+}
+
+/// 下载版本声明的客户端日志配置，返回需要追加的 JVM 参数
+pub(crate) async fn prepare_logging_config(
+    version: &VersionInfo,
+    minecraft_dir: &Path,
+) -> Result<Option<String>> {
+    let Some(config) = version
+        .logging
+        .as_ref()
+        .and_then(|logging| logging.get("client"))
+    else {
+        return Ok(None);
+    };
+
+    let path = minecraft_dir
+        .join("assets")
+        .join("log_configs")
+        .join(&config.file.id);
+    download::download_file(&config.file.url, &path, Some(&config.file.sha1)).await?;
+
+    Ok(Some(
+        config
+            .argument
+            .replace("${path}", &path.to_string_lossy()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser() -> Log4jStreamParser {
+        Log4jStreamParser::new("process".to_string(), LogSource::Stdout)
+    }
+
+    /// 依次输入多行，收集产生的事件
+    fn push_all(parser: &mut Log4jStreamParser, text: &str) -> Vec<GameLogEvent> {
+        text.lines().filter_map(|line| parser.push_line(line.to_string())).collect()
+    }
+
+    #[test]
+    fn buffers_multi_line_events() {
+        let mut parser = parser();
+        let xml = r#"<log4j:Event logger="net.minecraft.client.Minecraft" timestamp="1700000000123" level="INFO" thread="Render thread">
+  <log4j:Message><![CDATA[Setting user: Player]]></log4j:Message>
+</log4j:Event>"#;
+        let mut lines = xml.lines();
+        assert!(parser.push_line(lines.next().unwrap().to_string()).is_none());
+        assert!(parser.push_line(lines.next().unwrap().to_string()).is_none());
+        let event = parser.push_line(lines.next().unwrap().to_string()).unwrap();
+
+        assert!(event.structured);
+        assert_eq!(event.process_id, "process");
+        assert_eq!(event.level, LogLevel::Info);
+        assert_eq!(event.thread.as_deref(), Some("Render thread"));
+        assert_eq!(event.logger.as_deref(), Some("net.minecraft.client.Minecraft"));
+        assert_eq!(event.message, "Setting user: Player");
+        assert_eq!(event.timestamp.unwrap().timestamp_millis(), 1_700_000_000_123);
+        assert!(event.throwable.is_none());
+        assert!(parser.finish().is_none());
+    }
+
+    #[test]
+    fn extracts_throwable_and_escaped_text() {
+        let mut parser = parser();
+        let text = r#"<log4j:Event logger="net.minecraft.server.MinecraftServer" timestamp="1700000001000" level="ERROR" thread="Server thread">
+  <log4j:Message><![CDATA[Encountered an unexpected exception]]></log4j:Message>
+  <log4j:Throwable><![CDATA[java.lang.NullPointerException: boom
+	at net.minecraft.server.MinecraftServer.tick(MinecraftServer.java:100)
+]]></log4j:Throwable>
+</log4j:Event>
+<log4j:Event logger="Fabric" timestamp="1700000002000" level="WARN" thread="main"><log4j:Message>a &lt; b &amp;&amp; c</log4j:Message></log4j:Event>"#;
+        let events = push_all(&mut parser, text);
+        assert_eq!(events.len(), 2);
+
+        assert_eq!(events[0].level, LogLevel::Error);
+        assert_eq!(events[0].message, "Encountered an unexpected exception");
+        let throwable = events[0].throwable.as_deref().unwrap();
+        assert!(throwable.starts_with("java.lang.NullPointerException: boom\n"));
+        assert!(throwable.contains("MinecraftServer.java:100"));
+
+        assert!(events[1].structured);
+        assert_eq!(events[1].level, LogLevel::Warn);
+        assert_eq!(events[1].message, "a < b && c");
+    }
+
+    #[test]
+    fn finish_flushes_partial_event() {
+        let mut parser = parser();
+        let text = r#"<log4j:Event logger="net.minecraft.client.Minecraft" timestamp="1700000000123" level="INFO" thread="Render thread">
+  <log4j:Message><![CDATA[Stopping!"#;
+        assert!(push_all(&mut parser, text).is_empty());
+        let event = parser.finish().unwrap();
+        assert!(!event.structured);
+        assert_eq!(event.message, text);
+        assert!(parser.finish().is_none());
+    }
+
+    #[test]
+    fn falls_back_to_plain_text() {
+        let mut parser = Log4jStreamParser::new("process".to_string(), LogSource::Stderr);
+        let text = "[12:34:56] [Render thread/WARN] [net.minecraft.client.sounds.SoundEngine]: Missing sound\n\
+                    [12:34:57] [main/info]: Loading Minecraft 1.20.1\n\
+                    Exception in thread \"main\" java.lang.IllegalStateException\n\
+                    <log4j:Event broken";
+        let events = push_all(&mut parser, text);
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|event| !event.structured && event.source == LogSource::Stderr));
+
+        assert_eq!(events[0].level, LogLevel::Warn);
+        assert_eq!(events[0].thread.as_deref(), Some("Render thread"));
+        assert_eq!(events[0].logger.as_deref(), Some("net.minecraft.client.sounds.SoundEngine"));
+        assert_eq!(events[0].message, "Missing sound");
+
+        assert_eq!(events[1].level, LogLevel::Info);
+        assert_eq!(events[1].thread.as_deref(), Some("main"));
+        assert!(events[1].logger.is_none());
+        assert_eq!(events[1].message, "Loading Minecraft 1.20.1");
+
+        assert_eq!(events[2].level, LogLevel::Unknown);
+        assert_eq!(events[2].message, "Exception in thread \"main\" java.lang.IllegalStateException");
+        assert!(events[2].timestamp.is_none());
+
+        // 未闭合的事件开头在结束时作为纯文本输出
+        assert_eq!(parser.finish().unwrap().message, "<log4j:Event broken");
+    }
+}
@@ -0,0 +1,309 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::DartFnFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+use sysinfo::{Pid, ProcessesToUpdate, Signal, System};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::{mpsc, Mutex};
+
+use crate::api::game_log::{GameLogEvent, Log4jStreamParser};
+use crate::api::launch::LaunchCommand;
+use crate::api::natives;
+use crate::frb_generated::StreamSink;
+
+/// 配置常量
+mod config {
+    use std::time::Duration;
+    /// 默认的优雅停止等待时间
+    pub const DEFAULT_STOP_TIMEOUT: Duration = Duration::from_secs(10);
+}
+
+/// 输出来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogSource {
+    Stdout,
+    Stderr,
+}
+
+/// 游戏进程信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameProcessInfo {
+    /// 启动器内部的进程 ID
+    pub process_id: String,
+    /// 系统进程 PID
+    pub pid: u32,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    /// 退出码，被信号终止时为空
+    pub exit_code: Option<i32>,
+    pub running: bool,
+}
+
+/// 停止请求
+#[derive(Debug, Clone, Copy)]
+enum StopRequest {
+    /// 立即终止
+    Kill,
+    /// 先发送 SIGTERM，超时后终止
+    Graceful(Duration),
+}
+
+/// 受管理的游戏进程
+struct ManagedProcess {
+    info: Arc<Mutex<GameProcessInfo>>,
+    stop_tx: mpsc::Sender<StopRequest>,
+}
+
+/// 正在运行的游戏进程表
+static PROCESSES: LazyLock<Mutex<HashMap<String, ManagedProcess>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 逐行读取输出，解析为日志事件后推送。非 UTF-8 内容按有损方式转换，保证管道始终被读空
+async fn pump_lines<R, F>(reader: R, process_id: String, source: LogSource, on_event: Arc<F>)
+where
+    R: AsyncRead + Unpin,
+    F: Fn(GameLogEvent) + Send + Sync + ?Sized,
+{
+    let mut parser = Log4jStreamParser::new(process_id, source);
+    let mut reader = BufReader::new(reader);
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        match reader.read_until(b'\n', &mut buffer).await {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        let line = String::from_utf8_lossy(&buffer);
+        let line = line.trim_end_matches(['\n', '\r']);
+        if let Some(event) = parser.push_line(line.to_string()) {
+            on_event(event);
+        }
+    }
+    if let Some(event) = parser.finish() {
+        on_event(event);
+    }
+}
+
+/// 向进程发送 SIGTERM (Windows 不支持时返回 false)
+fn send_terminate(pid: u32) -> bool {
+    let mut system = System::new();
+    let pid = Pid::from_u32(pid);
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    system
+        .process(pid)
+        .and_then(|process| process.kill_with(Signal::Term))
+        .unwrap_or(false)
+}
+
+/// 启动游戏进程并在后台监控，进程退出后回调 `on_exit`
+pub(crate) async fn spawn_game_process<L, E>(
+    command: &LaunchCommand,
+    on_log: Arc<L>,
+    on_exit: E,
+) -> Result<GameProcessInfo>
+where
+    L: Fn(GameLogEvent) + Send + Sync + ?Sized + 'static,
+    E: FnOnce(GameProcessInfo) -> DartFnFuture<()> + Send + 'static,
+{
+    let mut child = Command::new(&command.java_path)
+        .args(&command.args)
+        .current_dir(&command.working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(false)
+        .spawn()
+        .map_err(|e| anyhow!("无法启动 Java 进程 {}: {}", command.java_path, e))?;
+
+    let process_id = uuid::Uuid::new_v4().simple().to_string();
+    let info = GameProcessInfo {
+        process_id: process_id.clone(),
+        pid: child.id().unwrap_or_default(),
+        start_time: Utc::now(),
+        end_time: None,
+        exit_code: None,
+        running: true,
+    };
+
+    let stdout_task = child.stdout.take().map(|stdout| {
+        tokio::spawn(pump_lines(stdout, process_id.clone(), LogSource::Stdout, on_log.clone()))
+    });
+    let stderr_task = child.stderr.take().map(|stderr| {
+        tokio::spawn(pump_lines(stderr, process_id.clone(), LogSource::Stderr, on_log.clone()))
+    });
+
+    let (stop_tx, mut stop_rx) = mpsc::channel::<StopRequest>(4);
+    let shared_info = Arc::new(Mutex::new(info.clone()));
+    PROCESSES.lock().await.insert(
+        process_id.clone(),
+        ManagedProcess {
+            info: shared_info.clone(),
+            stop_tx,
+        },
+    );
+
+    let natives_dir = command.natives_dir.clone();
+    let pid = info.pid;
+    tokio::spawn(async move {
+        let status = loop {
+            tokio::select! {
+                status = child.wait() => break status,
+                Some(request) = stop_rx.recv() => match request {
+                    StopRequest::Kill => {
+                        child.start_kill().ok();
+                    }
+                    StopRequest::Graceful(timeout) => {
+                        if !send_terminate(pid) {
+                            child.start_kill().ok();
+                            continue;
+                        }
+                        match tokio::time::timeout(timeout, child.wait()).await {
+                            Ok(status) => break status,
+                            Err(_) => {
+                                child.start_kill().ok();
+                            }
+                        }
+                    }
+                },
+            }
+        };
+
+        // 等待输出全部转发完毕再报告退出
+        for task in [stdout_task, stderr_task].into_iter().flatten() {
+            task.await.ok();
+        }
+
+        let final_info = {
+            let mut info = shared_info.lock().await;
+            info.running = false;
+            info.end_time = Some(Utc::now());
+            info.exit_code = status.ok().and_then(|status| status.code());
+            info.clone()
+        };
+        PROCESSES.lock().await.remove(&final_info.process_id);
+
+        if let Some(natives_dir) = natives_dir {
+            natives::cleanup_natives(Path::new(&natives_dir)).await.ok();
+        }
+
+        on_exit(final_info).await;
+    });
+
+    Ok(info)
+}
+
+/// 启动游戏，解析后的日志事件推送到 `log_sink`，退出时回调 `on_exit`
+pub async fn start_game(
+    command: LaunchCommand,
+    log_sink: StreamSink<GameLogEvent>,
+    on_exit: impl Fn(GameProcessInfo) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<GameProcessInfo> {
+    let on_log = move |event: GameLogEvent| {
+        log_sink.add(event).ok();
+    };
+    spawn_game_process(&command, Arc::new(on_log), move |info| on_exit(info)).await
+}
+
+/// 列出正在运行的游戏进程
+pub async fn list_game_processes() -> Vec<GameProcessInfo> {
+    let processes = PROCESSES.lock().await;
+    let mut result = Vec::with_capacity(processes.len());
+    for process in processes.values() {
+        result.push(process.info.lock().await.clone());
+    }
+    result.sort_by_key(|info| info.start_time);
+    result
+}
+
+/// 获取指定游戏进程的信息
+pub async fn get_game_process(process_id: String) -> Option<GameProcessInfo> {
+    let processes = PROCESSES.lock().await;
+    let process = processes.get(&process_id)?;
+    let info = process.info.lock().await.clone();
+    Some(info)
+}
+
+async fn send_stop_request(process_id: &str, request: StopRequest) -> Result<()> {
+    let stop_tx = PROCESSES
+        .lock()
+        .await
+        .get(process_id)
+        .map(|process| process.stop_tx.clone())
+        .ok_or_else(|| anyhow!("游戏进程不存在或已退出: {}", process_id))?;
+    stop_tx
+        .send(request)
+        .await
+        .map_err(|_| anyhow!("游戏进程已退出: {}", process_id))
+}
+
+/// 立即终止游戏进程
+pub async fn kill_game(process_id: String) -> Result<()> {
+    send_stop_request(&process_id, StopRequest::Kill).await
+}
+
+/// 优雅停止游戏进程，超时后强制终止
+pub async fn stop_game(process_id: String, timeout_secs: Option<u64>) -> Result<()> {
+    let timeout = timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or(config::DEFAULT_STOP_TIMEOUT);
+    send_stop_request(&process_id, StopRequest::Graceful(timeout)).await
+}
+
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pumps_output_of_dummy_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = LaunchCommand {
+            java_path: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                // 中间一行不是合法 UTF-8，之后的输出仍应被读取
+                r"printf 'hello\n\377bad\r\nafter\n'; printf 'oops' >&2; exit 3".to_string(),
+            ],
+            working_dir: dir.path().to_string_lossy().to_string(),
+            natives_dir: None,
+        };
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let collected = events.clone();
+        let on_log = move |event: GameLogEvent| collected.lock().unwrap().push(event);
+        let (exit_tx, exit_rx) = tokio::sync::oneshot::channel();
+        let info = spawn_game_process(&command, Arc::new(on_log), move |info| {
+            Box::pin(async move {
+                exit_tx.send(info).ok();
+            }) as DartFnFuture<()>
+        })
+        .await
+        .unwrap();
+        assert!(info.running);
+
+        let exited = tokio::time::timeout(Duration::from_secs(10), exit_rx)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!exited.running);
+        assert_eq!(exited.exit_code, Some(3));
+        assert!(get_game_process(info.process_id.clone()).await.is_none());
+
+        let events = events.lock().unwrap();
+        let lines = |source: LogSource| {
+            events
+                .iter()
+                .filter(|event| event.source == source && event.process_id == info.process_id)
+                .map(|event| event.message.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lines(LogSource::Stdout), ["hello", "\u{FFFD}bad", "after"]);
+        assert_eq!(lines(LogSource::Stderr), ["oops"]);
+    }
+}
@@ -0,0 +1,404 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tokio::fs;
+use tokio::sync::Mutex;
+
+use crate::api::database;
+use crate::api::launch::Resolution;
+use crate::api::mod_loader::ModLoader;
+use crate::config::CURRENT_FORMAT_VERSION;
+
+/// 配置常量
+mod config {
+    /// 资源目录下存放实例的子目录
+    pub const INSTANCES_DIR: &str = "instances";
+    /// 实例清单文件名
+    pub const MANIFEST_FILE: &str = "instance.json";
+    /// 创建 / 复制实例时使用的临时目录前缀
+    pub const STAGING_PREFIX: &str = ".staging-";
+    /// 删除实例时使用的临时目录前缀
+    pub const TRASH_PREFIX: &str = ".trash-";
+    /// 名称中没有可用字符时使用的目录名
+    pub const FALLBACK_ID: &str = "instance";
+}
+
+/// 实例清单 (`<资源目录>/instances/<id>/instance.json`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceManifest {
+    pub format_version: usize,
+    /// 实例目录名，创建后不再改变
+    pub id: String,
+    /// 显示名称
+    pub name: String,
+    pub game_version: String,
+    pub loader: ModLoader,
+    pub loader_version: Option<String>,
+    /// 覆盖全局设置的 Java 路径
+    pub java_path: Option<String>,
+    pub max_memory_mb: Option<u32>,
+    pub min_memory_mb: Option<u32>,
+    #[serde(default)]
+    pub jvm_args: Vec<String>,
+    pub resolution: Option<Resolution>,
+    /// 图标路径或 data URL
+    pub icon: Option<String>,
+    /// 累计游戏时长 (秒)
+    #[serde(default)]
+    pub play_time_secs: u64,
+    pub created: DateTime<Utc>,
+    pub last_played: Option<DateTime<Utc>>,
+    /// 实例目录 (同时也是游戏目录)，不写入清单
+    #[serde(skip)]
+    pub path: String,
+}
+
+pub(crate) use config::MANIFEST_FILE;
+
+/// 新建实例的参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewInstance {
+    pub name: String,
+    pub game_version: String,
+    pub loader: ModLoader,
+    pub loader_version: Option<String>,
+    pub icon: Option<String>,
+}
+
+/// 实例存储的全局锁，保证目录分配与清单读写互不交错
+static STORE_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// 实例根目录
+pub(crate) fn instances_dir(resource_dir: &Path) -> PathBuf {
+    resource_dir.join(config::INSTANCES_DIR)
+}
+
+/// 由显示名称生成目录名：只保留字母数字与 `-_.`，空白替换为 `-`
+fn slugify(name: &str) -> String {
+    let slug: String = name
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') => Some(c),
+            c if c.is_whitespace() => Some('-'),
+            _ => None,
+        })
+        .collect();
+    let slug = slug.trim_start_matches('.').to_string();
+    if slug.is_empty() {
+        config::FALLBACK_ID.to_string()
+    } else {
+        slug
+    }
+}
+
+/// 分配未被占用的实例目录名
+async fn allocate_id(root: &Path, name: &str) -> Result<String> {
+    let base = slugify(name);
+    let mut id = base.clone();
+    let mut suffix = 2;
+    while fs::try_exists(root.join(&id)).await? {
+        id = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+    Ok(id)
+}
+
+/// 校验实例 ID，防止路径穿越
+fn instance_path(resource_dir: &Path, id: &str) -> Result<PathBuf> {
+    if id.is_empty() || id.starts_with('.') || id.contains(['/', '\\']) {
+        return Err(anyhow!("无效的实例 ID: {}", id));
+    }
+    Ok(instances_dir(resource_dir).join(id))
+}
+
+/// 读取实例清单
+pub(crate) async fn read_manifest(instance_dir: &Path) -> Result<InstanceManifest> {
+    let text = fs::read_to_string(instance_dir.join(config::MANIFEST_FILE)).await?;
+    let mut manifest: InstanceManifest = serde_json::from_str(&text)?;
+    manifest.path = instance_dir.to_string_lossy().to_string();
+    Ok(manifest)
+}
+
+/// 写入实例清单：先写临时文件再重命名，避免留下半个文件
+pub(crate) async fn write_manifest(instance_dir: &Path, manifest: &InstanceManifest) -> Result<()> {
+    let path = instance_dir.join(config::MANIFEST_FILE);
+    let temp = instance_dir.join(format!("{}.tmp", config::MANIFEST_FILE));
+    fs::write(&temp, serde_json::to_string_pretty(manifest)?).await?;
+    fs::rename(&temp, &path).await?;
+    Ok(())
+}
+
+/// 在锁内读取、修改并写回实例清单
+pub(crate) async fn update_manifest<F>(resource_dir: &Path, id: &str, update: F) -> Result<InstanceManifest>
+where
+    F: FnOnce(&mut InstanceManifest),
+{
+    let _guard = STORE_LOCK.lock().await;
+    let dir = instance_path(resource_dir, id)?;
+    let mut manifest = read_manifest(&dir).await?;
+    update(&mut manifest);
+    write_manifest(&dir, &manifest).await?;
+    database::sync_instance_index(&manifest).await;
+    Ok(manifest)
+}
+
+/// 递归复制目录，符号链接按原样重建而不是复制其指向的内容
+pub(crate) fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() {
+            copy_symlink(&entry.path(), &target)?;
+        } else if file_type.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// 以相同的指向创建符号链接 (相对链接保持相对)
+fn copy_symlink(link: &Path, target: &Path) -> std::io::Result<()> {
+    let destination = std::fs::read_link(link)?;
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(&destination, target)
+    }
+    #[cfg(windows)]
+    {
+        // Windows 区分目录链接与文件链接，目标失效时按文件链接处理
+        if std::fs::metadata(link).is_ok_and(|metadata| metadata.is_dir()) {
+            std::os::windows::fs::symlink_dir(&destination, target)
+        } else {
+            std::os::windows::fs::symlink_file(&destination, target)
+        }
+    }
+}
+
+/// 在临时目录中准备好实例后整体重命名到位，失败时清理临时目录
+async fn commit_staging(staging: &Path, target: &Path, manifest: &InstanceManifest) -> Result<()> {
+    let result = async {
+        write_manifest(staging, manifest).await?;
+        fs::rename(staging, target).await?;
+        Ok(())
+    }
+    .await;
+    if result.is_err() {
+        fs::remove_dir_all(staging).await.ok();
+    }
+    result
+}
+
+/// 新建实例，返回写入的清单
+pub async fn create_instance(resource_dir: String, options: NewInstance) -> Result<InstanceManifest> {
+    let _guard = STORE_LOCK.lock().await;
+    let root = instances_dir(Path::new(&resource_dir));
+    fs::create_dir_all(&root).await?;
+
+    let id = allocate_id(&root, &options.name).await?;
+    let target = root.join(&id);
+    let manifest = InstanceManifest {
+        format_version: CURRENT_FORMAT_VERSION,
+        id,
+        name: options.name,
+        game_version: options.game_version,
+        loader: options.loader,
+        loader_version: options.loader_version,
+        java_path: None,
+        max_memory_mb: None,
+        min_memory_mb: None,
+        jvm_args: Vec::new(),
+        resolution: None,
+        icon: options.icon,
+        play_time_secs: 0,
+        created: Utc::now(),
+        last_played: None,
+        path: target.to_string_lossy().to_string(),
+    };
+
+    let staging = root.join(format!("{}{}", config::STAGING_PREFIX, uuid::Uuid::new_v4().simple()));
+    fs::create_dir_all(&staging).await?;
+    commit_staging(&staging, &target, &manifest).await?;
+    database::sync_instance_index(&manifest).await;
+    Ok(manifest)
+}
+
+/// 列出全部实例 (按名称排序)，清单损坏的目录会被跳过
+pub async fn list_instances(resource_dir: String) -> Result<Vec<InstanceManifest>> {
+    let root = instances_dir(Path::new(&resource_dir));
+    if !fs::try_exists(&root).await? {
+        return Ok(Vec::new());
+    }
+
+    let mut instances = Vec::new();
+    let mut entries = fs::read_dir(&root).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        if entry.file_name().to_string_lossy().starts_with('.') || !entry.file_type().await?.is_dir() {
+            continue;
+        }
+        if let Ok(manifest) = read_manifest(&entry.path()).await {
+            instances.push(manifest);
+        }
+    }
+    instances.sort_by_key(|instance| instance.name.to_lowercase());
+    Ok(instances)
+}
+
+/// 读取单个实例
+pub async fn get_instance(resource_dir: String, id: String) -> Result<InstanceManifest> {
+    read_manifest(&instance_path(Path::new(&resource_dir), &id)?).await
+}
+
+/// 保存实例设置 (Java、内存、JVM 参数、分辨率、图标等)，`id` 与游戏时长以磁盘上的为准
+pub async fn save_instance(resource_dir: String, manifest: InstanceManifest) -> Result<InstanceManifest> {
+    update_manifest(Path::new(&resource_dir), &manifest.id.clone(), move |current| {
+        *current = InstanceManifest {
+            format_version: current.format_version,
+            id: current.id.clone(),
+            play_time_secs: current.play_time_secs,
+            created: current.created,
+            last_played: current.last_played,
+            path: current.path.clone(),
+            ..manifest
+        };
+    })
+    .await
+}
+
+/// 重命名实例 (只修改显示名称，目录保持不变)
+pub async fn rename_instance(resource_dir: String, id: String, name: String) -> Result<InstanceManifest> {
+    if name.trim().is_empty() {
+        return Err(anyhow!("实例名称不能为空"));
+    }
+    update_manifest(Path::new(&resource_dir), &id, |manifest| manifest.name = name).await
+}
+
+/// 复制实例 (含全部文件)，新实例的游戏时长清零。
+/// 文件先复制到临时目录，只在分配目录并登记清单时持有存储锁
+pub async fn duplicate_instance(resource_dir: String, id: String, new_name: String) -> Result<InstanceManifest> {
+    let resource_dir = Path::new(&resource_dir);
+    let source = instance_path(resource_dir, &id)?;
+    let mut manifest = read_manifest(&source).await?;
+
+    let root = instances_dir(resource_dir);
+    let staging = root.join(format!("{}{}", config::STAGING_PREFIX, uuid::Uuid::new_v4().simple()));
+    let copy_result = {
+        let (source, staging) = (source.clone(), staging.clone());
+        tokio::task::spawn_blocking(move || copy_dir_all(&source, &staging)).await?
+    };
+    if let Err(e) = copy_result {
+        fs::remove_dir_all(&staging).await.ok();
+        return Err(anyhow!("复制实例失败: {}", e));
+    }
+
+    let _guard = STORE_LOCK.lock().await;
+    let new_id = match allocate_id(&root, &new_name).await {
+        Ok(new_id) => new_id,
+        Err(e) => {
+            fs::remove_dir_all(&staging).await.ok();
+            return Err(e);
+        }
+    };
+    let target = root.join(&new_id);
+    manifest.id = new_id;
+    manifest.name = new_name;
+    manifest.play_time_secs = 0;
+    manifest.created = Utc::now();
+    manifest.last_played = None;
+    manifest.path = target.to_string_lossy().to_string();
+    commit_staging(&staging, &target, &manifest).await?;
+    database::sync_instance_index(&manifest).await;
+    Ok(manifest)
+}
+
+/// 删除实例：先移入临时目录使其立即从列表中消失，再删除文件
+pub async fn delete_instance(resource_dir: String, id: String) -> Result<()> {
+    let _guard = STORE_LOCK.lock().await;
+    let resource_dir = Path::new(&resource_dir);
+    let dir = instance_path(resource_dir, &id)?;
+    if !fs::try_exists(&dir).await? {
+        return Ok(());
+    }
+    let trash = instances_dir(resource_dir).join(format!("{}{}", config::TRASH_PREFIX, uuid::Uuid::new_v4().simple()));
+    fs::rename(&dir, &trash).await?;
+    if database::is_initialized() {
+        database::remove_instance(&id).await.ok();
+    }
+    fs::remove_dir_all(&trash).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_instance(name: &str) -> NewInstance {
+        NewInstance {
+            name: name.to_string(),
+            game_version: "1.20.1".to_string(),
+            loader: ModLoader::Vanilla,
+            loader_version: None,
+            icon: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicates_files_and_resets_play_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        let original = create_instance(resource_dir.clone(), new_instance("Survival")).await.unwrap();
+        let original = update_manifest(dir.path(), &original.id, |manifest| manifest.play_time_secs = 3600)
+            .await
+            .unwrap();
+        let source = Path::new(&original.path);
+        fs::create_dir_all(source.join("saves/world")).await.unwrap();
+        fs::write(source.join("saves/world/level.dat"), b"level").await.unwrap();
+
+        let copy = duplicate_instance(resource_dir.clone(), original.id.clone(), "Survival".to_string())
+            .await
+            .unwrap();
+        assert_eq!(copy.id, "Survival-2");
+        assert_eq!(copy.play_time_secs, 0);
+        let target = Path::new(&copy.path);
+        assert_eq!(fs::read(target.join("saves/world/level.dat")).await.unwrap(), b"level");
+        assert_eq!(read_manifest(target).await.unwrap().id, "Survival-2");
+
+        // 没有残留的临时目录
+        let names: Vec<String> = std::fs::read_dir(instances_dir(dir.path()))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        assert!(names.iter().all(|name| !name.starts_with('.')), "{:?}", names);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn duplicates_symlinks_as_links() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        let original = create_instance(resource_dir.clone(), new_instance("Linked")).await.unwrap();
+        let source = Path::new(&original.path);
+        let shared = dir.path().join("shared-mods");
+        std::fs::create_dir_all(&shared).unwrap();
+        std::fs::write(shared.join("mod.jar"), b"mod").unwrap();
+        std::os::unix::fs::symlink(&shared, source.join("mods")).unwrap();
+        // 指向上级目录的链接若被跟随会无限递归
+        std::os::unix::fs::symlink("..", source.join("loop")).unwrap();
+        std::os::unix::fs::symlink("missing.txt", source.join("dangling")).unwrap();
+
+        let copy = duplicate_instance(resource_dir, original.id, "Linked copy".to_string())
+            .await
+            .unwrap();
+        let target = Path::new(&copy.path);
+        assert_eq!(std::fs::read_link(target.join("mods")).unwrap(), shared);
+        assert_eq!(std::fs::read_link(target.join("loop")).unwrap(), Path::new(".."));
+        assert_eq!(std::fs::read_link(target.join("dangling")).unwrap(), Path::new("missing.txt"));
+        assert_eq!(std::fs::read(target.join("mods/mod.jar")).unwrap(), b"mod");
+    }
+}
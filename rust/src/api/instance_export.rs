@@ -0,0 +1,536 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::sync::mpsc;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::api::database;
+use crate::api::instance::{self, InstanceManifest, NewInstance};
+use crate::api::java_download::ProgressCallback;
+use crate::api::modpack;
+use crate::archive;
+use crate::checksum;
+use crate::config::CURRENT_FORMAT_VERSION;
+use crate::download;
+
+/// 配置常量
+mod config {
+    /// 压缩包中的导出清单
+    pub const EXPORT_MANIFEST: &str = "aml-export.json";
+    /// 压缩包中存放实例文件的目录
+    pub const FILES_PREFIX: &str = "files/";
+    /// 默认导出的条目
+    pub const DEFAULT_ENTRIES: &[&str] = &["mods", "config", "saves", "resourcepacks", "options.txt"];
+    /// 导入时引用文件的并发下载数
+    pub const CONCURRENT_DOWNLOADS: usize = 8;
+}
+
+/// 导出选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOptions {
+    /// 要导出的顶层条目 (相对实例目录，如 `mods`、`saves`、`options.txt`)
+    pub include: Vec<String>,
+    /// 排除规则 (glob，相对实例目录，如 `saves/*/backups/**`、`**/*.log`)
+    pub exclude: Vec<String>,
+    /// 能从下载记录找到来源的文件只写入下载地址，导入时重新下载
+    pub reference_downloads: bool,
+}
+
+/// 只以下载地址形式保存的文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileReference {
+    /// 相对实例目录，使用 `/` 分隔
+    path: String,
+    url: String,
+    sha1: String,
+    size: u64,
+}
+
+/// 导出清单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ExportManifest {
+    format_version: usize,
+    instance: InstanceManifest,
+    /// 实例目录内的图标 (相对路径)
+    icon_file: Option<String>,
+    #[serde(default)]
+    references: Vec<FileReference>,
+}
+
+/// 待写入压缩包的文件
+#[frb(ignore)]
+pub(crate) struct ExportFile {
+    pub relative: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// 默认导出的条目
+pub fn default_export_entries() -> Vec<String> {
+    config::DEFAULT_ENTRIES.iter().map(|entry| entry.to_string()).collect()
+}
+
+pub(crate) fn build_globset(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).map_err(|e| anyhow!("无效的排除规则 {}: {}", pattern, e))?);
+    }
+    Ok(builder.build()?)
+}
+
+/// 收集要导出的文件，匹配排除规则的目录整个跳过
+pub(crate) fn collect_files(root: &Path, include: &[String], exclude: &GlobSet) -> io::Result<Vec<ExportFile>> {
+    fn walk(
+        root: &Path,
+        relative: &str,
+        exclude: &GlobSet,
+        visited: &mut HashSet<PathBuf>,
+        files: &mut Vec<ExportFile>,
+    ) -> io::Result<()> {
+        let path = root.join(relative);
+        let Ok(metadata) = std::fs::symlink_metadata(&path) else {
+            return Ok(());
+        };
+        if exclude.is_match(relative) {
+            return Ok(());
+        }
+        // 链接进来的目录 (导入时的 Link 模式) 按实际内容导出，失效的链接直接跳过
+        let metadata = if metadata.file_type().is_symlink() {
+            match std::fs::metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    tracing::warn!("跳过无法解析的链接 {}: {}", path.display(), e);
+                    return Ok(());
+                }
+            }
+        } else {
+            metadata
+        };
+        if metadata.is_dir() {
+            // 链接成环时同一目录只遍历一次
+            if !visited.insert(std::fs::canonicalize(&path)?) {
+                return Ok(());
+            }
+            for entry in std::fs::read_dir(&path)? {
+                let name = entry?.file_name().to_string_lossy().to_string();
+                walk(root, &format!("{}/{}", relative, name), exclude, visited, files)?;
+            }
+        } else {
+            files.push(ExportFile {
+                relative: relative.to_string(),
+                path,
+                size: metadata.len(),
+            });
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    let mut visited = HashSet::new();
+    for entry in include {
+        let entry = entry.trim_matches('/');
+        if entry.is_empty() || entry.split('/').any(|part| part == "..") || entry == instance::MANIFEST_FILE {
+            continue;
+        }
+        walk(root, entry, exclude, &mut visited, &mut files)?;
+    }
+    Ok(files)
+}
+
+/// 把能从下载记录找到来源且内容未改动的文件转换为引用
+async fn split_references(files: Vec<ExportFile>) -> Result<(Vec<ExportFile>, Vec<FileReference>)> {
+    let mut kept = Vec::new();
+    let mut references = Vec::new();
+    for file in files {
+        let source = database::download_source(&file.path).await?;
+        let Some((source, expected)) = source.and_then(|source| source.sha1.clone().map(|sha1| (source, sha1))) else {
+            kept.push(file);
+            continue;
+        };
+        if checksum::verify_file_sha1(&file.path, &expected).await.is_err() {
+            kept.push(file);
+            continue;
+        }
+        references.push(FileReference {
+            path: file.relative,
+            url: source.url,
+            sha1: expected,
+            size: file.size,
+        });
+    }
+    Ok((kept, references))
+}
+
+/// 流式写入压缩包，每写完一个文件发送一次累计字节数
+fn write_archive(
+    dest: &Path,
+    manifest: &ExportManifest,
+    files: &[ExportFile],
+    progress: mpsc::UnboundedSender<u64>,
+) -> Result<()> {
+    let mut zip = ZipWriter::new(BufWriter::new(File::create(dest)?));
+    let options = FileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .large_file(true);
+
+    zip.start_file(config::EXPORT_MANIFEST, options)?;
+    serde_json::to_writer_pretty(&mut zip, manifest)?;
+
+    let mut written = 0;
+    for file in files {
+        zip.start_file(format!("{}{}", config::FILES_PREFIX, file.relative), options)?;
+        io::copy(&mut BufReader::new(File::open(&file.path)?), &mut zip)?;
+        written += file.size;
+        progress.send(written).ok();
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+/// 导出实例为 zip 压缩包
+pub async fn export_instance(
+    resource_dir: String,
+    instance_id: String,
+    dest_path: String,
+    options: ExportOptions,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<()> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let root = PathBuf::from(&manifest.path);
+    let exclude = build_globset(&options.exclude)?;
+
+    on_progress(0.0, "收集文件".to_string()).await;
+    let mut include = options.include.clone();
+    let icon_file = manifest
+        .icon
+        .as_deref()
+        .and_then(|icon| Path::new(icon).strip_prefix(&root).ok())
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"));
+    include.extend(icon_file.clone());
+    let files = {
+        let root = root.clone();
+        tokio::task::spawn_blocking(move || collect_files(&root, &include, &exclude)).await??
+    };
+    let (files, references) = if options.reference_downloads && database::is_initialized() {
+        split_references(files).await?
+    } else {
+        (files, Vec::new())
+    };
+
+    let export = ExportManifest {
+        format_version: CURRENT_FORMAT_VERSION,
+        instance: manifest,
+        icon_file,
+        references,
+    };
+
+    let dest = PathBuf::from(&dest_path);
+    let part = dest.with_extension("zip.part");
+    let total: u64 = files.iter().map(|file| file.size).sum::<u64>().max(1);
+    let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+    let writer = {
+        let part = part.clone();
+        tokio::task::spawn_blocking(move || write_archive(&part, &export, &files, progress_tx))
+    };
+    while let Some(written) = progress_rx.recv().await {
+        on_progress(written as f64 / total as f64 * 0.99, "写入压缩包".to_string()).await;
+    }
+
+    match writer.await? {
+        Ok(()) => {
+            fs::rename(&part, &dest).await?;
+            on_progress(1.0, "导出完成".to_string()).await;
+            Ok(())
+        }
+        Err(e) => {
+            fs::remove_file(&part).await.ok();
+            Err(e)
+        }
+    }
+}
+
+/// 读取压缩包中的导出清单
+fn read_export_manifest(archive_path: &Path) -> Result<ExportManifest> {
+    let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+    let mut entry = archive
+        .by_name(config::EXPORT_MANIFEST)
+        .map_err(|_| anyhow!("不是有效的实例导出文件: 缺少 {}", config::EXPORT_MANIFEST))?;
+    let mut text = String::new();
+    entry.read_to_string(&mut text)?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// 流式解压 `files/` 下的条目到实例目录，拒绝越出目标目录的路径
+fn extract_files(archive_path: &Path, target: &Path) -> Result<()> {
+    let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+    archive::extract_zip(
+        &mut archive,
+        target,
+        |name| {
+            let relative = name.strip_prefix(config::FILES_PREFIX.trim_end_matches('/')).ok()?;
+            (relative.as_os_str() != instance::MANIFEST_FILE).then(|| relative.to_path_buf())
+        },
+        |_, _| {},
+    )?;
+    Ok(())
+}
+
+/// 下载引用文件
+async fn download_references(
+    target: &Path,
+    references: &[FileReference],
+    on_progress: &ProgressCallback,
+) -> Result<()> {
+    use futures::StreamExt;
+
+    // 先校验全部路径，避免下载到一半才发现越出实例目录的条目
+    let mut targets = Vec::with_capacity(references.len());
+    for reference in references {
+        let relative = modpack::safe_relative_path(&reference.path)
+            .ok_or_else(|| anyhow!("无效的文件路径: {}", reference.path))?;
+        targets.push((reference, target.join(relative)));
+    }
+
+    let total = targets.len();
+    let downloads: Vec<_> = targets
+        .into_iter()
+        .map(|(reference, dest)| async move {
+            download::download_file(&reference.url, &dest, Some(&reference.sha1)).await
+        })
+        .collect();
+    let mut downloads = futures::stream::iter(downloads).buffer_unordered(config::CONCURRENT_DOWNLOADS);
+
+    let mut completed = 0;
+    while let Some(result) = downloads.next().await {
+        result?;
+        completed += 1;
+        let progress = 0.5 + 0.5 * completed as f64 / total as f64;
+        on_progress(progress, format!("下载文件... {}/{}", completed, total)).await;
+    }
+    Ok(())
+}
+
+/// 从导出的压缩包导入实例，仅以引用保存的文件会重新下载
+pub async fn import_instance_archive(
+    resource_dir: String,
+    archive_path: String,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<InstanceManifest> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    let archive_path = PathBuf::from(archive_path);
+
+    let export = {
+        let archive_path = archive_path.clone();
+        tokio::task::spawn_blocking(move || read_export_manifest(&archive_path)).await??
+    };
+    let source = export.instance;
+    let created = instance::create_instance(
+        resource_dir.clone(),
+        NewInstance {
+            name: source.name.clone(),
+            game_version: source.game_version.clone(),
+            loader: source.loader,
+            loader_version: source.loader_version.clone(),
+            icon: None,
+        },
+    )
+    .await?;
+
+    let result = async {
+        let target = PathBuf::from(&created.path);
+        on_progress(0.05, "解压文件".to_string()).await;
+        {
+            let (archive_path, target) = (archive_path.clone(), target.clone());
+            tokio::task::spawn_blocking(move || extract_files(&archive_path, &target)).await??;
+        }
+
+        on_progress(0.5, "下载文件".to_string()).await;
+        download_references(&target, &export.references, &on_progress).await?;
+
+        let icon = match &export.icon_file {
+            Some(relative) => Some(target.join(relative).to_string_lossy().to_string()),
+            None => source.icon.clone().filter(|icon| icon.starts_with("data:")),
+        };
+        // Java 路径与游戏时长属于原机器，不随实例导入
+        let manifest = instance::update_manifest(Path::new(&resource_dir), &created.id, |manifest| {
+            manifest.max_memory_mb = source.max_memory_mb;
+            manifest.min_memory_mb = source.min_memory_mb;
+            manifest.jvm_args = source.jvm_args;
+            manifest.resolution = source.resolution;
+            manifest.icon = icon;
+        })
+        .await?;
+        on_progress(1.0, "导入完成".to_string()).await;
+        Ok(manifest)
+    }
+    .await;
+
+    if result.is_err() {
+        instance::delete_instance(resource_dir, created.id).await.ok();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::database::tests::with_pool;
+    use crate::api::database::CachedDownload;
+    use crate::api::forge::tests::no_progress;
+    use crate::api::mod_loader::ModLoader;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn relatives(files: &[ExportFile]) -> Vec<&str> {
+        let mut relatives: Vec<&str> = files.iter().map(|file| file.relative.as_str()).collect();
+        relatives.sort();
+        relatives
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_dangling_and_looping_links() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("config/nested")).unwrap();
+        std::fs::write(root.join("config/a.toml"), "a").unwrap();
+        std::os::unix::fs::symlink(root.join("missing"), root.join("config/broken")).unwrap();
+        std::os::unix::fs::symlink(root.join("config"), root.join("config/nested/loop")).unwrap();
+
+        let include = ["config".to_string()];
+        let files = collect_files(root, &include, &build_globset(&[]).unwrap()).unwrap();
+        assert_eq!(relatives(&files), ["config/a.toml"]);
+    }
+
+    #[test]
+    fn excludes_matching_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("mods/.cache")).unwrap();
+        std::fs::write(root.join("mods/a.jar"), "a").unwrap();
+        std::fs::write(root.join("mods/.cache/b"), "b").unwrap();
+        std::fs::write(root.join(instance::MANIFEST_FILE), "{}").unwrap();
+
+        let include = ["mods".to_string(), instance::MANIFEST_FILE.to_string(), "../escape".to_string()];
+        let exclude = build_globset(&["mods/.cache".to_string()]).unwrap();
+        let files = collect_files(root, &include, &exclude).unwrap();
+        assert_eq!(relatives(&files), ["mods/a.jar"]);
+    }
+
+    #[tokio::test]
+    async fn round_trips_export_and_import() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        let server = MockServer::start().await;
+        Mock::given(path("/a.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"jar".to_vec()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        with_pool(dir.path(), async {
+            let source = instance::create_instance(
+                resource_dir.clone(),
+                NewInstance {
+                    name: "Pack".to_string(),
+                    game_version: "1.20.1".to_string(),
+                    loader: ModLoader::Fabric,
+                    loader_version: Some("0.15.0".to_string()),
+                    icon: None,
+                },
+            )
+            .await
+            .unwrap();
+            let root = PathBuf::from(&source.path);
+            std::fs::create_dir_all(root.join("mods")).unwrap();
+            std::fs::create_dir_all(root.join("saves/world/backups")).unwrap();
+            std::fs::write(root.join("mods/a.jar"), "jar").unwrap();
+            std::fs::write(root.join("mods/local.jar"), "local").unwrap();
+            std::fs::write(root.join("saves/world/level.dat"), "level").unwrap();
+            std::fs::write(root.join("saves/world/backups/old.zip"), "old").unwrap();
+            std::fs::write(root.join("icon.png"), "png").unwrap();
+            database::record_download(&CachedDownload {
+                url: format!("{}/a.jar", server.uri()),
+                path: root.join("mods/a.jar").to_string_lossy().to_string(),
+                sha1: Some(checksum::hex_string(&<sha1::Sha1 as sha1::Digest>::digest(b"jar"))),
+                size: 3,
+                fetched_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+            instance::update_manifest(dir.path(), &source.id, |manifest| {
+                manifest.max_memory_mb = Some(4096);
+                manifest.java_path = Some("/usr/bin/java".to_string());
+                manifest.icon = Some(root.join("icon.png").to_string_lossy().to_string());
+            })
+            .await
+            .unwrap();
+
+            let archive = dir.path().join("pack.zip");
+            let options = ExportOptions {
+                include: vec!["mods".to_string(), "saves".to_string()],
+                exclude: vec!["saves/*/backups".to_string()],
+                reference_downloads: true,
+            };
+            export_instance(
+                resource_dir.clone(),
+                source.id.clone(),
+                archive.to_string_lossy().to_string(),
+                options,
+                no_progress(),
+            )
+            .await
+            .unwrap();
+            let mut zip = ZipArchive::new(File::open(&archive).unwrap()).unwrap();
+            let mut names: Vec<&str> = zip.file_names().collect();
+            names.sort();
+            assert_eq!(
+                names,
+                [config::EXPORT_MANIFEST, "files/icon.png", "files/mods/local.jar", "files/saves/world/level.dat"]
+            );
+            assert!(zip.by_name("files/mods/a.jar").is_err());
+
+            // 源实例删除后缓存失效，引用文件只能从下载地址取回
+            instance::delete_instance(resource_dir.clone(), source.id.clone()).await.unwrap();
+            let imported = import_instance_archive(resource_dir.clone(), archive.to_string_lossy().to_string(), no_progress())
+                .await
+                .unwrap();
+            let target = PathBuf::from(&imported.path);
+            assert_eq!(std::fs::read_to_string(target.join("mods/a.jar")).unwrap(), "jar");
+            assert_eq!(std::fs::read_to_string(target.join("mods/local.jar")).unwrap(), "local");
+            assert_eq!(std::fs::read_to_string(target.join("saves/world/level.dat")).unwrap(), "level");
+            assert!(!target.join("saves/world/backups").exists());
+            assert_eq!(imported.name, "Pack");
+            assert_eq!(imported.loader, ModLoader::Fabric);
+            assert_eq!(imported.loader_version.as_deref(), Some("0.15.0"));
+            assert_eq!(imported.max_memory_mb, Some(4096));
+            assert_eq!(imported.java_path, None);
+            assert_eq!(imported.icon, Some(target.join("icon.png").to_string_lossy().to_string()));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn rejects_unsafe_reference_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        for path in ["../escape.jar", "..\\escape.jar", "C:\\escape.jar", "/abs.jar", ""] {
+            let references = [FileReference {
+                path: path.to_string(),
+                url: format!("{}/file", server.uri()),
+                sha1: String::new(),
+                size: 0,
+            }];
+            let result = download_references(dir.path(), &references, &no_progress()).await;
+            assert!(result.is_err(), "{:?}", path);
+        }
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,954 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::instance::{self, InstanceManifest, NewInstance};
+use crate::api::launch::Resolution;
+use crate::api::mod_loader::{self, ModLoader};
+use crate::api::vanilla;
+use crate::config::META_URL;
+use crate::minecraft;
+
+/// 配置常量
+mod config {
+    /// 官方启动器的配置文件
+    pub const LAUNCHER_PROFILES: &str = "launcher_profiles.json";
+    /// MultiMC / Prism 的实例配置与组件列表
+    pub const MULTIMC_CONFIG: &str = "instance.cfg";
+    pub const MULTIMC_PACK: &str = "mmc-pack.json";
+    /// MultiMC / Prism 实例中的游戏目录 (新旧两种命名)
+    pub const MULTIMC_GAME_DIRS: &[&str] = &[".minecraft", "minecraft"];
+    /// CurseForge 实例描述文件
+    pub const CURSEFORGE_INSTANCE: &str = "minecraftinstance.json";
+    /// HMCL 的单版本设置 (位于 versions/<版本>/)
+    pub const HMCL_VERSION_SETTING: &str = "hmclversion.cfg";
+    /// HMCL 的版本图标
+    pub const HMCL_ICON: &str = "icon.png";
+    /// 从共享的 .minecraft 目录导入时只复制这些游戏内容
+    pub const SHARED_GAME_ENTRIES: &[&str] = &[
+        "mods",
+        "config",
+        "saves",
+        "resourcepacks",
+        "shaderpacks",
+        "screenshots",
+        "schematics",
+        "options.txt",
+        "optionsof.txt",
+        "optionsshaders.txt",
+        "servers.dat",
+    ];
+    /// 从独立游戏目录导入时跳过的条目 (启动器数据、可重新下载的文件与日志)
+    pub const EXCLUDED_ENTRIES: &[&str] = &[
+        "versions",
+        "libraries",
+        "assets",
+        "natives",
+        "logs",
+        "crash-reports",
+        ".fabric",
+        ".mixin.out",
+        "minecraftinstance.json",
+        ".curseclient",
+        "hmclversion.cfg",
+    ];
+}
+
+/// 导入来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportSource {
+    /// 官方启动器 (launcher_profiles.json)
+    VanillaLauncher,
+    /// MultiMC / Prism Launcher
+    MultiMc,
+    CurseForge,
+    /// HMCL (.minecraft/versions 下的每个版本)
+    Hmcl,
+}
+
+/// 游戏文件的导入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportMode {
+    Copy,
+    /// 创建符号链接，失败时回退为复制
+    Link,
+}
+
+/// 可导入的实例
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportCandidate {
+    pub source: ImportSource,
+    /// 官方启动器与 HMCL 为 .minecraft 目录，其余为实例目录
+    pub location: String,
+    /// 官方启动器中的配置键，HMCL 中的版本 ID
+    pub profile_key: Option<String>,
+    pub name: String,
+    pub game_version: String,
+    pub loader: ModLoader,
+    pub loader_version: Option<String>,
+    /// 游戏文件所在目录
+    pub game_dir: String,
+}
+
+/// 解析出的实例设置
+#[derive(Debug, Clone, Default)]
+#[frb(ignore)]
+struct ImportSettings {
+    java_path: Option<String>,
+    max_memory_mb: Option<u32>,
+    min_memory_mb: Option<u32>,
+    jvm_args: Vec<String>,
+    resolution: Option<Resolution>,
+    /// data URL 形式的图标
+    icon_data: Option<String>,
+    /// 需要复制到实例目录的图标文件
+    icon_file: Option<PathBuf>,
+    play_time_secs: u64,
+    last_played: Option<DateTime<Utc>>,
+}
+
+/// 解析结果
+struct ParsedInstance {
+    candidate: ImportCandidate,
+    settings: ImportSettings,
+    /// 游戏目录是否为多个配置共享的 .minecraft (只复制游戏内容)
+    shared_game_dir: bool,
+    /// 游戏目录中额外跳过的条目 (如 HMCL 版本隔离目录中的版本 JSON 与 jar)
+    excluded_entries: Vec<String>,
+}
+
+/// 解析 `-Xmx2G` / `512m` 形式的内存大小 (MB)
+fn parse_memory_mb(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let (number, unit) = value.split_at(value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len()));
+    let number: u64 = number.parse().ok()?;
+    let mb = match unit.to_ascii_lowercase().as_str() {
+        "g" => number * 1024,
+        "m" | "" => number,
+        "k" => number / 1024,
+        _ => return None,
+    };
+    u32::try_from(mb).ok()
+}
+
+/// 拆分 JVM 参数：提取内存设置，其余原样保留
+fn split_java_args(args: &str, settings: &mut ImportSettings) {
+    for arg in args.split_whitespace() {
+        if let Some(max) = arg.strip_prefix("-Xmx").and_then(parse_memory_mb) {
+            settings.max_memory_mb = Some(max);
+        } else if let Some(min) = arg.strip_prefix("-Xms").and_then(parse_memory_mb) {
+            settings.min_memory_mb = Some(min);
+        } else {
+            settings.jvm_args.push(arg.to_string());
+        }
+    }
+}
+
+/// 官方启动器配置
+#[derive(Debug, Deserialize)]
+struct LauncherProfiles {
+    #[serde(default)]
+    profiles: HashMap<String, VanillaProfile>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VanillaProfile {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    profile_type: Option<String>,
+    last_version_id: Option<String>,
+    game_dir: Option<String>,
+    java_dir: Option<String>,
+    java_args: Option<String>,
+    icon: Option<String>,
+    resolution: Option<Resolution>,
+    last_used: Option<String>,
+}
+
+/// 由版本 ID 推断游戏版本与加载器：读取本地版本 JSON，`latest-*` 查询版本清单
+async fn resolve_vanilla_version(
+    minecraft_dir: &Path,
+    version_id: &str,
+) -> Result<(String, ModLoader, Option<String>)> {
+    if let Some(kind) = version_id.strip_prefix("latest-") {
+        let latest = vanilla::fetch_version_manifest(META_URL)
+            .await?
+            .latest
+            .ok_or_else(|| anyhow!("版本清单缺少最新版本信息"))?;
+        let game_version = if kind == "snapshot" { latest.snapshot } else { latest.release };
+        return Ok((game_version, ModLoader::Vanilla, None));
+    }
+
+    let path = minecraft::version_json_path(&minecraft_dir.join("versions"), version_id);
+    let Ok(version) = minecraft::read_version_info(&path).await else {
+        return Ok((version_id.to_string(), ModLoader::Vanilla, None));
+    };
+    let game_version = version.inherits_from.clone().unwrap_or_else(|| version.id.clone());
+    Ok(match mod_loader::detect_loader(&version) {
+        Some((loader, loader_version)) => (game_version, loader, Some(loader_version)),
+        None => (game_version, ModLoader::Vanilla, None),
+    })
+}
+
+/// 解析官方启动器的全部配置
+async fn parse_vanilla_profiles(minecraft_dir: &Path) -> Result<Vec<ParsedInstance>> {
+    let text = fs::read_to_string(minecraft_dir.join(config::LAUNCHER_PROFILES)).await?;
+    let profiles: LauncherProfiles = serde_json::from_str(&text)?;
+
+    let mut parsed = Vec::new();
+    for (key, profile) in profiles.profiles {
+        let version_id = match profile.profile_type.as_deref() {
+            Some(kind @ ("latest-release" | "latest-snapshot")) => kind.to_string(),
+            _ => match profile.last_version_id {
+                Some(id) => id,
+                None => continue,
+            },
+        };
+        let Ok((game_version, loader, loader_version)) = resolve_vanilla_version(minecraft_dir, &version_id).await
+        else {
+            continue;
+        };
+
+        let mut settings = ImportSettings {
+            java_path: profile.java_dir,
+            resolution: profile.resolution,
+            last_played: profile
+                .last_used
+                .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+                .map(|time| time.with_timezone(&Utc)),
+            ..Default::default()
+        };
+        if let Some(args) = &profile.java_args {
+            split_java_args(args, &mut settings);
+        }
+        // 自定义图标以 data URL 形式保存，内置图标 (如 "Grass") 忽略
+        settings.icon_data = profile.icon.filter(|icon| icon.starts_with("data:"));
+
+        let name = match (profile.name.trim(), version_id.as_str()) {
+            ("", "latest-snapshot") => "Latest snapshot".to_string(),
+            ("", "latest-release") => "Latest release".to_string(),
+            ("", _) => version_id.clone(),
+            (name, _) => name.to_string(),
+        };
+        let game_dir = profile.game_dir.map(PathBuf::from);
+        parsed.push(ParsedInstance {
+            shared_game_dir: game_dir.is_none(),
+            excluded_entries: Vec::new(),
+            candidate: ImportCandidate {
+                source: ImportSource::VanillaLauncher,
+                location: minecraft_dir.to_string_lossy().to_string(),
+                profile_key: Some(key),
+                name,
+                game_version,
+                loader,
+                loader_version,
+                game_dir: game_dir
+                    .unwrap_or_else(|| minecraft_dir.to_path_buf())
+                    .to_string_lossy()
+                    .to_string(),
+            },
+            settings,
+        });
+    }
+    parsed.sort_by(|a, b| a.candidate.name.cmp(&b.candidate.name));
+    Ok(parsed)
+}
+
+/// MultiMC / Prism 组件列表
+#[derive(Debug, Deserialize)]
+struct MmcPack {
+    components: Vec<MmcComponent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MmcComponent {
+    uid: String,
+    version: Option<String>,
+    cached_version: Option<String>,
+}
+
+/// Forge 系加载器在 MultiMC / CurseForge 中只记录加载器版本号，转换为完整 Maven 版本
+pub(crate) fn forge_maven_version(loader: ModLoader, game_version: &str, version: &str) -> String {
+    let needs_prefix = match loader {
+        ModLoader::Forge => true,
+        // 1.20.1 的 NeoForge 沿用 Forge 的版本格式
+        ModLoader::NeoForge => game_version == "1.20.1",
+        _ => false,
+    };
+    if needs_prefix && !version.starts_with(&format!("{}-", game_version)) {
+        format!("{}-{}", game_version, version)
+    } else {
+        version.to_string()
+    }
+}
+
+/// 读取 MultiMC 的 instance.cfg (无节的 INI，Prism 会额外写入 `[General]` 节头)
+fn parse_multimc_config(text: &str) -> Result<HashMap<String, String>> {
+    let flat: String = text
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('['))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    serde_ini::from_str(&flat).map_err(|e| anyhow!("解析 instance.cfg 失败: {}", e))
+}
+
+/// 解析单个 MultiMC / Prism 实例目录
+async fn parse_multimc_instance(dir: &Path) -> Result<ParsedInstance> {
+    let cfg = parse_multimc_config(&fs::read_to_string(dir.join(config::MULTIMC_CONFIG)).await?)?;
+    let pack: MmcPack = serde_json::from_str(&fs::read_to_string(dir.join(config::MULTIMC_PACK)).await?)?;
+
+    let component_version = |uid: &str| {
+        pack.components
+            .iter()
+            .find(|component| component.uid == uid)
+            .and_then(|component| component.version.clone().or(component.cached_version.clone()))
+    };
+    let game_version = component_version("net.minecraft").ok_or_else(|| anyhow!("mmc-pack.json 缺少 Minecraft 组件"))?;
+    let (loader, loader_version) = [
+        ("net.fabricmc.fabric-loader", ModLoader::Fabric),
+        ("org.quiltmc.quilt-loader", ModLoader::Quilt),
+        ("net.minecraftforge", ModLoader::Forge),
+        ("net.neoforged", ModLoader::NeoForge),
+    ]
+    .into_iter()
+    .find_map(|(uid, loader)| {
+        component_version(uid).map(|version| (loader, Some(forge_maven_version(loader, &game_version, &version))))
+    })
+    .unwrap_or((ModLoader::Vanilla, None));
+
+    let flag = |key: &str| cfg.get(key).is_some_and(|value| value == "true");
+    let number = |key: &str| cfg.get(key).and_then(|value| value.trim().parse::<u64>().ok());
+
+    let mut settings = ImportSettings {
+        play_time_secs: number("totalTimePlayed").unwrap_or(0),
+        last_played: number("lastLaunchTime")
+            .filter(|millis| *millis > 0)
+            .and_then(|millis| Utc.timestamp_millis_opt(millis as i64).single()),
+        ..Default::default()
+    };
+    if flag("OverrideJavaLocation") || flag("OverrideJava") {
+        settings.java_path = cfg.get("JavaPath").filter(|path| !path.is_empty()).cloned();
+    }
+    if flag("OverrideMemory") {
+        settings.max_memory_mb = number("MaxMemAlloc").map(|mb| mb as u32);
+        settings.min_memory_mb = number("MinMemAlloc").map(|mb| mb as u32);
+    }
+    if flag("OverrideJavaArgs") {
+        if let Some(args) = cfg.get("JvmArgs") {
+            split_java_args(args, &mut settings);
+        }
+    }
+    if flag("OverrideWindow") {
+        if let (Some(width), Some(height)) = (number("MinecraftWinWidth"), number("MinecraftWinHeight")) {
+            settings.resolution = Some(Resolution {
+                width: width as u32,
+                height: height as u32,
+            });
+        }
+    }
+    // 自定义图标保存在启动器的 icons 目录
+    if let (Some(key), Some(launcher_dir)) = (cfg.get("iconKey"), dir.parent().and_then(Path::parent)) {
+        let icon = launcher_dir.join("icons").join(format!("{}.png", key));
+        if fs::try_exists(&icon).await.unwrap_or(false) {
+            settings.icon_file = Some(icon);
+        }
+    }
+
+    let mut game_dir = dir.join(config::MULTIMC_GAME_DIRS[0]);
+    for name in config::MULTIMC_GAME_DIRS {
+        if fs::try_exists(dir.join(name)).await? {
+            game_dir = dir.join(name);
+            break;
+        }
+    }
+
+    let fallback_name = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+    Ok(ParsedInstance {
+        candidate: ImportCandidate {
+            source: ImportSource::MultiMc,
+            location: dir.to_string_lossy().to_string(),
+            profile_key: None,
+            name: cfg.get("name").cloned().unwrap_or(fallback_name),
+            game_version,
+            loader,
+            loader_version,
+            game_dir: game_dir.to_string_lossy().to_string(),
+        },
+        settings,
+        shared_game_dir: false,
+        excluded_entries: Vec::new(),
+    })
+}
+
+/// CurseForge 实例描述
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CurseForgeInstance {
+    name: String,
+    game_version: String,
+    base_mod_loader: Option<CurseForgeModLoader>,
+    /// 分配的内存 (MB)
+    allocated_memory: Option<u32>,
+    java_args_override: Option<String>,
+    last_played: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurseForgeModLoader {
+    /// 如 `forge-47.2.0`、`fabric-0.15.3-1.20.1`、`neoforge-20.4.80-beta`
+    name: String,
+}
+
+/// 解析 CurseForge 的加载器名称
+fn parse_curseforge_loader(name: &str, game_version: &str) -> (ModLoader, Option<String>) {
+    let Some((kind, version)) = name.split_once('-') else {
+        return (ModLoader::Vanilla, None);
+    };
+    let loader = match kind {
+        "forge" => ModLoader::Forge,
+        "neoforge" => ModLoader::NeoForge,
+        "fabric" => ModLoader::Fabric,
+        "quilt" => ModLoader::Quilt,
+        _ => return (ModLoader::Vanilla, None),
+    };
+    let version = version
+        .strip_suffix(&format!("-{}", game_version))
+        .unwrap_or(version);
+    (loader, Some(forge_maven_version(loader, game_version, version)))
+}
+
+/// 解析单个 CurseForge 实例目录
+async fn parse_curseforge_instance(dir: &Path) -> Result<ParsedInstance> {
+    let text = fs::read_to_string(dir.join(config::CURSEFORGE_INSTANCE)).await?;
+    let cf: CurseForgeInstance = serde_json::from_str(&text)?;
+    let (loader, loader_version) = cf
+        .base_mod_loader
+        .as_ref()
+        .map(|base| parse_curseforge_loader(&base.name, &cf.game_version))
+        .unwrap_or((ModLoader::Vanilla, None));
+
+    let mut settings = ImportSettings {
+        max_memory_mb: cf.allocated_memory,
+        last_played: cf
+            .last_played
+            .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+            .map(|time| time.with_timezone(&Utc))
+            .filter(|time| time.timestamp() > 0),
+        ..Default::default()
+    };
+    if let Some(args) = &cf.java_args_override {
+        split_java_args(args, &mut settings);
+    }
+
+    Ok(ParsedInstance {
+        candidate: ImportCandidate {
+            source: ImportSource::CurseForge,
+            location: dir.to_string_lossy().to_string(),
+            profile_key: None,
+            name: cf.name,
+            game_version: cf.game_version,
+            loader,
+            loader_version,
+            game_dir: dir.to_string_lossy().to_string(),
+        },
+        settings,
+        shared_game_dir: false,
+        excluded_entries: Vec::new(),
+    })
+}
+
+/// HMCL 的单版本设置 (hmclversion.cfg)
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+#[frb(ignore)]
+struct HmclVersionSetting {
+    /// 为 true 时使用全局设置，忽略本文件的其余字段
+    uses_global: bool,
+    java_dir: Option<String>,
+    java_args: Option<String>,
+    max_memory: Option<u32>,
+    min_memory: Option<u32>,
+    width: Option<u32>,
+    height: Option<u32>,
+    /// 0 为共享的 .minecraft，1 为版本目录 (版本隔离)，2 为自定义目录
+    game_dir_type: Option<u32>,
+    game_dir: Option<String>,
+}
+
+/// HMCL 写入版本 JSON 的组件列表
+#[derive(Debug, Default, Deserialize)]
+#[frb(ignore)]
+struct HmclVersionJson {
+    #[serde(default)]
+    patches: Vec<HmclPatch>,
+}
+
+#[derive(Debug, Deserialize)]
+#[frb(ignore)]
+struct HmclPatch {
+    id: String,
+    version: Option<String>,
+}
+
+/// 解析 HMCL 的单个版本
+async fn parse_hmcl_version(minecraft_dir: &Path, version_id: &str) -> Result<ParsedInstance> {
+    let version_dir = minecraft_dir.join("versions").join(version_id);
+    let json_path = minecraft::version_json_path(&minecraft_dir.join("versions"), version_id);
+    let text = fs::read_to_string(&json_path).await?;
+
+    // HMCL 合并后的版本 JSON 在 patches 中记录原版与加载器版本，没有时按依赖库识别
+    let patches = serde_json::from_str::<HmclVersionJson>(&text).unwrap_or_default().patches;
+    let patch_version = |id: &str| {
+        patches
+            .iter()
+            .find(|patch| patch.id == id)
+            .and_then(|patch| patch.version.clone())
+    };
+    let (game_version, loader, loader_version) = match patch_version("game") {
+        Some(game_version) => {
+            let loader = [
+                ("fabric", ModLoader::Fabric),
+                ("quilt", ModLoader::Quilt),
+                ("forge", ModLoader::Forge),
+                ("neoforge", ModLoader::NeoForge),
+            ]
+            .into_iter()
+            .find_map(|(id, loader)| patch_version(id).map(|version| (loader, version)));
+            match loader {
+                Some((loader, version)) => {
+                    let version = forge_maven_version(loader, &game_version, &version);
+                    (game_version, loader, Some(version))
+                }
+                None => (game_version, ModLoader::Vanilla, None),
+            }
+        }
+        None => resolve_vanilla_version(minecraft_dir, version_id).await?,
+    };
+
+    let setting: HmclVersionSetting = match fs::read_to_string(version_dir.join(config::HMCL_VERSION_SETTING)).await {
+        Ok(text) => serde_json::from_str(&text).unwrap_or_default(),
+        Err(_) => HmclVersionSetting::default(),
+    };
+    let mut settings = ImportSettings::default();
+    if !setting.uses_global {
+        settings.java_path = setting.java_dir.filter(|path| !path.is_empty());
+        settings.max_memory_mb = setting.max_memory.filter(|mb| *mb > 0);
+        settings.min_memory_mb = setting.min_memory.filter(|mb| *mb > 0);
+        if let Some(args) = &setting.java_args {
+            split_java_args(args, &mut settings);
+        }
+        if let (Some(width), Some(height)) = (setting.width, setting.height) {
+            if width > 0 && height > 0 {
+                settings.resolution = Some(Resolution { width, height });
+            }
+        }
+    }
+    let icon = version_dir.join(config::HMCL_ICON);
+    if fs::try_exists(&icon).await.unwrap_or(false) {
+        settings.icon_file = Some(icon);
+    }
+
+    let (game_dir, shared_game_dir) = match (setting.game_dir_type, setting.game_dir) {
+        (Some(1), _) => (version_dir.clone(), false),
+        (Some(2), Some(dir)) if !dir.is_empty() => (PathBuf::from(dir), false),
+        _ => (minecraft_dir.to_path_buf(), true),
+    };
+    Ok(ParsedInstance {
+        candidate: ImportCandidate {
+            source: ImportSource::Hmcl,
+            location: minecraft_dir.to_string_lossy().to_string(),
+            profile_key: Some(version_id.to_string()),
+            name: version_id.to_string(),
+            game_version,
+            loader,
+            loader_version,
+            game_dir: game_dir.to_string_lossy().to_string(),
+        },
+        settings,
+        shared_game_dir,
+        // 版本隔离目录同时存放版本 JSON、客户端 jar 与图标
+        excluded_entries: [".json", ".jar"]
+            .iter()
+            .map(|ext| format!("{}{}", version_id, ext))
+            .chain([config::HMCL_ICON.to_string()])
+            .collect(),
+    })
+}
+
+/// 解析目录下的全部实例，无法解析的子目录跳过
+async fn scan_instances_dir<F, Fut>(instances_dir: &Path, marker: &str, parse: F) -> Result<Vec<ImportCandidate>>
+where
+    F: Fn(PathBuf) -> Fut,
+    Fut: std::future::Future<Output = Result<ParsedInstance>>,
+{
+    let mut candidates = Vec::new();
+    let mut entries = fs::read_dir(instances_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        if !fs::try_exists(entry.path().join(marker)).await? {
+            continue;
+        }
+        if let Ok(parsed) = parse(entry.path()).await {
+            candidates.push(parsed.candidate);
+        }
+    }
+    candidates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(candidates)
+}
+
+/// 链接文件或目录，平台不支持时返回错误
+fn link_entry(source: &Path, target: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(source, target)
+    }
+    #[cfg(windows)]
+    {
+        if source.is_dir() {
+            std::os::windows::fs::symlink_dir(source, target)
+        } else {
+            std::os::windows::fs::symlink_file(source, target)
+        }
+    }
+}
+
+/// 将游戏文件复制或链接到实例目录
+fn transfer_game_files(
+    source: &Path,
+    target: &Path,
+    shared: bool,
+    excluded: &[String],
+    mode: ImportMode,
+) -> std::io::Result<()> {
+    let mut names = Vec::new();
+    if shared {
+        names.extend(config::SHARED_GAME_ENTRIES.iter().map(|name| name.to_string()));
+    } else {
+        for entry in std::fs::read_dir(source)? {
+            names.push(entry?.file_name().to_string_lossy().to_string());
+        }
+    }
+
+    for name in names {
+        if config::EXCLUDED_ENTRIES.contains(&name.as_str())
+            || excluded.contains(&name)
+            || name == instance::MANIFEST_FILE
+        {
+            continue;
+        }
+        let from = source.join(&name);
+        let to = target.join(&name);
+        if !from.exists() {
+            continue;
+        }
+        if mode == ImportMode::Link && link_entry(&from, &to).is_ok() {
+            continue;
+        }
+        if from.is_dir() {
+            instance::copy_dir_all(&from, &to)?;
+        } else {
+            std::fs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
+
+/// 重新解析导入来源 (扫描结果可能已过期)
+async fn reparse(candidate: &ImportCandidate) -> Result<ParsedInstance> {
+    let location = Path::new(&candidate.location);
+    match candidate.source {
+        ImportSource::VanillaLauncher => parse_vanilla_profiles(location)
+            .await?
+            .into_iter()
+            .find(|parsed| parsed.candidate.profile_key == candidate.profile_key)
+            .ok_or_else(|| anyhow!("启动器配置不存在: {:?}", candidate.profile_key)),
+        ImportSource::MultiMc => parse_multimc_instance(location).await,
+        ImportSource::CurseForge => parse_curseforge_instance(location).await,
+        ImportSource::Hmcl => {
+            let version_id = candidate
+                .profile_key
+                .as_deref()
+                .ok_or_else(|| anyhow!("HMCL 导入缺少版本 ID"))?;
+            parse_hmcl_version(location, version_id).await
+        }
+    }
+}
+
+/// 创建实例、写入设置并迁移游戏文件
+async fn create_imported(resource_dir: &str, parsed: ParsedInstance, mode: ImportMode) -> Result<InstanceManifest> {
+    let ParsedInstance {
+        candidate,
+        settings,
+        shared_game_dir,
+        excluded_entries,
+    } = parsed;
+    let created = instance::create_instance(
+        resource_dir.to_string(),
+        NewInstance {
+            name: candidate.name.clone(),
+            game_version: candidate.game_version.clone(),
+            loader: candidate.loader,
+            loader_version: candidate.loader_version.clone(),
+            icon: None,
+        },
+    )
+    .await?;
+
+    let result = async {
+        let target = PathBuf::from(&created.path);
+        let icon = match &settings.icon_file {
+            Some(icon) => {
+                let copied = target.join("icon.png");
+                fs::copy(icon, &copied).await?;
+                Some(copied.to_string_lossy().to_string())
+            }
+            None => settings.icon_data.clone(),
+        };
+
+        let source = PathBuf::from(&candidate.game_dir);
+        if fs::try_exists(&source).await? {
+            let target = target.clone();
+            tokio::task::spawn_blocking(move || {
+                transfer_game_files(&source, &target, shared_game_dir, &excluded_entries, mode)
+            })
+            .await??;
+        }
+
+        instance::update_manifest(Path::new(resource_dir), &created.id, |manifest| {
+            manifest.java_path = settings.java_path;
+            manifest.max_memory_mb = settings.max_memory_mb;
+            manifest.min_memory_mb = settings.min_memory_mb;
+            manifest.jvm_args = settings.jvm_args;
+            manifest.resolution = settings.resolution;
+            manifest.icon = icon;
+            manifest.play_time_secs = settings.play_time_secs;
+            manifest.last_played = settings.last_played;
+        })
+        .await
+    }
+    .await;
+
+    if result.is_err() {
+        instance::delete_instance(resource_dir.to_string(), created.id).await.ok();
+    }
+    result
+}
+
+/// 列出官方启动器 (`.minecraft/launcher_profiles.json`) 中可导入的配置
+pub async fn scan_vanilla_launcher(minecraft_dir: String) -> Result<Vec<ImportCandidate>> {
+    Ok(parse_vanilla_profiles(Path::new(&minecraft_dir))
+        .await?
+        .into_iter()
+        .map(|parsed| parsed.candidate)
+        .collect())
+}
+
+/// 列出 MultiMC / Prism 实例目录 (`<启动器>/instances`) 中可导入的实例
+pub async fn scan_multimc_instances(instances_dir: String) -> Result<Vec<ImportCandidate>> {
+    scan_instances_dir(Path::new(&instances_dir), config::MULTIMC_PACK, |dir| async move {
+        parse_multimc_instance(&dir).await
+    })
+    .await
+}
+
+/// 列出 CurseForge 实例目录 (`curseforge/minecraft/Instances`) 中可导入的实例
+pub async fn scan_curseforge_instances(instances_dir: String) -> Result<Vec<ImportCandidate>> {
+    scan_instances_dir(Path::new(&instances_dir), config::CURSEFORGE_INSTANCE, |dir| async move {
+        parse_curseforge_instance(&dir).await
+    })
+    .await
+}
+
+/// 列出 HMCL 使用的 .minecraft 目录中可导入的版本
+pub async fn scan_hmcl_versions(minecraft_dir: String) -> Result<Vec<ImportCandidate>> {
+    let minecraft_dir = Path::new(&minecraft_dir);
+    let mut candidates = Vec::new();
+    let mut entries = fs::read_dir(minecraft_dir.join("versions")).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let version_id = entry.file_name().to_string_lossy().to_string();
+        if let Ok(parsed) = parse_hmcl_version(minecraft_dir, &version_id).await {
+            candidates.push(parsed.candidate);
+        }
+    }
+    candidates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(candidates)
+}
+
+/// 导入实例：按原设置创建新实例，并复制或链接游戏文件 (mods、config、saves 等)。
+/// 游戏版本与加载器需另行通过 `install_loader` 安装
+pub async fn import_instance(
+    resource_dir: String,
+    candidate: ImportCandidate,
+    mode: ImportMode,
+) -> Result<InstanceManifest> {
+    let parsed = reparse(&candidate).await?;
+    create_imported(&resource_dir, parsed, mode).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(path: &str) -> String {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/import")
+            .join(path)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// 实例目录下的条目名 (不含清单)
+    fn entries(dir: &str) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name != instance::MANIFEST_FILE)
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn vanilla_launcher_profiles() {
+        let candidates = scan_vanilla_launcher(fixture("vanilla/.minecraft")).await.unwrap();
+        let summary: Vec<_> = candidates
+            .iter()
+            .map(|c| (c.name.as_str(), c.game_version.as_str(), c.loader, c.loader_version.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("1.19.4", "1.19.4", ModLoader::Vanilla, None),
+                ("Fabric", "1.20.1", ModLoader::Fabric, Some("0.15.3")),
+            ]
+        );
+
+        let resource_dir = tempfile::tempdir().unwrap();
+        let fabric = candidates.into_iter().find(|c| c.name == "Fabric").unwrap();
+        let manifest = import_instance(resource_dir.path().to_string_lossy().to_string(), fabric, ImportMode::Copy)
+            .await
+            .unwrap();
+        assert_eq!(manifest.max_memory_mb, Some(4096));
+        assert_eq!(manifest.min_memory_mb, Some(1024));
+        assert_eq!(manifest.jvm_args, ["-XX:+UseG1GC"]);
+        assert_eq!(manifest.resolution.map(|r| (r.width, r.height)), Some((1280, 720)));
+        assert_eq!(manifest.icon, None);
+        assert!(manifest.last_played.is_some());
+        // 共享的 .minecraft 只复制游戏内容
+        assert_eq!(entries(&manifest.path), ["mods", "options.txt", "saves"]);
+    }
+
+    #[tokio::test]
+    async fn prism_instance() {
+        let candidates = scan_multimc_instances(fixture("prism/instances")).await.unwrap();
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert_eq!(candidate.name, "Modded Forge");
+        assert_eq!(candidate.loader, ModLoader::Forge);
+        assert_eq!(candidate.loader_version.as_deref(), Some("1.20.1-47.2.0"));
+
+        let resource_dir = tempfile::tempdir().unwrap();
+        let manifest = import_instance(
+            resource_dir.path().to_string_lossy().to_string(),
+            candidate.clone(),
+            ImportMode::Copy,
+        )
+        .await
+        .unwrap();
+        assert_eq!(manifest.max_memory_mb, Some(6144));
+        assert_eq!(manifest.min_memory_mb, Some(2048));
+        assert_eq!(manifest.jvm_args, ["-XX:+UseG1GC", "-Dfml.readTimeout=180"]);
+        assert_eq!(manifest.play_time_secs, 7200);
+        assert_eq!(manifest.last_played.map(|time| time.timestamp()), Some(1704067200));
+        assert!(manifest.icon.as_deref().is_some_and(|icon| icon.ends_with("icon.png")));
+        assert_eq!(entries(&manifest.path), ["icon.png", "mods"]);
+    }
+
+    #[tokio::test]
+    async fn multimc_instance() {
+        let candidates = scan_multimc_instances(fixture("multimc/instances")).await.unwrap();
+        let candidate = &candidates[0];
+        assert_eq!(
+            (candidate.name.as_str(), candidate.game_version.as_str(), candidate.loader),
+            ("Classic Fabric", "1.18.2", ModLoader::Fabric)
+        );
+        assert!(candidate.game_dir.ends_with("minecraft"));
+
+        let resource_dir = tempfile::tempdir().unwrap();
+        let manifest = import_instance(
+            resource_dir.path().to_string_lossy().to_string(),
+            candidate.clone(),
+            ImportMode::Copy,
+        )
+        .await
+        .unwrap();
+        assert_eq!(manifest.java_path.as_deref(), Some("/usr/lib/jvm/java-17/bin/java"));
+        assert_eq!(manifest.resolution.map(|r| (r.width, r.height)), Some((1024, 768)));
+        assert_eq!(manifest.max_memory_mb, None);
+        assert_eq!(entries(&manifest.path), ["options.txt"]);
+    }
+
+    #[tokio::test]
+    async fn curseforge_instance() {
+        let candidates = scan_curseforge_instances(fixture("curseforge/Instances")).await.unwrap();
+        let candidate = &candidates[0];
+        assert_eq!(candidate.name, "All the Pack");
+        assert_eq!(candidate.loader, ModLoader::NeoForge);
+        assert_eq!(candidate.loader_version.as_deref(), Some("20.4.80-beta"));
+
+        let resource_dir = tempfile::tempdir().unwrap();
+        let manifest = import_instance(
+            resource_dir.path().to_string_lossy().to_string(),
+            candidate.clone(),
+            ImportMode::Copy,
+        )
+        .await
+        .unwrap();
+        assert_eq!(manifest.max_memory_mb, Some(8192));
+        assert_eq!(manifest.jvm_args, ["-XX:+UseZGC"]);
+        assert_eq!(entries(&manifest.path), ["mods"]);
+    }
+
+    #[tokio::test]
+    async fn hmcl_versions() {
+        let candidates = scan_hmcl_versions(fixture("hmcl/.minecraft")).await.unwrap();
+        let summary: Vec<_> = candidates
+            .iter()
+            .map(|c| (c.name.as_str(), c.game_version.as_str(), c.loader, c.loader_version.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("1.20.1", "1.20.1", ModLoader::Vanilla, None),
+                ("Fabric-1.20.1", "1.20.1", ModLoader::Fabric, Some("0.15.3")),
+            ]
+        );
+
+        let resource_dir = tempfile::tempdir().unwrap();
+        let isolated = candidates[1].clone();
+        assert!(isolated.game_dir.ends_with("Fabric-1.20.1"));
+        let manifest = import_instance(resource_dir.path().to_string_lossy().to_string(), isolated, ImportMode::Copy)
+            .await
+            .unwrap();
+        assert_eq!(manifest.max_memory_mb, Some(4096));
+        assert_eq!(manifest.min_memory_mb, Some(512));
+        assert_eq!(manifest.jvm_args, ["-XX:+UseZGC"]);
+        assert_eq!(manifest.java_path, None);
+        assert_eq!(manifest.resolution.map(|r| (r.width, r.height)), Some((854, 480)));
+        // 版本 JSON、jar 与 HMCL 设置不属于游戏文件
+        assert_eq!(entries(&manifest.path), ["icon.png", "mods"]);
+
+        // 未开启版本隔离的版本使用共享的 .minecraft
+        let shared = candidates[0].clone();
+        let manifest = import_instance(resource_dir.path().to_string_lossy().to_string(), shared, ImportMode::Copy)
+            .await
+            .unwrap();
+        assert_eq!(entries(&manifest.path), ["mods"]);
+    }
+}
@@ -4,14 +4,16 @@ use serde::{Deserialize, Serialize};
 use serde_json;
 use std::path::{Path, PathBuf};
 use tokio::fs;
+use tokio::sync::mpsc;
 use anyhow::{anyhow, Result};
 use zip::ZipArchive;
-use std::io::{Cursor, Read};
+use std::io::Cursor;
 use tokio::process::Command;
 use futures::StreamExt;
 use std::sync::Arc;
 use sysinfo::System;
 
+use crate::archive;
 use crate::config::AZUL_API_BASE_URL;
 
 /// 配置常量
@@ -199,75 +201,50 @@ async fn download_file(
     Ok(bytes)
 }
 
-/// 解压ZIP文件
+/// 解压ZIP文件，返回压缩包的根目录名
 async fn extract_zip(
-    zip_data: &[u8],
+    zip_data: Vec<u8>,
     extract_to: &Path,
     on_progress: Option<&Arc<impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync>>,
 ) -> Result<String> {
-    use std::io::Read;
-    
-    // 先同步解析ZIP文件内容
-    let cursor = Cursor::new(zip_data);
-    let mut archive = ZipArchive::new(cursor)?;
-    
-    let mut root_dir_name = None;
-    let mut file_entries = Vec::new();
-    let total_files = archive.len();
-    
-    // 获取根目录名和所有文件信息
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)?;
-        let file_name = file.name().to_string();
-        let is_dir = file.is_dir();
-        
-        if is_dir && root_dir_name.is_none() {
-            let parts: Vec<&str> = file_name.split('/').collect();
-            if !parts.is_empty() && !parts[0].is_empty() {
-                root_dir_name = Some(parts[0].to_string());
+    let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+    let extract_to = extract_to.to_path_buf();
+    let extractor = tokio::task::spawn_blocking(move || -> Result<String> {
+        let mut archive = ZipArchive::new(Cursor::new(zip_data))?;
+
+        // 根目录名取第一个目录条目的首段
+        let mut root_dir_name = None;
+        for i in 0..archive.len() {
+            let entry = archive.by_index(i)?;
+            if entry.is_dir() {
+                root_dir_name = entry
+                    .enclosed_name()
+                    .and_then(|name| name.components().next())
+                    .map(|root| root.as_os_str().to_string_lossy().to_string());
+                break;
             }
         }
-        
-        if is_dir {
-            file_entries.push((file_name, None, true));
-        } else {
-            let mut buffer = Vec::new();
-            file.read_to_end(&mut buffer)?;
-            file_entries.push((file_name, Some(buffer), false));
-        }
-    }
-    
-    // 异步创建文件和目录
-    for (i, (file_name, data, is_dir)) in file_entries.iter().enumerate() {
-        let file_path = extract_to.join(file_name);
-        
-        if *is_dir {
-            fs::create_dir_all(&file_path).await?;
-        } else {
-            if let Some(parent) = file_path.parent() {
-                fs::create_dir_all(parent).await?;
-            }
-            
-            if let Some(buffer) = data {
-                fs::write(&file_path, buffer).await?;
+
+        archive::extract_zip(&mut archive, &extract_to, |name| Some(name.to_path_buf()), |done, total| {
+            // 每处理指定数量文件报告一次进度
+            if done % config::FILE_BATCH_SIZE == 0 || done == total {
+                progress_tx.send((done, total)).ok();
             }
-        }
-        
+        })?;
+        Ok(root_dir_name.unwrap_or_else(|| "unknown".to_string()))
+    });
+
+    while let Some((done, total)) = progress_rx.recv().await {
         // 更新解压进度 (使用配置常量)
         if let Some(callback) = on_progress {
-            let progress = (i + 1) as f64 / total_files as f64;
+            let progress = done as f64 / total as f64;
             let progress_range = config::PROGRESS_EXTRACT_END - config::PROGRESS_EXTRACT_START;
             let overall_progress = config::PROGRESS_EXTRACT_START + (progress * progress_range);
-            callback(overall_progress, format!("解压中... {}/{} 文件", i + 1, total_files)).await;
-        }
-        
-        // 每处理指定数量文件就让出控制权
-        if (i + 1) % config::FILE_BATCH_SIZE == 0 {
-            tokio::task::yield_now().await;
+            callback(overall_progress, format!("解压中... {}/{} 文件", done, total)).await;
         }
     }
-    
-    Ok(root_dir_name.unwrap_or_else(|| "unknown".to_string()))
+
+    extractor.await?
 }
 
 /// 获取默认的 Java 安装目录
@@ -317,7 +294,7 @@ async fn download_and_extract_java(
     
     // 解压 ZIP 文件
     on_progress(0.85, "正在解析压缩文件...".to_string()).await;
-    let root_dir_name = extract_zip(&file_bytes, java_versions_dir, Some(on_progress)).await?;
+    let root_dir_name = extract_zip(file_bytes, java_versions_dir, Some(on_progress)).await?;
     
     Ok(root_dir_name)
 }
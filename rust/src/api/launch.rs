@@ -0,0 +1,526 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::api::game_log;
+use crate::api::java_download::{get_max_memory, JavaRuntimeVersion};
+use crate::minecraft::{self, VersionInfo};
+
+/// 启动参数配置常量
+mod config {
+    /// 启动器名称 (${launcher_name})
+    pub const LAUNCHER_NAME: &str = "AML";
+    /// 启动器版本 (${launcher_version})
+    pub const LAUNCHER_VERSION: &str = env!("CARGO_PKG_VERSION");
+    /// 默认最大内存上限 (MB)
+    pub const DEFAULT_MAX_MEMORY_CAP_MB: u32 = 4096;
+    /// 最小内存 (MB)
+    pub const MIN_MEMORY_MB: u32 = 512;
+}
+
+/// 正则表达式缓存
+mod regex_cache {
+    use regex::Regex;
+    use std::sync::LazyLock;
+
+    /// `${...}` 占位符
+    pub static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"\$\{([^}]+)\}").unwrap()
+    });
+}
+
+/// 启动所用的账户信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub username: String,
+    /// 无连字符的 UUID
+    pub uuid: String,
+    pub access_token: String,
+    /// msa / legacy / mojang
+    pub user_type: String,
+}
+
+/// 游戏窗口分辨率
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Resolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 启动选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchOptions {
+    /// 版本 JSON 路径 (versions/<id>/<id>.json，inheritsFrom 会自动展开)
+    pub version_json_path: String,
+    /// .minecraft 根目录 (包含 libraries / assets / versions)
+    pub minecraft_dir: String,
+    /// 实例目录 (${game_directory})
+    pub game_dir: String,
+    /// 本次启动使用的原生库目录
+    pub natives_dir: String,
+    pub java: JavaRuntimeVersion,
+    pub account: Account,
+    /// 最大内存 (MB)，为空时按系统内存推算
+    pub max_memory_mb: Option<u32>,
+    /// 最小内存 (MB)
+    pub min_memory_mb: Option<u32>,
+    pub resolution: Option<Resolution>,
+    /// 用户自定义 JVM 参数
+    pub extra_jvm_args: Vec<String>,
+    /// 用户自定义游戏参数
+    pub extra_game_args: Vec<String>,
+}
+
+/// 生成的启动命令
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchCommand {
+    pub java_path: String,
+    /// 不含 Java 可执行文件本身的参数列表
+    pub args: Vec<String>,
+    pub working_dir: String,
+    /// 本次启动使用的原生库目录，进程退出后清理
+    pub natives_dir: Option<String>,
+}
+
+/// 根据系统内存计算默认最大内存 (MB)
+pub async fn default_max_memory_mb() -> u32 {
+    let total_mb = (get_max_memory().await / 1024).max(0) as u32;
+    (total_mb / 4).clamp(config::MIN_MEMORY_MB, config::DEFAULT_MAX_MEMORY_CAP_MB)
+}
+
+/// classpath 分隔符
+pub(crate) fn classpath_separator() -> &'static str {
+    if cfg!(target_os = "windows") {
+        ";"
+    } else {
+        ":"
+    }
+}
+
+/// 构建 classpath：按版本 JSON 中的顺序加入库 (同一构件只保留第一次出现)，最后加入客户端 jar
+pub(crate) fn build_classpath(
+    version: &VersionInfo,
+    libraries_dir: &Path,
+    client_jar: &Path,
+) -> Result<Vec<PathBuf>> {
+    let mut seen = HashSet::new();
+    let mut classpath = Vec::new();
+
+    for library in &version.libraries {
+        if !library.include_in_classpath || library.is_native_only() || !library.is_allowed() {
+            continue;
+        }
+        if !seen.insert(library.maven_key()) {
+            continue;
+        }
+        classpath.push(libraries_dir.join(library.artifact_path()?));
+    }
+
+    classpath.push(client_jar.to_path_buf());
+    Ok(classpath)
+}
+
+/// 替换字符串中的 `${...}` 占位符，未知占位符保持原样
+pub(crate) fn substitute_placeholders(value: &str, variables: &HashMap<&str, String>) -> String {
+    regex_cache::PLACEHOLDER_REGEX
+        .replace_all(value, |captures: &regex::Captures| {
+            variables
+                .get(&captures[1])
+                .cloned()
+                .unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+/// 生成完整的启动参数
+pub(crate) fn build_launch_args(
+    version: &VersionInfo,
+    options: &LaunchOptions,
+    max_memory_mb: u32,
+    logging_argument: Option<&str>,
+) -> Result<Vec<String>> {
+    let minecraft_dir = Path::new(&options.minecraft_dir);
+    let libraries_dir = minecraft_dir.join("libraries");
+    let assets_dir = minecraft_dir.join("assets");
+    let jar_id = version.client_jar_id();
+    let client_jar = minecraft_dir
+        .join("versions")
+        .join(jar_id)
+        .join(format!("{}.jar", jar_id));
+
+    if version.main_class.is_empty() {
+        return Err(anyhow!("版本 {} 缺少主类", version.id));
+    }
+
+    let classpath = build_classpath(version, &libraries_dir, &client_jar)?
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join(classpath_separator());
+
+    let assets_index_name = version
+        .asset_index
+        .as_ref()
+        .map(|index| index.id.clone())
+        .or_else(|| version.assets.clone())
+        .unwrap_or_else(|| "legacy".to_string());
+    // 1.7.2 之前的版本从 virtual/legacy 读取资源
+    let game_assets = if assets_index_name == "legacy" || assets_index_name == "pre-1.6" {
+        assets_dir.join("virtual").join("legacy")
+    } else {
+        assets_dir.clone()
+    };
+
+    let account = &options.account;
+    let mut variables: HashMap<&str, String> = HashMap::new();
+    variables.insert("auth_player_name", account.username.clone());
+    variables.insert("auth_uuid", account.uuid.clone());
+    variables.insert("auth_access_token", account.access_token.clone());
+    variables.insert("auth_session", format!("token:{}:{}", account.access_token, account.uuid));
+    variables.insert("auth_xuid", "0".to_string());
+    variables.insert("clientid", "0".to_string());
+    variables.insert("user_type", account.user_type.clone());
+    variables.insert("user_properties", "{}".to_string());
+    variables.insert("version_name", version.id.clone());
+    variables.insert(
+        "version_type",
+        version.version_type.clone().unwrap_or_else(|| "release".to_string()),
+    );
+    variables.insert("game_directory", options.game_dir.clone());
+    variables.insert("assets_root", assets_dir.to_string_lossy().to_string());
+    variables.insert("game_assets", game_assets.to_string_lossy().to_string());
+    variables.insert("assets_index_name", assets_index_name);
+    variables.insert("natives_directory", options.natives_dir.clone());
+    variables.insert("library_directory", libraries_dir.to_string_lossy().to_string());
+    variables.insert("classpath_separator", classpath_separator().to_string());
+    variables.insert("classpath", classpath);
+    variables.insert("launcher_name", config::LAUNCHER_NAME.to_string());
+    variables.insert("launcher_version", config::LAUNCHER_VERSION.to_string());
+    if let Some(resolution) = options.resolution {
+        variables.insert("resolution_width", resolution.width.to_string());
+        variables.insert("resolution_height", resolution.height.to_string());
+    }
+
+    let mut features = HashMap::new();
+    features.insert("has_custom_resolution".to_string(), options.resolution.is_some());
+    features.insert("is_demo_user".to_string(), false);
+
+    // JVM 参数：内存 -> 版本 JSON -> 日志配置 -> 用户自定义
+    let min_memory_mb = options
+        .min_memory_mb
+        .unwrap_or(config::MIN_MEMORY_MB)
+        .min(max_memory_mb);
+    let mut jvm_args = vec![
+        format!("-Xms{}M", min_memory_mb),
+        format!("-Xmx{}M", max_memory_mb),
+    ];
+    match &version.arguments {
+        Some(arguments) if !arguments.jvm.is_empty() => {
+            for argument in &arguments.jvm {
+                jvm_args.extend(argument.resolve(&features));
+            }
+        }
+        // 旧版本 JSON 不包含 JVM 参数，使用官方启动器的默认值
+        _ => {
+            jvm_args.push("-Djava.library.path=${natives_directory}".to_string());
+            jvm_args.push("-cp".to_string());
+            jvm_args.push("${classpath}".to_string());
+        }
+    }
+    if let Some(argument) = logging_argument {
+        jvm_args.push(argument.to_string());
+    }
+    jvm_args.extend(options.extra_jvm_args.iter().cloned());
+
+    // 游戏参数：旧版 minecraftArguments (加载器可覆盖) 与新版 arguments.game
+    let mut game_args = Vec::new();
+    if let Some(legacy) = &version.minecraft_arguments {
+        game_args.extend(legacy.split_whitespace().map(str::to_string));
+        if options.resolution.is_some() {
+            game_args.extend([
+                "--width".to_string(),
+                "${resolution_width}".to_string(),
+                "--height".to_string(),
+                "${resolution_height}".to_string(),
+            ]);
+        }
+    }
+    if let Some(arguments) = &version.arguments {
+        for argument in &arguments.game {
+            game_args.extend(argument.resolve(&features));
+        }
+    }
+    game_args.extend(options.extra_game_args.iter().cloned());
+
+    let mut args = Vec::with_capacity(jvm_args.len() + game_args.len() + 1);
+    args.extend(jvm_args.iter().map(|arg| substitute_placeholders(arg, &variables)));
+    args.push(version.main_class.clone());
+    args.extend(game_args.iter().map(|arg| substitute_placeholders(arg, &variables)));
+    Ok(args)
+}
+
+/// 生成启动命令
+pub async fn build_launch_command(options: LaunchOptions) -> Result<LaunchCommand> {
+    let version = minecraft::read_resolved_version_info(Path::new(&options.version_json_path)).await?;
+
+    if let Some(required) = &version.java_version {
+        if options.java.major_version < required.major_version {
+            return Err(anyhow!(
+                "版本 {} 需要 Java {}，当前为 Java {}",
+                version.id,
+                required.major_version,
+                options.java.major_version
+            ));
+        }
+    }
+
+    let max_memory_mb = match options.max_memory_mb {
+        Some(memory) => memory,
+        None => default_max_memory_mb().await,
+    };
+    // 日志配置让游戏输出 log4j XML 事件，便于解析为结构化日志；准备失败时退回纯文本日志，不影响启动
+    let logging_argument = game_log::prepare_logging_config(&version, Path::new(&options.minecraft_dir))
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("无法准备版本 {} 的日志配置，将不使用 log4j 配置启动: {}", version.id, e);
+            None
+        });
+    let args = build_launch_args(&version, &options, max_memory_mb, logging_argument.as_deref())?;
+
+    Ok(LaunchCommand {
+        java_path: options.java.path.clone(),
+        args,
+        working_dir: options.game_dir.clone(),
+        natives_dir: Some(options.natives_dir.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY: &str = include_str!("../../tests/fixtures/versions/legacy.json");
+    const MODERN: &str = include_str!("../../tests/fixtures/versions/modern.json");
+    const FABRIC_CHILD: &str = include_str!("../../tests/fixtures/versions/fabric-child.json");
+
+    fn options(minecraft_dir: &Path, resolution: Option<Resolution>) -> LaunchOptions {
+        LaunchOptions {
+            version_json_path: String::new(),
+            minecraft_dir: minecraft_dir.to_string_lossy().to_string(),
+            game_dir: "/instances/test".to_string(),
+            natives_dir: "/natives".to_string(),
+            java: JavaRuntimeVersion {
+                version: "21.0.1".to_string(),
+                path: "java".to_string(),
+                major_version: 21,
+            },
+            account: Account {
+                username: "Steve".to_string(),
+                uuid: "0123456789abcdef0123456789abcdef".to_string(),
+                access_token: "token".to_string(),
+                user_type: "msa".to_string(),
+            },
+            max_memory_mb: None,
+            min_memory_mb: Some(1024),
+            resolution,
+            extra_jvm_args: vec!["-XX:+UseG1GC".to_string()],
+            extra_game_args: vec!["--quickPlayPath".to_string()],
+        }
+    }
+
+    fn join(base: &Path, relative: &str) -> String {
+        relative
+            .split('/')
+            .fold(base.to_path_buf(), |path, segment| path.join(segment))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    fn classpath(base: &Path, entries: &[&str]) -> String {
+        entries
+            .iter()
+            .map(|entry| join(base, entry))
+            .collect::<Vec<_>>()
+            .join(classpath_separator())
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn legacy_minecraft_arguments() {
+        let minecraft_dir = Path::new("/mc");
+        let version: VersionInfo = serde_json::from_str(LEGACY).unwrap();
+        let resolution = Resolution { width: 854, height: 480 };
+        let args = build_launch_args(&version, &options(minecraft_dir, Some(resolution)), 2048, None).unwrap();
+
+        let mut expected = strings(&["-Xms1024M", "-Xmx2048M", "-Djava.library.path=/natives", "-cp"]);
+        // 原生库不进 classpath，guava 只保留第一次出现的版本
+        expected.push(classpath(
+            minecraft_dir,
+            &[
+                "libraries/com/mojang/netty/1.6/netty-1.6.jar",
+                "libraries/com/google/guava/guava/17.0/guava-17.0.jar",
+                "versions/1.8.9/1.8.9.jar",
+            ],
+        ));
+        expected.extend(strings(&["-XX:+UseG1GC", "net.minecraft.client.main.Main"]));
+        expected.extend(strings(&[
+            "--username",
+            "Steve",
+            "--version",
+            "1.8.9",
+            "--gameDir",
+            "/instances/test",
+            "--assetsDir",
+        ]));
+        expected.push(join(minecraft_dir, "assets"));
+        expected.extend(strings(&[
+            "--assetIndex",
+            "1.8",
+            "--uuid",
+            "0123456789abcdef0123456789abcdef",
+            "--accessToken",
+            "token",
+            "--userProperties",
+            "{}",
+            "--userType",
+            "msa",
+            "--width",
+            "854",
+            "--height",
+            "480",
+            "--quickPlayPath",
+        ]));
+        assert_eq!(args, expected);
+    }
+
+    #[test]
+    fn modern_arguments_with_rules() {
+        let minecraft_dir = Path::new("/mc");
+        let version: VersionInfo = serde_json::from_str(MODERN).unwrap();
+        let args = build_launch_args(
+            &version,
+            &options(minecraft_dir, None),
+            4096,
+            Some("-Dlog4j.configurationFile=/mc/log.xml"),
+        )
+        .unwrap();
+
+        let is_osx = minecraft::current_os_name() == "osx";
+        let mut expected = strings(&["-Xms1024M", "-Xmx4096M"]);
+        if is_osx {
+            expected.push("-XstartOnFirstThread".to_string());
+        }
+        expected.extend(strings(&["-Djava.library.path=/natives", "-Dminecraft.launcher.brand=AML", "-cp"]));
+        let mut entries = vec!["libraries/com/mojang/logging/1.1.1/logging-1.1.1.jar"];
+        if is_osx {
+            entries.push("libraries/ca/weblite/java-objc-bridge/1.1/java-objc-bridge-1.1.jar");
+        }
+        entries.push("versions/1.20.1/1.20.1.jar");
+        expected.push(classpath(minecraft_dir, &entries));
+        expected.extend(strings(&[
+            "-Dlog4j.configurationFile=/mc/log.xml",
+            "-XX:+UseG1GC",
+            "net.minecraft.client.main.Main",
+            "--username",
+            "Steve",
+            "--version",
+            "1.20.1",
+            "--gameDir",
+            "/instances/test",
+            "--assetsDir",
+        ]));
+        expected.push(join(minecraft_dir, "assets"));
+        // 未设置分辨率且不是试玩账户：--demo 与 --width/--height 都被规则排除
+        expected.extend(strings(&["--assetIndex", "5", "--accessToken", "token", "--quickPlayPath"]));
+        assert_eq!(args, expected);
+    }
+
+    #[tokio::test]
+    async fn inherits_from_child() {
+        let root = tempfile::tempdir().unwrap();
+        let versions_dir = root.path().join("versions");
+        for (id, json) in [("1.20.1", MODERN), ("fabric-loader-0.15.0-1.20.1", FABRIC_CHILD)] {
+            let path = minecraft::version_json_path(&versions_dir, id);
+            tokio::fs::create_dir_all(path.parent().unwrap()).await.unwrap();
+            tokio::fs::write(&path, json).await.unwrap();
+        }
+        let child_path = minecraft::version_json_path(&versions_dir, "fabric-loader-0.15.0-1.20.1");
+        let version = minecraft::read_resolved_version_info(&child_path).await.unwrap();
+        let resolution = Resolution { width: 1280, height: 720 };
+        let args = build_launch_args(&version, &options(root.path(), Some(resolution)), 4096, None).unwrap();
+
+        let is_osx = minecraft::current_os_name() == "osx";
+        let mut expected = strings(&["-Xms1024M", "-Xmx4096M"]);
+        if is_osx {
+            expected.push("-XstartOnFirstThread".to_string());
+        }
+        expected.extend(strings(&["-Djava.library.path=/natives", "-Dminecraft.launcher.brand=AML", "-cp"]));
+        // 子版本的库在前，同名构件以子版本为准，客户端 jar 来自父版本
+        let mut entries = vec![
+            "libraries/net/fabricmc/fabric-loader/0.15.0/fabric-loader-0.15.0.jar",
+            "libraries/com/mojang/logging/1.0.0/logging-1.0.0.jar",
+        ];
+        if is_osx {
+            entries.push("libraries/ca/weblite/java-objc-bridge/1.1/java-objc-bridge-1.1.jar");
+        }
+        entries.push("versions/1.20.1/1.20.1.jar");
+        expected.push(classpath(root.path(), &entries));
+        expected.extend(strings(&[
+            "-DFabricMcEmu= net.minecraft.client.main.Main ",
+            "-XX:+UseG1GC",
+            "net.fabricmc.loader.impl.launch.knot.KnotClient",
+            "--username",
+            "Steve",
+            "--version",
+            "fabric-loader-0.15.0-1.20.1",
+            "--gameDir",
+            "/instances/test",
+            "--assetsDir",
+        ]));
+        expected.push(join(root.path(), "assets"));
+        expected.extend(strings(&[
+            "--assetIndex",
+            "5",
+            "--accessToken",
+            "token",
+            "--width",
+            "1280",
+            "--height",
+            "720",
+            "--quickPlayPath",
+        ]));
+        assert_eq!(args, expected);
+    }
+
+    #[tokio::test]
+    async fn launches_without_unavailable_logging_config() {
+        let root = tempfile::tempdir().unwrap();
+        let mut version: serde_json::Value = serde_json::from_str(MODERN).unwrap();
+        version["logging"] = serde_json::json!({
+            "client": {
+                "argument": "-Dlog4j.configurationFile=${path}",
+                "file": {
+                    "id": "client-1.12.xml",
+                    "sha1": "0000000000000000000000000000000000000000",
+                    "size": 0,
+                    "url": "http://127.0.0.1:9/client-1.12.xml"
+                },
+                "type": "log4j2-xml"
+            }
+        });
+        let path = minecraft::version_json_path(&root.path().join("versions"), "1.20.1");
+        tokio::fs::create_dir_all(path.parent().unwrap()).await.unwrap();
+        tokio::fs::write(&path, version.to_string()).await.unwrap();
+
+        let mut options = options(root.path(), None);
+        options.version_json_path = path.to_string_lossy().to_string();
+        options.max_memory_mb = Some(2048);
+        let command = build_launch_command(options).await.unwrap();
+        assert!(command.args.iter().all(|arg| !arg.starts_with("-Dlog4j.configurationFile")));
+        assert!(command.args.contains(&"net.minecraft.client.main.Main".to_string()));
+    }
+}
@@ -0,0 +1,236 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::frb;
+use futures::StreamExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::api::java_download::ProgressCallback;
+use crate::download;
+use crate::minecraft::{self, Library, LibraryDownload};
+
+/// 配置常量
+mod config {
+    /// 同时下载的依赖库数量
+    pub const CONCURRENT_DOWNLOADS: usize = 8;
+    /// 未声明仓库地址时使用的默认 Maven 仓库
+    pub const DEFAULT_MAVEN_URL: &str = "https://libraries.minecraft.net/";
+}
+
+/// 严格校验：仓库没有提供 `.sha1` 时拒绝下载，而不是不经校验地保存
+static STRICT_VERIFICATION: AtomicBool = AtomicBool::new(false);
+
+/// 是否开启严格校验
+pub(crate) fn is_strict_verification() -> bool {
+    STRICT_VERIFICATION.load(Ordering::Relaxed)
+}
+
+/// 单个依赖库下载任务
+#[derive(Debug, Clone)]
+#[frb(ignore)]
+pub(crate) struct LibraryTask {
+    pub url: String,
+    pub path: PathBuf,
+    /// 已知的 SHA-1，为空时尝试读取 Maven 仓库中的 `.sha1` 文件
+    pub sha1: Option<String>,
+}
+
+/// 由下载信息生成任务
+fn task_from_download(download: &LibraryDownload, libraries_dir: &Path, fallback: PathBuf) -> Option<LibraryTask> {
+    if download.url.is_empty() {
+        return None;
+    }
+    let relative = download.path.as_ref().map(PathBuf::from).unwrap_or(fallback);
+    Some(LibraryTask {
+        url: download.url.clone(),
+        path: libraries_dir.join(relative),
+        sha1: (!download.sha1.is_empty()).then(|| download.sha1.clone()),
+    })
+}
+
+/// 计算当前系统需要下载的依赖库
+pub(crate) fn library_tasks(libraries: &[Library], libraries_dir: &Path) -> Result<Vec<LibraryTask>> {
+    let mut tasks = Vec::new();
+
+    for library in libraries {
+        if !library.downloadable || !library.is_allowed() {
+            continue;
+        }
+        let downloads = library.downloads.as_ref();
+
+        // 主构件：优先使用 downloads.artifact，否则按 Maven 仓库地址拼接
+        if let Some(artifact) = downloads.and_then(|downloads| downloads.artifact.as_ref()) {
+            tasks.extend(task_from_download(artifact, libraries_dir, library.artifact_path()?));
+        } else if !library.is_native_only() {
+            let repository = library.url.as_deref().unwrap_or(config::DEFAULT_MAVEN_URL);
+            tasks.push(LibraryTask {
+                url: format!(
+                    "{}/{}",
+                    repository.trim_end_matches('/'),
+                    minecraft::maven_url_path(&library.name, None)?
+                ),
+                path: libraries_dir.join(library.artifact_path()?),
+                // 旧版 Forge 可能同时列出 jar 与 pack.xz 的校验值，只有唯一值时才能直接使用
+                sha1: library
+                    .checksums
+                    .as_ref()
+                    .filter(|checksums| checksums.len() == 1)
+                    .map(|checksums| checksums[0].clone()),
+            });
+        }
+
+        // 旧版原生库分类器
+        if let Some(classifier) = library.native_classifier() {
+            if let Some(download) = downloads
+                .and_then(|downloads| downloads.classifiers.as_ref())
+                .and_then(|classifiers| classifiers.get(&classifier))
+            {
+                tasks.extend(task_from_download(
+                    download,
+                    libraries_dir,
+                    minecraft::maven_path(&library.name, Some(&classifier))?,
+                ));
+            }
+        }
+    }
+
+    Ok(tasks)
+}
+
+/// 读取 Maven 仓库中构件旁的 `.sha1` 文件，仓库未提供时返回 None
+pub(crate) async fn fetch_maven_sha1(url: &str) -> Option<String> {
+    let text = download::fetch_text(&format!("{}.sha1", url)).await.ok()?;
+    text.split_whitespace()
+        .next()
+        .filter(|hash| hash.len() == 40)
+        .map(str::to_string)
+}
+
+/// 执行单个下载任务，没有校验值而未经校验保存时返回文件路径
+async fn run_task(task: LibraryTask, strict: bool) -> Result<Option<PathBuf>> {
+    // 没有已知校验值且文件已存在时不再请求 `.sha1`
+    if task.sha1.is_none() && tokio::fs::try_exists(&task.path).await? {
+        return Ok(None);
+    }
+    let sha1 = match task.sha1 {
+        Some(sha1) => Some(sha1),
+        None => fetch_maven_sha1(&task.url).await,
+    };
+    if sha1.is_none() && strict {
+        return Err(anyhow!("{} 没有可用的校验值，严格校验模式下拒绝下载", task.url));
+    }
+    download::download_file(&task.url, &task.path, sha1.as_deref()).await?;
+    Ok(sha1.is_none().then_some(task.path))
+}
+
+/// 下载依赖库，进度映射到 `progress_range` 区间，返回未经校验下载的文件
+pub(crate) async fn install_libraries(
+    libraries: &[Library],
+    libraries_dir: &Path,
+    on_progress: Option<&ProgressCallback>,
+    progress_range: (f64, f64),
+) -> Result<Vec<PathBuf>> {
+    let tasks = library_tasks(libraries, libraries_dir)?;
+    let total = tasks.len();
+    let strict = is_strict_verification();
+    let mut results = futures::stream::iter(tasks.into_iter().map(|task| run_task(task, strict)))
+        .buffer_unordered(config::CONCURRENT_DOWNLOADS);
+
+    let mut completed = 0;
+    let mut unverified = Vec::new();
+    while let Some(result) = results.next().await {
+        unverified.extend(result?);
+        completed += 1;
+        if let Some(callback) = on_progress {
+            let (start, end) = progress_range;
+            let progress = start + (end - start) * completed as f64 / total as f64;
+            callback(progress, format!("下载依赖库... {}/{}", completed, total)).await;
+        }
+    }
+
+    if let Some(callback) = on_progress.filter(|_| !unverified.is_empty()) {
+        let names = unverified
+            .iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(", ");
+        callback(
+            progress_range.1,
+            format!("警告: {} 个依赖库没有校验值，未经校验: {}", unverified.len(), names),
+        )
+        .await;
+    }
+    Ok(unverified)
+}
+
+/// 下载版本 JSON (含 inheritsFrom 父版本) 中当前系统需要的依赖库，返回未经校验下载的文件路径
+pub async fn install_version_libraries(version_json_path: String, libraries_dir: String) -> Result<Vec<String>> {
+    let version = minecraft::read_resolved_version_info(Path::new(&version_json_path)).await?;
+    let unverified = install_libraries(&version.libraries, Path::new(&libraries_dir), None, (0.0, 1.0)).await?;
+    Ok(unverified
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect())
+}
+
+/// 开启或关闭严格校验。开启后 Maven 仓库没有提供 `.sha1` 的依赖库会下载失败
+pub fn set_strict_library_verification(strict: bool) {
+    STRICT_VERIFICATION.store(strict, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const JAR: &[u8] = b"library bytes";
+    /// JAR 内容的 SHA-1
+    const JAR_SHA1: &str = "fe49591df2f11d4368a3a84a54d331d06ab1387b";
+
+    async fn server(sidecar: Option<&str>) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(path("/lib.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(JAR))
+            .mount(&server)
+            .await;
+        let sidecar = match sidecar {
+            Some(sha1) => ResponseTemplate::new(200).set_body_string(sha1),
+            None => ResponseTemplate::new(404),
+        };
+        Mock::given(path("/lib.jar.sha1")).respond_with(sidecar).mount(&server).await;
+        server
+    }
+
+    fn task(server: &MockServer, dir: &Path) -> LibraryTask {
+        LibraryTask {
+            url: format!("{}/lib.jar", server.uri()),
+            path: dir.join("lib.jar"),
+            sha1: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn verifies_with_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = server(Some(&format!("{}  lib.jar\n", JAR_SHA1))).await;
+        assert_eq!(run_task(task(&server, dir.path()), true).await.unwrap(), None);
+        assert_eq!(tokio::fs::read(dir.path().join("lib.jar")).await.unwrap(), JAR);
+    }
+
+    #[tokio::test]
+    async fn reports_missing_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = server(None).await;
+        let unverified = run_task(task(&server, dir.path()), false).await.unwrap();
+        assert_eq!(unverified, Some(dir.path().join("lib.jar")));
+    }
+
+    #[tokio::test]
+    async fn strict_rejects_missing_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = server(None).await;
+        assert!(run_task(task(&server, dir.path()), true).await.is_err());
+        assert!(!dir.path().join("lib.jar").exists());
+    }
+}
@@ -0,0 +1,247 @@
+use anyhow::{anyhow, Result};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::api::java_download::ProgressCallback;
+use crate::api::{libraries, vanilla};
+use crate::download;
+use crate::minecraft::{self, VersionInfo};
+
+/// Modrinth 元数据中代表任意游戏版本的占位符
+pub(crate) const GAME_VERSION_PLACEHOLDER: &str = "${modrinth.gameVersion}";
+
+/// 加载器版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoaderVersion {
+    pub id: String,
+    pub stable: bool,
+}
+
+/// Modrinth 元数据清单 (`<loader>/v<format>/manifest.json`)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MetaManifest {
+    pub game_versions: Vec<MetaGameVersion>,
+}
+
+/// 清单中的游戏版本
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct MetaGameVersion {
+    pub id: String,
+    #[serde(default)]
+    pub loaders: Vec<MetaLoaderVersion>,
+}
+
+/// 清单中的加载器版本，`url` 指向部分版本 JSON
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct MetaLoaderVersion {
+    pub id: String,
+    pub url: String,
+    #[serde(default)]
+    pub stable: bool,
+}
+
+impl MetaManifest {
+    /// 指定游戏版本可用的加载器。
+    /// Fabric / Quilt 的加载器与游戏版本无关，统一挂在占位符版本下，
+    /// 具体游戏版本的条目只用于声明支持
+    pub(crate) fn loaders_for(&self, game_version: &str) -> Vec<&MetaLoaderVersion> {
+        let Some(entry) = self.game_versions.iter().find(|entry| entry.id == game_version) else {
+            return Vec::new();
+        };
+        if !entry.loaders.is_empty() {
+            return entry.loaders.iter().collect();
+        }
+        self.game_versions
+            .iter()
+            .find(|entry| entry.id == GAME_VERSION_PLACEHOLDER)
+            .map(|entry| entry.loaders.iter().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// 获取 Modrinth 元数据清单
+pub(crate) async fn fetch_manifest(meta_url: &str, loader: &str, format_version: u32) -> Result<MetaManifest> {
+    download::fetch_json(&format!("{}{}/v{}/manifest.json", meta_url, loader, format_version)).await
+}
+
+/// 获取部分版本 JSON 并替换游戏版本占位符
+pub(crate) async fn fetch_partial_version(url: &str, game_version: &str) -> Result<VersionInfo> {
+    let text = download::fetch_text(url).await?;
+    let text = text.replace(GAME_VERSION_PLACEHOLDER, game_version);
+    serde_json::from_str(&text).map_err(|e| anyhow!("解析加载器版本 JSON 失败 {}: {}", url, e))
+}
+
+/// 从 Modrinth 元数据列出加载器版本
+pub(crate) async fn list_meta_loaders(
+    meta_url: &str,
+    loader: &str,
+    format_version: u32,
+    game_version: &str,
+) -> Result<Vec<LoaderVersion>> {
+    let manifest = fetch_manifest(meta_url, loader, format_version).await?;
+    Ok(manifest
+        .loaders_for(game_version)
+        .into_iter()
+        .map(|loader| LoaderVersion {
+            id: loader.id.clone(),
+            stable: loader.stable,
+        })
+        .collect())
+}
+
+/// 从 Modrinth 元数据获取指定加载器版本的部分版本 JSON
+pub(crate) async fn fetch_meta_profile(
+    meta_url: &str,
+    loader: &str,
+    format_version: u32,
+    game_version: &str,
+    loader_version: &str,
+) -> Result<VersionInfo> {
+    let manifest = fetch_manifest(meta_url, loader, format_version).await?;
+    let entry = manifest
+        .loaders_for(game_version)
+        .into_iter()
+        .find(|entry| entry.id == loader_version)
+        .ok_or_else(|| anyhow!("{} {} 不支持游戏版本 {}", loader, loader_version, game_version))?;
+    fetch_partial_version(&entry.url, game_version).await
+}
+
+/// Fabric 风格元数据接口 (meta.fabricmc.net / meta.quiltmc.org) 的加载器条目
+#[derive(Debug, Deserialize)]
+struct UpstreamLoaderEntry {
+    loader: UpstreamLoader,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpstreamLoader {
+    version: String,
+    /// Quilt 不提供该字段，以版本号中是否包含预发布标记判断
+    stable: Option<bool>,
+}
+
+/// 从 Fabric 风格元数据接口列出加载器版本
+pub(crate) async fn list_upstream_loaders(api_url: &str, game_version: &str) -> Result<Vec<LoaderVersion>> {
+    let entries: Vec<UpstreamLoaderEntry> =
+        download::fetch_json(&format!("{}versions/loader/{}", api_url, game_version)).await?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| LoaderVersion {
+            stable: entry
+                .loader
+                .stable
+                .unwrap_or_else(|| !entry.loader.version.contains('-')),
+            id: entry.loader.version,
+        })
+        .collect())
+}
+
+/// 从 Fabric 风格元数据接口获取启动配置 (inheritsFrom 版本 JSON)
+pub(crate) async fn fetch_upstream_profile(
+    api_url: &str,
+    game_version: &str,
+    loader_version: &str,
+) -> Result<VersionInfo> {
+    download::fetch_json(&format!(
+        "{}versions/loader/{}/{}/profile/json",
+        api_url, game_version, loader_version
+    ))
+    .await
+}
+
+/// 读取 maven-metadata.xml 中的全部版本 (按发布顺序)
+pub(crate) async fn fetch_maven_versions(metadata_url: &str) -> Result<Vec<String>> {
+    let xml = download::fetch_text(metadata_url).await?;
+    let mut reader = Reader::from_str(&xml);
+    reader.config_mut().trim_text(true);
+
+    let mut versions = Vec::new();
+    let mut in_version = false;
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) => in_version = element.name().as_ref() == b"version",
+            Event::Text(text) if in_version => versions.push(text.unescape()?.into_owned()),
+            Event::End(_) => in_version = false,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(versions)
+}
+
+/// 将加载器版本 JSON 写入 versions 目录，返回写入路径
+pub(crate) async fn write_loader_version(minecraft_dir: &Path, version: &VersionInfo) -> Result<PathBuf> {
+    if version.inherits_from.is_none() {
+        return Err(anyhow!("加载器版本 {} 缺少 inheritsFrom", version.id));
+    }
+    let path = minecraft::version_json_path(&minecraft_dir.join("versions"), &version.id);
+    minecraft::write_version_info(&path, version).await?;
+    Ok(path)
+}
+
+/// Fabric 风格加载器 (Fabric / Quilt) 的元数据来源：
+/// 优先使用 Modrinth 元数据，失败时回退到加载器官方元数据接口
+pub(crate) struct FabricLikeSource<'a> {
+    /// 显示名称
+    pub name: &'a str,
+    /// Modrinth 元数据中的加载器名称
+    pub meta_loader: &'a str,
+    pub format_version: u32,
+    pub meta_url: &'a str,
+    pub upstream_url: &'a str,
+}
+
+impl FabricLikeSource<'_> {
+    /// 列出指定游戏版本可用的加载器
+    pub(crate) async fn list_loaders(&self, game_version: &str) -> Result<Vec<LoaderVersion>> {
+        match list_meta_loaders(self.meta_url, self.meta_loader, self.format_version, game_version).await {
+            Ok(loaders) if !loaders.is_empty() => Ok(loaders),
+            _ => list_upstream_loaders(self.upstream_url, game_version).await,
+        }
+    }
+
+    /// 获取 inheritsFrom 版本 JSON
+    pub(crate) async fn fetch_profile(&self, game_version: &str, loader_version: &str) -> Result<VersionInfo> {
+        match fetch_meta_profile(
+            self.meta_url,
+            self.meta_loader,
+            self.format_version,
+            game_version,
+            loader_version,
+        )
+        .await
+        {
+            Ok(profile) => Ok(profile),
+            Err(_) => fetch_upstream_profile(self.upstream_url, game_version, loader_version).await,
+        }
+    }
+
+    /// 安装加载器：写入版本 JSON 并下载加载器依赖库，返回新版本 ID
+    pub(crate) async fn install(
+        &self,
+        minecraft_dir: &Path,
+        game_version: &str,
+        loader_version: &str,
+        on_progress: &ProgressCallback,
+    ) -> Result<String> {
+        on_progress(0.05, format!("准备 Minecraft {}", game_version)).await;
+        vanilla::ensure_version_json(minecraft_dir, game_version, self.meta_url).await?;
+
+        on_progress(0.15, format!("获取 {} {} 版本信息", self.name, loader_version)).await;
+        let profile = self.fetch_profile(game_version, loader_version).await?;
+        write_loader_version(minecraft_dir, &profile).await?;
+
+        libraries::install_libraries(
+            &profile.libraries,
+            &minecraft_dir.join("libraries"),
+            Some(on_progress),
+            (0.2, 1.0),
+        )
+        .await?;
+
+        on_progress(1.0, format!("{} {} 安装完成", self.name, loader_version)).await;
+        Ok(profile.id)
+    }
+}
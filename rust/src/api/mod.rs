@@ -1,2 +1,28 @@
 pub mod simple;
-pub mod java_download;
\ No newline at end of file
+pub mod java_download;
+pub mod natives;
+pub mod launch;
+pub mod game_process;
+pub mod game_log;
+pub mod crash_report;
+pub mod libraries;
+pub mod loader_meta;
+pub mod vanilla;
+pub mod fabric;
+pub mod quilt;
+pub mod forge;
+pub mod neoforge;
+pub mod mod_loader;
+pub mod instance;
+pub mod instance_import;
+pub mod instance_export;
+pub mod database;
+pub mod play_session;
+pub mod modrinth;
+pub mod modrinth_cache;
+pub mod modpack;
+pub mod content;
+pub mod dependency;
+pub mod content_scan;
+pub mod content_update;
+pub mod mod_compat;
\ No newline at end of file
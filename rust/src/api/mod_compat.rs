@@ -0,0 +1,605 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::instance::{self, InstanceManifest};
+use crate::api::mod_loader::ModLoader;
+use crate::mod_metadata::{self, DependencyKind, ModDependency, ModMetadata, VersionRange};
+use crate::version_range::LooseVersion;
+
+/// 配置常量
+mod config {
+    /// 模组目录
+    pub const MODS_DIR: &str = "mods";
+    /// 模组文件扩展名
+    pub const MOD_EXTENSION: &str = "jar";
+    /// 加载器自身提供的模组 ID
+    pub const LOADER_IDS: &[&str] = &["fabricloader", "quilt_loader", "forge", "neoforge"];
+}
+
+/// 问题的严重程度，Error 会导致游戏无法启动或崩溃
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompatSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatIssueKind {
+    /// 缺少必需的前置模组
+    MissingDependency,
+    /// 依赖的版本不在要求的范围内
+    VersionMismatch,
+    /// 与已安装的模组冲突
+    Incompatible,
+    /// 多个文件提供同一个模组
+    DuplicateMod,
+    /// 模组不适用于实例的加载器
+    WrongLoader,
+    /// 需要更高版本的 Java
+    JavaVersion,
+    /// 无法读取模组元数据
+    UnknownMod,
+}
+
+/// 兼容性检查发现的问题
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatIssue {
+    pub severity: CompatSeverity,
+    pub kind: CompatIssueKind,
+    pub mod_id: Option<String>,
+    /// 相对实例目录的文件路径
+    pub file: Option<String>,
+    pub message: String,
+    /// 建议的处理方式
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatReport {
+    /// 检查的模组文件数
+    pub checked_mods: usize,
+    /// 按严重程度排序，Error 在前
+    pub issues: Vec<CompatIssue>,
+}
+
+impl CompatReport {
+    fn new(checked_mods: usize, mut issues: Vec<CompatIssue>) -> Self {
+        issues.sort_by_key(|issue| (issue.severity, issue.file.clone()));
+        Self { checked_mods, issues }
+    }
+}
+
+/// 一个已启用的模组文件
+struct ModFile {
+    path: String,
+    /// jar 中的全部元数据
+    metadata: Result<Vec<ModMetadata>>,
+    /// 由 class 文件推断的 Java 主版本
+    java_version: Option<u16>,
+}
+
+/// 提供某个模组 ID 的来源
+#[derive(Clone)]
+struct Provider {
+    /// 版本未知时不检查范围
+    version: Option<String>,
+    /// 加载器、游戏或 Java 本身提供时为空
+    file: Option<String>,
+}
+
+/// 实例加载器能否加载为 `mod_loader` 编写的模组
+fn accepts(loader: ModLoader, game_version: &str, mod_loader: ModLoader) -> bool {
+    loader == mod_loader
+        || (loader == ModLoader::Quilt && mod_loader == ModLoader::Fabric)
+        // 1.20.1 的 NeoForge 兼容 Forge 模组
+        || (loader == ModLoader::NeoForge && mod_loader == ModLoader::Forge && game_version == "1.20.1")
+}
+
+/// 游戏、Java 与加载器提供的模组 ID
+fn builtin_providers(manifest: &InstanceManifest, java_major_version: Option<i32>) -> HashMap<String, Provider> {
+    let builtin = |version: Option<String>| Provider { version, file: None };
+    let loader_version = manifest.loader_version.as_deref().map(|version| {
+        version
+            .strip_prefix(&format!("{}-", manifest.game_version))
+            .unwrap_or(version)
+            .to_string()
+    });
+
+    let mut providers = HashMap::new();
+    providers.insert("minecraft".to_string(), builtin(Some(manifest.game_version.clone())));
+    if let Some(java) = java_major_version {
+        providers.insert("java".to_string(), builtin(Some(java.to_string())));
+    }
+    let ids: &[&str] = match manifest.loader {
+        ModLoader::Vanilla => &[],
+        ModLoader::Fabric => &["fabricloader"],
+        ModLoader::Quilt => &["quilt_loader"],
+        ModLoader::Forge => &["forge"],
+        ModLoader::NeoForge if manifest.game_version == "1.20.1" => &["neoforge", "forge"],
+        ModLoader::NeoForge => &["neoforge"],
+    };
+    for id in ids {
+        providers.insert(id.to_string(), builtin(loader_version.clone()));
+    }
+    // 以下 ID 的版本与加载器本身不同：Quilt 提供兼容的 fabricloader，旧版 Forge 带有 FML
+    match manifest.loader {
+        ModLoader::Quilt => {
+            providers.insert("fabricloader".to_string(), builtin(None));
+        }
+        ModLoader::Forge => {
+            providers.insert("fml".to_string(), builtin(None));
+        }
+        _ => {}
+    }
+    providers
+}
+
+/// 列出 mods 目录中已启用的模组文件
+async fn enabled_mod_files(instance_dir: &Path) -> Result<Vec<String>> {
+    let dir = instance_dir.join(config::MODS_DIR);
+    if !fs::try_exists(&dir).await? {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    let mut entries = fs::read_dir(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_jar = Path::new(&name)
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case(config::MOD_EXTENSION));
+        if is_jar && entry.metadata().await?.is_file() {
+            files.push(format!("{}/{}", config::MODS_DIR, name));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn display_name(metadata: &ModMetadata) -> &str {
+    metadata.name.as_deref().unwrap_or(&metadata.mod_id)
+}
+
+/// 登记模组及其内嵌模组提供的 ID。顶层文件重复提供同一 ID 时返回先登记的来源
+fn register(
+    providers: &mut HashMap<String, Provider>,
+    metadata: &ModMetadata,
+    file: &str,
+    bundled: bool,
+) -> Vec<(String, Provider)> {
+    let mut duplicates = Vec::new();
+    let provider = Provider {
+        version: metadata.version.clone(),
+        file: Some(file.to_string()),
+    };
+    for id in std::iter::once(&metadata.mod_id).chain(&metadata.provides) {
+        match providers.get(id) {
+            // 内嵌模组由加载器去重，不视为重复
+            Some(existing) if !bundled && existing.file.as_deref().is_some_and(|existing| existing != file) => {
+                duplicates.push((id.clone(), existing.clone()));
+            }
+            Some(_) => {}
+            None => {
+                providers.insert(id.clone(), provider.clone());
+            }
+        }
+    }
+    for nested in &metadata.bundled {
+        register(providers, nested, file, true);
+    }
+    duplicates
+}
+
+/// 检查单个依赖，满足时返回 None
+fn check_dependency(
+    manifest: &InstanceManifest,
+    providers: &HashMap<String, Provider>,
+    metadata: &ModMetadata,
+    file: &str,
+    dependency: &ModDependency,
+) -> Option<CompatIssue> {
+    let name = display_name(metadata);
+    let range = dependency.range.describe();
+    let issue = |severity, kind, message: String, suggestion: String| {
+        Some(CompatIssue {
+            severity,
+            kind,
+            mod_id: Some(metadata.mod_id.clone()),
+            file: Some(file.to_string()),
+            message,
+            suggestion,
+        })
+    };
+
+    let Some(provider) = providers.get(&dependency.mod_id) else {
+        // 未指定 Java 版本时不检查 Java 依赖
+        if dependency.kind != DependencyKind::Required || dependency.mod_id == "java" {
+            return None;
+        }
+        let suggestion = match dependency.range {
+            VersionRange::Any => format!("安装前置模组 {}", dependency.mod_id),
+            _ => format!("安装满足 {} 的前置模组 {}", range, dependency.mod_id),
+        };
+        return issue(
+            CompatSeverity::Error,
+            CompatIssueKind::MissingDependency,
+            format!("{} 需要前置模组 {}", name, dependency.mod_id),
+            suggestion,
+        );
+    };
+    let version = provider.version.as_deref()?;
+    // 无法解析的范围不做判断
+    let matched = dependency.range.matches(version).ok()?;
+    let provider_file = provider.file.as_deref().unwrap_or_default();
+
+    match dependency.kind {
+        DependencyKind::Incompatible if matched => issue(
+            CompatSeverity::Error,
+            CompatIssueKind::Incompatible,
+            format!("{} 与 {} {} 不兼容", name, dependency.mod_id, version),
+            match &provider.file {
+                Some(other) => format!("禁用 {} 或 {} 其中之一", file, other),
+                None => format!("更换 {} 的版本或禁用 {}", name, file),
+            },
+        ),
+        DependencyKind::Required | DependencyKind::Optional if !matched => {
+            let severity = if dependency.kind == DependencyKind::Required {
+                CompatSeverity::Error
+            } else {
+                CompatSeverity::Warning
+            };
+            let message = format!("{} 需要 {} {}，当前为 {}", name, dependency.mod_id, range, version);
+            match dependency.mod_id.as_str() {
+                "java" => issue(
+                    severity,
+                    CompatIssueKind::JavaVersion,
+                    message,
+                    format!("在实例设置中改用满足 {} 的 Java 版本", range),
+                ),
+                "minecraft" => issue(
+                    severity,
+                    CompatIssueKind::VersionMismatch,
+                    message,
+                    format!("{} 不支持 Minecraft {}，请安装适用于该游戏版本的 {}", name, manifest.game_version, name),
+                ),
+                id if config::LOADER_IDS.contains(&id) && provider.file.is_none() => issue(
+                    severity,
+                    CompatIssueKind::VersionMismatch,
+                    message,
+                    format!("将 {} 加载器更新到满足 {} 的版本，或更换 {} 的版本", manifest.loader.as_str(), range, name),
+                ),
+                _ => issue(
+                    severity,
+                    CompatIssueKind::VersionMismatch,
+                    message,
+                    format!("将 {} ({}) 更换为满足 {} 的版本，或更换 {} 的版本", dependency.mod_id, provider_file, range, name),
+                ),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// 检查已读取的模组文件
+fn check_mods(manifest: &InstanceManifest, java_major_version: Option<i32>, files: Vec<ModFile>) -> Vec<CompatIssue> {
+    let mut issues = Vec::new();
+    let loader = manifest.loader;
+
+    // 为每个文件选出适用于实例加载器的元数据，同名加载器优先
+    let mut mods: Vec<(String, ModMetadata, Option<u16>)> = Vec::new();
+    for file in files {
+        let metadata = match file.metadata {
+            Ok(metadata) if !metadata.is_empty() => metadata,
+            _ => {
+                issues.push(CompatIssue {
+                    severity: CompatSeverity::Warning,
+                    kind: CompatIssueKind::UnknownMod,
+                    mod_id: None,
+                    file: Some(file.path.clone()),
+                    message: format!("无法读取 {} 的模组信息", file.path),
+                    suggestion: format!("确认该文件是适用于 {} 的模组，否则将其移出 mods 目录", loader.as_str()),
+                });
+                continue;
+            }
+        };
+        let selected = metadata
+            .iter()
+            .position(|metadata| metadata.loader == loader)
+            .or_else(|| {
+                metadata
+                    .iter()
+                    .position(|metadata| accepts(loader, &manifest.game_version, metadata.loader))
+            });
+        match selected {
+            Some(index) => mods.push((file.path, metadata.into_iter().nth(index).unwrap(), file.java_version)),
+            None => {
+                let first = &metadata[0];
+                let mut loaders: Vec<&str> = metadata.iter().map(|metadata| metadata.loader.as_str()).collect();
+                loaders.dedup();
+                issues.push(CompatIssue {
+                    severity: CompatSeverity::Error,
+                    kind: CompatIssueKind::WrongLoader,
+                    mod_id: Some(first.mod_id.clone()),
+                    file: Some(file.path.clone()),
+                    message: format!("{} 适用于 {}，实例使用 {}", display_name(first), loaders.join(" / "), loader.as_str()),
+                    suggestion: if loader == ModLoader::Vanilla {
+                        "为实例安装模组加载器，或禁用该模组".to_string()
+                    } else {
+                        format!("安装 {} 的 {} 版本，或禁用 {}", display_name(first), loader.as_str(), file.path)
+                    },
+                });
+            }
+        }
+    }
+
+    let mut providers = builtin_providers(manifest, java_major_version);
+    for (file, metadata, _) in &mods {
+        for (id, existing) in register(&mut providers, metadata, file, false) {
+            let Some(existing_file) = existing.file else {
+                continue;
+            };
+            // 保留较新的版本
+            let newer_is_existing = match (&existing.version, &metadata.version) {
+                (Some(a), Some(b)) => LooseVersion::parse(a) >= LooseVersion::parse(b),
+                _ => true,
+            };
+            let (keep, remove) = if newer_is_existing {
+                (existing_file.as_str(), file.as_str())
+            } else {
+                (file.as_str(), existing_file.as_str())
+            };
+            issues.push(CompatIssue {
+                severity: CompatSeverity::Error,
+                kind: CompatIssueKind::DuplicateMod,
+                mod_id: Some(id.clone()),
+                file: Some(file.clone()),
+                message: format!("{} 与 {} 都提供了模组 {}", existing_file, file, id),
+                suggestion: format!("保留 {}，禁用 {}", keep, remove),
+            });
+        }
+    }
+
+    for (file, metadata, class_java_version) in &mods {
+        let mut java_reported = false;
+        for dependency in metadata.dependencies.iter().filter(|dependency| dependency.mod_id != metadata.mod_id) {
+            if let Some(issue) = check_dependency(manifest, &providers, metadata, file, dependency) {
+                java_reported |= issue.kind == CompatIssueKind::JavaVersion;
+                issues.push(issue);
+            }
+        }
+        if let (Some(java), Some(required)) = (java_major_version, class_java_version) {
+            if !java_reported && i32::from(*required) > java {
+                issues.push(CompatIssue {
+                    severity: CompatSeverity::Error,
+                    kind: CompatIssueKind::JavaVersion,
+                    mod_id: Some(metadata.mod_id.clone()),
+                    file: Some(file.clone()),
+                    message: format!("{} 需要 Java {}，当前为 Java {}", display_name(metadata), required, java),
+                    suggestion: format!("在实例设置中改用 Java {} 或更高版本", required),
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// 启动前检查已启用模组的兼容性：缺少的前置、版本范围、重复模组、加载器与 Java 版本。
+/// `java_major_version` 为启动使用的 Java 主版本，为空时不检查 Java
+pub async fn check_mod_compatibility(
+    resource_dir: String,
+    instance_id: String,
+    java_major_version: Option<i32>,
+) -> Result<CompatReport> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let instance_dir = PathBuf::from(&manifest.path);
+    let paths = enabled_mod_files(&instance_dir).await?;
+    let checked_mods = paths.len();
+
+    let files = tokio::task::spawn_blocking(move || {
+        paths
+            .into_iter()
+            .map(|path| {
+                let absolute = instance_dir.join(&path);
+                ModFile {
+                    metadata: mod_metadata::read_mod_metadata(&absolute),
+                    java_version: mod_metadata::class_java_version(&absolute).ok().flatten(),
+                    path,
+                }
+            })
+            .collect::<Vec<_>>()
+    })
+    .await?;
+
+    let issues = check_mods(&manifest, java_major_version, files);
+    Ok(CompatReport::new(checked_mods, issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn manifest(loader: ModLoader, loader_version: &str) -> InstanceManifest {
+        InstanceManifest {
+            format_version: 1,
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            game_version: "1.20.1".to_string(),
+            loader,
+            loader_version: Some(loader_version.to_string()),
+            java_path: None,
+            max_memory_mb: None,
+            min_memory_mb: None,
+            jvm_args: Vec::new(),
+            resolution: None,
+            icon: None,
+            play_time_secs: 0,
+            created: chrono::Utc::now(),
+            last_played: None,
+            path: String::new(),
+        }
+    }
+
+    fn metadata(loader: ModLoader, mod_id: &str, version: &str, dependencies: Vec<ModDependency>) -> ModMetadata {
+        ModMetadata {
+            loader,
+            mod_id: mod_id.to_string(),
+            name: None,
+            version: Some(version.to_string()),
+            description: None,
+            authors: Vec::new(),
+            provides: Vec::new(),
+            dependencies,
+            icon: None,
+            bundled: Vec::new(),
+        }
+    }
+
+    fn depends(mod_id: &str, predicate: &str, kind: DependencyKind) -> ModDependency {
+        ModDependency {
+            mod_id: mod_id.to_string(),
+            range: VersionRange::Fabric(vec![predicate.to_string()]),
+            kind,
+        }
+    }
+
+    fn file(path: &str, metadata: Vec<ModMetadata>) -> ModFile {
+        ModFile {
+            path: path.to_string(),
+            metadata: Ok(metadata),
+            java_version: None,
+        }
+    }
+
+    fn kinds(issues: &[CompatIssue]) -> Vec<(CompatIssueKind, Option<&str>)> {
+        issues.iter().map(|issue| (issue.kind, issue.mod_id.as_deref())).collect()
+    }
+
+    #[test]
+    fn reports_missing_and_mismatched_dependencies() {
+        let manifest = manifest(ModLoader::Fabric, "0.15.3");
+        let files = vec![
+            file(
+                "mods/a.jar",
+                vec![metadata(
+                    ModLoader::Fabric,
+                    "a",
+                    "1.0",
+                    vec![
+                        depends("fabric-api", "*", DependencyKind::Required),
+                        depends("minecraft", "~1.19", DependencyKind::Required),
+                        depends("fabricloader", ">=0.16", DependencyKind::Required),
+                        depends("modmenu", "*", DependencyKind::Optional),
+                    ],
+                )],
+            ),
+            file(
+                "mods/b.jar",
+                vec![metadata(ModLoader::Fabric, "b", "1.0", vec![depends("a", ">=2", DependencyKind::Optional)])],
+            ),
+        ];
+        let issues = check_mods(&manifest, None, files);
+        assert_eq!(
+            kinds(&issues),
+            [
+                (CompatIssueKind::MissingDependency, Some("a")),
+                (CompatIssueKind::VersionMismatch, Some("a")),
+                (CompatIssueKind::VersionMismatch, Some("a")),
+                (CompatIssueKind::VersionMismatch, Some("b")),
+            ]
+        );
+        assert_eq!(issues[3].severity, CompatSeverity::Warning);
+    }
+
+    #[test]
+    fn bundled_mods_satisfy_dependencies() {
+        let manifest = manifest(ModLoader::Fabric, "0.15.3");
+        let mut api = metadata(ModLoader::Fabric, "fabric-api", "0.92.0", Vec::new());
+        api.bundled.push(metadata(ModLoader::Fabric, "fabric-api-base", "0.4.31", Vec::new()));
+        let files = vec![
+            file("mods/api.jar", vec![api]),
+            file(
+                "mods/a.jar",
+                vec![metadata(
+                    ModLoader::Fabric,
+                    "a",
+                    "1.0",
+                    vec![depends("fabric-api-base", ">=0.4", DependencyKind::Required)],
+                )],
+            ),
+        ];
+        assert!(check_mods(&manifest, None, files).is_empty());
+    }
+
+    #[test]
+    fn reports_duplicates_keeping_the_newer_file() {
+        let manifest = manifest(ModLoader::Fabric, "0.15.3");
+        let files = vec![
+            file("mods/sodium-old.jar", vec![metadata(ModLoader::Fabric, "sodium", "0.5.3", Vec::new())]),
+            file("mods/sodium-new.jar", vec![metadata(ModLoader::Fabric, "sodium", "0.5.8", Vec::new())]),
+        ];
+        let issues = check_mods(&manifest, None, files);
+        assert_eq!(kinds(&issues), [(CompatIssueKind::DuplicateMod, Some("sodium"))]);
+        assert_eq!(issues[0].suggestion, "保留 mods/sodium-new.jar，禁用 mods/sodium-old.jar");
+    }
+
+    #[test]
+    fn checks_loader_and_incompatibilities() {
+        let quilt = manifest(ModLoader::Quilt, "0.23.0");
+        let files = vec![
+            // Quilt 可以加载 Fabric 模组
+            file("mods/fabric.jar", vec![metadata(ModLoader::Fabric, "a", "1.0", Vec::new())]),
+            file("mods/forge.jar", vec![metadata(ModLoader::Forge, "b", "1.0", Vec::new())]),
+            file(
+                "mods/c.jar",
+                vec![metadata(ModLoader::Quilt, "c", "1.0", vec![depends("a", "<2", DependencyKind::Incompatible)])],
+            ),
+        ];
+        let issues = check_mods(&quilt, None, files);
+        assert_eq!(
+            kinds(&issues),
+            [
+                (CompatIssueKind::WrongLoader, Some("b")),
+                (CompatIssueKind::Incompatible, Some("c")),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_java_version_and_unreadable_files() {
+        let manifest = manifest(ModLoader::Fabric, "0.15.3");
+        let mut class_only = file("mods/a.jar", vec![metadata(ModLoader::Fabric, "a", "1.0", Vec::new())]);
+        class_only.java_version = Some(21);
+        // 依赖已经报告了 Java 版本时不重复报告
+        let mut declared = file(
+            "mods/b.jar",
+            vec![metadata(ModLoader::Fabric, "b", "1.0", vec![depends("java", ">=21", DependencyKind::Required)])],
+        );
+        declared.java_version = Some(21);
+        let unreadable = ModFile {
+            path: "mods/c.jar".to_string(),
+            metadata: Err(anyhow!("invalid zip")),
+            java_version: None,
+        };
+
+        let issues = check_mods(&manifest, Some(17), vec![class_only, declared, unreadable]);
+        assert_eq!(
+            kinds(&issues),
+            [
+                (CompatIssueKind::UnknownMod, None),
+                (CompatIssueKind::JavaVersion, Some("a")),
+                (CompatIssueKind::JavaVersion, Some("b")),
+            ]
+        );
+        // 未指定 Java 版本时不检查
+        let files = vec![file(
+            "mods/b.jar",
+            vec![metadata(ModLoader::Fabric, "b", "1.0", vec![depends("java", ">=21", DependencyKind::Required)])],
+        )];
+        assert!(check_mods(&manifest, None, files).is_empty());
+    }
+}
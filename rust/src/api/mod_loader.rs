@@ -0,0 +1,303 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::DartFnFuture;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::api::fabric::FABRIC_SOURCE;
+use crate::api::java_download::ProgressCallback;
+use crate::api::loader_meta::LoaderVersion;
+use crate::api::quilt::QUILT_SOURCE;
+use crate::api::{forge, libraries, neoforge, vanilla};
+use crate::config::META_URL;
+use crate::minecraft::{self, VersionInfo};
+
+/// 模组加载器
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModLoader {
+    Vanilla,
+    Fabric,
+    Quilt,
+    Forge,
+    NeoForge,
+}
+
+impl ModLoader {
+    /// 与 Modrinth 一致的加载器标识
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ModLoader::Vanilla => "minecraft",
+            ModLoader::Fabric => "fabric",
+            ModLoader::Quilt => "quilt",
+            ModLoader::Forge => "forge",
+            ModLoader::NeoForge => "neoforge",
+        }
+    }
+
+    /// 加载器主构件的 Maven 坐标前缀 (`group:artifact:`)，用于识别已安装的版本 JSON
+    fn artifact_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            ModLoader::Vanilla => &[],
+            ModLoader::Fabric => &["net.fabricmc:fabric-loader:"],
+            ModLoader::Quilt => &["org.quiltmc:quilt-loader:"],
+            ModLoader::Forge => &["net.minecraftforge:forge:"],
+            ModLoader::NeoForge => &["net.neoforged:neoforge:", "net.neoforged:forge:"],
+        }
+    }
+
+    /// 版本 JSON 是否为该加载器指定版本的启动配置
+    fn matches(&self, version: &VersionInfo, game_version: &str, loader_version: &str) -> bool {
+        if *self == ModLoader::Vanilla {
+            return version.id == game_version && version.inherits_from.is_none();
+        }
+        version.inherits_from.as_deref() == Some(game_version)
+            && detect_loader(version).is_some_and(|(loader, version)| loader == *self && version == loader_version)
+    }
+}
+
+/// 由版本 JSON 的依赖库识别加载器及其版本，未识别时返回 None (原版)。
+/// 1.20.1 的 NeoForge 仍带有 `net.minecraftforge:forge` 构件，因此先于 Forge 检查
+pub(crate) fn detect_loader(version: &VersionInfo) -> Option<(ModLoader, String)> {
+    [ModLoader::Fabric, ModLoader::Quilt, ModLoader::NeoForge, ModLoader::Forge]
+        .into_iter()
+        .find_map(|loader| {
+            version.libraries.iter().find_map(|library| {
+                loader.artifact_prefixes().iter().find_map(|prefix| {
+                    let rest = library.name.strip_prefix(prefix)?;
+                    let loader_version = rest.split([':', '@']).next()?;
+                    Some((loader, loader_version.to_string()))
+                })
+            })
+        })
+}
+
+/// 查找已安装的启动配置 (版本 JSON 路径)
+async fn find_profile(
+    minecraft_dir: &Path,
+    loader: ModLoader,
+    game_version: &str,
+    loader_version: &str,
+) -> Result<Option<PathBuf>> {
+    let versions_dir = minecraft_dir.join("versions");
+    if loader == ModLoader::Vanilla {
+        let path = minecraft::version_json_path(&versions_dir, game_version);
+        return Ok(fs::try_exists(&path).await?.then_some(path));
+    }
+    if !fs::try_exists(&versions_dir).await? {
+        return Ok(None);
+    }
+
+    let mut entries = fs::read_dir(&versions_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let id = entry.file_name().to_string_lossy().to_string();
+        let path = minecraft::version_json_path(&versions_dir, &id);
+        let Ok(version) = minecraft::read_version_info(&path).await else {
+            continue;
+        };
+        if loader.matches(&version, game_version, loader_version) {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+/// 安装原版：版本 JSON、客户端 jar 与依赖库
+async fn install_vanilla(minecraft_dir: &Path, game_version: &str, on_progress: &ProgressCallback) -> Result<String> {
+    on_progress(0.05, format!("准备 Minecraft {}", game_version)).await;
+    let version = vanilla::ensure_version_json(minecraft_dir, game_version, META_URL).await?;
+    on_progress(0.1, "下载客户端".to_string()).await;
+    vanilla::ensure_client_jar(minecraft_dir, &version).await?;
+    libraries::install_libraries(
+        &version.libraries,
+        &minecraft_dir.join("libraries"),
+        Some(on_progress),
+        (0.2, 1.0),
+    )
+    .await?;
+    on_progress(1.0, format!("Minecraft {} 安装完成", game_version)).await;
+    Ok(version.id)
+}
+
+/// 列出指定游戏版本可用的加载器版本，原版返回空列表
+pub async fn list_loader_versions(loader: ModLoader, game_version: String) -> Result<Vec<LoaderVersion>> {
+    match loader {
+        ModLoader::Vanilla => Ok(Vec::new()),
+        ModLoader::Fabric => FABRIC_SOURCE.list_loaders(&game_version).await,
+        ModLoader::Quilt => QUILT_SOURCE.list_loaders(&game_version).await,
+        ModLoader::Forge => forge::list_forge_versions(game_version).await,
+        ModLoader::NeoForge => neoforge::list_neoforge_versions(game_version).await,
+    }
+}
+
+/// 安装游戏版本及加载器，返回可用于启动的版本 ID。
+/// 原版忽略 `loader_version`；Forge / NeoForge 需要 `java_path` 运行安装处理器
+pub async fn install_loader(
+    minecraft_dir: String,
+    loader: ModLoader,
+    game_version: String,
+    loader_version: Option<String>,
+    java_path: Option<String>,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<String> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    let minecraft_dir = Path::new(&minecraft_dir);
+
+    let require_version = || loader_version.clone().ok_or_else(|| anyhow!("未指定 {} 版本", loader.as_str()));
+    match loader {
+        ModLoader::Vanilla => install_vanilla(minecraft_dir, &game_version, &on_progress).await,
+        ModLoader::Fabric => {
+            FABRIC_SOURCE
+                .install(minecraft_dir, &game_version, &require_version()?, &on_progress)
+                .await
+        }
+        ModLoader::Quilt => {
+            QUILT_SOURCE
+                .install(minecraft_dir, &game_version, &require_version()?, &on_progress)
+                .await
+        }
+        ModLoader::Forge | ModLoader::NeoForge => {
+            let java_path = java_path.ok_or_else(|| anyhow!("安装 {} 需要指定 Java", loader.as_str()))?;
+            let loader_version = require_version()?;
+            let (name, installer_url) = if loader == ModLoader::Forge {
+                ("Forge", forge::forge_installer_url(&loader_version)?)
+            } else {
+                ("NeoForge", neoforge::installer_url(&game_version, &loader_version)?)
+            };
+            forge::install_with_installer(
+                name,
+                &installer_url,
+                minecraft_dir,
+                &game_version,
+                &java_path,
+                &on_progress,
+            )
+            .await
+        }
+    }
+}
+
+/// 查找已安装的启动配置，返回版本 JSON 路径 (可直接用于 `LaunchOptions::version_json_path`)，未安装时返回 None
+pub async fn resolve_launch_profile(
+    minecraft_dir: String,
+    loader: ModLoader,
+    game_version: String,
+    loader_version: Option<String>,
+) -> Result<Option<String>> {
+    let loader_version = loader_version.unwrap_or_default();
+    Ok(find_profile(Path::new(&minecraft_dir), loader, &game_version, &loader_version)
+        .await?
+        .map(|path| path.to_string_lossy().to_string()))
+}
+
+/// 卸载加载器的启动配置 (删除对应 versions 子目录)，共享的依赖库保留。
+/// 原版仍被其他版本继承时拒绝卸载
+pub async fn uninstall_loader(
+    minecraft_dir: String,
+    loader: ModLoader,
+    game_version: String,
+    loader_version: Option<String>,
+) -> Result<()> {
+    let minecraft_dir = Path::new(&minecraft_dir);
+    let loader_version = loader_version.unwrap_or_default();
+    let Some(path) = find_profile(minecraft_dir, loader, &game_version, &loader_version).await? else {
+        return Ok(());
+    };
+
+    if loader == ModLoader::Vanilla {
+        let mut entries = fs::read_dir(minecraft_dir.join("versions")).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let id = entry.file_name().to_string_lossy().to_string();
+            let child = minecraft::version_json_path(&minecraft_dir.join("versions"), &id);
+            if let Ok(version) = minecraft::read_version_info(&child).await {
+                if version.inherits_from.as_deref() == Some(game_version.as_str()) {
+                    return Err(anyhow!("Minecraft {} 仍被 {} 使用", game_version, version.id));
+                }
+            }
+        }
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::remove_dir_all(dir).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VANILLA: &str = include_str!("../../tests/fixtures/versions/modern.json");
+    const FABRIC: &str = include_str!("../../tests/fixtures/versions/fabric-child.json");
+    const QUILT: &str = include_str!("../../tests/fixtures/versions/quilt-child.json");
+    const FORGE: &str = include_str!("../../tests/fixtures/versions/forge-1.20.1.json");
+    const NEOFORGE_LEGACY: &str = include_str!("../../tests/fixtures/versions/neoforge-1.20.1.json");
+    const NEOFORGE: &str = include_str!("../../tests/fixtures/versions/neoforge-1.21.json");
+
+    fn detect(json: &str) -> Option<(ModLoader, String)> {
+        detect_loader(&serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn detects_loaders() {
+        assert_eq!(detect(VANILLA), None);
+        assert_eq!(detect(FABRIC), Some((ModLoader::Fabric, "0.15.0".to_string())));
+        assert_eq!(detect(QUILT), Some((ModLoader::Quilt, "0.23.1".to_string())));
+        assert_eq!(detect(FORGE), Some((ModLoader::Forge, "1.20.1-47.1.0".to_string())));
+        assert_eq!(detect(NEOFORGE_LEGACY), Some((ModLoader::NeoForge, "1.20.1-47.1.106".to_string())));
+        assert_eq!(detect(NEOFORGE), Some((ModLoader::NeoForge, "21.0.10".to_string())));
+    }
+
+    async fn write_version(minecraft_dir: &Path, json: &str) {
+        let version: VersionInfo = serde_json::from_str(json).unwrap();
+        let path = minecraft::version_json_path(&minecraft_dir.join("versions"), &version.id);
+        fs::create_dir_all(path.parent().unwrap()).await.unwrap();
+        fs::write(path, json).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn resolves_and_uninstalls_profiles() {
+        let dir = tempfile::tempdir().unwrap();
+        let minecraft_dir = dir.path().to_string_lossy().to_string();
+        for json in [VANILLA, FABRIC, NEOFORGE_LEGACY] {
+            write_version(dir.path(), json).await;
+        }
+        let resolve = |loader, loader_version: Option<&str>| {
+            resolve_launch_profile(
+                minecraft_dir.clone(),
+                loader,
+                "1.20.1".to_string(),
+                loader_version.map(str::to_string),
+            )
+        };
+
+        let fabric = resolve(ModLoader::Fabric, Some("0.15.0")).await.unwrap().unwrap();
+        assert!(fabric.ends_with("fabric-loader-0.15.0-1.20.1.json"));
+        assert!(resolve(ModLoader::Fabric, Some("0.14.0")).await.unwrap().is_none());
+        assert!(resolve(ModLoader::Quilt, Some("0.15.0")).await.unwrap().is_none());
+        let neoforge = resolve(ModLoader::NeoForge, Some("1.20.1-47.1.106")).await.unwrap().unwrap();
+        assert!(neoforge.ends_with("1.20.1-forge-47.1.106.json"));
+        // NeoForge 的启动配置不会被当作 Forge
+        assert!(resolve(ModLoader::Forge, Some("1.20.1-47.1.106")).await.unwrap().is_none());
+        let vanilla = resolve(ModLoader::Vanilla, None).await.unwrap().unwrap();
+        assert!(vanilla.ends_with("1.20.1.json"));
+
+        // 仍被加载器继承的原版不能卸载
+        let uninstall = |loader, loader_version: Option<&str>| {
+            uninstall_loader(
+                minecraft_dir.clone(),
+                loader,
+                "1.20.1".to_string(),
+                loader_version.map(str::to_string),
+            )
+        };
+        assert!(uninstall(ModLoader::Vanilla, None).await.is_err());
+        uninstall(ModLoader::Fabric, Some("0.15.0")).await.unwrap();
+        assert!(!dir.path().join("versions/fabric-loader-0.15.0-1.20.1").exists());
+        uninstall(ModLoader::NeoForge, Some("1.20.1-47.1.106")).await.unwrap();
+        // 未安装的配置忽略
+        uninstall(ModLoader::Fabric, Some("0.15.0")).await.unwrap();
+        uninstall(ModLoader::Vanilla, None).await.unwrap();
+        assert!(!dir.path().join("versions/1.20.1").exists());
+    }
+}
@@ -0,0 +1,872 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::DartFnFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read};
+use std::path::{Component, Path, PathBuf};
+use tokio::fs;
+use tokio::sync::mpsc;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::api::instance::{self, InstanceManifest, NewInstance};
+use crate::api::instance_export::{self, ExportFile};
+use crate::api::instance_import::forge_maven_version;
+use crate::api::java_download::ProgressCallback;
+use crate::api::mod_loader::ModLoader;
+use crate::api::modrinth::{HashAlgorithm, ModrinthClient};
+use crate::archive;
+use crate::checksum;
+use crate::download;
+
+/// 配置常量
+mod config {
+    /// 整合包索引文件
+    pub const INDEX_FILE: &str = "modrinth.index.json";
+    /// 支持的索引格式版本
+    pub const FORMAT_VERSION: u32 = 1;
+    /// 覆盖目录，后者优先
+    pub const OVERRIDE_DIRS: &[&str] = &["overrides", "client-overrides"];
+    /// 整合包文件的并发下载数
+    pub const CONCURRENT_DOWNLOADS: usize = 8;
+    /// 导出时到 Modrinth 查询哈希的目录，其余文件直接放入覆盖目录
+    pub const LOOKUP_DIRS: &[&str] = &["mods", "resourcepacks", "shaderpacks"];
+}
+
+/// 文件在某一端的支持情况
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnvSupport {
+    Required,
+    Optional,
+    Unsupported,
+}
+
+impl EnvSupport {
+    /// 由 Modrinth 项目的 `client_side` / `server_side` 转换，unknown 视为必需
+    fn from_side(side: &str) -> Self {
+        match side {
+            "optional" => EnvSupport::Optional,
+            "unsupported" => EnvSupport::Unsupported,
+            _ => EnvSupport::Required,
+        }
+    }
+}
+
+/// 文件的客户端 / 服务端支持情况
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PackEnv {
+    client: EnvSupport,
+    server: EnvSupport,
+}
+
+/// 整合包中需要下载的文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackFile {
+    path: String,
+    hashes: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    env: Option<PackEnv>,
+    downloads: Vec<String>,
+    file_size: u64,
+}
+
+impl PackFile {
+    fn client_support(&self) -> EnvSupport {
+        self.env.as_ref().map_or(EnvSupport::Required, |env| env.client)
+    }
+}
+
+/// `modrinth.index.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackIndex {
+    format_version: u32,
+    game: String,
+    version_id: String,
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    files: Vec<PackFile>,
+    dependencies: HashMap<String, String>,
+}
+
+impl PackIndex {
+    /// 由实例的加载器生成 `dependencies`
+    fn dependencies_of(manifest: &InstanceManifest) -> Result<HashMap<String, String>> {
+        let mut dependencies = HashMap::from([("minecraft".to_string(), manifest.game_version.clone())]);
+        let key = match manifest.loader {
+            ModLoader::Vanilla => return Ok(dependencies),
+            ModLoader::Fabric => "fabric-loader",
+            ModLoader::Quilt => "quilt-loader",
+            ModLoader::Forge => "forge",
+            ModLoader::NeoForge => "neoforge",
+        };
+        let version = manifest
+            .loader_version
+            .as_deref()
+            .ok_or_else(|| anyhow!("实例未指定 {} 版本", manifest.loader.as_str()))?;
+        // 整合包中 Forge 系只记录加载器版本号，与 forge_maven_version 相反
+        let version = version
+            .strip_prefix(&format!("{}-", manifest.game_version))
+            .unwrap_or(version);
+        dependencies.insert(key.to_string(), version.to_string());
+        Ok(dependencies)
+    }
+
+    /// 由 `dependencies` 得到游戏版本与加载器
+    fn loader(&self) -> Result<(String, ModLoader, Option<String>)> {
+        let game_version = self
+            .dependencies
+            .get("minecraft")
+            .cloned()
+            .ok_or_else(|| anyhow!("整合包未指定 Minecraft 版本"))?;
+        let mut loader = (ModLoader::Vanilla, None);
+        for (id, version) in &self.dependencies {
+            let found = match id.as_str() {
+                "minecraft" => continue,
+                "fabric-loader" => ModLoader::Fabric,
+                "quilt-loader" => ModLoader::Quilt,
+                "forge" => ModLoader::Forge,
+                "neoforge" => ModLoader::NeoForge,
+                other => return Err(anyhow!("不支持的整合包依赖: {}", other)),
+            };
+            if loader.0 != ModLoader::Vanilla {
+                return Err(anyhow!("整合包指定了多个加载器"));
+            }
+            loader = (found, Some(forge_maven_version(found, &game_version, version)));
+        }
+        Ok((game_version, loader.0, loader.1))
+    }
+}
+
+/// 整合包的可选文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MrpackOptionalFile {
+    pub path: String,
+    pub size: u64,
+}
+
+/// 整合包概要，用于导入前展示
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MrpackInfo {
+    pub name: String,
+    pub version_id: String,
+    pub summary: Option<String>,
+    pub game_version: String,
+    pub loader: ModLoader,
+    pub loader_version: Option<String>,
+    /// 客户端需要下载的文件数
+    pub file_count: u32,
+    /// 客户端可选的文件，导入时可跳过
+    pub optional_files: Vec<MrpackOptionalFile>,
+}
+
+/// 只允许普通的相对路径，拒绝绝对路径与 `..`
+pub(crate) fn safe_relative_path(path: &str) -> Option<PathBuf> {
+    let relative = PathBuf::from(path);
+    let valid = !path.is_empty()
+        && !path.contains('\\')
+        && relative.components().all(|component| matches!(component, Component::Normal(_)));
+    valid.then_some(relative)
+}
+
+/// 读取整合包索引
+fn read_index(archive_path: &Path) -> Result<PackIndex> {
+    let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+    let mut entry = archive
+        .by_name(config::INDEX_FILE)
+        .map_err(|_| anyhow!("不是有效的 Modrinth 整合包: 缺少 {}", config::INDEX_FILE))?;
+    let mut text = String::new();
+    entry.read_to_string(&mut text)?;
+    let index: PackIndex = serde_json::from_str(&text)?;
+    if index.game != "minecraft" {
+        return Err(anyhow!("不支持的游戏: {}", index.game));
+    }
+    if index.format_version != config::FORMAT_VERSION {
+        return Err(anyhow!("不支持的整合包格式版本: {}", index.format_version));
+    }
+    Ok(index)
+}
+
+/// 解压覆盖目录，`client-overrides` 覆盖 `overrides` 中的同名文件
+fn extract_overrides(archive_path: &Path, target: &Path) -> Result<()> {
+    let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+    for prefix in config::OVERRIDE_DIRS {
+        archive::extract_zip(
+            &mut archive,
+            target,
+            |name| {
+                let relative = name.strip_prefix(prefix).ok()?;
+                (relative.as_os_str() != instance::MANIFEST_FILE).then(|| relative.to_path_buf())
+            },
+            |_, _| {},
+        )?;
+    }
+    Ok(())
+}
+
+/// 依次尝试各下载地址，并校验 SHA-1 与 SHA-512
+async fn download_pack_file(target: &Path, file: &PackFile) -> Result<()> {
+    let relative = safe_relative_path(&file.path).ok_or_else(|| anyhow!("无效的文件路径: {}", file.path))?;
+    if relative.as_os_str() == instance::MANIFEST_FILE {
+        return Err(anyhow!("无效的文件路径: {}", file.path));
+    }
+    let sha1 = file
+        .hashes
+        .get("sha1")
+        .ok_or_else(|| anyhow!("{} 缺少 SHA-1", file.path))?;
+    let dest = target.join(relative);
+
+    let mut last_error = anyhow!("{} 没有下载地址", file.path);
+    for url in &file.downloads {
+        match download::download_file(url, &dest, Some(sha1)).await {
+            Ok(()) => {
+                if let Some(sha512) = file.hashes.get("sha512") {
+                    if let Err(e) = checksum::verify_file_sha512(&dest, sha512).await {
+                        fs::remove_file(&dest).await.ok();
+                        return Err(e);
+                    }
+                }
+                return Ok(());
+            }
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+/// 下载客户端需要的文件，跳过不支持客户端的文件与用户排除的可选文件
+async fn download_pack_files(
+    target: &Path,
+    files: &[PackFile],
+    skipped_optional: &[String],
+    on_progress: &ProgressCallback,
+) -> Result<()> {
+    use futures::StreamExt;
+
+    let files: Vec<&PackFile> = files
+        .iter()
+        .filter(|file| match file.client_support() {
+            EnvSupport::Required => true,
+            EnvSupport::Optional => !skipped_optional.contains(&file.path),
+            EnvSupport::Unsupported => false,
+        })
+        .collect();
+    let total = files.len();
+    let downloads: Vec<_> = files.into_iter().map(|file| download_pack_file(target, file)).collect();
+    let mut downloads = futures::stream::iter(downloads).buffer_unordered(config::CONCURRENT_DOWNLOADS);
+
+    let mut completed = 0;
+    while let Some(result) = downloads.next().await {
+        result?;
+        completed += 1;
+        let progress = 0.1 + 0.8 * completed as f64 / total as f64;
+        on_progress(progress, format!("下载文件... {}/{}", completed, total)).await;
+    }
+    Ok(())
+}
+
+/// 读取 `.mrpack` 的概要信息
+pub async fn read_mrpack(path: String) -> Result<MrpackInfo> {
+    let index = tokio::task::spawn_blocking(move || read_index(Path::new(&path))).await??;
+    let (game_version, loader, loader_version) = index.loader()?;
+    let client_files = || {
+        index
+            .files
+            .iter()
+            .filter(|file| file.client_support() != EnvSupport::Unsupported)
+    };
+    Ok(MrpackInfo {
+        name: index.name.clone(),
+        version_id: index.version_id.clone(),
+        summary: index.summary.clone(),
+        game_version,
+        loader,
+        loader_version,
+        file_count: client_files().count() as u32,
+        optional_files: client_files()
+            .filter(|file| file.client_support() == EnvSupport::Optional)
+            .map(|file| MrpackOptionalFile {
+                path: file.path.clone(),
+                size: file.file_size,
+            })
+            .collect(),
+    })
+}
+
+/// 从 `.mrpack` 创建实例，`skipped_optional` 为不安装的可选文件路径
+pub async fn import_mrpack(
+    resource_dir: String,
+    path: String,
+    skipped_optional: Vec<String>,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<InstanceManifest> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    install_mrpack(resource_dir, PathBuf::from(path), skipped_optional, &on_progress).await
+}
+
+/// 下载 Modrinth 上的整合包版本并创建实例
+pub async fn import_mrpack_version(
+    resource_dir: String,
+    version_id: String,
+    skipped_optional: Vec<String>,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<InstanceManifest> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    on_progress(0.0, "获取整合包信息".to_string()).await;
+    let version = ModrinthClient::default().version(&version_id).await?;
+    let file = version
+        .files
+        .iter()
+        .find(|file| file.filename.ends_with(".mrpack"))
+        .or(version.primary_file())
+        .ok_or_else(|| anyhow!("版本 {} 没有可下载的文件", version_id))?;
+
+    let archive = std::env::temp_dir().join(format!("aml-{}.mrpack", uuid::Uuid::new_v4().simple()));
+    on_progress(0.02, format!("下载 {}", file.filename)).await;
+    download::download_file(&file.url, &archive, Some(&file.hashes.sha1)).await?;
+    let result = install_mrpack(resource_dir, archive.clone(), skipped_optional, &on_progress).await;
+    fs::remove_file(&archive).await.ok();
+    result
+}
+
+async fn install_mrpack(
+    resource_dir: String,
+    archive_path: PathBuf,
+    skipped_optional: Vec<String>,
+    on_progress: &ProgressCallback,
+) -> Result<InstanceManifest> {
+    let index = {
+        let archive_path = archive_path.clone();
+        tokio::task::spawn_blocking(move || read_index(&archive_path)).await??
+    };
+    let (game_version, loader, loader_version) = index.loader()?;
+    let created = instance::create_instance(
+        resource_dir.clone(),
+        NewInstance {
+            name: index.name.clone(),
+            game_version,
+            loader,
+            loader_version,
+            icon: None,
+        },
+    )
+    .await?;
+
+    let result = async {
+        let target = PathBuf::from(&created.path);
+        on_progress(0.1, "下载文件".to_string()).await;
+        download_pack_files(&target, &index.files, &skipped_optional, on_progress).await?;
+
+        on_progress(0.9, "应用覆盖文件".to_string()).await;
+        tokio::task::spawn_blocking(move || extract_overrides(&archive_path, &target)).await??;
+        on_progress(1.0, "导入完成".to_string()).await;
+        Ok(created.clone())
+    }
+    .await;
+
+    if result.is_err() {
+        instance::delete_instance(resource_dir, created.id).await.ok();
+    }
+    result
+}
+
+/// 导出整合包的选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MrpackExportOptions {
+    /// 整合包名称，为空时使用实例名称
+    pub name: Option<String>,
+    /// 整合包版本号
+    pub version_id: String,
+    pub summary: Option<String>,
+    /// 要导出的顶层条目 (相对实例目录)
+    pub include: Vec<String>,
+    /// 排除规则 (glob，相对实例目录)
+    pub exclude: Vec<String>,
+}
+
+/// 按哈希到 Modrinth 查询文件，已知的文件转为下载引用，其余留作覆盖文件。
+/// 无法访问 Modrinth 时全部文件放入覆盖目录
+async fn split_known_files(
+    client: &ModrinthClient,
+    files: Vec<ExportFile>,
+) -> Result<(Vec<ExportFile>, Vec<PackFile>)> {
+    let mut candidates = Vec::new();
+    let mut overrides = Vec::new();
+    for file in files {
+        let lookup = config::LOOKUP_DIRS
+            .iter()
+            .any(|dir| file.relative.starts_with(&format!("{}/", dir)));
+        if lookup {
+            let sha1 = checksum::file_sha1(&file.path).await?;
+            candidates.push((file, sha1));
+        } else {
+            overrides.push(file);
+        }
+    }
+    if candidates.is_empty() {
+        return Ok((overrides, Vec::new()));
+    }
+
+    let hashes: Vec<String> = candidates.iter().map(|(_, sha1)| sha1.clone()).collect();
+    let versions = match client.versions_from_hashes(&hashes, HashAlgorithm::Sha1).await {
+        Ok(versions) => versions,
+        Err(e) => {
+            tracing::warn!("无法查询 Modrinth，全部文件放入覆盖目录: {}", e);
+            overrides.extend(candidates.into_iter().map(|(file, _)| file));
+            return Ok((overrides, Vec::new()));
+        }
+    };
+    let mut project_ids: Vec<String> = versions.values().map(|version| version.project_id.clone()).collect();
+    project_ids.sort();
+    project_ids.dedup();
+    // 项目信息只用于标注客户端 / 服务端支持，查询失败时不标注
+    let projects: HashMap<String, _> = match client.projects(&project_ids).await {
+        Ok(projects) => projects.into_iter().map(|project| (project.id.clone(), project)).collect(),
+        Err(e) => {
+            tracing::warn!("无法获取 Modrinth 项目信息: {}", e);
+            HashMap::new()
+        }
+    };
+
+    let mut known = Vec::new();
+    for (file, sha1) in candidates {
+        let remote = versions.get(&sha1).and_then(|version| {
+            let remote = version.files.iter().find(|remote| remote.hashes.sha1 == sha1)?;
+            Some((version, remote))
+        });
+        let Some((version, remote)) = remote else {
+            overrides.push(file);
+            continue;
+        };
+        let env = projects.get(&version.project_id).map(|project| PackEnv {
+            client: EnvSupport::from_side(&project.client_side),
+            server: EnvSupport::from_side(&project.server_side),
+        });
+        known.push(PackFile {
+            path: file.relative,
+            hashes: HashMap::from([
+                ("sha1".to_string(), sha1),
+                ("sha512".to_string(), remote.hashes.sha512.clone()),
+            ]),
+            env,
+            downloads: vec![remote.url.clone()],
+            file_size: file.size,
+        });
+    }
+    known.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok((overrides, known))
+}
+
+/// 流式写入整合包，每写完一个文件发送一次累计字节数
+fn write_mrpack(
+    dest: &Path,
+    index: &PackIndex,
+    overrides: &[ExportFile],
+    progress: mpsc::UnboundedSender<u64>,
+) -> Result<()> {
+    let mut zip = ZipWriter::new(BufWriter::new(File::create(dest)?));
+    let options = FileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .large_file(true);
+
+    zip.start_file(config::INDEX_FILE, options)?;
+    serde_json::to_writer_pretty(&mut zip, index)?;
+
+    let mut written = 0;
+    for file in overrides {
+        zip.start_file(format!("{}/{}", config::OVERRIDE_DIRS[0], file.relative), options)?;
+        io::copy(&mut BufReader::new(File::open(&file.path)?), &mut zip)?;
+        written += file.size;
+        progress.send(written).ok();
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+/// 导出实例为 `.mrpack`，Modrinth 上能找到的文件以下载地址引用，其余放入 `overrides/`
+pub async fn export_mrpack(
+    resource_dir: String,
+    instance_id: String,
+    dest_path: String,
+    options: MrpackExportOptions,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<()> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    export_mrpack_with(
+        &ModrinthClient::default(),
+        resource_dir,
+        instance_id,
+        PathBuf::from(dest_path),
+        options,
+        &on_progress,
+    )
+    .await
+}
+
+async fn export_mrpack_with(
+    client: &ModrinthClient,
+    resource_dir: String,
+    instance_id: String,
+    dest: PathBuf,
+    options: MrpackExportOptions,
+    on_progress: &ProgressCallback,
+) -> Result<()> {
+    let manifest = instance::get_instance(resource_dir, instance_id).await?;
+    let dependencies = PackIndex::dependencies_of(&manifest)?;
+    let root = PathBuf::from(&manifest.path);
+    let exclude = instance_export::build_globset(&options.exclude)?;
+
+    on_progress(0.0, "收集文件".to_string()).await;
+    let files = {
+        let (root, include) = (root.clone(), options.include.clone());
+        tokio::task::spawn_blocking(move || instance_export::collect_files(&root, &include, &exclude)).await??
+    };
+    on_progress(0.1, "查询 Modrinth".to_string()).await;
+    let (overrides, known) = split_known_files(client, files).await?;
+
+    let index = PackIndex {
+        format_version: config::FORMAT_VERSION,
+        game: "minecraft".to_string(),
+        version_id: options.version_id,
+        name: options.name.filter(|name| !name.is_empty()).unwrap_or(manifest.name),
+        summary: options.summary.filter(|summary| !summary.is_empty()),
+        files: known,
+        dependencies,
+    };
+
+    let part = dest.with_extension("mrpack.part");
+    let total: u64 = overrides.iter().map(|file| file.size).sum::<u64>().max(1);
+    let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+    let writer = {
+        let part = part.clone();
+        tokio::task::spawn_blocking(move || write_mrpack(&part, &index, &overrides, progress_tx))
+    };
+    while let Some(written) = progress_rx.recv().await {
+        on_progress(0.2 + written as f64 / total as f64 * 0.79, "写入整合包".to_string()).await;
+    }
+
+    match writer.await? {
+        Ok(()) => {
+            fs::rename(&part, &dest).await?;
+            on_progress(1.0, "导出完成".to_string()).await;
+            Ok(())
+        }
+        Err(e) => {
+            fs::remove_file(&part).await.ok();
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::forge::tests::no_progress;
+    use crate::api::modrinth::tests::unreachable_url;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// 创建带有一个已收录模组、一个自制模组与配置文件的 Fabric 实例
+    async fn fabric_instance(resource_dir: &Path) -> InstanceManifest {
+        let manifest = instance::create_instance(
+            resource_dir.to_string_lossy().to_string(),
+            NewInstance {
+                name: "Pack".to_string(),
+                game_version: "1.20.1".to_string(),
+                loader: ModLoader::Fabric,
+                loader_version: Some("0.15.3".to_string()),
+                icon: None,
+            },
+        )
+        .await
+        .unwrap();
+        let root = Path::new(&manifest.path);
+        std::fs::create_dir_all(root.join("mods")).unwrap();
+        std::fs::create_dir_all(root.join("config")).unwrap();
+        std::fs::write(root.join("mods/known.jar"), "known").unwrap();
+        std::fs::write(root.join("mods/custom.jar"), "custom").unwrap();
+        std::fs::write(root.join("config/a.toml"), "a = 1").unwrap();
+        manifest
+    }
+
+    fn export_options() -> MrpackExportOptions {
+        MrpackExportOptions {
+            name: None,
+            version_id: "1.0.0".to_string(),
+            summary: None,
+            include: vec!["mods".to_string(), "config".to_string()],
+            exclude: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn export_then_import_round_trip() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let source = fabric_instance(source_dir.path()).await;
+        let known = Path::new(&source.path).join("mods/known.jar");
+        let sha1 = checksum::file_sha1(&known).await.unwrap();
+        let sha512 = checksum::file_sha512(&known).await.unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/version_files"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                sha1.clone(): {
+                    "id": "v1",
+                    "project_id": "p1",
+                    "files": [{
+                        "url": format!("{}/files/known.jar", server.uri()),
+                        "filename": "known.jar",
+                        "primary": true,
+                        "hashes": { "sha1": sha1, "sha512": sha512 },
+                    }],
+                },
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(path("/projects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "p1", "client_side": "required", "server_side": "optional" },
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(path("/files/known.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes("known"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ModrinthClient::new(&format!("{}/", server.uri()));
+        let pack = source_dir.path().join("pack.mrpack");
+        export_mrpack_with(
+            &client,
+            source_dir.path().to_string_lossy().to_string(),
+            source.id.clone(),
+            pack.clone(),
+            export_options(),
+            &no_progress(),
+        )
+        .await
+        .unwrap();
+
+        let info = read_mrpack(pack.to_string_lossy().to_string()).await.unwrap();
+        assert_eq!(info.name, "Pack");
+        assert_eq!(info.game_version, "1.20.1");
+        assert_eq!(info.loader, ModLoader::Fabric);
+        assert_eq!(info.loader_version.as_deref(), Some("0.15.3"));
+        assert_eq!(info.file_count, 1);
+
+        let target_dir = tempfile::tempdir().unwrap();
+        let imported = install_mrpack(
+            target_dir.path().to_string_lossy().to_string(),
+            pack,
+            Vec::new(),
+            &no_progress(),
+        )
+        .await
+        .unwrap();
+        let root = Path::new(&imported.path);
+        assert_eq!(std::fs::read_to_string(root.join("mods/known.jar")).unwrap(), "known");
+        assert_eq!(std::fs::read_to_string(root.join("mods/custom.jar")).unwrap(), "custom");
+        assert_eq!(std::fs::read_to_string(root.join("config/a.toml")).unwrap(), "a = 1");
+    }
+
+    #[tokio::test]
+    async fn exports_everything_as_overrides_when_modrinth_is_unreachable() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let source = fabric_instance(source_dir.path()).await;
+
+        let client = ModrinthClient::new(&format!("{}/", unreachable_url()));
+
+        let pack = source_dir.path().join("pack.mrpack");
+        export_mrpack_with(
+            &client,
+            source_dir.path().to_string_lossy().to_string(),
+            source.id,
+            pack.clone(),
+            export_options(),
+            &no_progress(),
+        )
+        .await
+        .unwrap();
+
+        let index = read_index(&pack).unwrap();
+        assert!(index.files.is_empty());
+        let archive = ZipArchive::new(File::open(&pack).unwrap()).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "modrinth.index.json",
+                "overrides/config/a.toml",
+                "overrides/mods/custom.jar",
+                "overrides/mods/known.jar",
+            ]
+        );
+    }
+
+    fn pack_file(server: &MockServer, path: &str, content: &str, client: EnvSupport) -> serde_json::Value {
+        use sha1::Digest;
+        serde_json::json!({
+            "path": path,
+            "hashes": {
+                "sha1": checksum::hex_string(&sha1::Sha1::digest(content)),
+                "sha512": checksum::hex_string(&sha2::Sha512::digest(content)),
+            },
+            "env": { "client": client, "server": EnvSupport::Required },
+            "downloads": [format!("{}/files/{}", server.uri(), content)],
+            "fileSize": content.len(),
+        })
+    }
+
+    /// 写入只含索引的整合包
+    fn write_pack(dest: &Path, files: Vec<serde_json::Value>, dependencies: serde_json::Value) {
+        let index = serde_json::json!({
+            "formatVersion": 1,
+            "game": "minecraft",
+            "versionId": "1.0.0",
+            "name": "Pack",
+            "files": files,
+            "dependencies": dependencies,
+        });
+        let mut zip = ZipWriter::new(File::create(dest).unwrap());
+        zip.start_file(config::INDEX_FILE, FileOptions::default()).unwrap();
+        serde_json::to_writer(&mut zip, &index).unwrap();
+        zip.finish().unwrap();
+    }
+
+    async fn serve_file(server: &MockServer, content: &str, expected: u64) {
+        Mock::given(path(format!("/files/{}", content)))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(content.to_string()))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    fn fabric_dependencies() -> serde_json::Value {
+        serde_json::json!({ "minecraft": "1.20.1", "fabric-loader": "0.15.3" })
+    }
+
+    #[tokio::test]
+    async fn skips_unsupported_and_skipped_optional_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        serve_file(&server, "required", 1).await;
+        serve_file(&server, "kept", 1).await;
+        serve_file(&server, "skipped", 0).await;
+        serve_file(&server, "server-only", 0).await;
+        let pack = dir.path().join("pack.mrpack");
+        write_pack(
+            &pack,
+            vec![
+                pack_file(&server, "mods/required.jar", "required", EnvSupport::Required),
+                pack_file(&server, "mods/kept.jar", "kept", EnvSupport::Optional),
+                pack_file(&server, "mods/skipped.jar", "skipped", EnvSupport::Optional),
+                pack_file(&server, "mods/server-only.jar", "server-only", EnvSupport::Unsupported),
+            ],
+            fabric_dependencies(),
+        );
+
+        let info = read_mrpack(pack.to_string_lossy().to_string()).await.unwrap();
+        assert_eq!(info.file_count, 3);
+        let optional: Vec<&str> = info.optional_files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(optional, ["mods/kept.jar", "mods/skipped.jar"]);
+
+        let imported = install_mrpack(
+            dir.path().to_string_lossy().to_string(),
+            pack,
+            vec!["mods/skipped.jar".to_string()],
+            &no_progress(),
+        )
+        .await
+        .unwrap();
+        let mods = Path::new(&imported.path).join("mods");
+        assert_eq!(std::fs::read_to_string(mods.join("required.jar")).unwrap(), "required");
+        assert_eq!(std::fs::read_to_string(mods.join("kept.jar")).unwrap(), "kept");
+        assert!(!mods.join("skipped.jar").exists());
+        assert!(!mods.join("server-only.jar").exists());
+    }
+
+    #[tokio::test]
+    async fn rejects_sha512_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = MockServer::start().await;
+        serve_file(&server, "tampered", 1).await;
+        let mut file = pack_file(&server, "mods/tampered.jar", "tampered", EnvSupport::Required);
+        file["hashes"]["sha512"] = serde_json::json!("0".repeat(128));
+        let pack = dir.path().join("pack.mrpack");
+        write_pack(&pack, vec![file], fabric_dependencies());
+
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        assert!(install_mrpack(resource_dir.clone(), pack, Vec::new(), &no_progress()).await.is_err());
+        // 失败的导入不留下实例
+        assert!(instance::list_instances(resource_dir).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_paths_outside_the_instance() {
+        let server = MockServer::start().await;
+        serve_file(&server, "escape", 0).await;
+        for path in ["../escape.jar", "mods/../../escape.jar", "..\\escape.jar", "/tmp/escape.jar"] {
+            let dir = tempfile::tempdir().unwrap();
+            let pack = dir.path().join("pack.mrpack");
+            write_pack(&pack, vec![pack_file(&server, path, "escape", EnvSupport::Required)], fabric_dependencies());
+
+            let resource_dir = dir.path().to_string_lossy().to_string();
+            let result = install_mrpack(resource_dir.clone(), pack, Vec::new(), &no_progress()).await;
+            assert!(result.is_err(), "{}", path);
+            assert!(instance::list_instances(resource_dir).await.unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn maps_loader_dependencies() {
+        let loader = |dependencies: serde_json::Value| {
+            let index: PackIndex = serde_json::from_value(serde_json::json!({
+                "formatVersion": 1,
+                "game": "minecraft",
+                "versionId": "1.0.0",
+                "name": "Pack",
+                "files": [],
+                "dependencies": dependencies,
+            }))
+            .unwrap();
+            index.loader().unwrap()
+        };
+        let expected = |game_version: &str, loader: ModLoader, version: &str| {
+            (game_version.to_string(), loader, Some(version.to_string()))
+        };
+
+        assert_eq!(
+            loader(serde_json::json!({ "minecraft": "1.20.1", "forge": "47.2.0" })),
+            expected("1.20.1", ModLoader::Forge, "1.20.1-47.2.0")
+        );
+        assert_eq!(
+            loader(serde_json::json!({ "minecraft": "1.20.1", "neoforge": "47.1.84" })),
+            expected("1.20.1", ModLoader::NeoForge, "1.20.1-47.1.84")
+        );
+        assert_eq!(
+            loader(serde_json::json!({ "minecraft": "1.21", "neoforge": "21.0.167" })),
+            expected("1.21", ModLoader::NeoForge, "21.0.167")
+        );
+        assert_eq!(
+            loader(serde_json::json!({ "minecraft": "1.20.1", "quilt-loader": "0.23.0" })),
+            expected("1.20.1", ModLoader::Quilt, "0.23.0")
+        );
+        assert_eq!(
+            loader(serde_json::json!({ "minecraft": "1.20.1" })),
+            ("1.20.1".to_string(), ModLoader::Vanilla, None)
+        );
+
+        // 导出时去掉游戏版本前缀，与导入互逆
+        let mut manifest = crate::api::database::tests::manifest("a", "A", "1.20.1", ModLoader::Forge);
+        manifest.loader_version = Some("1.20.1-47.2.0".to_string());
+        let dependencies = PackIndex::dependencies_of(&manifest).unwrap();
+        assert_eq!(dependencies["forge"], "47.2.0");
+    }
+}
@@ -0,0 +1,1105 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::frb;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::api::database;
+use crate::api::modrinth_cache::{self, Freshness};
+use crate::config::MODRINTH_API_URL;
+use crate::download;
+
+/// 配置常量
+mod config {
+    use std::time::Duration;
+    /// 触发限流 (429) 后的最大重试次数
+    pub const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+    /// 响应未给出重置时间时的等待时间
+    pub const DEFAULT_RESET_WAIT: Duration = Duration::from_secs(10);
+    /// 单次批量查询的最大 ID 数，避免 URL 过长
+    pub const MAX_IDS_PER_REQUEST: usize = 500;
+}
+
+/// 限流状态 (来自 `X-Ratelimit-*` 响应头)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    /// 当前窗口内剩余的请求数
+    pub remaining: u32,
+    /// 每个窗口允许的请求数
+    pub limit: u32,
+    /// 距窗口重置的秒数
+    pub reset_secs: u64,
+}
+
+/// 最近一次响应的限流状态及其记录时间
+static RATE_LIMIT: Mutex<Option<(RateLimitStatus, Instant)>> = Mutex::new(None);
+
+/// 文件哈希算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+/// 搜索参数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchQuery {
+    pub query: Option<String>,
+    /// 外层为 AND，内层为 OR，如 `[["project_type:mod"], ["versions:1.20.1"]]`
+    pub facets: Vec<Vec<String>>,
+    /// relevance / downloads / follows / newest / updated
+    pub index: Option<String>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+/// 搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    pub offset: u32,
+    pub limit: u32,
+    pub total_hits: u32,
+}
+
+/// 搜索结果中的项目
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchHit {
+    pub project_id: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub project_type: String,
+    pub author: String,
+    pub categories: Vec<String>,
+    pub display_categories: Vec<String>,
+    pub versions: Vec<String>,
+    pub client_side: String,
+    pub server_side: String,
+    pub downloads: u64,
+    pub follows: u64,
+    pub icon_url: Option<String>,
+    pub color: Option<u32>,
+    pub latest_version: Option<String>,
+    pub license: String,
+    pub gallery: Vec<String>,
+    pub featured_gallery: Option<String>,
+    pub date_created: Option<DateTime<Utc>>,
+    pub date_modified: Option<DateTime<Utc>>,
+}
+
+/// 项目许可证
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct License {
+    pub id: String,
+    pub name: String,
+    pub url: Option<String>,
+}
+
+/// 项目画廊图片
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GalleryImage {
+    pub url: String,
+    pub featured: bool,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+/// 项目详情
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Project {
+    pub id: String,
+    pub slug: String,
+    pub project_type: String,
+    pub team: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub categories: Vec<String>,
+    pub additional_categories: Vec<String>,
+    pub client_side: String,
+    pub server_side: String,
+    pub status: String,
+    pub downloads: u64,
+    pub followers: u64,
+    pub icon_url: Option<String>,
+    pub color: Option<u32>,
+    pub license: License,
+    pub source_url: Option<String>,
+    pub issues_url: Option<String>,
+    pub wiki_url: Option<String>,
+    pub discord_url: Option<String>,
+    pub versions: Vec<String>,
+    pub game_versions: Vec<String>,
+    pub loaders: Vec<String>,
+    pub gallery: Vec<GalleryImage>,
+    pub published: Option<DateTime<Utc>>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+/// 文件哈希
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FileHashes {
+    pub sha1: String,
+    pub sha512: String,
+}
+
+/// 版本中的文件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VersionFile {
+    pub hashes: FileHashes,
+    pub url: String,
+    pub filename: String,
+    pub primary: bool,
+    pub size: u64,
+    /// required-resource-pack / optional-resource-pack 等，普通文件为空
+    pub file_type: Option<String>,
+}
+
+/// 版本依赖
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VersionDependency {
+    pub version_id: Option<String>,
+    pub project_id: Option<String>,
+    pub file_name: Option<String>,
+    /// required / optional / incompatible / embedded
+    pub dependency_type: String,
+}
+
+/// 项目版本
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Version {
+    pub id: String,
+    pub project_id: String,
+    pub author_id: String,
+    pub name: String,
+    pub version_number: String,
+    pub changelog: Option<String>,
+    /// release / beta / alpha
+    pub version_type: String,
+    pub status: String,
+    pub featured: bool,
+    pub downloads: u64,
+    pub game_versions: Vec<String>,
+    pub loaders: Vec<String>,
+    pub files: Vec<VersionFile>,
+    pub dependencies: Vec<VersionDependency>,
+    pub date_published: Option<DateTime<Utc>>,
+}
+
+impl Version {
+    /// 主文件：标记为 primary 的文件，没有时取第一个
+    pub(crate) fn primary_file(&self) -> Option<&VersionFile> {
+        self.files.iter().find(|file| file.primary).or(self.files.first())
+    }
+}
+
+/// 用户
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModrinthUser {
+    pub id: String,
+    pub username: String,
+    pub name: Option<String>,
+    pub avatar_url: Option<String>,
+}
+
+/// 团队成员
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeamMember {
+    pub team_id: String,
+    pub user: ModrinthUser,
+    pub role: String,
+    pub ordering: i64,
+}
+
+/// 分类标签
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CategoryTag {
+    pub name: String,
+    pub project_type: String,
+    pub header: String,
+    /// SVG 图标
+    pub icon: String,
+}
+
+/// 加载器标签
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoaderTag {
+    pub name: String,
+    pub supported_project_types: Vec<String>,
+    pub icon: String,
+}
+
+/// 游戏版本标签
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameVersionTag {
+    pub version: String,
+    /// release / snapshot / alpha / beta
+    pub version_type: String,
+    pub date: Option<DateTime<Utc>>,
+    pub major: bool,
+}
+
+/// 读取限流响应头
+fn read_rate_limit(response: &reqwest::Response) -> Option<RateLimitStatus> {
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+    };
+    Some(RateLimitStatus {
+        remaining: header("x-ratelimit-remaining")? as u32,
+        limit: header("x-ratelimit-limit").unwrap_or_default() as u32,
+        reset_secs: header("x-ratelimit-reset").unwrap_or_default(),
+    })
+}
+
+/// 额度耗尽时等待到窗口重置
+async fn wait_for_rate_limit() {
+    let wait = {
+        let state = RATE_LIMIT.lock().unwrap_or_else(|e| e.into_inner());
+        match *state {
+            Some((status, recorded)) if status.remaining == 0 => {
+                Duration::from_secs(status.reset_secs).checked_sub(recorded.elapsed())
+            }
+            _ => None,
+        }
+    };
+    if let Some(wait) = wait {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+fn parse<T: DeserializeOwned>(url: &str, text: &str) -> Result<T> {
+    serde_json::from_str(text).map_err(|e| anyhow!("解析 {} 失败: {}", url, e))
+}
+
+/// Modrinth API v2 客户端，GET 请求经由持久缓存 (见 `modrinth_cache`)
+#[derive(Debug, Clone)]
+#[frb(ignore)]
+pub(crate) struct ModrinthClient {
+    base_url: String,
+    /// 响应缓存所在的数据库，为空时不缓存
+    cache: Option<SqlitePool>,
+    /// 离线模式：只读缓存，不发出网络请求
+    offline: bool,
+    /// 当前时间，用于判断缓存是否过期
+    clock: fn() -> DateTime<Utc>,
+}
+
+impl Default for ModrinthClient {
+    fn default() -> Self {
+        Self::new(MODRINTH_API_URL)
+    }
+}
+
+impl ModrinthClient {
+    /// `base_url` 以 `/` 结尾，如 `https://api.modrinth.com/v2/`。
+    /// 使用全局数据库作为缓存，并按当前设置决定是否离线
+    pub(crate) fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            cache: database::pool().ok().cloned(),
+            offline: modrinth_cache::is_offline(),
+            clock: Utc::now,
+        }
+    }
+
+    /// 使用指定的缓存数据库、离线模式与时钟
+    #[cfg(test)]
+    pub(crate) fn with_cache(mut self, cache: SqlitePool, offline: bool, clock: fn() -> DateTime<Utc>) -> Self {
+        self.cache = Some(cache);
+        self.offline = offline;
+        self.clock = clock;
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// 发送请求并返回响应文本，记录限流状态；遇到 429 时等待重置后重试
+    async fn send(&self, build: impl Fn() -> RequestBuilder) -> Result<String> {
+        let mut attempt = 0;
+        loop {
+            wait_for_rate_limit().await;
+            let response = build().send().await?;
+            let rate_limit = read_rate_limit(&response);
+            if let Some(status) = rate_limit {
+                *RATE_LIMIT.lock().unwrap_or_else(|e| e.into_inner()) = Some((status, Instant::now()));
+            }
+
+            let status = response.status();
+            if status == StatusCode::TOO_MANY_REQUESTS && attempt < config::MAX_RATE_LIMIT_RETRIES {
+                attempt += 1;
+                let wait = rate_limit
+                    .map(|status| Duration::from_secs(status.reset_secs.max(1)))
+                    .unwrap_or(config::DEFAULT_RESET_WAIT);
+                tokio::time::sleep(wait).await;
+                continue;
+            }
+            let url = response.url().to_string();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(anyhow!("Modrinth 请求失败 {}: {} {}", url, status, body));
+            }
+            return Ok(response.text().await?);
+        }
+    }
+
+    /// 请求并写入缓存，`key` 即完整的请求地址
+    async fn fetch_and_store(&self, key: &str) -> Result<String> {
+        let text = self.send(|| download::client().get(key)).await?;
+        modrinth_cache::store_response(self.cache.as_ref(), key, &text, (self.clock)()).await?;
+        Ok(text)
+    }
+
+    /// 在后台刷新过期的缓存
+    fn revalidate(&self, key: String) {
+        if !modrinth_cache::begin_refresh(&key) {
+            return;
+        }
+        let client = self.clone();
+        tokio::spawn(async move {
+            client.fetch_and_store(&key).await.ok();
+            modrinth_cache::end_refresh(&key);
+        });
+    }
+
+    /// GET 请求。可缓存的请求在有效期内直接读取缓存；过期后先返回缓存并在后台刷新；
+    /// 超出重新验证窗口时同步请求，网络不可用时仍返回缓存。离线模式只读缓存
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, String)]) -> Result<T> {
+        let url = self.url(path);
+        let request = || download::client().get(&url).query(query);
+        let Some(ttl) = modrinth_cache::ttl(path) else {
+            return parse(&url, &self.send(request).await?);
+        };
+        let key = if query.is_empty() {
+            url.clone()
+        } else {
+            request().build()?.url().to_string()
+        };
+
+        let text = match modrinth_cache::cached_response(self.cache.as_ref(), &key).await {
+            None if self.offline => return Err(modrinth_cache::offline_error(&key)),
+            None => self.fetch_and_store(&key).await?,
+            Some(cached) if self.offline => cached.body,
+            Some(cached) => match cached.freshness(ttl, (self.clock)()) {
+                Freshness::Fresh => cached.body,
+                Freshness::Stale => {
+                    self.revalidate(key.clone());
+                    cached.body
+                }
+                Freshness::Expired => match self.fetch_and_store(&key).await {
+                    Ok(text) => text,
+                    Err(e) if modrinth_cache::is_network_error(&e) => cached.body,
+                    Err(e) => return Err(e),
+                },
+            },
+        };
+        parse(&key, &text)
+    }
+
+    /// 批量获取项目或版本。每一项按单项请求的地址 (`{entity}/{key}`) 分别缓存，
+    /// `keys_of` 给出可用于请求该项的全部键 (ID 与 slug)；
+    /// 只请求缺少或已超出重新验证窗口的项，离线时只返回已缓存的项
+    async fn get_many<T>(
+        &self,
+        batch_path: &str,
+        entity: &str,
+        ids: &[String],
+        keys_of: fn(&T) -> Vec<&str>,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let ttl = modrinth_cache::ttl(batch_path).unwrap_or_default();
+        let now = (self.clock)();
+        let key_of = |id: &str| self.url(&format!("{}/{}", entity, id));
+
+        let mut found: HashMap<String, T> = HashMap::new();
+        let mut fallback: HashMap<String, T> = HashMap::new();
+        let mut missing = Vec::new();
+        let mut stale = Vec::new();
+        for id in ids {
+            let cached = modrinth_cache::cached_response(self.cache.as_ref(), &key_of(id))
+                .await
+                .and_then(|cached| Some((cached.freshness(ttl, now), serde_json::from_str::<T>(&cached.body).ok()?)));
+            match cached {
+                Some((_, item)) if self.offline => {
+                    found.insert(id.clone(), item);
+                }
+                Some((Freshness::Fresh, item)) => {
+                    found.insert(id.clone(), item);
+                }
+                Some((Freshness::Stale, item)) => {
+                    found.insert(id.clone(), item);
+                    stale.push(id.clone());
+                }
+                Some((Freshness::Expired, item)) => {
+                    fallback.insert(id.clone(), item);
+                    missing.push(id.clone());
+                }
+                None => missing.push(id.clone()),
+            }
+        }
+        if self.offline {
+            return Ok(ids.iter().filter_map(|id| found.remove(id)).collect());
+        }
+
+        for chunk in missing.chunks(config::MAX_IDS_PER_REQUEST) {
+            match self.fetch_batch::<T>(batch_path, entity, chunk, keys_of).await {
+                // 以请求时使用的键 (可能是 slug) 对应返回的项
+                Ok(items) => {
+                    for item in items {
+                        let keys = keys_of(&item);
+                        let Some(id) = chunk.iter().find(|id| keys.contains(&id.as_str())) else {
+                            continue;
+                        };
+                        found.insert(id.clone(), item);
+                    }
+                }
+                Err(e) if modrinth_cache::is_network_error(&e) => {
+                    found.extend(chunk.iter().filter_map(|id| fallback.remove_entry(id)));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        stale.retain(|id| modrinth_cache::begin_refresh(&key_of(id)));
+        if !stale.is_empty() {
+            let client = self.clone();
+            let batch_path = batch_path.to_string();
+            let entity = entity.to_string();
+            tokio::spawn(async move {
+                for chunk in stale.chunks(config::MAX_IDS_PER_REQUEST) {
+                    client.fetch_batch::<T>(&batch_path, &entity, chunk, keys_of).await.ok();
+                }
+                for id in &stale {
+                    modrinth_cache::end_refresh(&client.url(&format!("{}/{}", entity, id)));
+                }
+            });
+        }
+
+        Ok(ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
+    /// 批量请求并按每个键对应的单项地址写入缓存
+    async fn fetch_batch<T>(&self, batch_path: &str, entity: &str, ids: &[String], keys_of: fn(&T) -> Vec<&str>) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let url = self.url(batch_path);
+        let ids = serde_json::to_string(ids)?;
+        let text = self
+            .send(|| download::client().get(&url).query(&[("ids", &ids)]))
+            .await?;
+        let items: Vec<T> = parse(&url, &text)?;
+        let fetched_at = (self.clock)();
+        for item in &items {
+            let body = serde_json::to_string(item)?;
+            for key in keys_of(item).into_iter().filter(|key| !key.is_empty()) {
+                let key = self.url(&format!("{}/{}", entity, key));
+                modrinth_cache::store_response(self.cache.as_ref(), &key, &body, fetched_at).await?;
+            }
+        }
+        Ok(items)
+    }
+
+    /// 下载图标并写入缓存
+    async fn fetch_icon(&self, url: &str) -> Result<Vec<u8>> {
+        let data = modrinth_cache::download_icon(url).await?;
+        modrinth_cache::store_icon(self.cache.as_ref(), url, &data, (self.clock)()).await?;
+        Ok(data)
+    }
+
+    /// 获取项目图标 (完整地址)，缓存策略与 GET 请求相同
+    pub(crate) async fn icon(&self, url: &str) -> Result<Vec<u8>> {
+        let Some(cached) = modrinth_cache::cached_icon(self.cache.as_ref(), url).await else {
+            if self.offline {
+                return Err(modrinth_cache::offline_error(url));
+            }
+            return self.fetch_icon(url).await;
+        };
+        if self.offline {
+            return Ok(cached.data);
+        }
+        match cached.freshness((self.clock)()) {
+            Freshness::Fresh => Ok(cached.data),
+            Freshness::Stale => {
+                if modrinth_cache::begin_refresh(url) {
+                    let (client, url) = (self.clone(), url.to_string());
+                    tokio::spawn(async move {
+                        client.fetch_icon(&url).await.ok();
+                        modrinth_cache::end_refresh(&url);
+                    });
+                }
+                Ok(cached.data)
+            }
+            Freshness::Expired => match self.fetch_icon(url).await {
+                Ok(data) => Ok(data),
+                Err(e) if modrinth_cache::is_network_error(&e) => Ok(cached.data),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    async fn post<T: DeserializeOwned>(&self, path: &str, body: &serde_json::Value) -> Result<T> {
+        let url = self.url(path);
+        let body = body.to_string();
+        let text = self
+            .send(|| {
+                download::client()
+                    .post(&url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body.clone())
+            })
+            .await?;
+        parse(&url, &text)
+    }
+
+    pub(crate) async fn search(&self, query: &SearchQuery) -> Result<SearchResults> {
+        let mut params = vec![
+            ("offset", query.offset.to_string()),
+            ("limit", query.limit.clamp(1, 100).to_string()),
+        ];
+        if let Some(text) = query.query.as_ref().filter(|text| !text.is_empty()) {
+            params.push(("query", text.clone()));
+        }
+        if let Some(index) = &query.index {
+            params.push(("index", index.clone()));
+        }
+        if !query.facets.is_empty() {
+            params.push(("facets", serde_json::to_string(&query.facets)?));
+        }
+        self.get("search", &params).await
+    }
+
+    pub(crate) async fn project(&self, id_or_slug: &str) -> Result<Project> {
+        self.get(&format!("project/{}", id_or_slug), &[]).await
+    }
+
+    pub(crate) async fn projects(&self, ids: &[String]) -> Result<Vec<Project>> {
+        self.get_many("projects", "project", ids, |project: &Project| vec![&project.id, &project.slug])
+            .await
+    }
+
+    pub(crate) async fn project_versions(
+        &self,
+        id_or_slug: &str,
+        loaders: &[String],
+        game_versions: &[String],
+    ) -> Result<Vec<Version>> {
+        let mut params = Vec::new();
+        if !loaders.is_empty() {
+            params.push(("loaders", serde_json::to_string(loaders)?));
+        }
+        if !game_versions.is_empty() {
+            params.push(("game_versions", serde_json::to_string(game_versions)?));
+        }
+        self.get(&format!("project/{}/version", id_or_slug), &params).await
+    }
+
+    pub(crate) async fn version(&self, id: &str) -> Result<Version> {
+        self.get(&format!("version/{}", id), &[]).await
+    }
+
+    pub(crate) async fn versions(&self, ids: &[String]) -> Result<Vec<Version>> {
+        self.get_many("versions", "version", ids, |version: &Version| vec![&version.id]).await
+    }
+
+    pub(crate) async fn project_members(&self, id_or_slug: &str) -> Result<Vec<TeamMember>> {
+        self.get(&format!("project/{}/members", id_or_slug), &[]).await
+    }
+
+    pub(crate) async fn team_members(&self, team_id: &str) -> Result<Vec<TeamMember>> {
+        self.get(&format!("team/{}/members", team_id), &[]).await
+    }
+
+    pub(crate) async fn version_from_hash(&self, hash: &str, algorithm: HashAlgorithm) -> Result<Version> {
+        self.get(
+            &format!("version_file/{}", hash),
+            &[("algorithm", algorithm.as_str().to_string())],
+        )
+        .await
+    }
+
+    /// 批量按哈希查询版本，返回 哈希 -> 版本 (未知的哈希不出现在结果中)
+    pub(crate) async fn versions_from_hashes(
+        &self,
+        hashes: &[String],
+        algorithm: HashAlgorithm,
+    ) -> Result<HashMap<String, Version>> {
+        if hashes.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.post(
+            "version_files",
+            &serde_json::json!({ "hashes": hashes, "algorithm": algorithm.as_str() }),
+        )
+        .await
+    }
+
+    /// 批量查询文件所属项目适用于指定加载器与游戏版本的最新版本，返回 哈希 -> 最新版本
+    pub(crate) async fn latest_versions_from_hashes(
+        &self,
+        hashes: &[String],
+        algorithm: HashAlgorithm,
+        loaders: &[String],
+        game_versions: &[String],
+    ) -> Result<HashMap<String, Version>> {
+        if hashes.is_empty() {
+            return Ok(HashMap::new());
+        }
+        self.post(
+            "version_files/update",
+            &serde_json::json!({
+                "hashes": hashes,
+                "algorithm": algorithm.as_str(),
+                "loaders": loaders,
+                "game_versions": game_versions,
+            }),
+        )
+        .await
+    }
+
+    pub(crate) async fn categories(&self) -> Result<Vec<CategoryTag>> {
+        self.get("tag/category", &[]).await
+    }
+
+    pub(crate) async fn loaders(&self) -> Result<Vec<LoaderTag>> {
+        self.get("tag/loader", &[]).await
+    }
+
+    pub(crate) async fn game_versions(&self) -> Result<Vec<GameVersionTag>> {
+        self.get("tag/game_version", &[]).await
+    }
+}
+
+/// 搜索项目
+pub async fn modrinth_search(query: SearchQuery) -> Result<SearchResults> {
+    ModrinthClient::default().search(&query).await
+}
+
+/// 获取项目详情 (ID 或 slug)
+pub async fn modrinth_get_project(id_or_slug: String) -> Result<Project> {
+    ModrinthClient::default().project(&id_or_slug).await
+}
+
+/// 批量获取项目
+pub async fn modrinth_get_projects(ids: Vec<String>) -> Result<Vec<Project>> {
+    ModrinthClient::default().projects(&ids).await
+}
+
+/// 列出项目的版本，可按加载器与游戏版本过滤
+pub async fn modrinth_get_project_versions(
+    id_or_slug: String,
+    loaders: Vec<String>,
+    game_versions: Vec<String>,
+) -> Result<Vec<Version>> {
+    ModrinthClient::default()
+        .project_versions(&id_or_slug, &loaders, &game_versions)
+        .await
+}
+
+/// 获取版本详情
+pub async fn modrinth_get_version(id: String) -> Result<Version> {
+    ModrinthClient::default().version(&id).await
+}
+
+/// 批量获取版本
+pub async fn modrinth_get_versions(ids: Vec<String>) -> Result<Vec<Version>> {
+    ModrinthClient::default().versions(&ids).await
+}
+
+/// 获取项目的团队成员
+pub async fn modrinth_get_project_members(id_or_slug: String) -> Result<Vec<TeamMember>> {
+    ModrinthClient::default().project_members(&id_or_slug).await
+}
+
+/// 获取团队成员
+pub async fn modrinth_get_team_members(team_id: String) -> Result<Vec<TeamMember>> {
+    ModrinthClient::default().team_members(&team_id).await
+}
+
+/// 按文件哈希查询版本
+pub async fn modrinth_get_version_from_hash(hash: String, algorithm: HashAlgorithm) -> Result<Version> {
+    ModrinthClient::default().version_from_hash(&hash, algorithm).await
+}
+
+/// 批量按文件哈希查询版本
+pub async fn modrinth_get_versions_from_hashes(
+    hashes: Vec<String>,
+    algorithm: HashAlgorithm,
+) -> Result<HashMap<String, Version>> {
+    ModrinthClient::default()
+        .versions_from_hashes(&hashes, algorithm)
+        .await
+}
+
+/// 分类标签
+pub async fn modrinth_get_categories() -> Result<Vec<CategoryTag>> {
+    ModrinthClient::default().categories().await
+}
+
+/// 加载器标签
+pub async fn modrinth_get_loaders() -> Result<Vec<LoaderTag>> {
+    ModrinthClient::default().loaders().await
+}
+
+/// 游戏版本标签
+pub async fn modrinth_get_game_versions() -> Result<Vec<GameVersionTag>> {
+    ModrinthClient::default().game_versions().await
+}
+
+/// 最近一次请求返回的限流状态，尚未请求过时为 None
+pub fn modrinth_rate_limit_status() -> Option<RateLimitStatus> {
+    RATE_LIMIT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .map(|(status, recorded)| RateLimitStatus {
+            reset_secs: status.reset_secs.saturating_sub(recorded.elapsed().as_secs()),
+            ..status
+        })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::database;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client(server: &MockServer) -> ModrinthClient {
+        ModrinthClient::new(&format!("{}/", server.uri()))
+    }
+
+    /// 没有服务监听的地址，请求直接连接失败
+    pub(crate) fn unreachable_url() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    #[tokio::test]
+    async fn search_sends_query_and_facets() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("query", "sodium"))
+            .and(query_param("limit", "100"))
+            .and(query_param("index", "downloads"))
+            .and(query_param("facets", r#"[["project_type:mod"],["versions:1.20.1"]]"#))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hits": [{ "project_id": "AANobbMI", "slug": "sodium", "title": "Sodium", "downloads": 42 }],
+                "offset": 0,
+                "limit": 100,
+                "total_hits": 1,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let results = client(&server)
+            .search(&SearchQuery {
+                query: Some("sodium".to_string()),
+                facets: vec![vec!["project_type:mod".to_string()], vec!["versions:1.20.1".to_string()]],
+                index: Some("downloads".to_string()),
+                offset: 0,
+                limit: 500,
+            })
+            .await
+            .unwrap();
+        assert_eq!(results.total_hits, 1);
+        assert_eq!(results.hits[0].slug, "sodium");
+        assert_eq!(results.hits[0].downloads, 42);
+    }
+
+    #[tokio::test]
+    async fn retries_after_rate_limit() {
+        let server = MockServer::start().await;
+        Mock::given(path("/tag/loader"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-limit", "300")
+                    .insert_header("x-ratelimit-reset", "1"),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/tag/loader"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "299")
+                    .insert_header("x-ratelimit-limit", "300")
+                    .insert_header("x-ratelimit-reset", "60")
+                    .set_body_json(serde_json::json!([{ "name": "fabric", "supported_project_types": ["mod"] }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let loaders = client(&server).loaders().await.unwrap();
+        assert_eq!(loaders.len(), 1);
+        assert_eq!(loaders[0].name, "fabric");
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_repeated_rate_limits() {
+        let server = MockServer::start().await;
+        Mock::given(path("/tag/category"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("x-ratelimit-remaining", "1")
+                    .insert_header("x-ratelimit-reset", "0"),
+            )
+            .expect(u64::from(config::MAX_RATE_LIMIT_RETRIES) + 1)
+            .mount(&server)
+            .await;
+
+        let error = client(&server).categories().await.unwrap_err();
+        assert!(error.to_string().contains("429"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn looks_up_versions_by_hash() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/version_files"))
+            .and(body_json(serde_json::json!({ "hashes": ["aaa", "bbb"], "algorithm": "sha1" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "aaa": {
+                    "id": "v1",
+                    "project_id": "p1",
+                    "version_number": "1.0.0",
+                    "files": [{ "filename": "a.jar", "primary": true, "hashes": { "sha1": "aaa" } }],
+                },
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let versions = client
+            .versions_from_hashes(&["aaa".to_string(), "bbb".to_string()], HashAlgorithm::Sha1)
+            .await
+            .unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions["aaa"].project_id, "p1");
+        assert_eq!(versions["aaa"].primary_file().unwrap().filename, "a.jar");
+
+        // 空列表不发请求
+        assert!(client.versions_from_hashes(&[], HashAlgorithm::Sha1).await.unwrap().is_empty());
+    }
+
+    const NOW: &str = "2024-06-01T00:00:00Z";
+
+    fn now() -> DateTime<Utc> {
+        NOW.parse().unwrap()
+    }
+
+    /// 使用临时数据库作为缓存的客户端，`base_url` 不以 `/` 结尾
+    async fn cached_client(base_url: &str, offline: bool) -> (ModrinthClient, SqlitePool, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = database::open_pool(&dir.path().to_string_lossy()).await.unwrap();
+        let client = ModrinthClient::new(&format!("{}/", base_url)).with_cache(pool.clone(), offline, now);
+        (client, pool, dir)
+    }
+
+    /// 写入 `age` 之前获取的缓存
+    async fn cache_project(pool: &SqlitePool, base_url: &str, id: &str, title: &str, age: chrono::Duration) {
+        let body = serde_json::json!({ "id": id, "title": title }).to_string();
+        let key = format!("{}/project/{}", base_url, id);
+        modrinth_cache::store_response(Some(pool), &key, &body, now() - age).await.unwrap();
+    }
+
+    async fn mount_project(server: &MockServer, id: &str, title: &str, expected: u64) {
+        Mock::given(path(format!("/project/{}", id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": id, "title": title })))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn fresh_cache_skips_request() {
+        let server = MockServer::start().await;
+        mount_project(&server, "abc", "remote", 0).await;
+        let (client, pool, _dir) = cached_client(&server.uri(), false).await;
+        cache_project(&pool, &server.uri(), "abc", "cached", chrono::Duration::minutes(1)).await;
+
+        assert_eq!(client.project("abc").await.unwrap().title, "cached");
+    }
+
+    #[tokio::test]
+    async fn stale_cache_is_returned_and_refreshed_in_background() {
+        let server = MockServer::start().await;
+        mount_project(&server, "abc", "remote", 1).await;
+        let (client, pool, _dir) = cached_client(&server.uri(), false).await;
+        cache_project(&pool, &server.uri(), "abc", "cached", chrono::Duration::hours(2)).await;
+
+        assert_eq!(client.project("abc").await.unwrap().title, "cached");
+        let key = format!("{}/project/abc", server.uri());
+        for _ in 0..100 {
+            let cached = modrinth_cache::cached_response(Some(&pool), &key).await.unwrap();
+            if cached.body.contains("remote") {
+                assert_eq!(cached.fetched_at, now());
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("缓存未在后台刷新");
+    }
+
+    #[tokio::test]
+    async fn expired_cache_is_refetched() {
+        let server = MockServer::start().await;
+        mount_project(&server, "abc", "remote", 1).await;
+        let (client, pool, _dir) = cached_client(&server.uri(), false).await;
+        cache_project(&pool, &server.uri(), "abc", "cached", chrono::Duration::days(30)).await;
+
+        assert_eq!(client.project("abc").await.unwrap().title, "remote");
+    }
+
+    #[tokio::test]
+    async fn expired_cache_is_used_when_network_fails() {
+        let base_url = unreachable_url();
+        let (client, pool, _dir) = cached_client(&base_url, false).await;
+        cache_project(&pool, &base_url, "abc", "cached", chrono::Duration::days(30)).await;
+
+        assert_eq!(client.project("abc").await.unwrap().title, "cached");
+    }
+
+    #[tokio::test]
+    async fn offline_reads_only_cache() {
+        let server = MockServer::start().await;
+        mount_project(&server, "abc", "remote", 0).await;
+        mount_project(&server, "missing", "remote", 0).await;
+        let (client, pool, _dir) = cached_client(&server.uri(), true).await;
+        cache_project(&pool, &server.uri(), "abc", "cached", chrono::Duration::days(30)).await;
+
+        assert_eq!(client.project("abc").await.unwrap().title, "cached");
+        assert!(client.project("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn projects_requested_by_slug_are_cached_offline() {
+        let server = MockServer::start().await;
+        Mock::given(path("/projects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "AANobbMI", "slug": "sodium", "title": "Sodium" },
+                { "id": "P7dR8mSH", "slug": "fabric-api", "title": "Fabric API" },
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let (client, pool, _dir) = cached_client(&server.uri(), false).await;
+
+        let requested = ["fabric-api".to_string(), "sodium".to_string()];
+        let titles: Vec<String> = client
+            .projects(&requested)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|project| project.title)
+            .collect();
+        assert_eq!(titles, ["Fabric API", "Sodium"]);
+
+        let offline = client.with_cache(pool, true, now);
+        for ids in [requested.to_vec(), vec!["AANobbMI".to_string(), "P7dR8mSH".to_string()]] {
+            assert_eq!(offline.projects(&ids).await.unwrap().len(), 2);
+        }
+    }
+
+    async fn mount_icon(server: &MockServer, data: &'static str, expected: u64) -> String {
+        Mock::given(path("/icon.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(data))
+            .expect(expected)
+            .mount(server)
+            .await;
+        format!("{}/icon.png", server.uri())
+    }
+
+    #[tokio::test]
+    async fn icons_are_cached_and_read_offline() {
+        let server = MockServer::start().await;
+        let url = mount_icon(&server, "png", 1).await;
+        let (client, pool, _dir) = cached_client(&server.uri(), false).await;
+
+        assert_eq!(client.icon(&url).await.unwrap(), b"png");
+        // 有效期内不再请求
+        assert_eq!(client.icon(&url).await.unwrap(), b"png");
+        let cached = modrinth_cache::cached_icon(Some(&pool), &url).await.unwrap();
+        assert_eq!(cached.fetched_at, now());
+
+        let offline = client.with_cache(pool, true, now);
+        assert_eq!(offline.icon(&url).await.unwrap(), b"png");
+        assert!(offline.icon(&format!("{}/missing.png", server.uri())).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn stale_icon_is_returned_and_refreshed_in_background() {
+        let server = MockServer::start().await;
+        let url = mount_icon(&server, "new", 1).await;
+        let (client, pool, _dir) = cached_client(&server.uri(), false).await;
+        let fetched_at = now() - chrono::Duration::days(8);
+        modrinth_cache::store_icon(Some(&pool), &url, b"old", fetched_at).await.unwrap();
+
+        assert_eq!(client.icon(&url).await.unwrap(), b"old");
+        for _ in 0..100 {
+            let cached = modrinth_cache::cached_icon(Some(&pool), &url).await.unwrap();
+            if cached.data == b"new" {
+                assert_eq!(cached.fetched_at, now());
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("图标未在后台刷新");
+    }
+
+    #[tokio::test]
+    async fn expired_icon_is_used_when_network_fails() {
+        let base_url = unreachable_url();
+        let (client, pool, _dir) = cached_client(&base_url, false).await;
+        let url = format!("{}/icon.png", base_url);
+        modrinth_cache::store_icon(Some(&pool), &url, b"old", now() - chrono::Duration::days(30))
+            .await
+            .unwrap();
+
+        assert_eq!(client.icon(&url).await.unwrap(), b"old");
+        assert!(client.icon(&format!("{}/missing.png", base_url)).await.is_err());
+    }
+}
@@ -0,0 +1,261 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use crate::api::database::pool;
+use crate::api::modrinth::ModrinthClient;
+use crate::download;
+
+/// 配置常量
+mod config {
+    use chrono::Duration;
+    /// 搜索结果的有效期
+    pub const SEARCH_TTL: Duration = Duration::minutes(5);
+    /// 项目详情的有效期
+    pub const PROJECT_TTL: Duration = Duration::hours(1);
+    /// 项目版本列表的有效期
+    pub const PROJECT_VERSIONS_TTL: Duration = Duration::minutes(30);
+    /// 单个版本的有效期 (版本发布后很少修改)
+    pub const VERSION_TTL: Duration = Duration::days(1);
+    /// 标签、团队成员的有效期
+    pub const STATIC_TTL: Duration = Duration::days(1);
+    /// 图标的有效期
+    pub const ICON_TTL: Duration = Duration::days(7);
+    /// 过期后仍先返回缓存并在后台刷新的时长，超过后同步请求
+    pub const STALE_WINDOW: Duration = Duration::days(7);
+    /// 图标大小上限
+    pub const MAX_ICON_SIZE: usize = 5 * 1024 * 1024;
+}
+
+/// 离线模式：只读缓存，不发出网络请求
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// 正在后台刷新的缓存键，避免重复请求
+static REFRESHING: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// 是否处于离线模式 (用户设置，创建客户端时读取)
+pub(crate) fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// 离线模式下缺少缓存时的错误
+pub(crate) fn offline_error(key: &str) -> anyhow::Error {
+    anyhow!("离线模式下没有 {} 的缓存", key)
+}
+
+/// 请求失败是否由网络导致 (可以回退到缓存)，HTTP 错误状态不算
+pub(crate) fn is_network_error(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|error| error.status().is_none())
+}
+
+/// 按请求路径 (相对 API 根地址) 决定缓存有效期，不缓存的请求返回 None
+pub(crate) fn ttl(path: &str) -> Option<Duration> {
+    let segments: Vec<&str> = path.split('/').collect();
+    match segments.as_slice() {
+        ["search"] => Some(config::SEARCH_TTL),
+        ["project", _] | ["projects"] => Some(config::PROJECT_TTL),
+        ["project", _, "version"] => Some(config::PROJECT_VERSIONS_TTL),
+        ["project", _, "members"] | ["team", _, "members"] | ["tag", _] => Some(config::STATIC_TTL),
+        ["version", _] | ["versions"] => Some(config::VERSION_TTL),
+        _ => None,
+    }
+}
+
+/// 缓存的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Freshness {
+    /// 在有效期内
+    Fresh,
+    /// 已过期但仍在重新验证窗口内：先返回缓存，后台刷新
+    Stale,
+    /// 超出重新验证窗口：需要同步请求，失败时才使用缓存
+    Expired,
+}
+
+/// 按获取时间与有效期判断缓存在 `now` 时的状态
+fn freshness(fetched_at: DateTime<Utc>, ttl: Duration, now: DateTime<Utc>) -> Freshness {
+    let age = now - fetched_at;
+    if age < ttl {
+        Freshness::Fresh
+    } else if age < ttl + config::STALE_WINDOW {
+        Freshness::Stale
+    } else {
+        Freshness::Expired
+    }
+}
+
+/// 缓存的响应
+#[derive(Debug, Clone)]
+pub(crate) struct CachedResponse {
+    pub body: String,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CachedResponse {
+    pub(crate) fn freshness(&self, ttl: Duration, now: DateTime<Utc>) -> Freshness {
+        freshness(self.fetched_at, ttl, now)
+    }
+}
+
+/// 读取缓存，没有缓存数据库或读取失败时返回 None
+pub(crate) async fn cached_response(cache: Option<&SqlitePool>, key: &str) -> Option<CachedResponse> {
+    let row = sqlx::query("SELECT body, fetched_at FROM modrinth_cache WHERE key = ?")
+        .bind(key)
+        .fetch_optional(cache?)
+        .await
+        .ok()??;
+    Some(CachedResponse {
+        body: row.try_get("body").ok()?,
+        fetched_at: row.try_get("fetched_at").ok()?,
+    })
+}
+
+/// 写入缓存，没有缓存数据库时跳过
+pub(crate) async fn store_response(
+    cache: Option<&SqlitePool>,
+    key: &str,
+    body: &str,
+    fetched_at: DateTime<Utc>,
+) -> Result<()> {
+    let Some(cache) = cache else {
+        return Ok(());
+    };
+    sqlx::query(
+        "INSERT INTO modrinth_cache (key, body, fetched_at) VALUES (?, ?, ?)
+         ON CONFLICT (key) DO UPDATE SET body = excluded.body, fetched_at = excluded.fetched_at",
+    )
+    .bind(key)
+    .bind(body)
+    .bind(fetched_at)
+    .execute(cache)
+    .await?;
+    Ok(())
+}
+
+/// 标记缓存键开始后台刷新，已在刷新时返回 false
+pub(crate) fn begin_refresh(key: &str) -> bool {
+    REFRESHING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key.to_string())
+}
+
+pub(crate) fn end_refresh(key: &str) {
+    REFRESHING.lock().unwrap_or_else(|e| e.into_inner()).remove(key);
+}
+
+/// 缓存的图标
+#[derive(Debug, Clone)]
+pub(crate) struct CachedIcon {
+    pub data: Vec<u8>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CachedIcon {
+    pub(crate) fn freshness(&self, now: DateTime<Utc>) -> Freshness {
+        freshness(self.fetched_at, config::ICON_TTL, now)
+    }
+}
+
+/// 读取缓存的图标，没有缓存数据库或读取失败时返回 None
+pub(crate) async fn cached_icon(cache: Option<&SqlitePool>, url: &str) -> Option<CachedIcon> {
+    let row = sqlx::query("SELECT data, fetched_at FROM modrinth_icon_cache WHERE url = ?")
+        .bind(url)
+        .fetch_optional(cache?)
+        .await
+        .ok()??;
+    Some(CachedIcon {
+        data: row.try_get("data").ok()?,
+        fetched_at: row.try_get("fetched_at").ok()?,
+    })
+}
+
+/// 写入图标缓存，没有缓存数据库时跳过
+pub(crate) async fn store_icon(
+    cache: Option<&SqlitePool>,
+    url: &str,
+    data: &[u8],
+    fetched_at: DateTime<Utc>,
+) -> Result<()> {
+    let Some(cache) = cache else {
+        return Ok(());
+    };
+    sqlx::query(
+        "INSERT INTO modrinth_icon_cache (url, data, fetched_at) VALUES (?, ?, ?)
+         ON CONFLICT (url) DO UPDATE SET data = excluded.data, fetched_at = excluded.fetched_at",
+    )
+    .bind(url)
+    .bind(data)
+    .bind(fetched_at)
+    .execute(cache)
+    .await?;
+    Ok(())
+}
+
+/// 下载图标，超过大小上限时返回错误
+pub(crate) async fn download_icon(url: &str) -> Result<Vec<u8>> {
+    let response = download::client().get(url).send().await?.error_for_status()?;
+    let bytes = response.bytes().await?;
+    if bytes.len() > config::MAX_ICON_SIZE {
+        return Err(anyhow!("图标过大: {}", url));
+    }
+    Ok(bytes.to_vec())
+}
+
+/// 获取项目图标，优先使用缓存 (过期后在后台刷新)，离线或请求失败时返回已缓存的图标
+pub async fn modrinth_get_icon(url: String) -> Result<Vec<u8>> {
+    ModrinthClient::default().icon(&url).await
+}
+
+/// 开启或关闭离线模式。离线时 Modrinth 请求只读缓存 (忽略有效期)，没有缓存时返回错误
+pub fn set_modrinth_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// 是否处于离线模式
+pub fn modrinth_is_offline() -> bool {
+    is_offline()
+}
+
+/// 清空 Modrinth 响应与图标缓存，返回删除的条目数
+pub async fn clear_modrinth_cache() -> Result<u64> {
+    let responses = sqlx::query("DELETE FROM modrinth_cache")
+        .execute(pool()?)
+        .await?
+        .rows_affected();
+    let icons = sqlx::query("DELETE FROM modrinth_icon_cache")
+        .execute(pool()?)
+        .await?
+        .rows_affected();
+    Ok(responses + icons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freshness_by_age() {
+        let now: DateTime<Utc> = "2024-06-01T00:00:00Z".parse().unwrap();
+        let ttl = Duration::hours(1);
+        let at = |age: Duration| freshness(now - age, ttl, now);
+        assert_eq!(at(Duration::minutes(59)), Freshness::Fresh);
+        assert_eq!(at(Duration::hours(1)), Freshness::Stale);
+        assert_eq!(at(ttl + config::STALE_WINDOW - Duration::seconds(1)), Freshness::Stale);
+        assert_eq!(at(ttl + config::STALE_WINDOW), Freshness::Expired);
+    }
+
+    #[test]
+    fn ttl_by_path() {
+        assert_eq!(ttl("search"), Some(config::SEARCH_TTL));
+        assert_eq!(ttl("project/sodium"), Some(config::PROJECT_TTL));
+        assert_eq!(ttl("project/sodium/version"), Some(config::PROJECT_VERSIONS_TTL));
+        assert_eq!(ttl("versions"), Some(config::VERSION_TTL));
+        assert_eq!(ttl("version_files"), None);
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::frb;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use zip::ZipArchive;
+
+use crate::archive;
+use crate::minecraft::{self, Library};
+
+/// 需要解压的原生库
+#[derive(Debug, Clone)]
+#[frb(ignore)]
+pub(crate) struct NativeArtifact {
+    /// 原生库 jar 的本地路径
+    pub jar_path: PathBuf,
+    /// 解压时排除的前缀 (如 `META-INF/`)
+    pub exclude: Vec<String>,
+}
+
+/// 解析库列表中当前系统需要解压的原生库 (1.19 之前的 LWJGL 2/3 分类器 jar)
+pub(crate) fn collect_native_artifacts(
+    libraries: &[Library],
+    libraries_dir: &Path,
+) -> Result<Vec<NativeArtifact>> {
+    let mut artifacts = Vec::new();
+
+    for library in libraries.iter().filter(|library| library.is_allowed()) {
+        let Some(classifier) = library.native_classifier() else {
+            continue;
+        };
+
+        // 优先使用下载信息中声明的路径，否则按 Maven 坐标推导
+        let declared_path = library
+            .downloads
+            .as_ref()
+            .and_then(|downloads| downloads.classifiers.as_ref())
+            .and_then(|classifiers| classifiers.get(&classifier))
+            .and_then(|download| download.path.clone());
+        let relative = match declared_path {
+            Some(path) => PathBuf::from(path),
+            None => minecraft::maven_path(&library.name, Some(&classifier))?,
+        };
+
+        artifacts.push(NativeArtifact {
+            jar_path: libraries_dir.join(relative),
+            exclude: library
+                .extract
+                .as_ref()
+                .and_then(|extract| extract.exclude.clone())
+                .unwrap_or_default(),
+        });
+    }
+
+    Ok(artifacts)
+}
+
+/// 解压单个原生库 jar，返回写出的文件数量
+fn extract_native_jar(artifact: &NativeArtifact, natives_dir: &Path) -> Result<usize> {
+    let file = File::open(&artifact.jar_path)
+        .map_err(|e| anyhow!("无法打开原生库 {}: {}", artifact.jar_path.display(), e))?;
+    let mut archive = ZipArchive::new(file)?;
+    archive::extract_zip(
+        &mut archive,
+        natives_dir,
+        |name| {
+            let excluded = artifact.exclude.iter().any(|prefix| name.starts_with(prefix));
+            (!excluded).then(|| name.to_path_buf())
+        },
+        |_, _| {},
+    )
+}
+
+/// 将原生库解压到指定目录，返回写出的文件数量
+pub(crate) async fn extract_natives(
+    libraries: &[Library],
+    libraries_dir: &Path,
+    natives_dir: &Path,
+) -> Result<usize> {
+    let artifacts = collect_native_artifacts(libraries, libraries_dir)?;
+    fs::create_dir_all(natives_dir).await?;
+
+    let natives_dir = natives_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        artifacts
+            .iter()
+            .try_fold(0, |total, artifact| Ok(total + extract_native_jar(artifact, &natives_dir)?))
+    })
+    .await?
+}
+
+/// 为一次启动创建独立的原生库目录 (`<natives_root>/<version>-<uuid>`)
+pub(crate) fn new_natives_dir(natives_root: &Path, version_id: &str) -> PathBuf {
+    natives_root.join(format!("{}-{}", version_id, uuid::Uuid::new_v4().simple()))
+}
+
+/// 删除本次启动使用的原生库目录
+pub(crate) async fn cleanup_natives(natives_dir: &Path) -> Result<()> {
+    if fs::try_exists(natives_dir).await? {
+        fs::remove_dir_all(natives_dir).await?;
+    }
+    Ok(())
+}
+
+/// 为指定版本解压原生库，返回本次启动使用的原生库目录
+pub async fn extract_version_natives(
+    version_json_path: String,
+    libraries_dir: String,
+    natives_root: String,
+) -> Result<String> {
+    let version = minecraft::read_resolved_version_info(Path::new(&version_json_path)).await?;
+    let natives_dir = new_natives_dir(Path::new(&natives_root), &version.id);
+
+    if let Err(e) = extract_natives(&version.libraries, Path::new(&libraries_dir), &natives_dir).await {
+        cleanup_natives(&natives_dir).await.ok();
+        return Err(e);
+    }
+
+    Ok(natives_dir.to_string_lossy().to_string())
+}
+
+/// 清理原生库目录
+pub async fn cleanup_version_natives(natives_dir: String) -> Result<()> {
+    cleanup_natives(Path::new(&natives_dir)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    fn write_jar(path: &Path, entries: &[&str]) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        for name in entries {
+            if name.ends_with('/') {
+                zip.add_directory(*name, FileOptions::default()).unwrap();
+            } else {
+                zip.start_file(*name, FileOptions::default()).unwrap();
+                zip.write_all(name.as_bytes()).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    #[tokio::test]
+    async fn extracts_and_cleans_up_natives() {
+        let dir = tempfile::tempdir().unwrap();
+        let libraries_dir = dir.path().join("libraries");
+        let classifier = format!("natives-{}-{}", minecraft::current_os_name(), minecraft::current_arch_bits());
+        write_jar(
+            &libraries_dir.join(minecraft::maven_path("org.lwjgl.lwjgl:lwjgl-platform:2.9.4", Some(&classifier)).unwrap()),
+            &["META-INF/", "META-INF/MANIFEST.MF", "liblwjgl.so", "linux/", "linux/x64/libopenal.so"],
+        );
+        // 其他系统的分类器不应被解压
+        write_jar(
+            &libraries_dir.join(minecraft::maven_path("org.lwjgl.lwjgl:lwjgl-platform:2.9.4", Some("natives-other")).unwrap()),
+            &["other.so"],
+        );
+
+        let version = serde_json::json!({
+            "id": "1.8.9",
+            "libraries": [
+                {
+                    "name": "org.lwjgl.lwjgl:lwjgl-platform:2.9.4",
+                    "natives": {
+                        "linux": "natives-linux-${arch}",
+                        "osx": "natives-osx-${arch}",
+                        "windows": "natives-windows-${arch}"
+                    },
+                    "extract": { "exclude": ["META-INF/"] }
+                },
+                { "name": "org.lwjgl.lwjgl:lwjgl-platform:2.9.4", "natives": { "unknown": "natives-other" } },
+                { "name": "org.lwjgl.lwjgl:lwjgl:2.9.4" }
+            ]
+        });
+        let version_path = dir.path().join("versions/1.8.9/1.8.9.json");
+        std::fs::create_dir_all(version_path.parent().unwrap()).unwrap();
+        std::fs::write(&version_path, version.to_string()).unwrap();
+
+        let natives_root = dir.path().join("natives");
+        let natives_dir = extract_version_natives(
+            version_path.to_string_lossy().to_string(),
+            libraries_dir.to_string_lossy().to_string(),
+            natives_root.to_string_lossy().to_string(),
+        )
+        .await
+        .unwrap();
+        let natives = Path::new(&natives_dir);
+        assert!(natives.starts_with(&natives_root));
+        assert!(natives.file_name().unwrap().to_string_lossy().starts_with("1.8.9-"));
+        assert_eq!(std::fs::read_to_string(natives.join("liblwjgl.so")).unwrap(), "liblwjgl.so");
+        assert!(natives.join("linux/x64/libopenal.so").is_file());
+        assert!(!natives.join("META-INF").exists());
+        assert!(!natives.join("other.so").exists());
+
+        cleanup_version_natives(natives_dir.clone()).await.unwrap();
+        assert!(!natives.exists());
+        // 重复清理不报错
+        cleanup_version_natives(natives_dir).await.unwrap();
+    }
+}
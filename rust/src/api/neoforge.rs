@@ -0,0 +1,208 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::DartFnFuture;
+use std::path::Path;
+
+use crate::api::forge;
+use crate::api::java_download::ProgressCallback;
+use crate::api::loader_meta::{self, LoaderVersion};
+use crate::config::{CURRENT_NEOFORGE_FORMAT_VERSION, META_URL};
+use crate::minecraft;
+
+/// 配置常量
+mod config {
+    /// NeoForged Maven 仓库
+    pub const NEOFORGE_MAVEN_URL: &str = "https://maven.neoforged.net/releases/";
+    /// NeoForge 的 Maven 坐标 (1.20.2 及之后)
+    pub const NEOFORGE_ARTIFACT: &str = "net.neoforged:neoforge";
+    /// 1.20.1 的 NeoForge 沿用 Forge 的坐标与版本格式
+    pub const LEGACY_NEOFORGE_ARTIFACT: &str = "net.neoforged:forge";
+    /// 使用旧坐标的游戏版本
+    pub const LEGACY_GAME_VERSION: &str = "1.20.1";
+    /// Modrinth 元数据中的加载器名称
+    pub const META_LOADER: &str = "neo";
+    /// 从该主版本号开始，NeoForge 版本号直接使用游戏版本号 (如 `26.1.0.1`)
+    pub const YEAR_VERSIONING_MAJOR: u32 = 25;
+}
+
+/// 由 NeoForge 版本号推算对应的游戏版本：
+/// `20.4.80-beta` -> `1.20.4`，`21.0.10` -> `1.21`，`26.1.0.1` -> `26.1`，
+/// 旧坐标的 `1.20.1-47.1.106` -> `1.20.1`
+pub(crate) fn game_version_of(neoforge_version: &str) -> Option<String> {
+    if let Some((game_version, _)) = neoforge_version.split_once('-') {
+        if game_version.starts_with("1.") {
+            return Some(game_version.to_string());
+        }
+    }
+
+    let numeric = neoforge_version.split('-').next()?;
+    let parts: Vec<u32> = numeric
+        .split('.')
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    let (major, minor) = (*parts.first()?, *parts.get(1)?);
+
+    if major >= config::YEAR_VERSIONING_MAJOR && parts.len() >= 4 {
+        return Some(match parts[2] {
+            0 => format!("{}.{}", major, minor),
+            patch => format!("{}.{}.{}", major, minor, patch),
+        });
+    }
+    Some(match minor {
+        0 => format!("1.{}", major),
+        minor => format!("1.{}.{}", major, minor),
+    })
+}
+
+/// 构件的 maven-metadata.xml 地址
+fn metadata_url(maven_url: &str, artifact: &str) -> String {
+    let (group, name) = artifact.split_once(':').unwrap_or((artifact, ""));
+    format!("{}{}/{}/maven-metadata.xml", maven_url, group.replace('.', "/"), name)
+}
+
+/// 从 Maven 元数据列出指定游戏版本的 NeoForge 版本 (新版本在前)
+async fn list_maven_versions(maven_url: &str, game_version: &str) -> Result<Vec<LoaderVersion>> {
+    let artifact = if game_version == config::LEGACY_GAME_VERSION {
+        config::LEGACY_NEOFORGE_ARTIFACT
+    } else {
+        config::NEOFORGE_ARTIFACT
+    };
+    Ok(loader_meta::fetch_maven_versions(&metadata_url(maven_url, artifact))
+        .await?
+        .into_iter()
+        .rev()
+        .filter(|version| game_version_of(version).as_deref() == Some(game_version))
+        .map(|version| LoaderVersion {
+            stable: !version.contains("-beta") && !version.contains("-alpha"),
+            id: version,
+        })
+        .collect())
+}
+
+/// NeoForge 安装器下载地址
+pub(crate) fn installer_url(game_version: &str, neoforge_version: &str) -> Result<String> {
+    installer_url_in(config::NEOFORGE_MAVEN_URL, game_version, neoforge_version)
+}
+
+/// 指定 Maven 仓库中的 NeoForge 安装器地址
+fn installer_url_in(maven_url: &str, game_version: &str, neoforge_version: &str) -> Result<String> {
+    let artifact = if neoforge_version.starts_with(&format!("{}-", config::LEGACY_GAME_VERSION)) {
+        config::LEGACY_NEOFORGE_ARTIFACT
+    } else {
+        config::NEOFORGE_ARTIFACT
+    };
+    if game_version_of(neoforge_version).as_deref() != Some(game_version) {
+        return Err(anyhow!("NeoForge {} 不适用于 Minecraft {}", neoforge_version, game_version));
+    }
+    let path = minecraft::maven_url_path(&format!("{}:{}", artifact, neoforge_version), Some("installer"))?;
+    Ok(format!("{}{}", maven_url, path))
+}
+
+/// 先查 Modrinth 元数据，不可用时回退到 Maven 仓库
+async fn list_versions_in(meta_url: &str, maven_url: &str, game_version: &str) -> Result<Vec<LoaderVersion>> {
+    match loader_meta::list_meta_loaders(
+        meta_url,
+        config::META_LOADER,
+        CURRENT_NEOFORGE_FORMAT_VERSION,
+        game_version,
+    )
+    .await
+    {
+        Ok(loaders) if !loaders.is_empty() => Ok(loaders),
+        _ => list_maven_versions(maven_url, game_version).await,
+    }
+}
+
+/// 列出指定游戏版本可用的 NeoForge 版本，Modrinth 元数据不可用时回退到 NeoForged Maven
+pub async fn list_neoforge_versions(game_version: String) -> Result<Vec<LoaderVersion>> {
+    list_versions_in(META_URL, config::NEOFORGE_MAVEN_URL, &game_version).await
+}
+
+/// 安装 NeoForge，`java_path` 用于运行安装处理器，返回新版本 ID
+pub async fn install_neoforge(
+    minecraft_dir: String,
+    game_version: String,
+    neoforge_version: String,
+    java_path: String,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<String> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    forge::install_with_installer(
+        "NeoForge",
+        &installer_url(&game_version, &neoforge_version)?,
+        Path::new(&minecraft_dir),
+        &game_version,
+        &java_path,
+        &on_progress,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::forge::tests::{installer_jar, maven_metadata, no_progress, prepare_vanilla};
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn derives_game_version() {
+        assert_eq!(game_version_of("20.4.80-beta").as_deref(), Some("1.20.4"));
+        assert_eq!(game_version_of("21.0.10").as_deref(), Some("1.21"));
+        assert_eq!(game_version_of("26.1.0.1").as_deref(), Some("26.1"));
+        assert_eq!(game_version_of("1.20.1-47.1.106").as_deref(), Some("1.20.1"));
+    }
+
+    #[tokio::test]
+    async fn lists_maven_versions_when_meta_unavailable() {
+        let server = MockServer::start().await;
+        Mock::given(path("/net/neoforged/neoforge/maven-metadata.xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(maven_metadata(&[
+                "20.4.80-beta",
+                "21.0.10",
+                "21.0.11-beta",
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(path("/net/neoforged/forge/maven-metadata.xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(maven_metadata(&["1.20.1-47.1.106"])))
+            .mount(&server)
+            .await;
+        let meta = format!("{}/meta/", server.uri());
+        let maven = format!("{}/", server.uri());
+
+        let versions = list_versions_in(&meta, &maven, "1.21").await.unwrap();
+        let versions: Vec<_> = versions.iter().map(|version| (version.id.as_str(), version.stable)).collect();
+        assert_eq!(versions, [("21.0.11-beta", false), ("21.0.10", true)]);
+
+        let legacy = list_versions_in(&meta, &maven, "1.20.1").await.unwrap();
+        assert_eq!(legacy[0].id, "1.20.1-47.1.106");
+    }
+
+    #[tokio::test]
+    async fn installs_from_maven() {
+        let server = MockServer::start().await;
+        let maven = format!("{}/", server.uri());
+        assert!(installer_url_in(&maven, "1.20.4", "21.0.10").is_err());
+        let url = installer_url_in(&maven, "1.21", "21.0.10").unwrap();
+        Mock::given(path("/net/neoforged/neoforge/21.0.10/neoforge-21.0.10-installer.jar"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(installer_jar(
+                "1.21",
+                "neoforge-21.0.10",
+                "maven/net/neoforged/neoforge/21.0.10/neoforge-21.0.10-universal.jar",
+            )))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        prepare_vanilla(dir.path(), "1.21").await;
+        let id = forge::install_with_installer("NeoForge", &url, dir.path(), "1.21", "java", &no_progress())
+            .await
+            .unwrap();
+        assert_eq!(id, "neoforge-21.0.10");
+        assert!(dir
+            .path()
+            .join("libraries/net/neoforged/neoforge/21.0.10/neoforge-21.0.10-universal.jar")
+            .exists());
+    }
+}
@@ -0,0 +1,589 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use flutter_rust_bridge::DartFnFuture;
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+use crate::api::crash_report;
+use crate::api::database::pool;
+use crate::api::game_log::GameLogEvent;
+use crate::api::game_process::{self, GameProcessInfo};
+use crate::api::instance;
+use crate::api::launch::LaunchCommand;
+use crate::frb_generated::StreamSink;
+
+/// 配置常量
+mod config {
+    use std::time::Duration;
+    /// 心跳间隔
+    pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+    /// 进程启动时间与会话开始时间允许的误差 (秒)，用于排除 PID 复用
+    pub const START_TIME_TOLERANCE_SECS: i64 = 120;
+    /// 统计每周会话数时默认返回的周数
+    pub const DEFAULT_WEEKS: u32 = 12;
+    /// 被用户中断 (SIGINT / SIGTERM) 时 JVM 的退出码，不计为崩溃
+    pub const INTERRUPTED_EXIT_CODES: &[i32] = &[130, 143];
+}
+
+/// 一次游戏会话
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaySession {
+    pub id: i64,
+    pub instance_id: String,
+    pub pid: Option<u32>,
+    pub java_version: Option<String>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub exit_code: Option<i32>,
+    pub crashed: bool,
+}
+
+impl PlaySession {
+    /// 会话时长 (秒)，未结束的会话按当前时间计算
+    pub(crate) fn duration_secs(&self) -> u64 {
+        let end = self.end_time.unwrap_or_else(Utc::now);
+        (end - self.start_time).num_seconds().max(0) as u64
+    }
+}
+
+/// 每周会话统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyPlayStats {
+    /// 该周周一 (UTC)
+    pub week_start: DateTime<Utc>,
+    pub sessions: u32,
+    pub play_time_secs: u64,
+}
+
+/// 游戏时长汇总
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayStats {
+    pub total_play_time_secs: u64,
+    pub session_count: u32,
+    pub crash_count: u32,
+    pub last_played: Option<DateTime<Utc>>,
+    /// 最近若干周的统计，旧的在前
+    pub weekly: Vec<WeeklyPlayStats>,
+}
+
+fn session_from_row(row: &SqliteRow) -> Result<PlaySession> {
+    Ok(PlaySession {
+        id: row.try_get("id")?,
+        instance_id: row.try_get("instance_id")?,
+        pid: row.try_get::<Option<i64>, _>("pid")?.map(|pid| pid as u32),
+        java_version: row.try_get("java_version")?,
+        start_time: row.try_get("start_time")?,
+        end_time: row.try_get("end_time")?,
+        exit_code: row.try_get("exit_code")?,
+        crashed: row.try_get("crashed")?,
+    })
+}
+
+/// 进程是否仍在运行，且启动时间与会话开始时间吻合
+fn process_alive(pid: u32, start_time: DateTime<Utc>) -> bool {
+    let mut system = System::new();
+    let pid = Pid::from_u32(pid);
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    system.process(pid).is_some_and(|process| {
+        (process.start_time() as i64 - start_time.timestamp()).abs() <= config::START_TIME_TOLERANCE_SECS
+    })
+}
+
+/// 记录会话开始，返回会话 ID
+async fn insert_session(pool: &SqlitePool, instance_id: &str, info: &GameProcessInfo, java_version: &str) -> Result<i64> {
+    let result = sqlx::query(
+        "INSERT INTO play_sessions (instance_id, pid, java_version, start_time, last_seen)
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(instance_id)
+    .bind(info.pid as i64)
+    .bind(java_version)
+    .bind(info.start_time)
+    .bind(info.start_time)
+    .execute(pool)
+    .await?;
+    Ok(result.last_insert_rowid())
+}
+
+/// 结束会话并把时长累加到实例清单
+async fn finish_session(
+    pool: &SqlitePool,
+    resource_dir: &Path,
+    session: &PlaySession,
+    end_time: DateTime<Utc>,
+    exit_code: Option<i32>,
+    crashed: bool,
+) -> Result<()> {
+    let updated = sqlx::query(
+        "UPDATE play_sessions SET end_time = ?, exit_code = ?, crashed = ?, last_seen = ?
+         WHERE id = ? AND end_time IS NULL",
+    )
+    .bind(end_time)
+    .bind(exit_code)
+    .bind(crashed)
+    .bind(end_time)
+    .bind(session.id)
+    .execute(pool)
+    .await?
+    .rows_affected();
+    if updated == 0 {
+        return Ok(());
+    }
+
+    let played = (end_time - session.start_time).num_seconds().max(0) as u64;
+    instance::update_manifest(resource_dir, &session.instance_id, |manifest| {
+        manifest.play_time_secs += played;
+        // 补记的旧会话不会让最近游玩时间倒退
+        manifest.last_played = manifest.last_played.max(Some(end_time));
+    })
+    .await?;
+    Ok(())
+}
+
+/// 定期更新会话心跳，进程退出后返回
+async fn heartbeat(pool: &SqlitePool, session_id: i64, pid: u32, start_time: DateTime<Utc>) {
+    loop {
+        tokio::time::sleep(config::HEARTBEAT_INTERVAL).await;
+        if !process_alive(pid, start_time) {
+            return;
+        }
+        let result = sqlx::query("UPDATE play_sessions SET last_seen = ? WHERE id = ? AND end_time IS NULL")
+            .bind(Utc::now())
+            .bind(session_id)
+            .execute(pool)
+            .await;
+        if !matches!(result, Ok(result) if result.rows_affected() > 0) {
+            return;
+        }
+    }
+}
+
+/// 读取单个会话
+async fn get_session(pool: &SqlitePool, id: i64) -> Result<PlaySession> {
+    let row = sqlx::query("SELECT * FROM play_sessions WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+    session_from_row(&row)
+}
+
+/// 启动游戏并记录会话
+async fn spawn_session<L, E>(
+    resource_dir: String,
+    instance_id: String,
+    command: LaunchCommand,
+    java_version: String,
+    on_log: Arc<L>,
+    on_exit: E,
+) -> Result<GameProcessInfo>
+where
+    L: Fn(GameLogEvent) + Send + Sync + 'static,
+    E: FnOnce(GameProcessInfo) -> DartFnFuture<()> + Send + 'static,
+{
+    let manifest = instance::get_instance(resource_dir.clone(), instance_id.clone()).await?;
+    // 退出回调与心跳在其他任务中运行，启动时取得连接池
+    let pool = pool().cloned();
+    let exit_pool = pool.as_ref().ok().cloned();
+    let (session_tx, session_rx) = tokio::sync::oneshot::channel::<i64>();
+
+    let exit_resource_dir = resource_dir.clone();
+    let info = game_process::spawn_game_process(&command, on_log, move |info: GameProcessInfo| {
+        Box::pin(async move {
+            if let (Ok(session_id), Some(pool)) = (session_rx.await, exit_pool) {
+                let end_time = info.end_time.unwrap_or_else(Utc::now);
+                let crash_file = crash_report::find_latest_crash_file(
+                    Path::new(&manifest.path),
+                    Some(SystemTime::from(info.start_time)),
+                )
+                .await;
+                let crashed = matches!(crash_file, Ok(Some(_)))
+                    || info
+                        .exit_code
+                        .is_some_and(|code| code != 0 && !config::INTERRUPTED_EXIT_CODES.contains(&code));
+                if let Ok(session) = get_session(&pool, session_id).await {
+                    finish_session(&pool, Path::new(&exit_resource_dir), &session, end_time, info.exit_code, crashed)
+                        .await
+                        .ok();
+                }
+            }
+            on_exit(info).await;
+        }) as DartFnFuture<()>
+    })
+    .await?;
+
+    // 游戏已经启动，记录会话或更新清单失败时只记录日志，仍返回进程信息以便界面管理该进程
+    let inserted = match &pool {
+        Ok(pool) => insert_session(pool, &instance_id, &info, &java_version).await,
+        Err(e) => Err(anyhow!("{}", e)),
+    };
+    match inserted {
+        Ok(session_id) => {
+            session_tx.send(session_id).ok();
+            if let Ok(pool) = pool {
+                tokio::spawn(async move { heartbeat(&pool, session_id, info.pid, info.start_time).await });
+            }
+        }
+        Err(e) => tracing::warn!("无法记录实例 {} 的游戏会话: {}", instance_id, e),
+    }
+
+    if let Err(e) = instance::update_manifest(Path::new(&resource_dir), &instance_id, |manifest| {
+        manifest.last_played = Some(info.start_time);
+    })
+    .await
+    {
+        tracing::warn!("无法更新实例 {} 的最近游玩时间: {}", instance_id, e);
+    }
+    Ok(info)
+}
+
+/// 启动实例的游戏并记录会话，退出时自动累加游戏时长并判断是否崩溃
+pub async fn start_instance_game(
+    resource_dir: String,
+    instance_id: String,
+    command: LaunchCommand,
+    java_version: String,
+    log_sink: StreamSink<GameLogEvent>,
+    on_exit: impl Fn(GameProcessInfo) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<GameProcessInfo> {
+    let on_log = move |event: GameLogEvent| {
+        log_sink.add(event).ok();
+    };
+    spawn_session(resource_dir, instance_id, command, java_version, Arc::new(on_log), move |info| {
+        on_exit(info)
+    })
+    .await
+}
+
+/// 启动器启动时调用：仍在运行的会话重新挂接并继续计时，
+/// 进程已不存在的会话以最后一次心跳作为结束时间补记。返回重新挂接的会话
+pub async fn reattach_play_sessions(resource_dir: String) -> Result<Vec<PlaySession>> {
+    let pool = pool()?;
+    let rows = sqlx::query("SELECT * FROM play_sessions WHERE end_time IS NULL")
+        .fetch_all(pool)
+        .await?;
+
+    let mut reattached = Vec::new();
+    for row in &rows {
+        let session = session_from_row(row)?;
+        let alive = session
+            .pid
+            .is_some_and(|pid| process_alive(pid, session.start_time));
+
+        if !alive {
+            let last_seen: Option<DateTime<Utc>> = row.try_get("last_seen")?;
+            let end_time = last_seen.unwrap_or(session.start_time);
+            finish_session(pool, Path::new(&resource_dir), &session, end_time, None, false).await?;
+            continue;
+        }
+
+        let (pid, session_for_task, resource_dir) = (session.pid.unwrap_or_default(), session.clone(), resource_dir.clone());
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            heartbeat(&pool, session_for_task.id, pid, session_for_task.start_time).await;
+            // 重新挂接的进程无法获取退出码
+            finish_session(&pool, Path::new(&resource_dir), &session_for_task, Utc::now(), None, false)
+                .await
+                .ok();
+        });
+        reattached.push(session);
+    }
+    Ok(reattached)
+}
+
+/// 列出会话 (新的在前)，`instance_id` 为空时列出全部实例
+pub async fn list_play_sessions(instance_id: Option<String>, limit: u32) -> Result<Vec<PlaySession>> {
+    let rows = sqlx::query(
+        "SELECT * FROM play_sessions
+         WHERE ?1 IS NULL OR instance_id = ?1
+         ORDER BY start_time DESC
+         LIMIT ?2",
+    )
+    .bind(instance_id)
+    .bind(limit as i64)
+    .fetch_all(pool()?)
+    .await?;
+    rows.iter().map(session_from_row).collect()
+}
+
+/// 游戏时长汇总，`instance_id` 为空时汇总全部实例；`weeks` 默认 12 周
+pub async fn get_play_stats(instance_id: Option<String>, weeks: Option<u32>) -> Result<PlayStats> {
+    let rows = sqlx::query("SELECT * FROM play_sessions WHERE ?1 IS NULL OR instance_id = ?1")
+        .bind(instance_id)
+        .fetch_all(pool()?)
+        .await?;
+    let sessions = rows.iter().map(session_from_row).collect::<Result<Vec<_>>>()?;
+
+    let weeks = weeks.unwrap_or(config::DEFAULT_WEEKS);
+    let today = Utc::now().date_naive();
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let mut weekly: Vec<WeeklyPlayStats> = (0..weeks)
+        .rev()
+        .map(|offset| WeeklyPlayStats {
+            week_start: (this_monday - Duration::weeks(offset as i64))
+                .and_hms_opt(0, 0, 0)
+                .unwrap_or_default()
+                .and_utc(),
+            sessions: 0,
+            play_time_secs: 0,
+        })
+        .collect();
+
+    let mut stats = PlayStats {
+        total_play_time_secs: 0,
+        session_count: sessions.len() as u32,
+        crash_count: 0,
+        last_played: None,
+        weekly: Vec::new(),
+    };
+    for session in &sessions {
+        let duration = session.duration_secs();
+        stats.total_play_time_secs += duration;
+        stats.crash_count += session.crashed as u32;
+        let played = session.end_time.unwrap_or(session.start_time);
+        stats.last_played = stats.last_played.max(Some(played));
+
+        if let Some(week) = weekly
+            .iter_mut()
+            .rev()
+            .find(|week| week.week_start <= session.start_time)
+            .filter(|week| session.start_time < week.week_start + Duration::weeks(1))
+        {
+            week.sessions += 1;
+            week.play_time_secs += duration;
+        }
+    }
+    stats.weekly = weekly;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::database::tests::with_pool;
+    use crate::api::instance::{InstanceManifest, NewInstance};
+    use crate::api::mod_loader::ModLoader;
+
+    async fn create_instance(resource_dir: &str) -> InstanceManifest {
+        instance::create_instance(
+            resource_dir.to_string(),
+            NewInstance {
+                name: "Session".to_string(),
+                game_version: "1.20.1".to_string(),
+                loader: ModLoader::Vanilla,
+                loader_version: None,
+                icon: None,
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn insert_row(
+        instance_id: &str,
+        pid: Option<u32>,
+        start_time: DateTime<Utc>,
+        end_time: Option<DateTime<Utc>>,
+        last_seen: Option<DateTime<Utc>>,
+        exit_code: Option<i32>,
+        crashed: bool,
+    ) -> i64 {
+        sqlx::query(
+            "INSERT INTO play_sessions (instance_id, pid, start_time, end_time, last_seen, exit_code, crashed)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(instance_id)
+        .bind(pid.map(i64::from))
+        .bind(start_time)
+        .bind(end_time)
+        .bind(last_seen)
+        .bind(exit_code)
+        .bind(crashed)
+        .execute(pool().unwrap())
+        .await
+        .unwrap()
+        .last_insert_rowid()
+    }
+
+    #[tokio::test]
+    async fn aggregates_play_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        with_pool(dir.path(), async {
+            let today = Utc::now().date_naive();
+            let this_monday = (today - Duration::days(today.weekday().num_days_from_monday() as i64))
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc();
+            let hours = |hours| Duration::hours(hours);
+
+            // 本周两次 (其中一次崩溃)、上周一次、很久以前一次，另一个实例一次
+            insert_row("a", None, this_monday + hours(1), Some(this_monday + hours(2)), None, Some(0), false).await;
+            insert_row("a", None, this_monday + hours(3), Some(this_monday + hours(4)), None, Some(1), true).await;
+            insert_row("a", None, this_monday - hours(24), Some(this_monday - hours(21)), None, Some(0), false).await;
+            let long_ago = this_monday - Duration::weeks(30);
+            insert_row("a", None, long_ago, Some(long_ago + hours(5)), None, None, false).await;
+            insert_row("b", None, this_monday + hours(5), Some(this_monday + hours(15)), None, Some(0), false).await;
+
+            let stats = get_play_stats(Some("a".to_string()), Some(2)).await.unwrap();
+            assert_eq!(stats.session_count, 4);
+            assert_eq!(stats.crash_count, 1);
+            assert_eq!(stats.total_play_time_secs, 10 * 3600);
+            assert_eq!(stats.last_played, Some(this_monday + hours(4)));
+            let weekly: Vec<_> = stats
+                .weekly
+                .iter()
+                .map(|week| (week.week_start, week.sessions, week.play_time_secs))
+                .collect();
+            assert_eq!(
+                weekly,
+                [(this_monday - Duration::weeks(1), 1, 3 * 3600), (this_monday, 2, 2 * 3600)]
+            );
+
+            let all = get_play_stats(None, None).await.unwrap();
+            assert_eq!(all.session_count, 5);
+            assert_eq!(all.total_play_time_secs, 20 * 3600);
+            assert_eq!(all.weekly.len(), config::DEFAULT_WEEKS as usize);
+            assert_eq!(all.weekly.last().unwrap().sessions, 3);
+
+            let sessions = list_play_sessions(Some("a".to_string()), 2).await.unwrap();
+            let starts: Vec<_> = sessions.iter().map(|session| session.start_time).collect();
+            assert_eq!(starts, [this_monday + hours(3), this_monday + hours(1)]);
+        })
+        .await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn reattaches_running_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        with_pool(dir.path(), async {
+            let manifest = create_instance(&resource_dir).await;
+            let mut running = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+            let mut exited = std::process::Command::new("true").spawn().unwrap();
+            exited.wait().unwrap();
+            let now = Utc::now();
+
+            let alive = insert_row(&manifest.id, Some(running.id()), now, None, Some(now), None, false).await;
+            // 进程已退出：以最后一次心跳作为结束时间
+            let start = now - Duration::hours(2);
+            let last_seen = start + Duration::minutes(10);
+            let dead = insert_row(&manifest.id, Some(exited.id()), start, None, Some(last_seen), None, false).await;
+            // PID 仍存在但启动时间对不上 (PID 被复用)，没有心跳时时长记为 0
+            let reused = insert_row(&manifest.id, Some(running.id()), now - Duration::days(1), None, None, None, false).await;
+
+            let reattached = reattach_play_sessions(resource_dir.clone()).await.unwrap();
+            running.kill().ok();
+            running.wait().ok();
+            let ids: Vec<i64> = reattached.iter().map(|session| session.id).collect();
+            assert_eq!(ids, [alive]);
+
+            let pool = pool().unwrap();
+            let dead = get_session(pool, dead).await.unwrap();
+            assert_eq!(dead.end_time, Some(last_seen));
+            assert!(!dead.crashed);
+            let reused = get_session(pool, reused).await.unwrap();
+            assert_eq!(reused.end_time, Some(reused.start_time));
+            assert!(get_session(pool, alive).await.unwrap().end_time.is_none());
+
+            let manifest = instance::get_instance(resource_dir.clone(), manifest.id).await.unwrap();
+            assert_eq!(manifest.play_time_secs, 600);
+            assert_eq!(manifest.last_played, Some(last_seen));
+        })
+        .await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn records_finished_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        with_pool(dir.path(), async {
+            let manifest = create_instance(&resource_dir).await;
+            let command = LaunchCommand {
+                java_path: "sh".to_string(),
+                args: vec!["-c".to_string(), "sleep 1; exit 3".to_string()],
+                working_dir: manifest.path.clone(),
+                natives_dir: None,
+            };
+            let (exit_tx, exit_rx) = tokio::sync::oneshot::channel();
+            let info = spawn_session(
+                resource_dir.clone(),
+                manifest.id.clone(),
+                command,
+                "17.0.8".to_string(),
+                Arc::new(|_: GameLogEvent| {}),
+                move |info| {
+                    Box::pin(async move {
+                        exit_tx.send(info).ok();
+                    }) as DartFnFuture<()>
+                },
+            )
+            .await
+            .unwrap();
+            let exited = tokio::time::timeout(std::time::Duration::from_secs(10), exit_rx)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let sessions = list_play_sessions(Some(manifest.id.clone()), 10).await.unwrap();
+            assert_eq!(sessions.len(), 1);
+            let session = &sessions[0];
+            assert_eq!(session.pid, Some(info.pid));
+            assert_eq!(session.java_version.as_deref(), Some("17.0.8"));
+            assert_eq!(session.exit_code, Some(3));
+            // 非正常退出码计为崩溃
+            assert!(session.crashed);
+            assert_eq!(session.end_time, exited.end_time);
+
+            let manifest = instance::get_instance(resource_dir.clone(), manifest.id).await.unwrap();
+            assert!(manifest.play_time_secs >= 1);
+            assert_eq!(manifest.last_played, exited.end_time);
+        })
+        .await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn returns_process_even_without_session_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource_dir = dir.path().to_string_lossy().to_string();
+        let manifest = create_instance(&resource_dir).await;
+        let command = LaunchCommand {
+            java_path: "sh".to_string(),
+            args: vec!["-c".to_string(), "exit 0".to_string()],
+            working_dir: manifest.path.clone(),
+            natives_dir: None,
+        };
+
+        // 数据库未初始化时会话无法写入，游戏进程仍应正常返回并被监控
+        let (exit_tx, exit_rx) = tokio::sync::oneshot::channel();
+        let info = spawn_session(
+            resource_dir.clone(),
+            manifest.id.clone(),
+            command,
+            "17".to_string(),
+            Arc::new(|_: GameLogEvent| {}),
+            move |info| {
+                Box::pin(async move {
+                    exit_tx.send(info).ok();
+                }) as DartFnFuture<()>
+            },
+        )
+        .await
+        .unwrap();
+        assert!(info.running);
+
+        let exited = tokio::time::timeout(std::time::Duration::from_secs(10), exit_rx)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(exited.exit_code, Some(0));
+        let manifest = instance::get_instance(resource_dir, manifest.id).await.unwrap();
+        assert_eq!(manifest.last_played, Some(info.start_time));
+    }
+}
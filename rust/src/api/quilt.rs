@@ -0,0 +1,89 @@
+use anyhow::Result;
+use flutter_rust_bridge::DartFnFuture;
+use std::path::Path;
+
+use crate::api::java_download::ProgressCallback;
+use crate::api::loader_meta::{FabricLikeSource, LoaderVersion};
+use crate::config::{CURRENT_QUILT_FORMAT_VERSION, META_URL, QUILT_META_URL};
+
+/// Quilt 元数据来源
+pub(crate) const QUILT_SOURCE: FabricLikeSource<'static> = quilt_source(META_URL, QUILT_META_URL);
+
+/// 使用指定 Modrinth 元数据地址与 Quilt 官方元数据地址的来源
+pub(crate) const fn quilt_source<'a>(meta_url: &'a str, upstream_url: &'a str) -> FabricLikeSource<'a> {
+    FabricLikeSource {
+        name: "Quilt",
+        meta_loader: "quilt",
+        format_version: CURRENT_QUILT_FORMAT_VERSION,
+        meta_url,
+        upstream_url,
+    }
+}
+
+/// 列出指定游戏版本可用的 Quilt 加载器，Modrinth 元数据不可用时回退到 meta.quiltmc.org
+pub async fn list_quilt_loaders(game_version: String) -> Result<Vec<LoaderVersion>> {
+    QUILT_SOURCE.list_loaders(&game_version).await
+}
+
+/// 安装 Quilt 加载器 (在原版旁写入 inheritsFrom 版本 JSON)，返回新版本 ID
+pub async fn install_quilt(
+    minecraft_dir: String,
+    game_version: String,
+    loader_version: String,
+    on_progress: impl Fn(f64, String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<String> {
+    let on_progress: ProgressCallback = Box::new(on_progress);
+    QUILT_SOURCE
+        .install(Path::new(&minecraft_dir), &game_version, &loader_version, &on_progress)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::matchers::path;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn upstream_loaders_infer_stability() {
+        let server = MockServer::start().await;
+        Mock::given(path("/upstream/versions/loader/1.20.1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                { "loader": { "version": "0.23.0" } },
+                { "loader": { "version": "0.24.0-beta.1" } }
+            ])))
+            .mount(&server)
+            .await;
+        let meta = format!("{}/meta/", server.uri());
+        let upstream = format!("{}/upstream/", server.uri());
+        let loaders = quilt_source(&meta, &upstream).list_loaders("1.20.1").await.unwrap();
+        let loaders: Vec<_> = loaders.iter().map(|loader| (loader.id.as_str(), loader.stable)).collect();
+        assert_eq!(loaders, [("0.23.0", true), ("0.24.0-beta.1", false)]);
+    }
+
+    #[tokio::test]
+    async fn upstream_profile_when_meta_lacks_loader() {
+        let server = MockServer::start().await;
+        Mock::given(path("/meta/quilt/v0/manifest.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "gameVersions": [] })))
+            .mount(&server)
+            .await;
+        Mock::given(path("/upstream/versions/loader/1.20.1/0.23.0/profile/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "quilt-loader-0.23.0-1.20.1",
+                "inheritsFrom": "1.20.1",
+                "mainClass": "org.quiltmc.loader.impl.launch.knot.KnotClient"
+            })))
+            .mount(&server)
+            .await;
+        let meta = format!("{}/meta/", server.uri());
+        let upstream = format!("{}/upstream/", server.uri());
+        let profile = quilt_source(&meta, &upstream)
+            .fetch_profile("1.20.1", "0.23.0")
+            .await
+            .unwrap();
+        assert_eq!(profile.id, "quilt-loader-0.23.0-1.20.1");
+        assert_eq!(profile.inherits_from.as_deref(), Some("1.20.1"));
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::download;
+use crate::minecraft::{self, VersionInfo};
+
+/// Minecraft 版本清单
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct VersionManifest {
+    pub latest: Option<LatestVersions>,
+    pub versions: Vec<ManifestVersion>,
+}
+
+/// 最新的正式版与快照
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct LatestVersions {
+    pub release: String,
+    pub snapshot: String,
+}
+
+/// 清单中的单个版本
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ManifestVersion {
+    pub id: String,
+    pub url: String,
+    pub sha1: String,
+}
+
+/// 获取 Minecraft 版本清单 (Modrinth 元数据镜像)
+pub(crate) async fn fetch_version_manifest(meta_url: &str) -> Result<VersionManifest> {
+    download::fetch_json(&format!("{}minecraft/v0/manifest.json", meta_url)).await
+}
+
+/// 确保原版版本 JSON 已下载到 versions 目录并返回其内容
+pub(crate) async fn ensure_version_json(
+    minecraft_dir: &Path,
+    game_version: &str,
+    meta_url: &str,
+) -> Result<VersionInfo> {
+    let path = minecraft::version_json_path(&minecraft_dir.join("versions"), game_version);
+    if let Ok(version) = minecraft::read_version_info(&path).await {
+        return Ok(version);
+    }
+
+    let manifest = fetch_version_manifest(meta_url).await?;
+    let entry = manifest
+        .versions
+        .iter()
+        .find(|version| version.id == game_version)
+        .ok_or_else(|| anyhow!("未找到 Minecraft 版本: {}", game_version))?;
+    download::download_file(&entry.url, &path, Some(&entry.sha1)).await?;
+    minecraft::read_version_info(&path).await
+}
+
+/// 确保客户端 jar 已下载 (Forge 处理器等需要读取原版 jar)
+pub(crate) async fn ensure_client_jar(minecraft_dir: &Path, version: &VersionInfo) -> Result<PathBuf> {
+    let path = minecraft_dir
+        .join("versions")
+        .join(&version.id)
+        .join(format!("{}.jar", version.id));
+    let client = version
+        .downloads
+        .as_ref()
+        .and_then(|downloads| downloads.get("client"))
+        .ok_or_else(|| anyhow!("版本 {} 缺少客户端下载信息", version.id))?;
+    download::download_file(&client.url, &path, Some(&client.sha1)).await?;
+    Ok(path)
+}
@@ -0,0 +1,96 @@
+//! zip 解压工具
+
+use anyhow::Result;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Seek};
+use std::path::{Component, Path, PathBuf};
+use zip::ZipArchive;
+
+/// 路径是否只由普通路径段组成 (不含 `..`、根目录与盘符)
+fn is_enclosed(path: &Path) -> bool {
+    !path.as_os_str().is_empty() && path.components().all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// 解压 zip 条目到目标目录，返回写出的文件数。
+///
+/// 条目路径经 `enclosed_name` 校验，`..` 与绝对路径一律跳过，不会写出到目标目录之外。
+/// `map` 把条目路径映射为相对目标目录的路径，返回 None 时跳过该条目；
+/// `on_entry` 在每个条目处理后以 (已处理数, 条目总数) 调用
+pub(crate) fn extract_zip<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    dest: &Path,
+    mut map: impl FnMut(&Path) -> Option<PathBuf>,
+    mut on_entry: impl FnMut(usize, usize),
+) -> Result<usize> {
+    let total = archive.len();
+    let mut extracted = 0;
+    for i in 0..total {
+        let mut entry = archive.by_index(i)?;
+        let relative = entry
+            .enclosed_name()
+            .and_then(&mut map)
+            .filter(|relative| is_enclosed(relative));
+        if let Some(relative) = relative {
+            let target = dest.join(relative);
+            if entry.is_dir() {
+                std::fs::create_dir_all(&target)?;
+            } else {
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                io::copy(&mut entry, &mut BufWriter::new(File::create(&target)?))?;
+                extracted += 1;
+            }
+        }
+        on_entry(i + 1, total);
+    }
+    Ok(extracted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    fn archive(entries: &[&str]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for name in entries {
+            zip.start_file(*name, FileOptions::default()).unwrap();
+            zip.write_all(name.as_bytes()).unwrap();
+        }
+        ZipArchive::new(zip.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn skips_entries_outside_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("out");
+        let mut archive = archive(&["../evil.txt", "/abs.txt", "a/../../evil2.txt", "ok/file.txt"]);
+
+        let extracted = extract_zip(&mut archive, &dest, |name| Some(name.to_path_buf()), |_, _| {}).unwrap();
+        assert_eq!(extracted, 1);
+        assert_eq!(std::fs::read_to_string(dest.join("ok/file.txt")).unwrap(), "ok/file.txt");
+        assert!(!dir.path().join("evil.txt").exists());
+        assert!(!dir.path().join("evil2.txt").exists());
+    }
+
+    #[test]
+    fn maps_entry_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut archive = archive(&["maven/net/a.jar", "other/b.jar", "maven"]);
+        let mut seen = Vec::new();
+
+        let extracted = extract_zip(
+            &mut archive,
+            dir.path(),
+            |name| name.strip_prefix("maven").ok().map(Path::to_path_buf),
+            |done, total| seen.push((done, total)),
+        )
+        .unwrap();
+        assert_eq!(extracted, 1);
+        assert!(dir.path().join("net/a.jar").is_file());
+        assert_eq!(seen, [(1, 3), (2, 3), (3, 3)]);
+    }
+}
@@ -0,0 +1,63 @@
+//! 文件校验工具
+
+use anyhow::{anyhow, Result};
+use sha1::{Digest, Sha1};
+use sha2::Sha512;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+
+/// 读取文件时使用的缓冲区大小
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+/// 流式计算文件摘要
+async fn file_digest<D: Digest>(path: &Path) -> Result<String> {
+    let mut file = File::open(path).await?;
+    let mut hasher = D::new();
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex_string(&hasher.finalize()))
+}
+
+/// 流式计算文件的 SHA-1
+pub async fn file_sha1(path: &Path) -> Result<String> {
+    file_digest::<Sha1>(path).await
+}
+
+/// 流式计算文件的 SHA-512
+pub async fn file_sha512(path: &Path) -> Result<String> {
+    file_digest::<Sha512>(path).await
+}
+
+/// 校验文件的 SHA-1
+pub async fn verify_file_sha1(path: &Path, expected: &str) -> Result<()> {
+    verify_digest(path, expected, file_sha1(path).await?)
+}
+
+/// 校验文件的 SHA-512
+pub async fn verify_file_sha512(path: &Path, expected: &str) -> Result<()> {
+    verify_digest(path, expected, file_sha512(path).await?)
+}
+
+fn verify_digest(path: &Path, expected: &str, actual: String) -> Result<()> {
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(anyhow!(
+            "文件校验失败 {}: 期望 {}，实际 {}",
+            path.display(),
+            expected,
+            actual
+        ));
+    }
+    Ok(())
+}
+
+/// 将摘要转换为小写十六进制字符串
+pub fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -30,3 +30,9 @@ pub const CURRENT_FORMAT_VERSION: usize = 0;
 
 /// Azul API 基础URL
 pub const AZUL_API_BASE_URL: &str = "https://api.azul.com/metadata/v1/zulu/packages";
+
+/// Fabric 官方元数据 API 基础URL (Modrinth 元数据不可用时回退)
+pub const FABRIC_META_URL: &str = "https://meta.fabricmc.net/v2/";
+
+/// Quilt 官方元数据 API 基础URL (Modrinth 元数据不可用时回退)
+pub const QUILT_META_URL: &str = "https://meta.quiltmc.org/v3/";
\ No newline at end of file
@@ -0,0 +1,166 @@
+//! 通用 HTTP 请求与文件下载工具
+
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use sha1::{Digest, Sha1};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::api::database::{self, CachedDownload};
+use crate::checksum;
+
+/// 配置常量
+mod config {
+    use std::time::Duration;
+    /// 连接超时
+    pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+    /// 单次请求超时
+    pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+    /// 下载重试次数
+    pub const MAX_RETRIES: u32 = 3;
+    /// 重试间隔
+    pub const RETRY_DELAY: Duration = Duration::from_secs(2);
+}
+
+/// 请求使用的 User-Agent
+pub const USER_AGENT: &str = concat!("ldoubil/AML/", env!("CARGO_PKG_VERSION"));
+
+/// 共享的 HTTP 客户端
+static CLIENT: LazyLock<Client> = LazyLock::new(|| {
+    Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(config::CONNECT_TIMEOUT)
+        .timeout(config::REQUEST_TIMEOUT)
+        .build()
+        .expect("无法创建 HTTP 客户端")
+});
+
+/// 获取共享的 HTTP 客户端
+pub fn client() -> &'static Client {
+    &CLIENT
+}
+
+/// 请求并解析 JSON
+pub async fn fetch_json<T: DeserializeOwned>(url: &str) -> Result<T> {
+    let text = fetch_text(url).await?;
+    serde_json::from_str(&text).map_err(|e| anyhow!("解析 {} 失败: {}", url, e))
+}
+
+/// 请求文本内容
+pub async fn fetch_text(url: &str) -> Result<String> {
+    let response = client().get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("请求失败 {}: {}", url, response.status()));
+    }
+    Ok(response.text().await?)
+}
+
+/// 临时文件路径 (下载完成并校验后再重命名)
+fn part_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// 单次下载尝试：流式写入临时文件并计算 SHA-1，返回实际的 SHA-1 与文件大小
+async fn download_once(url: &str, dest: &Path, sha1: Option<&str>) -> Result<(String, u64)> {
+    let response = client().get(url).send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("下载失败 {}: {}", url, response.status()));
+    }
+
+    let part = part_path(dest);
+    let mut file = fs::File::create(&part).await?;
+    let mut hasher = Sha1::new();
+    let mut size = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        hasher.update(&chunk);
+        size += chunk.len() as u64;
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    drop(file);
+
+    let actual = checksum::hex_string(&hasher.finalize());
+    if let Some(expected) = sha1 {
+        if !actual.eq_ignore_ascii_case(expected) {
+            fs::remove_file(&part).await.ok();
+            return Err(anyhow!("文件校验失败 {}: 期望 {}，实际 {}", url, expected, actual));
+        }
+    }
+
+    fs::rename(&part, dest).await?;
+    Ok((actual, size))
+}
+
+/// 同一 URL 已下载到其他位置且校验通过时直接复制，省去重复下载
+async fn copy_from_cache(url: &str, dest: &Path, sha1: Option<&str>) -> bool {
+    let Ok(Some(entry)) = database::cached_download(url).await else {
+        return false;
+    };
+    let source = Path::new(&entry.path);
+    if source == dest {
+        return false;
+    }
+    let expected = match (sha1, entry.sha1.as_deref()) {
+        (Some(expected), _) | (None, Some(expected)) => expected,
+        (None, None) => return false,
+    };
+    if checksum::verify_file_sha1(source, expected).await.is_err() {
+        return false;
+    }
+    let part = part_path(dest);
+    if fs::copy(source, &part).await.is_err() || fs::rename(&part, dest).await.is_err() {
+        fs::remove_file(&part).await.ok();
+        return false;
+    }
+    true
+}
+
+/// 下载文件到指定路径，已存在且校验通过时跳过；失败时自动重试
+pub async fn download_file(url: &str, dest: &Path, sha1: Option<&str>) -> Result<()> {
+    if fs::try_exists(dest).await? {
+        match sha1 {
+            Some(expected) if checksum::verify_file_sha1(dest, expected).await.is_ok() => return Ok(()),
+            None => return Ok(()),
+            _ => {}
+        }
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+
+    if database::is_initialized() && copy_from_cache(url, dest, sha1).await {
+        return Ok(());
+    }
+
+    let mut last_error = None;
+    for attempt in 0..config::MAX_RETRIES {
+        if attempt > 0 {
+            tokio::time::sleep(config::RETRY_DELAY).await;
+        }
+        match download_once(url, dest, sha1).await {
+            Ok((actual_sha1, size)) => {
+                if database::is_initialized() {
+                    let entry = CachedDownload {
+                        url: url.to_string(),
+                        path: dest.to_string_lossy().to_string(),
+                        sha1: Some(actual_sha1),
+                        size,
+                        fetched_at: chrono::Utc::now(),
+                    };
+                    database::record_download(&entry).await.ok();
+                }
+                return Ok(());
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| anyhow!("下载失败: {}", url)))
+}
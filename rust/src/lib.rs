@@ -1,3 +1,9 @@
 mod frb_generated;
 pub mod api;
-mod config;
\ No newline at end of file
+mod archive;
+mod checksum;
+mod config;
+mod download;
+mod minecraft;
+mod mod_metadata;
+mod version_range;
\ No newline at end of file
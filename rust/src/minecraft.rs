@@ -0,0 +1,508 @@
+//! Minecraft 版本 JSON 数据模型 (与 lib/domain/minecraft.dart 对应)
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 版本详细信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionInfo {
+    /// 版本 ID
+    pub id: String,
+    /// 继承的父版本 ID (加载器版本 JSON 使用)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherits_from: Option<String>,
+    /// 版本类型
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub version_type: Option<String>,
+    /// 发布时间
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_time: Option<String>,
+    /// 最近更新时间
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// 客户端 jar 所属的版本 ID (缺省为自身或父版本)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jar: Option<String>,
+    /// 传递给游戏或 JVM 的参数 (1.13+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<Arguments>,
+    /// 旧版游戏参数字符串 (1.13 之前)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minecraft_arguments: Option<String>,
+    /// 游戏资源索引
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_index: Option<AssetIndex>,
+    /// 资源版本 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assets: Option<String>,
+    /// 版本下载信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<HashMap<String, Download>>,
+    /// 支持的 Java 版本
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub java_version: Option<JavaVersion>,
+    /// 日志配置 (key 为 client / server)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<HashMap<String, LoggingConfig>>,
+    /// 游戏主类
+    #[serde(default)]
+    pub main_class: String,
+    /// 依赖库列表
+    #[serde(default)]
+    pub libraries: Vec<Library>,
+}
+
+/// 文件下载信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Download {
+    pub sha1: String,
+    pub size: u64,
+    pub url: String,
+}
+
+/// 日志配置文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingFile {
+    pub id: String,
+    pub sha1: String,
+    #[serde(default)]
+    pub size: u64,
+    pub url: String,
+}
+
+/// 日志配置 (log4j2-xml)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingConfig {
+    /// JVM 参数模板，包含 `${path}`
+    pub argument: String,
+    pub file: LoggingFile,
+    #[serde(rename = "type")]
+    pub config_type: String,
+}
+
+/// 游戏资源索引信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetIndex {
+    pub id: String,
+    #[serde(default)]
+    pub sha1: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub total_size: u64,
+    #[serde(default)]
+    pub url: String,
+}
+
+/// Java 版本信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JavaVersion {
+    #[serde(default)]
+    pub component: String,
+    pub major_version: i32,
+}
+
+/// 传递给游戏和 JVM 的参数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Arguments {
+    #[serde(default)]
+    pub game: Vec<Argument>,
+    #[serde(default)]
+    pub jvm: Vec<Argument>,
+}
+
+/// 参数定义：普通字符串或带规则的参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Argument {
+    Normal(String),
+    Ruled { rules: Vec<Rule>, value: ArgumentValue },
+}
+
+/// 参数值容器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArgumentValue {
+    Single(String),
+    Many(Vec<String>),
+}
+
+impl Argument {
+    /// 按规则展开参数
+    pub fn resolve(&self, features: &HashMap<String, bool>) -> Vec<String> {
+        match self {
+            Argument::Normal(value) => vec![value.clone()],
+            Argument::Ruled { rules, value } if rules_allow(rules, features) => match value {
+                ArgumentValue::Single(value) => vec![value.clone()],
+                ArgumentValue::Many(values) => values.clone(),
+            },
+            Argument::Ruled { .. } => Vec::new(),
+        }
+    }
+}
+
+/// 规则动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleAction {
+    Allow,
+    Disallow,
+}
+
+/// 操作系统规则
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OsRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+}
+
+/// 规则信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rule {
+    pub action: RuleAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os: Option<OsRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<HashMap<String, bool>>,
+}
+
+/// 库文件下载信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryDownload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default)]
+    pub sha1: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub url: String,
+}
+
+/// 游戏依赖库下载信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryDownloads {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<LibraryDownload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classifiers: Option<HashMap<String, LibraryDownload>>,
+}
+
+/// 库文件解压规则
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryExtract {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<String>>,
+}
+
+/// 游戏依赖库信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Library {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<LibraryDownloads>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extract: Option<LibraryExtract>,
+    /// Maven 名称 (groupId:artifactId:version[:classifier][@ext])
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// 操作系统 -> 原生库分类器
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub natives: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Rule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksums: Option<Vec<String>>,
+    #[serde(default = "default_true")]
+    pub include_in_classpath: bool,
+    #[serde(default = "default_true")]
+    pub downloadable: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 当前系统在版本 JSON 中的名称
+pub fn current_os_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    }
+}
+
+/// 当前系统位数 (用于 `${arch}` 替换)
+pub fn current_arch_bits() -> &'static str {
+    if cfg!(target_pointer_width = "64") {
+        "64"
+    } else {
+        "32"
+    }
+}
+
+/// 当前系统架构在版本 JSON 中的名称
+pub fn current_arch_name() -> &'static str {
+    if cfg!(target_arch = "x86_64") {
+        "x86_64"
+    } else if cfg!(target_arch = "aarch64") {
+        "arm64"
+    } else if cfg!(target_arch = "x86") {
+        "x86"
+    } else {
+        "unknown"
+    }
+}
+
+impl OsRule {
+    fn matches(&self) -> bool {
+        if let Some(name) = &self.name {
+            // Modrinth 元数据会使用 osx-arm64 / linux-arm64 之类的名称
+            let current = match current_arch_name() {
+                "arm64" => format!("{}-arm64", current_os_name()),
+                _ => current_os_name().to_string(),
+            };
+            if name != current_os_name() && *name != current {
+                return false;
+            }
+        }
+        if let Some(arch) = &self.arch {
+            if arch != current_arch_name() && !(arch == "x86" && current_arch_bits() == "32") {
+                return false;
+            }
+        }
+        // version 是针对系统版本号的正则，启动器普遍忽略该字段
+        true
+    }
+}
+
+impl Rule {
+    /// 判断规则是否命中当前环境
+    fn matches(&self, features: &HashMap<String, bool>) -> bool {
+        if let Some(os) = &self.os {
+            if !os.matches() {
+                return false;
+            }
+        }
+        if let Some(required) = &self.features {
+            for (key, value) in required {
+                if features.get(key).copied().unwrap_or(false) != *value {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// 按 Mojang 语义计算规则列表：无规则即允许，否则以最后一条命中的规则为准
+pub fn rules_allow(rules: &[Rule], features: &HashMap<String, bool>) -> bool {
+    if rules.is_empty() {
+        return true;
+    }
+    let mut allowed = false;
+    for rule in rules {
+        if rule.matches(features) {
+            allowed = rule.action == RuleAction::Allow;
+        }
+    }
+    allowed
+}
+
+impl Library {
+    /// 当前系统下是否需要该库
+    pub fn is_allowed(&self) -> bool {
+        self.rules
+            .as_deref()
+            .map(|rules| rules_allow(rules, &HashMap::new()))
+            .unwrap_or(true)
+    }
+
+    /// 是否为仅包含原生库分类器的旧版库 (不加入 classpath)
+    pub fn is_native_only(&self) -> bool {
+        self.natives.is_some()
+            && self
+                .downloads
+                .as_ref()
+                .and_then(|downloads| downloads.artifact.as_ref())
+                .is_none()
+    }
+
+    /// 库在本地仓库中的相对路径
+    pub fn artifact_path(&self) -> Result<PathBuf> {
+        match self
+            .downloads
+            .as_ref()
+            .and_then(|downloads| downloads.artifact.as_ref())
+            .and_then(|artifact| artifact.path.as_ref())
+        {
+            Some(path) => Ok(PathBuf::from(path)),
+            None => maven_path(&self.name, None),
+        }
+    }
+
+    /// 去掉版本号后的 Maven 标识 (group:artifact[:classifier])，用于去重
+    pub fn maven_key(&self) -> String {
+        let coords = self.name.split('@').next().unwrap_or(&self.name);
+        let parts: Vec<&str> = coords.split(':').collect();
+        match parts.as_slice() {
+            [group, artifact, _version, classifier, ..] => format!("{}:{}:{}", group, artifact, classifier),
+            [group, artifact, ..] => format!("{}:{}", group, artifact),
+            _ => coords.to_string(),
+        }
+    }
+
+    /// 当前系统对应的原生库分类器 (已替换 `${arch}`)
+    pub fn native_classifier(&self) -> Option<String> {
+        self.natives
+            .as_ref()?
+            .get(current_os_name())
+            .map(|classifier| classifier.replace("${arch}", current_arch_bits()))
+    }
+}
+
+/// 将 Maven 坐标转换为仓库相对路径
+pub fn maven_path(name: &str, classifier: Option<&str>) -> Result<PathBuf> {
+    let (coords, ext) = match name.split_once('@') {
+        Some((coords, ext)) => (coords, ext),
+        None => (name, "jar"),
+    };
+    let parts: Vec<&str> = coords.split(':').collect();
+    if parts.len() < 3 {
+        return Err(anyhow!("无效的 Maven 坐标: {}", name));
+    }
+    let (group, artifact, version) = (parts[0], parts[1], parts[2]);
+    let classifier = classifier.or_else(|| parts.get(3).copied());
+
+    let file_name = match classifier {
+        Some(classifier) => format!("{}-{}-{}.{}", artifact, version, classifier, ext),
+        None => format!("{}-{}.{}", artifact, version, ext),
+    };
+
+    let mut path = PathBuf::new();
+    for segment in group.split('.') {
+        path.push(segment);
+    }
+    path.push(artifact);
+    path.push(version);
+    path.push(file_name);
+    Ok(path)
+}
+
+impl VersionInfo {
+    /// 客户端 jar 所属的版本 ID
+    pub fn client_jar_id(&self) -> &str {
+        self.jar
+            .as_deref()
+            .or(self.inherits_from.as_deref())
+            .unwrap_or(&self.id)
+    }
+
+    /// 将自身 (子版本) 合并到父版本之上：子版本的库优先，参数追加在父版本之后
+    pub fn merge_into_parent(self, parent: VersionInfo) -> VersionInfo {
+        let jar = self
+            .jar
+            .clone()
+            .unwrap_or_else(|| parent.client_jar_id().to_string());
+
+        let mut libraries = self.libraries;
+        libraries.extend(parent.libraries);
+
+        let arguments = match (parent.arguments, self.arguments) {
+            (Some(mut merged), Some(child)) => {
+                merged.game.extend(child.game);
+                merged.jvm.extend(child.jvm);
+                Some(merged)
+            }
+            (parent, child) => child.or(parent),
+        };
+
+        VersionInfo {
+            id: self.id,
+            inherits_from: parent.inherits_from,
+            version_type: self.version_type.or(parent.version_type),
+            release_time: self.release_time.or(parent.release_time),
+            time: self.time.or(parent.time),
+            jar: Some(jar),
+            arguments,
+            minecraft_arguments: self.minecraft_arguments.or(parent.minecraft_arguments),
+            asset_index: self.asset_index.or(parent.asset_index),
+            assets: self.assets.or(parent.assets),
+            downloads: self.downloads.or(parent.downloads),
+            java_version: self.java_version.or(parent.java_version),
+            logging: self.logging.or(parent.logging),
+            main_class: if self.main_class.is_empty() {
+                parent.main_class
+            } else {
+                self.main_class
+            },
+            libraries,
+        }
+    }
+}
+
+/// 版本 JSON 在 versions 目录中的路径
+pub fn version_json_path(versions_dir: &Path, id: &str) -> PathBuf {
+    versions_dir.join(id).join(format!("{}.json", id))
+}
+
+/// 将 Maven 坐标转换为 URL 路径 (使用 `/` 分隔)
+pub fn maven_url_path(name: &str, classifier: Option<&str>) -> Result<String> {
+    let path = maven_path(name, classifier)?;
+    Ok(path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
+/// 读取版本 JSON 文件
+pub async fn read_version_info(path: &Path) -> Result<VersionInfo> {
+    let text = tokio::fs::read_to_string(path).await?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// 写入版本 JSON 文件
+pub async fn write_version_info(path: &Path, version: &VersionInfo) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, serde_json::to_vec_pretty(version)?).await?;
+    Ok(())
+}
+
+/// 读取版本 JSON 并展开 inheritsFrom 链 (父版本位于同一 versions 目录)
+pub async fn read_resolved_version_info(path: &Path) -> Result<VersionInfo> {
+    /// inheritsFrom 链的最大深度，防止循环引用
+    const MAX_INHERIT_DEPTH: usize = 8;
+
+    let versions_dir = path
+        .parent()
+        .and_then(Path::parent)
+        .ok_or_else(|| anyhow!("无效的版本 JSON 路径: {}", path.display()))?;
+
+    let mut version = read_version_info(path).await?;
+    let mut depth = 0;
+    while let Some(parent_id) = version.inherits_from.clone() {
+        depth += 1;
+        if depth > MAX_INHERIT_DEPTH {
+            return Err(anyhow!("版本 {} 的 inheritsFrom 层级过深", version.id));
+        }
+        let parent = read_version_info(&version_json_path(versions_dir, &parent_id))
+            .await
+            .map_err(|e| anyhow!("无法读取父版本 {}: {}", parent_id, e))?;
+        version = version.merge_into_parent(parent);
+    }
+    Ok(version)
+}
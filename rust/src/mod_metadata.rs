@@ -0,0 +1,877 @@
+//! 读取模组 jar 内嵌的元数据 (Fabric、Quilt、Forge、NeoForge 与旧版 Forge 格式)
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::api::mod_loader::ModLoader;
+use crate::version_range;
+
+/// 配置常量
+mod config {
+    /// 读取内嵌图标的大小上限
+    pub const MAX_ICON_SIZE: u64 = 1024 * 1024;
+    /// 读取内嵌 jar 的大小上限
+    pub const MAX_NESTED_JAR_SIZE: u64 = 64 * 1024 * 1024;
+    /// 内嵌 jar 的最大嵌套层数
+    pub const MAX_NESTED_DEPTH: usize = 3;
+    /// Forge 的 Jar-in-Jar 清单
+    pub const JARJAR_METADATA: &str = "META-INF/jarjar/metadata.json";
+    /// class 文件主版本号与 Java 版本的差值 (Java 8 = 52)
+    pub const CLASS_VERSION_OFFSET: u16 = 44;
+}
+
+/// 依赖类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DependencyKind {
+    Required,
+    Optional,
+    Incompatible,
+}
+
+/// 依赖的版本范围
+#[derive(Debug, Clone)]
+pub(crate) enum VersionRange {
+    Any,
+    /// Fabric / Quilt 的版本谓词，任一满足即可
+    Fabric(Vec<String>),
+    /// Forge 的 Maven 版本范围
+    Maven(String),
+}
+
+impl VersionRange {
+    /// 版本是否在范围内，范围无法解析时返回错误
+    pub(crate) fn matches(&self, version: &str) -> Result<bool> {
+        match self {
+            VersionRange::Any => Ok(true),
+            VersionRange::Fabric(predicates) => version_range::matches_fabric(version, predicates),
+            VersionRange::Maven(spec) => version_range::matches_maven(version, spec),
+        }
+    }
+
+    /// 用于提示的范围文本
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            VersionRange::Any => "*".to_string(),
+            VersionRange::Fabric(predicates) => predicates.join(" || "),
+            VersionRange::Maven(spec) => spec.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ModDependency {
+    pub mod_id: String,
+    pub range: VersionRange,
+    pub kind: DependencyKind,
+}
+
+/// 一个模组的元数据
+#[derive(Debug, Clone)]
+pub(crate) struct ModMetadata {
+    pub loader: ModLoader,
+    pub mod_id: String,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub authors: Vec<String>,
+    /// 同时提供的模组 ID (Fabric / Quilt 的 `provides`)
+    pub provides: Vec<String>,
+    pub dependencies: Vec<ModDependency>,
+    /// 图标在 jar 中的路径
+    pub icon: Option<String>,
+    /// 通过 Jar-in-Jar 内嵌的模组
+    pub bundled: Vec<ModMetadata>,
+}
+
+impl ModMetadata {
+    fn new(loader: ModLoader, mod_id: String) -> Self {
+        Self {
+            loader,
+            mod_id,
+            name: None,
+            version: None,
+            description: None,
+            authors: Vec::new(),
+            provides: Vec::new(),
+            dependencies: Vec::new(),
+            icon: None,
+            bundled: Vec::new(),
+        }
+    }
+}
+
+// Fabric
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FabricPerson {
+    Name(String),
+    Object { name: String },
+}
+
+impl FabricPerson {
+    fn into_name(self) -> String {
+        match self {
+            FabricPerson::Name(name) | FabricPerson::Object { name } => name,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FabricIcon {
+    Path(String),
+    /// 尺寸 -> 路径
+    Sizes(HashMap<String, String>),
+}
+
+impl FabricIcon {
+    /// 多个尺寸时取最大的
+    fn into_path(self) -> Option<String> {
+        match self {
+            FabricIcon::Path(path) => Some(path),
+            FabricIcon::Sizes(sizes) => sizes
+                .into_iter()
+                .max_by_key(|(size, _)| size.parse::<u32>().unwrap_or_default())
+                .map(|(_, path)| path),
+        }
+    }
+}
+
+/// 单个谓词字符串或谓词数组
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FabricPredicates {
+    One(String),
+    Any(Vec<String>),
+}
+
+impl FabricPredicates {
+    fn into_range(self) -> VersionRange {
+        let predicates = match self {
+            FabricPredicates::One(predicate) => vec![predicate],
+            FabricPredicates::Any(predicates) => predicates,
+        };
+        if predicates.iter().all(|predicate| matches!(predicate.trim(), "" | "*")) {
+            VersionRange::Any
+        } else {
+            VersionRange::Fabric(predicates)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct FabricNestedJar {
+    file: String,
+}
+
+/// fabric.mod.json
+#[derive(Deserialize)]
+struct FabricModJson {
+    id: String,
+    version: Option<String>,
+    name: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    authors: Vec<FabricPerson>,
+    icon: Option<FabricIcon>,
+    #[serde(default)]
+    provides: Vec<String>,
+    #[serde(default)]
+    depends: HashMap<String, FabricPredicates>,
+    #[serde(default)]
+    recommends: HashMap<String, FabricPredicates>,
+    #[serde(default)]
+    breaks: HashMap<String, FabricPredicates>,
+    #[serde(default)]
+    jars: Vec<FabricNestedJar>,
+}
+
+fn fabric_dependencies(entries: HashMap<String, FabricPredicates>, kind: DependencyKind) -> Vec<ModDependency> {
+    entries
+        .into_iter()
+        .map(|(mod_id, predicates)| ModDependency {
+            mod_id,
+            range: predicates.into_range(),
+            kind,
+        })
+        .collect()
+}
+
+// Quilt
+
+#[derive(Deserialize, Default)]
+struct QuiltMetadata {
+    name: Option<String>,
+    description: Option<String>,
+    /// 名称 -> 角色
+    #[serde(default)]
+    contributors: HashMap<String, String>,
+    icon: Option<FabricIcon>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum QuiltDependency {
+    Id(String),
+    Object {
+        id: String,
+        /// 谓词字符串、谓词数组或 `{ "any": [...] }` 等对象 (对象不做检查)
+        versions: Option<serde_json::Value>,
+        #[serde(default)]
+        optional: bool,
+    },
+}
+
+impl QuiltDependency {
+    fn into_dependency(self, kind: DependencyKind) -> ModDependency {
+        match self {
+            QuiltDependency::Id(id) => ModDependency {
+                mod_id: id,
+                range: VersionRange::Any,
+                kind,
+            },
+            QuiltDependency::Object { id, versions, optional } => ModDependency {
+                mod_id: id,
+                range: versions
+                    .and_then(|versions| serde_json::from_value::<FabricPredicates>(versions).ok())
+                    .map(FabricPredicates::into_range)
+                    .unwrap_or(VersionRange::Any),
+                kind: if optional && kind == DependencyKind::Required {
+                    DependencyKind::Optional
+                } else {
+                    kind
+                },
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum QuiltProvides {
+    Id(String),
+    Object { id: String },
+}
+
+#[derive(Deserialize)]
+struct QuiltLoader {
+    id: String,
+    version: Option<String>,
+    #[serde(default)]
+    metadata: QuiltMetadata,
+    #[serde(default)]
+    provides: Vec<QuiltProvides>,
+    #[serde(default)]
+    depends: Vec<QuiltDependency>,
+    #[serde(default)]
+    breaks: Vec<QuiltDependency>,
+    #[serde(default)]
+    jars: Vec<String>,
+}
+
+/// quilt.mod.json
+#[derive(Deserialize)]
+struct QuiltModJson {
+    quilt_loader: QuiltLoader,
+}
+
+// Forge / NeoForge
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModsTomlEntry {
+    mod_id: String,
+    version: Option<String>,
+    display_name: Option<String>,
+    description: Option<String>,
+    /// 字符串或字符串数组
+    authors: Option<toml::Value>,
+    logo_file: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModsTomlDependency {
+    mod_id: String,
+    /// Forge 使用 `mandatory`，NeoForge 使用 `type`
+    mandatory: Option<bool>,
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    version_range: Option<String>,
+    side: Option<String>,
+}
+
+impl ModsTomlDependency {
+    /// 只在服务端生效的依赖返回 None
+    fn into_dependency(self) -> Option<ModDependency> {
+        if self.side.as_deref().is_some_and(|side| side.eq_ignore_ascii_case("server")) {
+            return None;
+        }
+        let kind = match self.kind.as_deref().map(str::to_lowercase).as_deref() {
+            Some("required") => DependencyKind::Required,
+            Some("incompatible") => DependencyKind::Incompatible,
+            Some(_) => DependencyKind::Optional,
+            None if self.mandatory.unwrap_or(false) => DependencyKind::Required,
+            None => DependencyKind::Optional,
+        };
+        Some(ModDependency {
+            mod_id: self.mod_id,
+            range: self.version_range.map(VersionRange::Maven).unwrap_or(VersionRange::Any),
+            kind,
+        })
+    }
+}
+
+/// META-INF/mods.toml 与 META-INF/neoforge.mods.toml
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModsToml {
+    logo_file: Option<String>,
+    #[serde(default)]
+    mods: Vec<ModsTomlEntry>,
+    /// 模组 ID -> 依赖列表
+    #[serde(default)]
+    dependencies: HashMap<String, Vec<ModsTomlDependency>>,
+}
+
+#[derive(Deserialize)]
+struct JarJarEntry {
+    path: String,
+}
+
+/// META-INF/jarjar/metadata.json
+#[derive(Deserialize)]
+struct JarJarMetadata {
+    #[serde(default)]
+    jars: Vec<JarJarEntry>,
+}
+
+#[derive(Deserialize)]
+struct McModEntry {
+    modid: String,
+    name: Option<String>,
+    version: Option<String>,
+    description: Option<String>,
+    #[serde(default, rename = "authorList")]
+    author_list: Vec<String>,
+    #[serde(rename = "logoFile")]
+    logo_file: Option<String>,
+    /// `modid@版本范围`
+    #[serde(default, rename = "requiredMods")]
+    required_mods: Vec<String>,
+}
+
+/// mcmod.info (数组，或旧格式的 `{"modList": [...]}`)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum McModInfo {
+    List(Vec<McModEntry>),
+    Wrapped {
+        #[serde(rename = "modList")]
+        mod_list: Vec<McModEntry>,
+    },
+}
+
+/// 读取 jar 中的文件，超过 `limit` 时返回 None
+pub(crate) fn read_entry<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str, limit: u64) -> Option<Vec<u8>> {
+    let entry = archive.by_name(name.trim_start_matches('/')).ok()?;
+    if entry.size() > limit {
+        return None;
+    }
+    let mut bytes = Vec::new();
+    entry.take(limit).read_to_end(&mut bytes).ok()?;
+    Some(bytes)
+}
+
+fn read_text<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Option<String> {
+    read_entry(archive, name, u64::MAX).map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// 读取模组图标
+pub(crate) fn read_icon<R: Read + Seek>(archive: &mut ZipArchive<R>, metadata: &ModMetadata) -> Option<Vec<u8>> {
+    read_entry(archive, metadata.icon.as_deref()?, config::MAX_ICON_SIZE)
+}
+
+/// `${file.jarVersion}` 等占位版本从 MANIFEST.MF 的 Implementation-Version 读取
+fn resolve_jar_version<R: Read + Seek>(archive: &mut ZipArchive<R>, version: Option<String>) -> Option<String> {
+    match version {
+        Some(version) if version.starts_with("${") => read_text(archive, "META-INF/MANIFEST.MF")?
+            .lines()
+            .find_map(|line| line.strip_prefix("Implementation-Version:"))
+            .map(|value| value.trim().to_string()),
+        version => version,
+    }
+}
+
+/// 读取内嵌 jar 中的模组
+fn read_nested<R: Read + Seek>(archive: &mut ZipArchive<R>, paths: &[String], depth: usize) -> Vec<ModMetadata> {
+    if depth >= config::MAX_NESTED_DEPTH {
+        return Vec::new();
+    }
+    paths
+        .iter()
+        .filter_map(|path| read_entry(archive, path, config::MAX_NESTED_JAR_SIZE))
+        .filter_map(|bytes| ZipArchive::new(Cursor::new(bytes)).ok())
+        .flat_map(|mut nested| read_archive(&mut nested, depth + 1))
+        .collect()
+}
+
+fn read_quilt<R: Read + Seek>(archive: &mut ZipArchive<R>, depth: usize) -> Option<ModMetadata> {
+    let text = read_text(archive, "quilt.mod.json")?;
+    let loader = serde_json::from_str::<QuiltModJson>(&text).ok()?.quilt_loader;
+    let mut metadata = ModMetadata::new(ModLoader::Quilt, loader.id);
+    metadata.name = loader.metadata.name;
+    metadata.version = loader.version;
+    metadata.description = loader.metadata.description;
+    metadata.authors = loader.metadata.contributors.into_keys().collect();
+    metadata.icon = loader.metadata.icon.and_then(FabricIcon::into_path);
+    metadata.provides = loader
+        .provides
+        .into_iter()
+        .map(|provides| match provides {
+            QuiltProvides::Id(id) | QuiltProvides::Object { id } => id,
+        })
+        .collect();
+    metadata.dependencies = loader
+        .depends
+        .into_iter()
+        .map(|dependency| dependency.into_dependency(DependencyKind::Required))
+        .chain(
+            loader
+                .breaks
+                .into_iter()
+                .map(|dependency| dependency.into_dependency(DependencyKind::Incompatible)),
+        )
+        .collect();
+    metadata.bundled = read_nested(archive, &loader.jars, depth);
+    Some(metadata)
+}
+
+fn read_fabric<R: Read + Seek>(archive: &mut ZipArchive<R>, depth: usize) -> Option<ModMetadata> {
+    let text = read_text(archive, "fabric.mod.json")?;
+    // 部分模组的 fabric.mod.json 含有未转义的换行
+    let json = serde_json::from_str::<FabricModJson>(&text.replace(['\n', '\r'], " ")).ok()?;
+    let mut metadata = ModMetadata::new(ModLoader::Fabric, json.id);
+    metadata.name = json.name;
+    metadata.version = json.version;
+    metadata.description = json.description;
+    metadata.authors = json.authors.into_iter().map(FabricPerson::into_name).collect();
+    metadata.icon = json.icon.and_then(FabricIcon::into_path);
+    metadata.provides = json.provides;
+    metadata.dependencies = fabric_dependencies(json.depends, DependencyKind::Required);
+    metadata.dependencies.extend(fabric_dependencies(json.recommends, DependencyKind::Optional));
+    metadata.dependencies.extend(fabric_dependencies(json.breaks, DependencyKind::Incompatible));
+    let jars: Vec<String> = json.jars.into_iter().map(|jar| jar.file).collect();
+    metadata.bundled = read_nested(archive, &jars, depth);
+    Some(metadata)
+}
+
+fn read_mods_toml<R: Read + Seek>(archive: &mut ZipArchive<R>, file: &str, depth: usize) -> Vec<ModMetadata> {
+    let Some(toml) = read_text(archive, file).and_then(|text| toml::from_str::<ModsToml>(&text).ok()) else {
+        return Vec::new();
+    };
+    let bundled = read_text(archive, config::JARJAR_METADATA)
+        .and_then(|text| serde_json::from_str::<JarJarMetadata>(&text).ok())
+        .map(|jarjar| {
+            let paths: Vec<String> = jarjar.jars.into_iter().map(|jar| jar.path).collect();
+            read_nested(archive, &paths, depth)
+        })
+        .unwrap_or_default();
+
+    let mut dependencies = toml.dependencies;
+    let mut mods = Vec::new();
+    for entry in toml.mods {
+        let entry_dependencies: Vec<ModDependency> = dependencies
+            .remove(&entry.mod_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(ModsTomlDependency::into_dependency)
+            .collect();
+        // 1.20.2 ~ 1.20.4 的 NeoForge 模组同样使用 mods.toml，以依赖 neoforge 区分
+        let loader = if file.contains("neoforge") || entry_dependencies.iter().any(|dependency| dependency.mod_id == "neoforge") {
+            ModLoader::NeoForge
+        } else {
+            ModLoader::Forge
+        };
+        let mut metadata = ModMetadata::new(loader, entry.mod_id);
+        metadata.name = entry.display_name;
+        metadata.version = resolve_jar_version(archive, entry.version);
+        metadata.description = entry.description.map(|description| description.trim().to_string());
+        metadata.authors = match entry.authors {
+            Some(toml::Value::String(authors)) => authors
+                .split(',')
+                .map(|author| author.trim().to_string())
+                .filter(|author| !author.is_empty())
+                .collect(),
+            Some(toml::Value::Array(authors)) => authors
+                .into_iter()
+                .filter_map(|author| author.as_str().map(str::to_string))
+                .collect(),
+            _ => Vec::new(),
+        };
+        metadata.icon = entry.logo_file.or(toml.logo_file.clone());
+        metadata.dependencies = entry_dependencies;
+        mods.push(metadata);
+    }
+    // 内嵌的模组归属于第一个模组
+    if let Some(first) = mods.first_mut() {
+        first.bundled = bundled;
+    }
+    mods
+}
+
+fn read_mcmod_info<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Vec<ModMetadata> {
+    let Some(text) = read_text(archive, "mcmod.info") else {
+        return Vec::new();
+    };
+    let entries = match serde_json::from_str::<McModInfo>(&text.replace(['\n', '\r'], " ")) {
+        Ok(McModInfo::List(entries)) | Ok(McModInfo::Wrapped { mod_list: entries }) => entries,
+        Err(_) => Vec::new(),
+    };
+    entries
+        .into_iter()
+        .map(|entry| {
+            let mut metadata = ModMetadata::new(ModLoader::Forge, entry.modid);
+            metadata.name = entry.name;
+            metadata.version = entry.version;
+            metadata.description = entry.description;
+            metadata.authors = entry.author_list;
+            metadata.icon = entry.logo_file;
+            metadata.dependencies = entry
+                .required_mods
+                .iter()
+                .map(|required| {
+                    let (mod_id, range) = match required.split_once('@') {
+                        Some((mod_id, range)) => (mod_id, VersionRange::Maven(range.to_string())),
+                        None => (required.as_str(), VersionRange::Any),
+                    };
+                    // 旧版写作 `Forge`、`FML`
+                    ModDependency {
+                        mod_id: mod_id.to_lowercase(),
+                        range,
+                        kind: DependencyKind::Required,
+                    }
+                })
+                .collect();
+            metadata
+        })
+        .collect()
+}
+
+/// 读取 jar 中的全部模组元数据，按 Quilt、Fabric、NeoForge、Forge、旧版 Forge 的顺序排列
+fn read_archive<R: Read + Seek>(archive: &mut ZipArchive<R>, depth: usize) -> Vec<ModMetadata> {
+    let mut mods = Vec::new();
+    mods.extend(read_quilt(archive, depth));
+    mods.extend(read_fabric(archive, depth));
+    mods.extend(read_mods_toml(archive, "META-INF/neoforge.mods.toml", depth));
+    mods.extend(read_mods_toml(archive, "META-INF/mods.toml", depth));
+    mods.extend(read_mcmod_info(archive));
+    mods
+}
+
+/// 读取模组文件的全部元数据，同时支持多个加载器的 jar 会返回多项
+pub(crate) fn read_mod_metadata(path: &Path) -> Result<Vec<ModMetadata>> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    Ok(read_archive(&mut archive, 0))
+}
+
+/// 读取第一项元数据及其图标
+pub(crate) fn read_primary_metadata(path: &Path) -> Result<Option<(ModMetadata, Option<Vec<u8>>)>> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    let Some(metadata) = read_archive(&mut archive, 0).into_iter().next() else {
+        return Ok(None);
+    };
+    let icon = read_icon(&mut archive, &metadata);
+    Ok(Some((metadata, icon)))
+}
+
+/// 由 jar 中的 class 文件版本推断所需的 Java 主版本
+pub(crate) fn class_java_version(path: &Path) -> Result<Option<u16>> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index)?;
+        let name = entry.name();
+        // 多版本 jar 的高版本 class 与 module-info 不代表最低要求
+        if !name.ends_with(".class") || name.starts_with("META-INF/") || name.ends_with("module-info.class") {
+            continue;
+        }
+        let mut header = [0u8; 8];
+        if entry.take(8).read_exact(&mut header).is_ok() && header[..4] == [0xCA, 0xFE, 0xBA, 0xBE] {
+            let major = u16::from_be_bytes([header[6], header[7]]);
+            return Ok(major.checked_sub(config::CLASS_VERSION_OFFSET));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    pub(crate) fn jar(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, bytes) in entries {
+            zip.start_file(*name, FileOptions::default()).unwrap();
+            zip.write_all(bytes).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn read(bytes: Vec<u8>) -> Vec<ModMetadata> {
+        read_archive(&mut ZipArchive::new(Cursor::new(bytes)).unwrap(), 0)
+    }
+
+    fn dependency<'a>(metadata: &'a ModMetadata, mod_id: &str) -> &'a ModDependency {
+        metadata
+            .dependencies
+            .iter()
+            .find(|dependency| dependency.mod_id == mod_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_fabric_mod_json() {
+        let json = r#"{
+            "id": "sodium",
+            "version": "0.5.3",
+            "name": "Sodium",
+            "description": "Fast
+rendering",
+            "authors": ["jellysquid3", { "name": "IMS" }],
+            "icon": { "16": "small.png", "128": "large.png" },
+            "provides": ["rubidium"],
+            "depends": { "fabricloader": ">=0.12", "minecraft": ["1.20", "1.20.1"], "fabric-api": "*" },
+            "recommends": { "modmenu": "*" },
+            "breaks": { "optifabric": "*" }
+        }"#;
+        let mods = read(jar(&[("fabric.mod.json", json.as_bytes())]));
+        assert_eq!(mods.len(), 1);
+        let sodium = &mods[0];
+        assert_eq!(sodium.loader, ModLoader::Fabric);
+        assert_eq!(sodium.mod_id, "sodium");
+        assert_eq!(sodium.version.as_deref(), Some("0.5.3"));
+        assert_eq!(sodium.description.as_deref(), Some("Fast rendering"));
+        assert_eq!(sodium.authors, ["jellysquid3", "IMS"]);
+        assert_eq!(sodium.icon.as_deref(), Some("large.png"));
+        assert_eq!(sodium.provides, ["rubidium"]);
+        assert_eq!(dependency(sodium, "minecraft").range.describe(), "1.20 || 1.20.1");
+        assert!(matches!(dependency(sodium, "fabric-api").range, VersionRange::Any));
+        assert_eq!(dependency(sodium, "fabricloader").kind, DependencyKind::Required);
+        assert_eq!(dependency(sodium, "modmenu").kind, DependencyKind::Optional);
+        assert_eq!(dependency(sodium, "optifabric").kind, DependencyKind::Incompatible);
+    }
+
+    #[test]
+    fn reads_quilt_mod_json() {
+        let json = r#"{
+            "quilt_loader": {
+                "id": "qsl",
+                "version": "6.1.0",
+                "metadata": { "name": "QSL", "contributors": { "Quilt": "Owner" }, "icon": "icon.png" },
+                "provides": ["quilted_fabric_api", { "id": "fabric-api" }],
+                "depends": ["quilt_loader", { "id": "minecraft", "versions": ">=1.20" }, { "id": "emi", "optional": true }],
+                "breaks": [{ "id": "old", "versions": { "any": ["<1.0"] } }]
+            }
+        }"#;
+        let mods = read(jar(&[("quilt.mod.json", json.as_bytes())]));
+        let qsl = &mods[0];
+        assert_eq!(qsl.loader, ModLoader::Quilt);
+        assert_eq!(qsl.name.as_deref(), Some("QSL"));
+        assert_eq!(qsl.authors, ["Quilt"]);
+        assert_eq!(qsl.icon.as_deref(), Some("icon.png"));
+        assert_eq!(qsl.provides, ["quilted_fabric_api", "fabric-api"]);
+        assert!(matches!(dependency(qsl, "quilt_loader").range, VersionRange::Any));
+        assert_eq!(dependency(qsl, "minecraft").range.describe(), ">=1.20");
+        assert_eq!(dependency(qsl, "emi").kind, DependencyKind::Optional);
+        // 对象形式的版本范围不做检查
+        assert!(matches!(dependency(qsl, "old").range, VersionRange::Any));
+        assert_eq!(dependency(qsl, "old").kind, DependencyKind::Incompatible);
+    }
+
+    #[test]
+    fn reads_forge_mods_toml() {
+        let toml = r#"
+            modLoader = "javafml"
+            logoFile = "logo.png"
+
+            [[mods]]
+            modId = "create"
+            version = "${file.jarVersion}"
+            displayName = "Create"
+            authors = "simibubi, Tropheus Jay"
+            description = '''
+            Building tools
+            '''
+
+            [[dependencies.create]]
+            modId = "forge"
+            mandatory = true
+            versionRange = "[47,)"
+            side = "BOTH"
+
+            [[dependencies.create]]
+            modId = "jei"
+            mandatory = false
+            side = "CLIENT"
+
+            [[dependencies.create]]
+            modId = "servercore"
+            mandatory = true
+            side = "SERVER"
+        "#;
+        let manifest = "Manifest-Version: 1.0\r\nImplementation-Version: 0.5.1.f\r\n";
+        let mods = read(jar(&[
+            ("META-INF/mods.toml", toml.as_bytes()),
+            ("META-INF/MANIFEST.MF", manifest.as_bytes()),
+        ]));
+        let create = &mods[0];
+        assert_eq!(create.loader, ModLoader::Forge);
+        assert_eq!(create.version.as_deref(), Some("0.5.1.f"));
+        assert_eq!(create.authors, ["simibubi", "Tropheus Jay"]);
+        assert_eq!(create.description.as_deref(), Some("Building tools"));
+        assert_eq!(create.icon.as_deref(), Some("logo.png"));
+        assert_eq!(create.dependencies.len(), 2);
+        assert_eq!(dependency(create, "forge").kind, DependencyKind::Required);
+        assert_eq!(dependency(create, "forge").range.describe(), "[47,)");
+        assert_eq!(dependency(create, "jei").kind, DependencyKind::Optional);
+    }
+
+    #[test]
+    fn reads_neoforge_mods_toml() {
+        let toml = r#"
+            [[mods]]
+            modId = "ftblibrary"
+            version = "${file.jarVersion}"
+            authors = ["FTB"]
+            logoFile = "ftb.png"
+
+            [[dependencies.ftblibrary]]
+            modId = "neoforge"
+            type = "required"
+            versionRange = "[21.0,)"
+
+            [[dependencies.ftblibrary]]
+            modId = "architectury"
+            type = "incompatible"
+        "#;
+        let manifest = "Implementation-Version: 2100.1.4\n";
+        let neoforge = read(jar(&[
+            ("META-INF/neoforge.mods.toml", toml.as_bytes()),
+            ("META-INF/MANIFEST.MF", manifest.as_bytes()),
+        ]));
+        assert_eq!(neoforge[0].loader, ModLoader::NeoForge);
+        assert_eq!(neoforge[0].version.as_deref(), Some("2100.1.4"));
+        assert_eq!(neoforge[0].authors, ["FTB"]);
+        assert_eq!(neoforge[0].icon.as_deref(), Some("ftb.png"));
+        assert_eq!(dependency(&neoforge[0], "architectury").kind, DependencyKind::Incompatible);
+
+        // 1.20.2 ~ 1.20.4 的 NeoForge 模组仍写在 mods.toml 中
+        let legacy = read(jar(&[("META-INF/mods.toml", toml.as_bytes())]));
+        assert_eq!(legacy[0].loader, ModLoader::NeoForge);
+        assert_eq!(legacy[0].version, None);
+    }
+
+    #[test]
+    fn reads_mcmod_info_mod_list() {
+        let info = r#"{
+            "modListVersion": 2,
+            "modList": [{
+                "modid": "journeymap",
+                "name": "JourneyMap",
+                "version": "5.7.1",
+                "authorList": ["techbrew"],
+                "logoFile": "/assets/logo.png",
+                "requiredMods": ["Forge@[14.23,)", "FML"]
+            }]
+        }"#;
+        let mods = read(jar(&[("mcmod.info", info.as_bytes())]));
+        let journeymap = &mods[0];
+        assert_eq!(journeymap.loader, ModLoader::Forge);
+        assert_eq!(journeymap.mod_id, "journeymap");
+        assert_eq!(journeymap.authors, ["techbrew"]);
+        assert_eq!(dependency(journeymap, "forge").range.describe(), "[14.23,)");
+        assert!(matches!(dependency(journeymap, "fml").range, VersionRange::Any));
+
+        let list = format!("[{}]", &info[info.find("[{").unwrap() + 1..info.rfind("}]").unwrap() + 1]);
+        assert_eq!(read(jar(&[("mcmod.info", list.as_bytes())]))[0].mod_id, "journeymap");
+    }
+
+    #[test]
+    fn reads_nested_jars() {
+        let fabric = |id: &str, jars: &[&str]| {
+            let jars: Vec<_> = jars.iter().map(|file| serde_json::json!({ "file": file })).collect();
+            serde_json::json!({ "id": id, "jars": jars }).to_string()
+        };
+        // 第四层超过嵌套上限，不再读取
+        let level4 = jar(&[("fabric.mod.json", fabric("level4", &[]).as_bytes())]);
+        let level3 = jar(&[
+            ("fabric.mod.json", fabric("level3", &["META-INF/jars/level4.jar"]).as_bytes()),
+            ("META-INF/jars/level4.jar", &level4),
+        ]);
+        let level2 = jar(&[
+            ("fabric.mod.json", fabric("level2", &["META-INF/jars/level3.jar"]).as_bytes()),
+            ("META-INF/jars/level3.jar", &level3),
+        ]);
+        let level1 = jar(&[
+            ("fabric.mod.json", fabric("level1", &["META-INF/jars/level2.jar"]).as_bytes()),
+            ("META-INF/jars/level2.jar", &level2),
+        ]);
+        let outer = jar(&[
+            ("fabric.mod.json", fabric("outer", &["META-INF/jars/level1.jar", "missing.jar"]).as_bytes()),
+            ("META-INF/jars/level1.jar", &level1),
+        ]);
+        let mods = read(outer);
+        let level1 = &mods[0].bundled[0];
+        let level2 = &level1.bundled[0];
+        let level3 = &level2.bundled[0];
+        assert_eq!(
+            (level1.mod_id.as_str(), level2.mod_id.as_str(), level3.mod_id.as_str()),
+            ("level1", "level2", "level3")
+        );
+        assert_eq!(mods[0].bundled.len(), 1);
+        assert!(level3.bundled.is_empty());
+
+        let nested = jar(&[("META-INF/mods.toml", b"[[mods]]\nmodId = \"mixinextras\"\n")]);
+        let forge = jar(&[
+            ("META-INF/mods.toml", b"[[mods]]\nmodId = \"first\"\n[[mods]]\nmodId = \"second\"\n"),
+            (config::JARJAR_METADATA, br#"{ "jars": [{ "path": "META-INF/jarjar/mixinextras.jar" }] }"#),
+            ("META-INF/jarjar/mixinextras.jar", &nested),
+        ]);
+        let mods = read(forge);
+        assert_eq!(mods[0].bundled[0].mod_id, "mixinextras");
+        assert!(mods[1].bundled.is_empty());
+    }
+
+    #[test]
+    fn detects_class_java_version() {
+        let class = |major: u16| {
+            let mut header = vec![0xCA, 0xFE, 0xBA, 0xBE, 0, 0];
+            header.extend(major.to_be_bytes());
+            header
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mod.jar");
+        std::fs::write(
+            &path,
+            jar(&[
+                ("META-INF/versions/21/a/A.class", &class(65)),
+                ("module-info.class", &class(65)),
+                ("a/A.class", &class(61)),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(class_java_version(&path).unwrap(), Some(17));
+
+        std::fs::write(&path, jar(&[("assets/a.png", b"png")])).unwrap();
+        assert_eq!(class_java_version(&path).unwrap(), None);
+    }
+}
@@ -0,0 +1,361 @@
+//! 版本号比较与版本范围 (Fabric 的语义化版本谓词与 Forge 使用的 Maven 版本范围)
+
+use anyhow::{anyhow, Result};
+use std::cmp::Ordering;
+
+/// 视为正式版的 Maven 限定符
+const RELEASE_QUALIFIERS: &[&str] = &["final", "ga", "release"];
+
+/// 宽松解析的版本号：开头的数字段 + 预发布标识，构建元数据 (`+...`) 忽略
+#[derive(Debug, Clone)]
+pub(crate) struct LooseVersion {
+    numbers: Vec<u64>,
+    pre: Vec<String>,
+}
+
+impl LooseVersion {
+    pub(crate) fn parse(version: &str) -> Self {
+        let version = version.trim().split('+').next().unwrap_or_default();
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+
+        let mut numbers = Vec::new();
+        let mut pre_parts = Vec::new();
+        let mut parts = core.split('.');
+        for part in parts.by_ref() {
+            let digits: String = part.chars().take_while(char::is_ascii_digit).collect();
+            if digits.is_empty() {
+                pre_parts.push(part.to_string());
+                break;
+            }
+            numbers.push(digits.parse().unwrap_or(u64::MAX));
+            // 如 `23w13a`、`1.20.1a`：数字之后的部分作为预发布标识
+            if digits.len() < part.len() {
+                pre_parts.push(part[digits.len()..].to_string());
+                break;
+            }
+        }
+        pre_parts.extend(parts.map(str::to_string));
+        if let Some(pre) = pre {
+            pre_parts.extend(pre.split(['.', '-']).map(str::to_string));
+        }
+        pre_parts.retain(|part| !part.is_empty());
+        // Fabric 中 `1.20-` 表示 1.20 最低的预发布版本
+        if pre == Some("") && pre_parts.is_empty() {
+            pre_parts.push(String::new());
+        }
+        if pre_parts.len() == 1 && RELEASE_QUALIFIERS.contains(&pre_parts[0].to_lowercase().as_str()) {
+            pre_parts.clear();
+        }
+        Self { numbers, pre: pre_parts }
+    }
+
+    /// 只比较数字段 (缺少的段视为 0)
+    fn cmp_numbers(&self, other: &Self) -> Ordering {
+        let len = self.numbers.len().max(other.numbers.len());
+        (0..len)
+            .map(|i| {
+                let a = self.numbers.get(i).copied().unwrap_or(0);
+                let b = other.numbers.get(i).copied().unwrap_or(0);
+                a.cmp(&b)
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl Ord for LooseVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_numbers(other).then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+            (true, true) => Ordering::Equal,
+            // 预发布版本低于对应的正式版
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                for (a, b) in self.pre.iter().zip(&other.pre) {
+                    let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+                        (Ok(a), Ok(b)) => a.cmp(&b),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => a.to_lowercase().cmp(&b.to_lowercase()),
+                    };
+                    if ordering.is_ne() {
+                        return ordering;
+                    }
+                }
+                self.pre.len().cmp(&other.pre.len())
+            }
+        })
+    }
+}
+
+impl PartialOrd for LooseVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for LooseVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for LooseVersion {}
+
+/// 带通配符 (`1.20.x`) 的版本：返回 [下限, 上限)
+fn wildcard_bounds(version: &str) -> Option<(LooseVersion, LooseVersion)> {
+    let parts: Vec<&str> = version.split('.').collect();
+    let wildcard = parts.iter().position(|part| matches!(*part, "x" | "X" | "*"))?;
+    let fixed: Vec<u64> = parts[..wildcard].iter().map(|part| part.parse().ok()).collect::<Option<_>>()?;
+    let lower = LooseVersion {
+        numbers: fixed.clone(),
+        pre: Vec::new(),
+    };
+    let upper = match fixed.split_last() {
+        Some((last, rest)) => {
+            let mut numbers = rest.to_vec();
+            numbers.push(last.saturating_add(1));
+            LooseVersion { numbers, pre: Vec::new() }
+        }
+        // `x` / `*` 匹配任意版本
+        None => LooseVersion {
+            numbers: vec![u64::MAX],
+            pre: Vec::new(),
+        },
+    };
+    Some((lower, upper))
+}
+
+/// 单个 Fabric 谓词项，如 `>=1.2`、`~1.20`、`^2.0.0`、`1.20.x`
+fn matches_fabric_term(version: &LooseVersion, term: &str) -> Result<bool> {
+    if term.is_empty() || term == "*" {
+        return Ok(true);
+    }
+    let operators = [">=", "<=", ">", "<", "=", "~", "^"];
+    let (operator, target) = operators
+        .iter()
+        .find_map(|operator| term.strip_prefix(operator).map(|rest| (*operator, rest.trim())))
+        .unwrap_or(("=", term));
+    if target.is_empty() {
+        return Err(anyhow!("无效的版本谓词: {}", term));
+    }
+
+    if let Some((lower, upper)) = wildcard_bounds(target) {
+        return Ok(match operator {
+            "=" | "~" | "^" => *version >= lower && *version < upper,
+            ">=" => *version >= lower,
+            ">" => *version >= upper,
+            "<" => *version < lower,
+            "<=" => *version < upper,
+            _ => unreachable!(),
+        });
+    }
+
+    let target_version = LooseVersion::parse(target);
+    if target_version.numbers.is_empty() {
+        return Err(anyhow!("无效的版本谓词: {}", term));
+    }
+    Ok(match operator {
+        ">=" => *version >= target_version,
+        "<=" => *version <= target_version,
+        ">" => *version > target_version,
+        "<" => *version < target_version,
+        "=" => *version == target_version,
+        // ~1.2.3 := >=1.2.3 <1.3；~1 := >=1 <2
+        "~" => {
+            let keep = target_version.numbers.len().clamp(1, 2);
+            *version >= target_version && version.cmp_numbers(&bump(&target_version, keep)).is_lt()
+        }
+        // ^1.2.3 := >=1.2.3 <2
+        "^" => *version >= target_version && version.cmp_numbers(&bump(&target_version, 1)).is_lt(),
+        _ => unreachable!(),
+    })
+}
+
+/// 保留前 `keep` 个数字段并把最后一段加一，如 bump(1.2.3, 2) = 1.3
+fn bump(version: &LooseVersion, keep: usize) -> LooseVersion {
+    let mut numbers: Vec<u64> = version.numbers.iter().take(keep).copied().collect();
+    if let Some(last) = numbers.last_mut() {
+        *last = last.saturating_add(1);
+    }
+    LooseVersion { numbers, pre: Vec::new() }
+}
+
+/// Fabric `depends` 的版本谓词：数组内任一满足即可，每项内以空格分隔的条件需全部满足
+pub(crate) fn matches_fabric(version: &str, predicates: &[String]) -> Result<bool> {
+    if predicates.is_empty() {
+        return Ok(true);
+    }
+    let version = LooseVersion::parse(version);
+    for predicate in predicates {
+        let mut matched = true;
+        for term in predicate.split_whitespace() {
+            matched &= matches_fabric_term(&version, term)?;
+        }
+        if matched {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Maven 版本范围的一段，如 `[1.0,2.0)`
+struct MavenRestriction {
+    lower: Option<(LooseVersion, bool)>,
+    upper: Option<(LooseVersion, bool)>,
+}
+
+impl MavenRestriction {
+    fn contains(&self, version: &LooseVersion) -> bool {
+        let above = self.lower.as_ref().is_none_or(|(lower, inclusive)| {
+            if *inclusive {
+                version >= lower
+            } else {
+                version > lower
+            }
+        });
+        let below = self.upper.as_ref().is_none_or(|(upper, inclusive)| {
+            if *inclusive {
+                version <= upper
+            } else {
+                version < upper
+            }
+        });
+        above && below
+    }
+}
+
+fn parse_maven_range(spec: &str) -> Result<Vec<MavenRestriction>> {
+    let invalid = || anyhow!("无效的版本范围: {}", spec);
+    let mut restrictions = Vec::new();
+    let mut rest = spec.trim();
+    while !rest.is_empty() {
+        let lower_inclusive = match rest.chars().next() {
+            Some('[') => true,
+            Some('(') => false,
+            _ => return Err(invalid()),
+        };
+        let end = rest.find([']', ')']).ok_or_else(invalid)?;
+        let upper_inclusive = rest[end..].starts_with(']');
+        let inner = &rest[1..end];
+        let bound = |text: &str, inclusive: bool| {
+            let text = text.trim();
+            (!text.is_empty()).then(|| (LooseVersion::parse(text), inclusive))
+        };
+        let restriction = match inner.split_once(',') {
+            Some((lower, upper)) => MavenRestriction {
+                lower: bound(lower, lower_inclusive),
+                upper: bound(upper, upper_inclusive),
+            },
+            // [1.0] 表示精确版本
+            None if lower_inclusive && upper_inclusive && !inner.trim().is_empty() => MavenRestriction {
+                lower: bound(inner, true),
+                upper: bound(inner, true),
+            },
+            None => return Err(invalid()),
+        };
+        restrictions.push(restriction);
+        rest = rest[end + 1..].trim_start().trim_start_matches(',').trim_start();
+    }
+    Ok(restrictions)
+}
+
+/// Maven 版本范围 (Forge `versionRange`)，多段之间为或；不带括号的版本号只是推荐版本，任何版本都满足
+pub(crate) fn matches_maven(version: &str, spec: &str) -> Result<bool> {
+    let spec = spec.trim();
+    if spec.is_empty() || spec == "*" || !spec.starts_with(['[', '(']) {
+        return Ok(true);
+    }
+    let version = LooseVersion::parse(version);
+    Ok(parse_maven_range(spec)?
+        .iter()
+        .any(|restriction| restriction.contains(&version)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fabric(version: &str, predicates: &[&str]) -> bool {
+        let predicates: Vec<String> = predicates.iter().map(|predicate| predicate.to_string()).collect();
+        matches_fabric(version, &predicates).unwrap()
+    }
+
+    fn maven(version: &str, spec: &str) -> bool {
+        matches_maven(version, spec).unwrap()
+    }
+
+    #[test]
+    fn fabric_comparisons() {
+        assert!(fabric("0.15.3", &[">=0.14.21"]));
+        assert!(!fabric("0.14.9", &[">=0.14.21"]));
+        assert!(fabric("1.20.1", &[">=1.20 <1.21"]));
+        assert!(!fabric("1.21", &[">=1.20 <1.21"]));
+        assert!(fabric("1.20.1", &["1.20.1"]));
+        assert!(fabric("1.20.1", &["*"]));
+        assert!(fabric("1.20.1", &[]));
+        assert!(matches_fabric("1.20.1", &[">=".to_string()]).is_err());
+    }
+
+    #[test]
+    fn fabric_tilde_and_caret() {
+        assert!(fabric("1.2.9", &["~1.2.3"]));
+        assert!(!fabric("1.3.0", &["~1.2.3"]));
+        assert!(fabric("1.9", &["~1"]));
+        assert!(!fabric("2.0", &["~1"]));
+        assert!(fabric("1.9.0", &["^1.2.3"]));
+        assert!(!fabric("2.0.0", &["^1.2.3"]));
+        assert!(!fabric("1.2.2", &["^1.2.3"]));
+    }
+
+    #[test]
+    fn fabric_wildcards_and_pre_releases() {
+        assert!(fabric("1.20.4", &["1.20.x"]));
+        assert!(!fabric("1.21", &["1.20.x"]));
+        assert!(!fabric("1.19.4", &["1.20.x"]));
+        assert!(fabric("1.21", &[">1.20.x"]));
+        // `1.20-` 包含 1.20 的预发布版本
+        assert!(fabric("1.20-rc.1", &[">=1.20-"]));
+        assert!(!fabric("1.20-rc.1", &[">=1.20"]));
+        assert!(fabric("1.20", &[">=1.20-"]));
+        // 数字段为最大值时计算上限不会溢出
+        let max = u64::MAX.to_string();
+        for predicate in [format!("{}.x", max), format!("~{}", max), format!("^{}", max)] {
+            assert!(matches_fabric(&max, &[predicate]).is_ok());
+        }
+    }
+
+    #[test]
+    fn fabric_or_arrays() {
+        assert!(fabric("1.19.4", &["1.20.x", "1.19.x"]));
+        assert!(!fabric("1.18.2", &["1.20.x", "1.19.x"]));
+    }
+
+    #[test]
+    fn maven_ranges() {
+        assert!(maven("1.5", "[1,2)"));
+        assert!(maven("1", "[1,2)"));
+        assert!(!maven("2", "[1,2)"));
+        assert!(maven("0.9", "(,1.0]"));
+        assert!(maven("1.0", "(,1.0]"));
+        assert!(!maven("1.0.1", "(,1.0]"));
+        assert!(maven("1.0", "[1.0]"));
+        assert!(!maven("1.0.1", "[1.0]"));
+        assert!(maven("47.2.0", "[47,)"));
+    }
+
+    #[test]
+    fn maven_multiple_ranges_and_soft_versions() {
+        assert!(maven("0.5", "(,1.0],[1.2,)"));
+        assert!(maven("1.3", "(,1.0],[1.2,)"));
+        assert!(!maven("1.1", "(,1.0],[1.2,)"));
+        // 不带括号的版本只是推荐版本
+        assert!(maven("0.1", "1.0"));
+        assert!(maven("0.1", "*"));
+        assert!(matches_maven("1.0", "[1.0").is_err());
+        assert!(matches_maven("1.0", "(1.0)").is_err());
+    }
+}